@@ -0,0 +1,1061 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// generates the kernel constant files 'src/arch/{x86_64,aarch64,riscv64}/flags.rs'.
+//
+// the constants are listed in the function `groups()` with their values on
+// each architecture (in the order of x86_64, aarch64 and riscv64), most of
+// them come from 'include/uapi/asm-generic' and have the same value on all
+// architectures, and the architecture specific values come from
+// 'arch/{x86,arm64}/include/uapi/asm', see `references()`.
+//
+// usage:
+//
+// ```sh
+// $ cargo run --example gen_flags -- x86_64 > src/arch/x86_64/flags.rs
+// $ cargo run --example gen_flags -- aarch64 > src/arch/aarch64/flags.rs
+// $ cargo run --example gen_flags -- riscv64 > src/arch/riscv64/flags.rs
+// ```
+//
+// each group is rendered as a `bitflags!` or `value_enum!` invocation (see
+// 'src/macros.rs'), the constants which are absent on the architecture
+// are omitted.
+
+use std::{env, fmt::Write, process};
+
+const ARCHS: [&str; 3] = ["x86_64", "aarch64", "riscv64"];
+
+enum Kind {
+    BitFlags,
+    ValueEnum,
+}
+
+enum Item {
+    Blank,
+
+    // (name, values in the order of `ARCHS`, comments)
+    Constant(&'static str, [Option<&'static str>; 3], Vec<&'static str>),
+}
+
+impl Item {
+    fn with_comment(self, comment: &'static str) -> Self {
+        match self {
+            Item::Constant(name, values, mut comments) => {
+                comments.push(comment);
+                Item::Constant(name, values, comments)
+            }
+            Item::Blank => unreachable!(),
+        }
+    }
+}
+
+// the constant which has the same value on all architectures.
+fn constant(name: &'static str, value: &'static str) -> Item {
+    Item::Constant(name, [Some(value); 3], vec![])
+}
+
+// the constant which has different values on the architectures, `None` if
+// the architecture does not define it.
+fn per_arch(name: &'static str, values: [Option<&'static str>; 3]) -> Item {
+    Item::Constant(name, values, vec![])
+}
+
+struct Group {
+    kind: Kind,
+    name: &'static str,
+    repr: &'static str,
+    comments: &'static [&'static str],
+    items: Vec<Item>,
+
+    // the code follows the definition, e.g. the associated constants.
+    extra: Option<&'static str>,
+}
+
+impl Group {
+    fn new(
+        kind: Kind,
+        name: &'static str,
+        repr: &'static str,
+        comments: &'static [&'static str],
+        items: Vec<Item>,
+    ) -> Self {
+        Self {
+            kind,
+            name,
+            repr,
+            comments,
+            items,
+            extra: None,
+        }
+    }
+
+    fn with_extra(self, extra: &'static str) -> Self {
+        Self {
+            extra: Some(extra),
+            ..self
+        }
+    }
+}
+
+// (file, the architectures which use the file)
+fn references() -> Vec<(&'static str, &'static [&'static str])> {
+    vec![
+        ("include/uapi/asm-generic/fcntl.h", &ARCHS),
+        ("arch/arm64/include/uapi/asm/fcntl.h", &["aarch64"]),
+        ("include/uapi/linux/fcntl.h", &ARCHS),
+        ("include/uapi/asm-generic/mman-common.h", &ARCHS),
+        ("arch/x86/include/uapi/asm/mman.h", &["x86_64"]),
+        ("include/uapi/linux/mman.h", &ARCHS),
+        ("include/uapi/linux/memfd.h", &ARCHS),
+        ("include/uapi/linux/sched.h", &ARCHS),
+        ("include/uapi/asm-generic/signal.h", &ARCHS),
+        ("include/uapi/asm-generic/signal-defs.h", &ARCHS),
+        ("arch/x86/include/uapi/asm/signal.h", &["x86_64"]),
+        ("arch/arm64/include/uapi/asm/signal.h", &["aarch64"]),
+        ("include/uapi/linux/wait.h", &ARCHS),
+        ("include/uapi/linux/signalfd.h", &ARCHS),
+        ("include/uapi/linux/eventfd.h", &ARCHS),
+        ("include/uapi/linux/timerfd.h", &ARCHS),
+        ("include/uapi/linux/time.h", &ARCHS),
+        ("include/linux/socket.h", &ARCHS),
+        ("include/linux/net.h", &ARCHS),
+        ("include/uapi/asm-generic/socket.h", &ARCHS),
+        ("include/uapi/linux/in.h", &ARCHS),
+        ("include/uapi/linux/in6.h", &ARCHS),
+        ("include/uapi/linux/tcp.h", &ARCHS),
+        ("include/uapi/asm-generic/poll.h", &ARCHS),
+        ("include/uapi/linux/eventpoll.h", &ARCHS),
+        ("include/uapi/linux/fs.h", &ARCHS),
+        ("include/uapi/linux/io_uring.h", &ARCHS),
+    ]
+}
+
+// the associated constants which are not flags or variants.
+const AT_FLAGS_IMPL: &str = r#"impl AtFlags {
+    // the default synchronization of `statx` (i.e. does whatever `stat` does),
+    // it is 0, so it is not one of the flags and it is never printed by `Debug`.
+    pub const AT_STATX_SYNC_AS_STAT: AtFlags = AtFlags::empty();
+}"#;
+
+const SIGNAL_IMPL: &str = r#"impl Signal {
+    // the range of the real-time signals of the kernel, note that glibc
+    // reserves the first 2 or 3 real-time signals for the internal use.
+    pub const SIGRTMIN: i32 = 32;
+    pub const SIGRTMAX: i32 = 64;
+}"#;
+
+fn groups() -> Vec<Group> {
+    vec![
+        Group::new(
+            Kind::BitFlags,
+            "OpenFlags",
+            "u32",
+            &["the flags of `open`, `openat`, `pipe2` and `dup3` etc."],
+            vec![
+                constant("O_RDONLY", "0")
+                    .with_comment("the access modes, `O_RDONLY` is 0, so `contains(O_RDONLY)` is always true."),
+                constant("O_WRONLY", "0o1"),
+                constant("O_RDWR", "0o2"),
+                Item::Blank,
+                constant("O_CREAT", "0o100"),
+                constant("O_EXCL", "0o200"),
+                constant("O_NOCTTY", "0o400"),
+                constant("O_TRUNC", "0o1000"),
+                constant("O_APPEND", "0o2000"),
+                constant("O_NONBLOCK", "0o4000"),
+                Item::Blank,
+                constant("O_SYNC", "0o4010000")
+                    .with_comment("`O_SYNC` is `__O_SYNC | O_DSYNC`"),
+                constant("O_DSYNC", "0o10000"),
+                constant("FASYNC", "0o20000"),
+                Item::Blank,
+                per_arch("O_DIRECT", [Some("0o40000"), Some("0o200000"), Some("0o40000")]),
+                per_arch("O_LARGEFILE", [Some("0o100000"), Some("0o400000"), Some("0o100000")]),
+                Item::Blank,
+                per_arch("O_TMPFILE", [Some("0o20200000"), Some("0o20040000"), Some("0o20200000")])
+                    .with_comment("`O_TMPFILE` is `__O_TMPFILE | O_DIRECTORY`"),
+                per_arch("O_DIRECTORY", [Some("0o200000"), Some("0o40000"), Some("0o200000")]),
+                per_arch("O_NOFOLLOW", [Some("0o400000"), Some("0o100000"), Some("0o400000")]),
+                Item::Blank,
+                constant("O_NOATIME", "0o1000000"),
+                constant("O_CLOEXEC", "0o2000000"),
+                constant("O_PATH", "0o10000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "AtFlags",
+            "u32",
+            &["the flags of the `*at` syscalls, e.g. `newfstatat`, `unlinkat` and `statx`."],
+            vec![
+                constant("AT_SYMLINK_NOFOLLOW", "0x100"),
+                Item::Blank,
+                constant("AT_REMOVEDIR", "0x200")
+                    .with_comment("`AT_REMOVEDIR` (for `unlinkat`) and `AT_EACCESS` (for `faccessat2`)")
+                    .with_comment("have the same value."),
+                constant("AT_EACCESS", "0x200"),
+                Item::Blank,
+                constant("AT_SYMLINK_FOLLOW", "0x400"),
+                constant("AT_NO_AUTOMOUNT", "0x800"),
+                constant("AT_EMPTY_PATH", "0x1000"),
+                Item::Blank,
+                constant("AT_STATX_FORCE_SYNC", "0x2000")
+                    .with_comment("for `statx`, see also `AtFlags::AT_STATX_SYNC_AS_STAT`"),
+                constant("AT_STATX_DONT_SYNC", "0x4000"),
+                Item::Blank,
+                constant("AT_RECURSIVE", "0x8000"),
+            ],
+        )
+        .with_extra(AT_FLAGS_IMPL),
+        Group::new(
+            Kind::BitFlags,
+            "ProtFlags",
+            "u32",
+            &["the memory protection of `mmap` and `mprotect`."],
+            vec![
+                constant("PROT_NONE", "0"),
+                constant("PROT_READ", "0x1"),
+                constant("PROT_WRITE", "0x2"),
+                constant("PROT_EXEC", "0x4"),
+                constant("PROT_SEM", "0x8"),
+                constant("PROT_GROWSDOWN", "0x01000000"),
+                constant("PROT_GROWSUP", "0x02000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "MapFlags",
+            "u32",
+            &["the flags of `mmap`."],
+            vec![
+                constant("MAP_SHARED_VALIDATE", "0x03")
+                    .with_comment("the mapping types, `MAP_SHARED_VALIDATE` is `MAP_SHARED | MAP_PRIVATE`"),
+                constant("MAP_SHARED", "0x01"),
+                constant("MAP_PRIVATE", "0x02"),
+                Item::Blank,
+                constant("MAP_FIXED", "0x10"),
+                constant("MAP_ANONYMOUS", "0x20"),
+                Item::Blank,
+                per_arch("MAP_32BIT", [Some("0x40"), None, None])
+                    .with_comment("maps into the first 2GB of the address space"),
+                Item::Blank,
+                constant("MAP_GROWSDOWN", "0x0100"),
+                constant("MAP_DENYWRITE", "0x0800"),
+                constant("MAP_EXECUTABLE", "0x1000"),
+                constant("MAP_LOCKED", "0x2000"),
+                constant("MAP_NORESERVE", "0x4000"),
+                constant("MAP_POPULATE", "0x8000"),
+                constant("MAP_NONBLOCK", "0x10000"),
+                constant("MAP_STACK", "0x20000"),
+                constant("MAP_HUGETLB", "0x40000"),
+                constant("MAP_SYNC", "0x80000"),
+                constant("MAP_FIXED_NOREPLACE", "0x100000"),
+                constant("MAP_UNINITIALIZED", "0x4000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "MremapFlags",
+            "u32",
+            &["the flags of `mremap`."],
+            vec![
+                constant("MREMAP_MAYMOVE", "0x1"),
+                constant("MREMAP_FIXED", "0x2"),
+                constant("MREMAP_DONTUNMAP", "0x4"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "MlockFlags",
+            "u32",
+            &["the flags of `mlock2`."],
+            vec![
+                constant("MLOCK_ONFAULT", "0x1")
+                    .with_comment("locks the pages when they are faulted in, instead of populating them"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "Advice",
+            "u32",
+            &["the argument `advice` of `madvise`."],
+            vec![
+                constant("MADV_NORMAL", "0"),
+                constant("MADV_RANDOM", "1"),
+                constant("MADV_SEQUENTIAL", "2"),
+                constant("MADV_WILLNEED", "3"),
+                constant("MADV_DONTNEED", "4"),
+                constant("MADV_FREE", "8"),
+                constant("MADV_REMOVE", "9"),
+                constant("MADV_DONTFORK", "10"),
+                constant("MADV_DOFORK", "11"),
+                constant("MADV_MERGEABLE", "12"),
+                constant("MADV_UNMERGEABLE", "13"),
+                constant("MADV_HUGEPAGE", "14"),
+                constant("MADV_NOHUGEPAGE", "15"),
+                constant("MADV_DONTDUMP", "16"),
+                constant("MADV_DODUMP", "17"),
+                constant("MADV_WIPEONFORK", "18"),
+                constant("MADV_KEEPONFORK", "19"),
+                constant("MADV_COLD", "20"),
+                constant("MADV_PAGEOUT", "21"),
+                constant("MADV_POPULATE_READ", "22"),
+                constant("MADV_POPULATE_WRITE", "23"),
+                constant("MADV_DONTNEED_LOCKED", "24"),
+                constant("MADV_COLLAPSE", "25"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "MemfdFlags",
+            "u32",
+            &["the flags of `memfd_create`."],
+            vec![
+                constant("MFD_CLOEXEC", "0x1"),
+                constant("MFD_ALLOW_SEALING", "0x2"),
+                constant("MFD_HUGETLB", "0x4"),
+                constant("MFD_NOEXEC_SEAL", "0x8"),
+                constant("MFD_EXEC", "0x10"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "CloneFlags",
+            "u64",
+            &[
+                "the flags of `clone`, `clone3` and `unshare`.",
+                "",
+                "the lowest byte of the flags of `clone` is the signal sent to the parent",
+                "when the child exits (e.g. `SIGCHLD`), it is printed in hex by `Debug`.",
+            ],
+            vec![
+                constant("CLONE_NEWTIME", "0x80"),
+                constant("CLONE_VM", "0x100"),
+                constant("CLONE_FS", "0x200"),
+                constant("CLONE_FILES", "0x400"),
+                constant("CLONE_SIGHAND", "0x800"),
+                constant("CLONE_PIDFD", "0x1000"),
+                constant("CLONE_PTRACE", "0x2000"),
+                constant("CLONE_VFORK", "0x4000"),
+                constant("CLONE_PARENT", "0x8000"),
+                constant("CLONE_THREAD", "0x10000"),
+                constant("CLONE_NEWNS", "0x20000"),
+                constant("CLONE_SYSVSEM", "0x40000"),
+                constant("CLONE_SETTLS", "0x80000"),
+                constant("CLONE_PARENT_SETTID", "0x100000"),
+                constant("CLONE_CHILD_CLEARTID", "0x200000"),
+                constant("CLONE_DETACHED", "0x400000"),
+                constant("CLONE_UNTRACED", "0x800000"),
+                constant("CLONE_CHILD_SETTID", "0x01000000"),
+                constant("CLONE_NEWCGROUP", "0x02000000"),
+                constant("CLONE_NEWUTS", "0x04000000"),
+                constant("CLONE_NEWIPC", "0x08000000"),
+                constant("CLONE_NEWUSER", "0x10000000"),
+                constant("CLONE_NEWPID", "0x20000000"),
+                constant("CLONE_NEWNET", "0x40000000"),
+                constant("CLONE_IO", "0x80000000"),
+                Item::Blank,
+                constant("CLONE_CLEAR_SIGHAND", "0x100000000")
+                    .with_comment("the following flags are only available for `clone3`"),
+                constant("CLONE_INTO_CGROUP", "0x200000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "WaitOptions",
+            "u32",
+            &["the options of `wait4` and `waitid`."],
+            vec![
+                constant("WNOHANG", "0x1"),
+                Item::Blank,
+                constant("WUNTRACED", "0x2")
+                    .with_comment("`WUNTRACED` (for `wait4`) and `WSTOPPED` (for `waitid`) have the same value."),
+                constant("WSTOPPED", "0x2"),
+                Item::Blank,
+                constant("WEXITED", "0x4"),
+                constant("WCONTINUED", "0x8"),
+                constant("WNOWAIT", "0x01000000"),
+                Item::Blank,
+                constant("__WNOTHREAD", "0x20000000"),
+                constant("__WALL", "0x40000000"),
+                constant("__WCLONE", "0x80000000"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "Signal",
+            "i32",
+            &[
+                "the standard signals, the real-time signals (`SIGRTMIN..=SIGRTMAX`)",
+                "are not listed since they are only numbers.",
+            ],
+            vec![
+                constant("SIGHUP", "1"),
+                constant("SIGINT", "2"),
+                constant("SIGQUIT", "3"),
+                constant("SIGILL", "4"),
+                constant("SIGTRAP", "5"),
+                constant("SIGABRT", "6"),
+                constant("SIGBUS", "7"),
+                constant("SIGFPE", "8"),
+                constant("SIGKILL", "9"),
+                constant("SIGUSR1", "10"),
+                constant("SIGSEGV", "11"),
+                constant("SIGUSR2", "12"),
+                constant("SIGPIPE", "13"),
+                constant("SIGALRM", "14"),
+                constant("SIGTERM", "15"),
+                constant("SIGSTKFLT", "16"),
+                constant("SIGCHLD", "17"),
+                constant("SIGCONT", "18"),
+                constant("SIGSTOP", "19"),
+                constant("SIGTSTP", "20"),
+                constant("SIGTTIN", "21"),
+                constant("SIGTTOU", "22"),
+                constant("SIGURG", "23"),
+                constant("SIGXCPU", "24"),
+                constant("SIGXFSZ", "25"),
+                constant("SIGVTALRM", "26"),
+                constant("SIGPROF", "27"),
+                constant("SIGWINCH", "28"),
+                constant("SIGIO", "29"),
+                constant("SIGPWR", "30"),
+                constant("SIGSYS", "31"),
+            ],
+        )
+        .with_extra(SIGNAL_IMPL),
+        Group::new(
+            Kind::BitFlags,
+            "SigactionFlags",
+            "u64",
+            &["the flags of `rt_sigaction`."],
+            vec![
+                constant("SA_NOCLDSTOP", "0x00000001"),
+                constant("SA_NOCLDWAIT", "0x00000002"),
+                constant("SA_SIGINFO", "0x00000004"),
+                constant("SA_UNSUPPORTED", "0x00000400"),
+                constant("SA_EXPOSE_TAGBITS", "0x00000800"),
+                per_arch("SA_RESTORER", [Some("0x04000000"), Some("0x04000000"), None])
+                    .with_comment("the obsolete flag which is not defined by the new architectures (e.g. riscv64)"),
+                constant("SA_ONSTACK", "0x08000000"),
+                constant("SA_RESTART", "0x10000000"),
+                constant("SA_NODEFER", "0x40000000"),
+                constant("SA_RESETHAND", "0x80000000"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "SigmaskHow",
+            "u32",
+            &["the argument `how` of `rt_sigprocmask`."],
+            vec![
+                constant("SIG_BLOCK", "0"),
+                constant("SIG_UNBLOCK", "1"),
+                constant("SIG_SETMASK", "2"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "SigaltstackFlags",
+            "u32",
+            &["the flags of `sigaltstack`."],
+            vec![
+                constant("SS_ONSTACK", "1"),
+                constant("SS_DISABLE", "2"),
+                constant("SS_AUTODISARM", "1 << 31"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "AddressFamily",
+            "u16",
+            &["the address families (domains) of `socket`."],
+            vec![
+                constant("AF_UNSPEC", "0"),
+                constant("AF_UNIX", "1"),
+                constant("AF_INET", "2"),
+                constant("AF_INET6", "10"),
+                constant("AF_NETLINK", "16"),
+                constant("AF_PACKET", "17"),
+                constant("AF_ALG", "38"),
+                constant("AF_VSOCK", "40"),
+                constant("AF_XDP", "44"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "SocketType",
+            "u32",
+            &[
+                "the socket types of `socket`, the type can be combined with",
+                "`SocketFlags` in the argument `type` of `socket` and `socketpair`.",
+            ],
+            vec![
+                constant("SOCK_STREAM", "1"),
+                constant("SOCK_DGRAM", "2"),
+                constant("SOCK_RAW", "3"),
+                constant("SOCK_RDM", "4"),
+                constant("SOCK_SEQPACKET", "5"),
+                constant("SOCK_DCCP", "6"),
+                constant("SOCK_PACKET", "10"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "SocketFlags",
+            "u32",
+            &["the flags of `socket`, `socketpair` and `accept4`."],
+            vec![
+                constant("SOCK_NONBLOCK", "0o4000"),
+                constant("SOCK_CLOEXEC", "0o2000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "MsgFlags",
+            "u32",
+            &["the flags of `sendto`, `recvfrom`, `sendmsg` and `recvmsg`."],
+            vec![
+                constant("MSG_OOB", "0x1"),
+                constant("MSG_PEEK", "0x2")
+                    .with_comment("receives the data without removing it from the queue"),
+                constant("MSG_DONTROUTE", "0x4"),
+                constant("MSG_CTRUNC", "0x8"),
+                constant("MSG_TRUNC", "0x20"),
+                constant("MSG_DONTWAIT", "0x40"),
+                constant("MSG_EOR", "0x80"),
+                constant("MSG_WAITALL", "0x100"),
+                constant("MSG_ERRQUEUE", "0x2000"),
+                constant("MSG_NOSIGNAL", "0x4000")
+                    .with_comment("does not raise `SIGPIPE` when the peer has closed the connection"),
+                constant("MSG_MORE", "0x8000"),
+                constant("MSG_CMSG_CLOEXEC", "0x40000000"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "ShutdownHow",
+            "u32",
+            &["the argument `how` of `shutdown`."],
+            vec![
+                constant("SHUT_RD", "0"),
+                constant("SHUT_WR", "1"),
+                constant("SHUT_RDWR", "2"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "SocketOption",
+            "i32",
+            &[
+                "the options of the level `SOL_SOCKET`.",
+                "",
+                "`SO_RCVTIMEO` and `SO_SNDTIMEO` are the `*_OLD` values, which take",
+                "`struct timeval` of 64-bit `time_t` on the 64-bit architectures.",
+            ],
+            vec![
+                constant("SO_DEBUG", "1"),
+                constant("SO_REUSEADDR", "2"),
+                constant("SO_TYPE", "3"),
+                constant("SO_ERROR", "4"),
+                constant("SO_DONTROUTE", "5"),
+                constant("SO_BROADCAST", "6"),
+                constant("SO_SNDBUF", "7"),
+                constant("SO_RCVBUF", "8"),
+                constant("SO_KEEPALIVE", "9"),
+                constant("SO_OOBINLINE", "10"),
+                constant("SO_LINGER", "13"),
+                constant("SO_REUSEPORT", "15"),
+                constant("SO_PASSCRED", "16"),
+                constant("SO_PEERCRED", "17"),
+                constant("SO_RCVLOWAT", "18"),
+                constant("SO_SNDLOWAT", "19"),
+                constant("SO_RCVTIMEO", "20"),
+                constant("SO_SNDTIMEO", "21"),
+                constant("SO_ACCEPTCONN", "30"),
+                constant("SO_PROTOCOL", "38"),
+                constant("SO_DOMAIN", "39"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "TcpOption",
+            "i32",
+            &["the options of the level `IPPROTO_TCP`."],
+            vec![
+                constant("TCP_NODELAY", "1"),
+                constant("TCP_MAXSEG", "2"),
+                constant("TCP_CORK", "3"),
+                constant("TCP_KEEPIDLE", "4"),
+                constant("TCP_KEEPINTVL", "5"),
+                constant("TCP_KEEPCNT", "6"),
+                constant("TCP_QUICKACK", "12"),
+                constant("TCP_USER_TIMEOUT", "18"),
+                constant("TCP_FASTOPEN", "23"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "IpOption",
+            "i32",
+            &["the options of the level `IPPROTO_IP`."],
+            vec![
+                constant("IP_TOS", "1"),
+                constant("IP_TTL", "2"),
+                constant("IP_MULTICAST_TTL", "33"),
+                constant("IP_MULTICAST_LOOP", "34"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "Ipv6Option",
+            "i32",
+            &["the options of the level `IPPROTO_IPV6`."],
+            vec![
+                constant("IPV6_UNICAST_HOPS", "16"),
+                constant("IPV6_MULTICAST_HOPS", "18"),
+                constant("IPV6_MULTICAST_LOOP", "19"),
+                constant("IPV6_V6ONLY", "26"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "PollEvents",
+            "i16",
+            &["the events of `poll` and `ppoll`, i.e. the type of the field `short events`."],
+            vec![
+                constant("POLLIN", "0x0001"),
+                constant("POLLPRI", "0x0002"),
+                constant("POLLOUT", "0x0004"),
+                constant("POLLERR", "0x0008"),
+                constant("POLLHUP", "0x0010"),
+                constant("POLLNVAL", "0x0020"),
+                constant("POLLRDNORM", "0x0040"),
+                constant("POLLRDBAND", "0x0080"),
+                constant("POLLWRNORM", "0x0100"),
+                constant("POLLWRBAND", "0x0200"),
+                constant("POLLMSG", "0x0400"),
+                constant("POLLREMOVE", "0x1000"),
+                constant("POLLRDHUP", "0x2000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "EpollFlags",
+            "u32",
+            &["the flags of `epoll_create1`, the value is the same as `O_CLOEXEC`."],
+            vec![
+                constant("EPOLL_CLOEXEC", "0o2000000"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "EpollOp",
+            "u32",
+            &["the operations of `epoll_ctl`."],
+            vec![
+                constant("EPOLL_CTL_ADD", "1"),
+                constant("EPOLL_CTL_DEL", "2"),
+                constant("EPOLL_CTL_MOD", "3"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "EpollEvents",
+            "u32",
+            &["the events of `epoll_ctl` and `epoll_wait`."],
+            vec![
+                constant("EPOLLIN", "0x00000001"),
+                constant("EPOLLPRI", "0x00000002"),
+                constant("EPOLLOUT", "0x00000004"),
+                constant("EPOLLERR", "0x00000008"),
+                constant("EPOLLHUP", "0x00000010"),
+                constant("EPOLLNVAL", "0x00000020"),
+                constant("EPOLLRDNORM", "0x00000040"),
+                constant("EPOLLRDBAND", "0x00000080"),
+                constant("EPOLLWRNORM", "0x00000100"),
+                constant("EPOLLWRBAND", "0x00000200"),
+                constant("EPOLLMSG", "0x00000400"),
+                constant("EPOLLRDHUP", "0x00002000"),
+                constant("EPOLLEXCLUSIVE", "1 << 28"),
+                constant("EPOLLWAKEUP", "1 << 29"),
+                constant("EPOLLONESHOT", "1 << 30"),
+                constant("EPOLLET", "1 << 31"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "SfdFlags",
+            "u32",
+            &["the flags of `signalfd4`, the values are the same as `O_NONBLOCK` and `O_CLOEXEC`."],
+            vec![
+                constant("SFD_NONBLOCK", "0o4000"),
+                constant("SFD_CLOEXEC", "0o2000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "EfdFlags",
+            "u32",
+            &["the flags of `eventfd2`."],
+            vec![
+                constant("EFD_SEMAPHORE", "0x1")
+                    .with_comment("reads 1 at a time instead of the whole counter"),
+                constant("EFD_NONBLOCK", "0o4000"),
+                constant("EFD_CLOEXEC", "0o2000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "TfdFlags",
+            "u32",
+            &["the flags of `timerfd_create`."],
+            vec![
+                constant("TFD_NONBLOCK", "0o4000"),
+                constant("TFD_CLOEXEC", "0o2000000"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "TfdTimerFlags",
+            "u32",
+            &["the flags of `timerfd_settime`."],
+            vec![
+                constant("TFD_TIMER_ABSTIME", "0x1"),
+                constant("TFD_TIMER_CANCEL_ON_SET", "0x2")
+                    .with_comment("for `CLOCK_REALTIME`, the timer is canceled if the clock is changed"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "ClockId",
+            "i32",
+            &["the clocks of `clock_gettime`, `timerfd_create` and so on."],
+            vec![
+                constant("CLOCK_REALTIME", "0"),
+                constant("CLOCK_MONOTONIC", "1"),
+                constant("CLOCK_PROCESS_CPUTIME_ID", "2"),
+                constant("CLOCK_THREAD_CPUTIME_ID", "3"),
+                constant("CLOCK_MONOTONIC_RAW", "4"),
+                constant("CLOCK_REALTIME_COARSE", "5"),
+                constant("CLOCK_MONOTONIC_COARSE", "6"),
+                constant("CLOCK_BOOTTIME", "7"),
+                constant("CLOCK_REALTIME_ALARM", "8"),
+                constant("CLOCK_BOOTTIME_ALARM", "9"),
+                constant("CLOCK_TAI", "11"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "IoUringSetupFlags",
+            "u32",
+            &["the flags of `io_uring_setup`."],
+            vec![
+                constant("IORING_SETUP_IOPOLL", "1 << 0")
+                    .with_comment("busy-polling for the completions (for `O_DIRECT` files only)"),
+                constant("IORING_SETUP_SQPOLL", "1 << 1")
+                    .with_comment("the submission queue is polled by a kernel thread"),
+                constant("IORING_SETUP_SQ_AFF", "1 << 2"),
+                constant("IORING_SETUP_CQSIZE", "1 << 3")
+                    .with_comment("the size of the completion queue is `io_uring_params.cq_entries`"),
+                constant("IORING_SETUP_CLAMP", "1 << 4"),
+                constant("IORING_SETUP_ATTACH_WQ", "1 << 5"),
+                constant("IORING_SETUP_R_DISABLED", "1 << 6"),
+                constant("IORING_SETUP_SUBMIT_ALL", "1 << 7"),
+                constant("IORING_SETUP_COOP_TASKRUN", "1 << 8"),
+                constant("IORING_SETUP_TASKRUN_FLAG", "1 << 9"),
+                constant("IORING_SETUP_SQE128", "1 << 10"),
+                constant("IORING_SETUP_CQE32", "1 << 11"),
+                constant("IORING_SETUP_SINGLE_ISSUER", "1 << 12"),
+                constant("IORING_SETUP_DEFER_TASKRUN", "1 << 13"),
+                constant("IORING_SETUP_NO_MMAP", "1 << 14"),
+                constant("IORING_SETUP_REGISTERED_FD_ONLY", "1 << 15"),
+                constant("IORING_SETUP_NO_SQARRAY", "1 << 16"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "IoUringFeatures",
+            "u32",
+            &["the features of io_uring, i.e. `io_uring_params.features`."],
+            vec![
+                constant("IORING_FEAT_SINGLE_MMAP", "1 << 0")
+                    .with_comment("the submission and completion queue rings share one mapping"),
+                constant("IORING_FEAT_NODROP", "1 << 1"),
+                constant("IORING_FEAT_SUBMIT_STABLE", "1 << 2"),
+                constant("IORING_FEAT_RW_CUR_POS", "1 << 3"),
+                constant("IORING_FEAT_CUR_PERSONALITY", "1 << 4"),
+                constant("IORING_FEAT_FAST_POLL", "1 << 5"),
+                constant("IORING_FEAT_POLL_32BITS", "1 << 6"),
+                constant("IORING_FEAT_SQPOLL_NONFIXED", "1 << 7"),
+                constant("IORING_FEAT_EXT_ARG", "1 << 8"),
+                constant("IORING_FEAT_NATIVE_WORKERS", "1 << 9"),
+                constant("IORING_FEAT_RSRC_TAGS", "1 << 10"),
+                constant("IORING_FEAT_CQE_SKIP", "1 << 11"),
+                constant("IORING_FEAT_LINKED_FILE", "1 << 12"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "IoUringEnterFlags",
+            "u32",
+            &["the flags of `io_uring_enter`."],
+            vec![
+                constant("IORING_ENTER_GETEVENTS", "1 << 0")
+                    .with_comment("waits for `min_complete` completions"),
+                constant("IORING_ENTER_SQ_WAKEUP", "1 << 1"),
+                constant("IORING_ENTER_SQ_WAIT", "1 << 2"),
+                constant("IORING_ENTER_EXT_ARG", "1 << 3"),
+                constant("IORING_ENTER_REGISTERED_RING", "1 << 4"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "SqeFlags",
+            "u8",
+            &["the flags of the submission queue entry, i.e. `io_uring_sqe.flags`."],
+            vec![
+                constant("IOSQE_FIXED_FILE", "1 << 0")
+                    .with_comment("`io_uring_sqe.fd` is the index of the registered files"),
+                constant("IOSQE_IO_DRAIN", "1 << 1")
+                    .with_comment("starts after all previous entries are completed"),
+                constant("IOSQE_IO_LINK", "1 << 2")
+                    .with_comment("the next entry starts after this entry is completed successfully"),
+                constant("IOSQE_IO_HARDLINK", "1 << 3")
+                    .with_comment("same as `IOSQE_IO_LINK`, but the chain is not broken by the failure"),
+                constant("IOSQE_ASYNC", "1 << 4"),
+                constant("IOSQE_BUFFER_SELECT", "1 << 5"),
+                constant("IOSQE_CQE_SKIP_SUCCESS", "1 << 6"),
+            ],
+        ),
+        Group::new(
+            Kind::BitFlags,
+            "TimeoutFlags",
+            "u32",
+            &["the flags of the timeout entry."],
+            vec![
+                constant("IORING_TIMEOUT_ABS", "1 << 0"),
+                constant("IORING_TIMEOUT_UPDATE", "1 << 1"),
+                constant("IORING_TIMEOUT_BOOTTIME", "1 << 2"),
+                constant("IORING_TIMEOUT_REALTIME", "1 << 3"),
+                constant("IORING_LINK_TIMEOUT_UPDATE", "1 << 4"),
+                constant("IORING_TIMEOUT_ETIME_SUCCESS", "1 << 5"),
+                constant("IORING_TIMEOUT_MULTISHOT", "1 << 6"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "IoUringOp",
+            "u8",
+            &["the operations of the submission queue entry, i.e. `io_uring_sqe.opcode`."],
+            vec![
+                constant("IORING_OP_NOP", "0"),
+                constant("IORING_OP_READV", "1"),
+                constant("IORING_OP_WRITEV", "2"),
+                constant("IORING_OP_FSYNC", "3"),
+                constant("IORING_OP_READ_FIXED", "4"),
+                constant("IORING_OP_WRITE_FIXED", "5"),
+                constant("IORING_OP_POLL_ADD", "6"),
+                constant("IORING_OP_POLL_REMOVE", "7"),
+                constant("IORING_OP_SYNC_FILE_RANGE", "8"),
+                constant("IORING_OP_SENDMSG", "9"),
+                constant("IORING_OP_RECVMSG", "10"),
+                constant("IORING_OP_TIMEOUT", "11"),
+                constant("IORING_OP_TIMEOUT_REMOVE", "12"),
+                constant("IORING_OP_ACCEPT", "13"),
+                constant("IORING_OP_ASYNC_CANCEL", "14"),
+                constant("IORING_OP_LINK_TIMEOUT", "15"),
+                constant("IORING_OP_CONNECT", "16"),
+                constant("IORING_OP_FALLOCATE", "17"),
+                constant("IORING_OP_OPENAT", "18"),
+                constant("IORING_OP_CLOSE", "19"),
+                constant("IORING_OP_FILES_UPDATE", "20"),
+                constant("IORING_OP_STATX", "21"),
+                constant("IORING_OP_READ", "22"),
+                constant("IORING_OP_WRITE", "23"),
+                constant("IORING_OP_FADVISE", "24"),
+                constant("IORING_OP_MADVISE", "25"),
+                constant("IORING_OP_SEND", "26"),
+                constant("IORING_OP_RECV", "27"),
+                constant("IORING_OP_OPENAT2", "28"),
+                constant("IORING_OP_EPOLL_CTL", "29"),
+                constant("IORING_OP_SPLICE", "30"),
+                constant("IORING_OP_PROVIDE_BUFFERS", "31"),
+                constant("IORING_OP_REMOVE_BUFFERS", "32"),
+                constant("IORING_OP_TEE", "33"),
+                constant("IORING_OP_SHUTDOWN", "34"),
+                constant("IORING_OP_RENAMEAT", "35"),
+                constant("IORING_OP_UNLINKAT", "36"),
+                constant("IORING_OP_MKDIRAT", "37"),
+                constant("IORING_OP_SYMLINKAT", "38"),
+                constant("IORING_OP_LINKAT", "39"),
+                constant("IORING_OP_MSG_RING", "40"),
+                constant("IORING_OP_FSETXATTR", "41"),
+                constant("IORING_OP_SETXATTR", "42"),
+                constant("IORING_OP_FGETXATTR", "43"),
+                constant("IORING_OP_GETXATTR", "44"),
+                constant("IORING_OP_SOCKET", "45"),
+                constant("IORING_OP_URING_CMD", "46"),
+                constant("IORING_OP_SEND_ZC", "47"),
+                constant("IORING_OP_SENDMSG_ZC", "48"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "IoUringRegisterOp",
+            "u32",
+            &["the operations of `io_uring_register`."],
+            vec![
+                constant("IORING_REGISTER_BUFFERS", "0"),
+                constant("IORING_UNREGISTER_BUFFERS", "1"),
+                constant("IORING_REGISTER_FILES", "2"),
+                constant("IORING_UNREGISTER_FILES", "3"),
+                constant("IORING_REGISTER_EVENTFD", "4"),
+                constant("IORING_UNREGISTER_EVENTFD", "5"),
+                constant("IORING_REGISTER_FILES_UPDATE", "6"),
+                constant("IORING_REGISTER_EVENTFD_ASYNC", "7"),
+                constant("IORING_REGISTER_PROBE", "8"),
+                constant("IORING_REGISTER_PERSONALITY", "9"),
+                constant("IORING_UNREGISTER_PERSONALITY", "10"),
+                constant("IORING_REGISTER_RESTRICTIONS", "11"),
+                constant("IORING_REGISTER_ENABLE_RINGS", "12"),
+            ],
+        ),
+        Group::new(
+            Kind::ValueEnum,
+            "Whence",
+            "u32",
+            &["the argument `whence` of `lseek`."],
+            vec![
+                constant("SEEK_SET", "0"),
+                constant("SEEK_CUR", "1"),
+                constant("SEEK_END", "2"),
+                constant("SEEK_DATA", "3"),
+                constant("SEEK_HOLE", "4"),
+            ],
+        ),
+
+    ]
+}
+
+fn render_header(out: &mut String, arch: &str) {
+    let references = references()
+        .into_iter()
+        .filter(|(_, archs)| archs.contains(&arch))
+        .map(|(file, _)| format!("// - '{}'", file))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    writeln!(
+        out,
+        r#"// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// THIS FILE IS GENERATED BY 'examples/gen_flags.rs', DO NOT EDIT.
+//
+// the kernel constants of {arch}, see 'src/flags.rs'.
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_flags -- {arch} > src/arch/{arch}/flags.rs
+// ```
+//
+// ref:
+{references}"#
+    )
+    .unwrap();
+}
+
+fn render_group(out: &mut String, group: &Group, arch_index: usize) {
+    writeln!(out).unwrap();
+    for comment in group.comments {
+        if comment.is_empty() {
+            writeln!(out, "//").unwrap();
+        } else {
+            writeln!(out, "// {}", comment).unwrap();
+        }
+    }
+
+    let mut lines: Vec<String> = vec![];
+    for item in &group.items {
+        match item {
+            Item::Blank => {
+                // no consecutive blank lines when the constants between
+                // them are omitted
+                if lines.last().is_some_and(|line| !line.is_empty()) {
+                    lines.push(String::new());
+                }
+            }
+            Item::Constant(name, values, comments) => {
+                let Some(value) = values[arch_index] else {
+                    continue;
+                };
+
+                for comment in comments {
+                    lines.push(format!("        // {}", comment));
+                }
+
+                match group.kind {
+                    Kind::BitFlags => lines.push(format!("        const {} = {};", name, value)),
+                    Kind::ValueEnum => lines.push(format!("        {} = {},", name, value)),
+                }
+            }
+        }
+    }
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let (macro_name, keyword) = match group.kind {
+        Kind::BitFlags => ("bitflags", "struct"),
+        Kind::ValueEnum => ("value_enum", "enum"),
+    };
+
+    writeln!(out, "{}! {{", macro_name).unwrap();
+    writeln!(out, "    pub {} {}: {} {{", keyword, group.name, group.repr).unwrap();
+    for line in lines {
+        writeln!(out, "{}", line).unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    if let Some(extra) = group.extra {
+        writeln!(out).unwrap();
+        writeln!(out, "{}", extra).unwrap();
+    }
+}
+
+fn render(arch: &str) -> String {
+    let arch_index = ARCHS.iter().position(|item| *item == arch).unwrap();
+
+    let mut out = String::new();
+    render_header(&mut out, arch);
+    for group in groups() {
+        render_group(&mut out, &group, arch_index);
+    }
+
+    out
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    if args.len() != 2 {
+        eprintln!("usage: gen_flags <x86_64|aarch64|riscv64>");
+        process::exit(1);
+    }
+
+    if !ARCHS.contains(&args[1].as_str()) {
+        eprintln!("unsupported arch: \"{}\"", args[1]);
+        process::exit(1);
+    }
+
+    print!("{}", render(&args[1]));
+}
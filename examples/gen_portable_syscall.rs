@@ -0,0 +1,155 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// generates 'src/portable/table.rs' from the syscall number tables of
+// x86_64, aarch64 and riscv64 (i.e. the enums `SysCallNum` in
+// 'src/arch/{x86_64,aarch64,riscv64}/number.rs').
+//
+// the table contains the union of the syscall names (in the order of
+// x86_64, and then the syscalls which are only available on aarch64 and
+// riscv64), and the syscall number of each architecture, so that
+// `PortableSysCall::number_for()` is a direct `match` instead of looking up
+// the name in the enum of the architecture. the reverse mapping (i.e.
+// `PortableSysCall::from_number_for()`) is generated as well.
+//
+// usage:
+//
+// ```sh
+// $ cargo run --example gen_portable_syscall > src/portable/table.rs
+// ```
+//
+// run it again after updating any of the syscall number tables.
+
+use std::{fmt::Write, str::FromStr};
+
+use syscall_util::arch::{aarch64, riscv64, x86_64};
+
+// the identifiers of the variants (e.g. "sysctl_", i.e. the output of `Debug`)
+// and the names in the kernel syscall tables (e.g. "_sysctl"), they are the
+// same for most syscalls.
+fn names() -> Vec<(String, &'static str)> {
+    let mut names: Vec<(String, &'static str)> = vec![];
+
+    let all_names = x86_64::number::SysCallNum::iter()
+        .map(|num| (format!("{:?}", num), num.name()))
+        .chain(aarch64::number::SysCallNum::iter().map(|num| (format!("{:?}", num), num.name())))
+        .chain(riscv64::number::SysCallNum::iter().map(|num| (format!("{:?}", num), num.name())));
+
+    for item in all_names {
+        if !names.contains(&item) {
+            names.push(item);
+        }
+    }
+
+    names
+}
+
+fn render_number(number: Option<usize>) -> String {
+    match number {
+        Some(number) => format!("Some({})", number),
+        None => "None".to_owned(),
+    }
+}
+
+fn render_table() -> String {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        r#"// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// THIS FILE IS GENERATED BY 'examples/gen_portable_syscall.rs', DO NOT EDIT.
+//
+// the union of the syscall names of x86_64, aarch64 and riscv64, and the
+// syscall numbers in the order of `[x86_64, aarch64, riscv64]` (i.e. the
+// order of `Arch::ALL`).
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_portable_syscall > src/portable/table.rs
+// ```
+//
+// the macro `portable_syscall!` generates the enum `PortableSysCall` as well
+// as the functions for looking up syscall numbers, and the macro
+// `portable_syscall_from_number!` generates the reverse lookup, see
+// 'src/portable.rs'.
+
+portable_syscall! {{"#
+    )
+    .unwrap();
+
+    for (identifier, name) in names() {
+        if identifier != name {
+            writeln!(out, "    #[name = \"{}\"]", name).unwrap();
+        }
+
+        let numbers = [
+            x86_64::number::SysCallNum::from_str(name)
+                .ok()
+                .map(|num| num as usize),
+            aarch64::number::SysCallNum::from_str(name)
+                .ok()
+                .map(|num| num as usize),
+            riscv64::number::SysCallNum::from_str(name)
+                .ok()
+                .map(|num| num as usize),
+        ];
+
+        writeln!(
+            out,
+            "    {} = [{}],",
+            identifier,
+            numbers
+                .into_iter()
+                .map(render_number)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+
+    writeln!(out).unwrap();
+    writeln!(out, "portable_syscall_from_number! {{").unwrap();
+    render_numbers(
+        &mut out,
+        "X86_64",
+        x86_64::number::SysCallNum::iter().map(|num| (num as usize, format!("{:?}", num))),
+    );
+    render_numbers(
+        &mut out,
+        "Aarch64",
+        aarch64::number::SysCallNum::iter().map(|num| (num as usize, format!("{:?}", num))),
+    );
+    render_numbers(
+        &mut out,
+        "Riscv64",
+        riscv64::number::SysCallNum::iter().map(|num| (num as usize, format!("{:?}", num))),
+    );
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+// the syscall numbers of an architecture (the variant name of `Arch`) and
+// the identifiers of their portable syscalls.
+fn render_numbers(out: &mut String, arch: &str, numbers: impl Iterator<Item = (usize, String)>) {
+    writeln!(out, "    {} {{", arch).unwrap();
+    for (number, identifier) in numbers {
+        writeln!(out, "        {} => {},", number, identifier).unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+}
+
+fn main() {
+    print!("{}", render_table());
+}
@@ -0,0 +1,592 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// generates the syscall number files from the kernel syscall tables:
+//
+// - 'src/arch/x86_64/number.rs' from 'arch/x86/entry/syscalls/syscall_64.tbl',
+//   the enum `SysCallNum` contains the syscalls of the ABI "common" and "64",
+//   and the enum `X32SysCallNum` contains the syscalls of the ABI "common"
+//   and "x32".
+// - 'src/arch/x86/number.rs' from 'arch/x86/entry/syscalls/syscall_32.tbl',
+//   the enum `SysCallNum` contains the syscalls of the ABI "i386".
+//
+// usage:
+//
+// ```sh
+// $ cargo run --example gen_syscall_num -- x86_64 src/arch/x86_64/syscall_64.tbl 6.14 \
+//       > src/arch/x86_64/number.rs
+// $ cargo run --example gen_syscall_num -- x86 src/arch/x86/syscall_32.tbl 6.14 \
+//       > src/arch/x86/number.rs
+// ```
+//
+// to update to a new kernel, copy the new '.tbl' files to
+// 'src/arch/{x86_64,x86}/' and run the commands above.
+//
+// the format of the '.tbl' file is:
+//
+// ```text
+// <number> <abi> <name> [<entry point> [<compat entry point> [noreturn]]]
+// ```
+//
+// - the fields are separated by tabs (or spaces).
+// - the lines starting with '#' are comments.
+// - the entry point is absent for the unimplemented syscalls, and the
+//   compat entry point is '-' if it is absent.
+
+use std::{env, fmt::Write, fs, process};
+
+struct TblEntry {
+    number: usize,
+    abi: String,
+    name: String,
+    entry_point: Option<String>,
+    noreturn: bool,
+}
+
+fn parse_tbl(text: &str) -> Result<Vec<TblEntry>, String> {
+    let mut entries = vec![];
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 3 {
+            return Err(format!("line {}: too few fields: \"{}\"", index + 1, line));
+        }
+
+        let number = fields[0]
+            .parse::<usize>()
+            .map_err(|_| format!("line {}: invalid number: \"{}\"", index + 1, fields[0]))?;
+
+        entries.push(TblEntry {
+            number,
+            abi: fields[1].to_owned(),
+            name: fields[2].to_owned(),
+            entry_point: fields.get(3).map(|s| s.to_string()),
+            noreturn: fields.get(5) == Some(&"noreturn"),
+        });
+    }
+
+    Ok(entries)
+}
+
+// converts the syscall name into a Rust identifier,
+// e.g. "_sysctl" -> "sysctl_", "break" -> "break_".
+//
+// the original name is kept by the attribute `#[name = "..."]` of the
+// variant, see the macro `syscall_num!`.
+fn to_identifier(name: &str) -> String {
+    match name.strip_prefix('_') {
+        Some(stripped) => format!("{}_", stripped),
+        None if name == "break" => format!("{}_", name),
+        None => name.to_owned(),
+    }
+}
+
+fn to_abi_variant(abi: &str) -> &'static str {
+    match abi {
+        "common" => "SysCallAbi::Common",
+        "64" => "SysCallAbi::X64",
+        "x32" => "SysCallAbi::X32",
+        "i386" => "SysCallAbi::I386",
+        _ => panic!("unsupported ABI: \"{}\"", abi),
+    }
+}
+
+// the x32 syscall numbers are the numbers in the table plus this bit,
+// see 'arch/x86/include/uapi/asm/unistd.h'.
+const X32_SYSCALL_BIT: usize = 0x40000000;
+
+// renders the enum (by the macro `syscall_num!`) and the metadata functions.
+//
+// - `enum_name`: the name of the enum, e.g. "SysCallNum".
+// - `native_abi`: the ABI other than "common", e.g. "64" or "x32",
+//   the ABI of the entries which are not "common" are `native_abi`.
+// - `number_bit`: the bit which is added to the syscall numbers.
+fn render_enum(
+    out: &mut String,
+    entries: &[&TblEntry],
+    enum_name: &str,
+    native_abi: &str,
+    number_bit: usize,
+) {
+    let to_number = |entry: &TblEntry| {
+        if number_bit == 0 {
+            format!("{}", entry.number)
+        } else {
+            format!("0x{:08x}", entry.number | number_bit)
+        }
+    };
+
+    writeln!(
+        out,
+        r#"syscall_num! {{
+    pub enum {enum_name} {{"#
+    )
+    .unwrap();
+
+    let width = entries
+        .iter()
+        .map(|entry| format!("{} = {},", to_identifier(&entry.name), to_number(entry)).len())
+        .max()
+        .unwrap_or(0);
+
+    let mut last_number = None;
+    for entry in entries {
+        if let Some(last) = last_number {
+            if entry.number > last + 1 {
+                writeln!(
+                    out,
+                    "        // {} - {} are not used",
+                    last + 1,
+                    entry.number - 1
+                )
+                .unwrap();
+            }
+        }
+        last_number = Some(entry.number);
+
+        // keeps the name of the kernel syscall table for `name()` and `FromStr`
+        let identifier = to_identifier(&entry.name);
+        if identifier != entry.name {
+            writeln!(out, "        #[name = \"{}\"]", entry.name).unwrap();
+        }
+
+        let item = format!("{} = {},", identifier, to_number(entry));
+        let mut comment = String::new();
+        if number_bit != 0 {
+            write!(comment, "{:<5}", entry.number).unwrap();
+        }
+        match &entry.entry_point {
+            Some(entry_point) => write!(comment, "{:<8}{}", entry.abi, entry_point).unwrap(),
+            None => comment.push_str(&entry.abi),
+        };
+        writeln!(
+            out,
+            "        {:<width$} // {}",
+            item,
+            comment,
+            width = width
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        out,
+        r#"    }}
+}}
+
+impl {enum_name} {{
+    // the ABI column of the syscall table.
+    pub fn abi(&self) -> SysCallAbi {{"#
+    )
+    .unwrap();
+
+    let native_entries = entries
+        .iter()
+        .filter(|entry| entry.abi == native_abi)
+        .collect::<Vec<_>>();
+
+    if native_entries.len() == entries.len() {
+        // all entries have the same ABI, e.g. "i386"
+        writeln!(out, "        {}", to_abi_variant(native_abi)).unwrap();
+    } else {
+        writeln!(out, "        match self {{").unwrap();
+
+        for (index, entry) in native_entries.iter().enumerate() {
+            let prefix = if index == 0 { "" } else { "| " };
+            let suffix = if index == native_entries.len() - 1 {
+                format!(" => {},", to_abi_variant(&entry.abi))
+            } else {
+                String::new()
+            };
+            writeln!(
+                out,
+                "            {}{}::{}{}",
+                prefix,
+                enum_name,
+                to_identifier(&entry.name),
+                suffix
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "            _ => SysCallAbi::Common,").unwrap();
+        writeln!(out, "        }}").unwrap();
+    }
+
+    writeln!(
+        out,
+        r#"    }}
+
+    // the name of the kernel function which implements the syscall,
+    // e.g. "sys_read", or `None` if the syscall is not implemented.
+    pub fn entry_point(&self) -> Option<&'static str> {{
+        match self {{"#
+    )
+    .unwrap();
+
+    for entry in entries {
+        if let Some(entry_point) = &entry.entry_point {
+            writeln!(
+                out,
+                "            {}::{} => Some(\"{}\"),",
+                enum_name,
+                to_identifier(&entry.name),
+                entry_point
+            )
+            .unwrap();
+        }
+    }
+
+    let noreturn_entries = entries
+        .iter()
+        .filter(|entry| entry.noreturn)
+        .map(|entry| format!("{}::{}", enum_name, to_identifier(&entry.name)))
+        .collect::<Vec<_>>();
+
+    writeln!(
+        out,
+        r#"            _ => None,
+        }}
+    }}
+
+    // whether the syscall never returns (e.g. `exit` and `exit_group`).
+    pub fn is_noreturn(&self) -> bool {{
+        matches!(self, {})
+    }}
+}}"#,
+        noreturn_entries.join(" | ")
+    )
+    .unwrap();
+}
+
+fn render_header(out: &mut String, arch: &str, tbl_name: &str, kernel_version: &str) {
+    let comment = match arch {
+        "x86_64" => {
+            r#"// for other arch such as aarch64 and newer arch such as riscv64, checkout
+// 'include/uapi/asm-generic/unistd.h' and
+// 'arch/{riscv}/include/uapi/asm/unistd.h'
+//
+// ref:
+// - https://www.chromium.org/chromium-os/developer-library/reference/linux-constants/syscalls/
+// - https://man7.org/linux/man-pages/man2/syscall.2.html
+// - https://syscalls.mebeim.net/?table=x86/64/x64/latest"#
+        }
+        _ => {
+            r#"// ref:
+// - https://man7.org/linux/man-pages/man2/syscall.2.html
+// - https://syscalls.mebeim.net/?table=x86/32/ia32/latest"#
+        }
+    };
+
+    writeln!(
+        out,
+        r#"// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// THIS FILE IS GENERATED BY 'examples/gen_syscall_num.rs', DO NOT EDIT.
+//
+// the following definition come from Linux (kernel {kernel_version}) source file:
+// 'arch/x86/entry/syscalls/{tbl_name}'
+// (a copy of the file is located at 'src/arch/{arch}/{tbl_name}')
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_syscall_num -- {arch} src/arch/{arch}/{tbl_name} {kernel_version} \
+//       > src/arch/{arch}/number.rs
+// ```
+//
+{comment}
+//
+// the macro `syscall_num!` generates the enum `SysCallNum` as well as
+// the functions for looking up syscall by number and name, see 'src/macros.rs'.
+
+use crate::arch::SysCallAbi;
+"#
+    )
+    .unwrap();
+}
+
+fn render_x86_64(entries: &[TblEntry], kernel_version: &str) -> String {
+    let x64_entries = entries
+        .iter()
+        .filter(|entry| entry.abi == "common" || entry.abi == "64")
+        .collect::<Vec<_>>();
+
+    let x32_entries = entries
+        .iter()
+        .filter(|entry| entry.abi == "common" || entry.abi == "x32")
+        .collect::<Vec<_>>();
+
+    let other_entries = entries
+        .iter()
+        .filter(|entry| !["common", "64", "x32"].contains(&entry.abi.as_str()))
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+
+    render_header(&mut out, "x86_64", "syscall_64.tbl", kernel_version);
+
+    writeln!(
+        out,
+        "// the syscalls of the x86_64 ABI (the ABI \"common\" and \"64\")."
+    )
+    .unwrap();
+
+    render_enum(&mut out, &x64_entries, "SysCallNum", "64", 0);
+
+    writeln!(
+        out,
+        r#"
+// the bit which is added to the syscall numbers of the x32 ABI,
+// i.e. the value of `rax` is `__X32_SYSCALL_BIT | <number in table>`.
+//
+// see 'arch/x86/include/uapi/asm/unistd.h'
+pub const X32_SYSCALL_BIT: usize = 0x{X32_SYSCALL_BIT:08x};
+
+// the syscalls of the x32 ABI (the ABI "common" and "x32"),
+// the numbers include the `X32_SYSCALL_BIT`.
+//
+// due to a historical design error, certain syscalls are numbered differently
+// in x32 as compared to native x86_64, these syscalls have numbers 512-547,
+// and the x86_64 (ABI "64") version of these syscalls are not available for x32.
+"#
+    )
+    .unwrap();
+
+    render_enum(
+        &mut out,
+        &x32_entries,
+        "X32SysCallNum",
+        "x32",
+        X32_SYSCALL_BIT,
+    );
+
+    if !other_entries.is_empty() {
+        writeln!(
+            out,
+            r#"
+// the following syscalls are not listed because they belong to other ABIs.
+//"#
+        )
+        .unwrap();
+
+        for entry in &other_entries {
+            writeln!(
+                out,
+                "// {}\t{}\t{}\t{}",
+                entry.number,
+                entry.abi,
+                entry.name,
+                entry.entry_point.as_deref().unwrap_or("")
+            )
+            .unwrap();
+        }
+    }
+
+    writeln!(
+        out,
+        r#"
+#[cfg(test)]
+mod tests {{
+    use std::str::FromStr;
+
+    use crate::arch::{{
+        x86_64::number::{{SysCallNum, X32SysCallNum, X32_SYSCALL_BIT}},
+        SysCallAbi,
+    }};
+
+    #[test]
+    fn test_lookup_syscall_num() {{
+        // the names and the numbers are unique
+        for num in SysCallNum::iter() {{
+            assert_eq!(SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(SysCallNum::from_str(num.name()), Ok(num));
+        }}
+
+        for num in X32SysCallNum::iter() {{
+            assert_eq!(X32SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(X32SysCallNum::from_str(num.name()), Ok(num));
+            assert_ne!(num as usize & X32_SYSCALL_BIT, 0);
+        }}
+
+        assert_eq!(SysCallNum::iter().count(), SysCallNum::ALL.len());
+        assert_eq!(SysCallNum::from_number(usize::MAX), None);
+        assert!(SysCallNum::from_str("foo").is_err());
+
+        // the renamed syscalls keep the names of the kernel syscall table
+        assert_eq!(SysCallNum::sysctl_.name(), "_sysctl");
+        assert_eq!(SysCallNum::from_str("_sysctl"), Ok(SysCallNum::sysctl_));
+        assert!(SysCallNum::from_str("sysctl_").is_err());
+    }}
+
+    #[test]
+    fn test_syscall_table() {{
+        // checks the generated enums against the kernel syscall table
+        let text = include_str!("syscall_64.tbl");
+        let mut x64_count = 0;
+        let mut x32_count = 0;
+
+        for line in text.lines() {{
+            if line.is_empty() || line.starts_with('#') {{
+                continue;
+            }}
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let number = fields[0].parse::<usize>().unwrap();
+            let abi = match fields[1] {{
+                "common" => SysCallAbi::Common,
+                "64" => SysCallAbi::X64,
+                "x32" => SysCallAbi::X32,
+                _ => continue,
+            }};
+            let name = fields[2];
+            let entry_point = fields.get(3).copied();
+            let noreturn = fields.get(5) == Some(&"noreturn");
+
+            if abi != SysCallAbi::X32 {{
+                let num = SysCallNum::from_number(number).unwrap();
+                assert_eq!(num.name(), name);
+                assert_eq!(num.abi(), abi);
+                assert_eq!(num.entry_point(), entry_point);
+                assert_eq!(num.is_noreturn(), noreturn);
+                x64_count += 1;
+            }}
+
+            if abi != SysCallAbi::X64 {{
+                let num = X32SysCallNum::from_number(number | X32_SYSCALL_BIT).unwrap();
+                assert_eq!(num.name(), name);
+                assert_eq!(num.abi(), abi);
+                assert_eq!(num.entry_point(), entry_point);
+                assert_eq!(num.is_noreturn(), noreturn);
+                x32_count += 1;
+            }}
+        }}
+
+        assert_eq!(x64_count, SysCallNum::ALL.len());
+        assert_eq!(x32_count, X32SysCallNum::ALL.len());
+    }}
+}}"#
+    )
+    .unwrap();
+
+    out
+}
+
+fn render_x86(entries: &[TblEntry], kernel_version: &str) -> String {
+    let i386_entries = entries
+        .iter()
+        .filter(|entry| entry.abi == "i386")
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+
+    render_header(&mut out, "x86", "syscall_32.tbl", kernel_version);
+
+    writeln!(
+        out,
+        r#"// the syscalls of the i386 ABI, i.e. the 32-bit x86 and the IA32
+// emulation (compat mode) on x86_64 which is invoked by `int 0x80`."#
+    )
+    .unwrap();
+
+    render_enum(&mut out, &i386_entries, "SysCallNum", "i386", 0);
+
+    writeln!(
+        out,
+        r#"
+#[cfg(test)]
+mod tests {{
+    use std::str::FromStr;
+
+    use crate::arch::{{x86::number::SysCallNum, SysCallAbi}};
+
+    #[test]
+    fn test_lookup_syscall_num() {{
+        // the names and the numbers are unique
+        for num in SysCallNum::iter() {{
+            assert_eq!(SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(SysCallNum::from_str(num.name()), Ok(num));
+            assert_eq!(num.abi(), SysCallAbi::I386);
+        }}
+
+        assert_eq!(SysCallNum::from_number(usize::MAX), None);
+        assert!(SysCallNum::from_str("foo").is_err());
+
+        // the renamed syscalls keep the names of the kernel syscall table
+        assert_eq!(SysCallNum::break_.name(), "break");
+        assert_eq!(SysCallNum::from_str("_llseek"), Ok(SysCallNum::llseek_));
+        assert_eq!(SysCallNum::from_str("_sysctl"), Ok(SysCallNum::sysctl_));
+    }}
+
+    #[test]
+    fn test_syscall_table() {{
+        // checks the generated enum against the kernel syscall table
+        let text = include_str!("syscall_32.tbl");
+        let mut count = 0;
+
+        for line in text.lines() {{
+            if line.is_empty() || line.starts_with('#') {{
+                continue;
+            }}
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let number = fields[0].parse::<usize>().unwrap();
+
+            let num = SysCallNum::from_number(number).unwrap();
+            assert_eq!(num.name(), fields[2]);
+            assert_eq!(num.entry_point(), fields.get(3).copied());
+            assert_eq!(num.is_noreturn(), fields.get(5) == Some(&"noreturn"));
+            count += 1;
+        }}
+
+        assert_eq!(count, SysCallNum::ALL.len());
+    }}
+}}"#
+    )
+    .unwrap();
+
+    out
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    if args.len() != 4 {
+        eprintln!(
+            "usage: gen_syscall_num <x86_64|x86> <path/to/syscall_{{64|32}}.tbl> <kernel version>"
+        );
+        process::exit(1);
+    }
+
+    let text = fs::read_to_string(&args[2]).unwrap_or_else(|e| {
+        eprintln!("failed to read \"{}\": {}", args[2], e);
+        process::exit(1);
+    });
+
+    let entries = parse_tbl(&text).unwrap_or_else(|e| {
+        eprintln!("failed to parse \"{}\": {}", args[2], e);
+        process::exit(1);
+    });
+
+    let content = match args[1].as_str() {
+        "x86_64" => render_x86_64(&entries, &args[3]),
+        "x86" => render_x86(&entries, &args[3]),
+        _ => {
+            eprintln!("unsupported arch: \"{}\"", args[1]);
+            process::exit(1);
+        }
+    };
+
+    print!("{}", content);
+}
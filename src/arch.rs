@@ -4,6 +4,70 @@
 // the Mozilla Public License version 2.0 and additional exceptions,
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
+// the syscall number tables (the module `number`) and the kernel constants
+// (the module `flags`) of all architectures are always available, e.g. for
+// decoding the syscalls of other architectures, but the module `call` is
+// only available on the target architecture
+// (except `x86::call` which is available on x86_64 through the IA32
+// emulation), see
 // https://doc.rust-lang.org/stable/reference/conditional-compilation.html?highlight=cfg#the-cfg-attribute
-#[cfg(target_arch = "x86_64")]
+pub mod aarch64;
+pub mod riscv64;
+pub mod x86;
 pub mod x86_64;
+
+use std::fmt::Display;
+
+// the maximum error number, the kernel returns the error as a negative value
+// in the range of `-MAX_ERRNO..=-1`, i.e. the rule of the C macro
+// `IS_ERR_VALUE` in 'include/linux/err.h'.
+//
+// the values out of this range are the successful results, although some of
+// them look negative as `isize`, e.g. the addresses returned by `mmap` (on
+// the architectures with the kernel space in the lower half), the file offsets
+// returned by `lseek` of some devices (e.g. '/dev/mem'), and the pointers
+// returned by `brk`.
+pub const MAX_ERRNO: usize = 4095;
+
+// the ABI column of the kernel syscall table ('.tbl' file).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SysCallAbi {
+    // the syscall is available for all ABIs of the architecture.
+    Common,
+
+    // the syscall is only available for the 64-bit ABI, i.e. x86_64.
+    X64,
+
+    // the syscall is only available for the x32 ABI, i.e. the 32-bit
+    // pointers on x86_64.
+    X32,
+
+    // the syscall of the 32-bit x86 (i.e. the file 'syscall_32.tbl').
+    I386,
+}
+
+// the error of parsing a syscall name into `SysCallNum`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseSysCallNumError {
+    name: String,
+}
+
+impl ParseSysCallNumError {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for ParseSysCallNumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown syscall name: \"{}\"", self.name)
+    }
+}
+
+impl std::error::Error for ParseSysCallNumError {}
@@ -0,0 +1,294 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// aarch64 ABI/calling convention of syscall
+//
+// | register | usage    |
+// |----------|----------|
+// | x8       | call num |
+// | x0       | 1st      | also use for store the return value.
+// | x1       | 2nd      |
+// | x2       | 3rd      |
+// | x3       | 4th      |
+// | x4       | 5th      |
+// | x5       | 6th      |
+//
+// the syscall is invoked by the instruction `svc #0`, and all
+// registers except `x0` are preserved by the kernel, so there is
+// no need to mark other registers as 'out' (unlike x86_64 which
+// clobbers `rcx` and `r11`).
+//
+// syscall example: print "hello world" to stdout
+//
+// ```asm
+// mov     x8, #64          // SYS_write
+// mov     x0, #1           // STDOUT_FILENO
+// adr     x1, message
+// mov     x2, #13          // message length
+// svc     #0
+// ```
+//
+// ref:
+// - https://man7.org/linux/man-pages/man2/syscall.2.html
+// - https://developer.arm.com/documentation/102374/latest/
+// - Rust inline assembly
+//   https://doc.rust-lang.org/stable/reference/inline-assembly.html
+use std::arch::asm;
+
+use crate::arch::{aarch64::number::SysCallNum, MAX_ERRNO};
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_without_args(num: usize) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        lateout("x0") result,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_1_arg(num: usize, arg1: usize) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        inlateout("x0") arg1 => result,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_2_args(num: usize, arg1: usize, arg2: usize) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        inlateout("x0") arg1 => result,
+        in("x1") arg2,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_3_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        inlateout("x0") arg1 => result,
+        in("x1") arg2,
+        in("x2") arg3,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_4_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        inlateout("x0") arg1 => result,
+        in("x1") arg2,
+        in("x2") arg3,
+        in("x3") arg4,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_5_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        inlateout("x0") arg1 => result,
+        in("x1") arg2,
+        in("x2") arg3,
+        in("x3") arg4,
+        in("x4") arg5,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_6_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "svc 0",
+        in("x8") num,
+        inlateout("x0") arg1 => result,
+        in("x1") arg2,
+        in("x2") arg3,
+        in("x3") arg4,
+        in("x4") arg5,
+        in("x5") arg6,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_x0(result)
+}
+
+// for the syscalls which never return on success, e.g. `exit`, `exit_group` and
+// `rt_sigreturn`, the caller must ensure that the syscall does not return (i.e. it
+// can not fail).
+//
+// note that `rt_sigreturn` finds the signal frame by the stack pointer, so it
+// can not be invoked by these functions from a signal handler, see
+// `rt_sigreturn_trampoline`.
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_without_args(num: usize) -> ! {
+    asm!(
+        "svc 0",
+        in("x8") num,
+        options(noreturn, nostack)
+    );
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_with_1_arg(num: usize, arg1: usize) -> ! {
+    asm!(
+        "svc 0",
+        in("x8") num,
+        in("x0") arg1,
+        options(noreturn, nostack)
+    );
+}
+
+// the trampoline which can be set to `sa_restorer` of `rt_sigaction` (with the
+// flag `SA_RESTORER`, otherwise the kernel uses the trampoline of VDSO), the signal
+// handler returns to it, and it invokes `rt_sigreturn` to restore the context
+// saved in the signal frame.
+//
+// it must be a naked function since the stack pointer must not be changed
+// before the syscall.
+#[allow(clippy::missing_safety_doc)]
+#[unsafe(naked)]
+pub unsafe extern "C" fn rt_sigreturn_trampoline() -> ! {
+    std::arch::naked_asm!(
+        "mov x8, #{num}",
+        "svc 0",
+        num = const SysCallNum::rt_sigreturn as usize,
+    )
+}
+
+// only the values in the range of `-MAX_ERRNO..=-1` are errors, see `arch::MAX_ERRNO`.
+#[inline(always)]
+fn convert_raw_return_code_from_x0(raw_code: isize) -> Result<usize, usize> {
+    if (-(MAX_ERRNO as isize)..=-1).contains(&raw_code) {
+        Err((-raw_code) as usize)
+    } else {
+        Ok(raw_code as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        arch::aarch64::{
+            call::{
+                convert_raw_return_code_from_x0, syscall_with_1_arg, syscall_with_4_args,
+                syscall_without_args,
+            },
+            number::SysCallNum,
+        },
+        errno::Errno,
+    };
+
+    #[test]
+    fn test_syscall() {
+        let result0 = unsafe { syscall_without_args(SysCallNum::getpid as usize) };
+        assert!(matches!(result0, Ok(pid) if pid > 0));
+
+        // AT_FDCWD = -100
+        let at_fdcwd = -100isize as usize;
+        let file_path0 = b"/this/file/should/not/exist\0";
+        let result1 = unsafe {
+            syscall_with_4_args(
+                SysCallNum::openat as usize,
+                at_fdcwd,
+                file_path0.as_ptr() as usize,
+                0,
+                0,
+            )
+        };
+        assert!(matches!(result1, Err(errno) if errno == Errno::ENOENT as usize));
+
+        let file_path1 = b"/dev/zero\0";
+        let result2 = unsafe {
+            syscall_with_4_args(
+                SysCallNum::openat as usize,
+                at_fdcwd,
+                file_path1.as_ptr() as usize,
+                0,
+                0,
+            )
+        };
+        let fd = result2.unwrap();
+
+        let result3 = unsafe { syscall_with_1_arg(SysCallNum::close as usize, fd) };
+        assert!(matches!(result3, Ok(0)));
+    }
+
+    #[test]
+    fn test_convert_raw_return_code() {
+        assert_eq!(convert_raw_return_code_from_x0(0), Ok(0));
+        assert_eq!(convert_raw_return_code_from_x0(-1), Err(1));
+        assert_eq!(convert_raw_return_code_from_x0(-4095), Err(4095));
+
+        // the values below -4095 are not errors, e.g. an address returned by `mmap`
+        assert_eq!(
+            convert_raw_return_code_from_x0(-4096),
+            Ok(usize::MAX - 4095)
+        );
+        assert_eq!(
+            convert_raw_return_code_from_x0(isize::MIN),
+            Ok(isize::MIN as usize)
+        );
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the following definition come from Linux (kernel 6.14) source files:
+// 'scripts/syscall.tbl' (which replaces 'include/uapi/asm-generic/unistd.h'
+// since kernel 6.11)
+// 'arch/arm64/kernel/Makefile.syscalls'
+//
+// aarch64 uses the generic syscall table, and it defines
+// '__ARCH_WANT_RENAMEAT', '__ARCH_WANT_NEW_STAT', '__ARCH_WANT_SET_GET_RLIMIT',
+// '__ARCH_WANT_SYS_CLONE3' and '__ARCH_WANT_MEMFD_SECRET' (which are the
+// ABIs in 'Makefile.syscalls' since kernel 6.11).
+//
+// note that the legacy syscalls such as `open`, `stat`, `fork`, `pipe`
+// and `poll` are not available, use `openat`, `newfstatat`, `clone`,
+// `pipe2` and `ppoll` instead.
+//
+// ref:
+// - https://syscalls.mebeim.net/?table=arm64/64/aarch64/latest
+//
+// the macro `syscall_num!` generates the enum `SysCallNum` as well as
+// the functions for looking up syscall by number and name, see 'src/macros.rs'.
+
+syscall_num! {
+    pub enum SysCallNum {
+        io_setup = 0,                  // sys_io_setup
+        io_destroy = 1,                // sys_io_destroy
+        io_submit = 2,                 // sys_io_submit
+        io_cancel = 3,                 // sys_io_cancel
+        io_getevents = 4,              // sys_io_getevents
+        setxattr = 5,                  // sys_setxattr
+        lsetxattr = 6,                 // sys_lsetxattr
+        fsetxattr = 7,                 // sys_fsetxattr
+        getxattr = 8,                  // sys_getxattr
+        lgetxattr = 9,                 // sys_lgetxattr
+        fgetxattr = 10,                // sys_fgetxattr
+        listxattr = 11,                // sys_listxattr
+        llistxattr = 12,               // sys_llistxattr
+        flistxattr = 13,               // sys_flistxattr
+        removexattr = 14,              // sys_removexattr
+        lremovexattr = 15,             // sys_lremovexattr
+        fremovexattr = 16,             // sys_fremovexattr
+        getcwd = 17,                   // sys_getcwd
+        lookup_dcookie = 18,           // sys_lookup_dcookie
+        eventfd2 = 19,                 // sys_eventfd2
+        epoll_create1 = 20,            // sys_epoll_create1
+        epoll_ctl = 21,                // sys_epoll_ctl
+        epoll_pwait = 22,              // sys_epoll_pwait
+        dup = 23,                      // sys_dup
+        dup3 = 24,                     // sys_dup3
+        fcntl = 25,                    // sys_fcntl
+        inotify_init1 = 26,            // sys_inotify_init1
+        inotify_add_watch = 27,        // sys_inotify_add_watch
+        inotify_rm_watch = 28,         // sys_inotify_rm_watch
+        ioctl = 29,                    // sys_ioctl
+        ioprio_set = 30,               // sys_ioprio_set
+        ioprio_get = 31,               // sys_ioprio_get
+        flock = 32,                    // sys_flock
+        mknodat = 33,                  // sys_mknodat
+        mkdirat = 34,                  // sys_mkdirat
+        unlinkat = 35,                 // sys_unlinkat
+        symlinkat = 36,                // sys_symlinkat
+        linkat = 37,                   // sys_linkat
+        renameat = 38,                 // sys_renameat
+        umount2 = 39,                  // sys_umount
+        mount = 40,                    // sys_mount
+        pivot_root = 41,               // sys_pivot_root
+        nfsservctl = 42,               // sys_ni_syscall
+        statfs = 43,                   // sys_statfs
+        fstatfs = 44,                  // sys_fstatfs
+        truncate = 45,                 // sys_truncate
+        ftruncate = 46,                // sys_ftruncate
+        fallocate = 47,                // sys_fallocate
+        faccessat = 48,                // sys_faccessat
+        chdir = 49,                    // sys_chdir
+        fchdir = 50,                   // sys_fchdir
+        chroot = 51,                   // sys_chroot
+        fchmod = 52,                   // sys_fchmod
+        fchmodat = 53,                 // sys_fchmodat
+        fchownat = 54,                 // sys_fchownat
+        fchown = 55,                   // sys_fchown
+        openat = 56,                   // sys_openat
+        close = 57,                    // sys_close
+        vhangup = 58,                  // sys_vhangup
+        pipe2 = 59,                    // sys_pipe2
+        quotactl = 60,                 // sys_quotactl
+        getdents64 = 61,               // sys_getdents64
+        lseek = 62,                    // sys_lseek
+        read = 63,                     // sys_read
+        write = 64,                    // sys_write
+        readv = 65,                    // sys_readv
+        writev = 66,                   // sys_writev
+        pread64 = 67,                  // sys_pread64
+        pwrite64 = 68,                 // sys_pwrite64
+        preadv = 69,                   // sys_preadv
+        pwritev = 70,                  // sys_pwritev
+        sendfile = 71,                 // sys_sendfile64
+        pselect6 = 72,                 // sys_pselect6
+        ppoll = 73,                    // sys_ppoll
+        signalfd4 = 74,                // sys_signalfd4
+        vmsplice = 75,                 // sys_vmsplice
+        splice = 76,                   // sys_splice
+        tee = 77,                      // sys_tee
+        readlinkat = 78,               // sys_readlinkat
+        newfstatat = 79,               // sys_newfstatat
+        fstat = 80,                    // sys_newfstat
+        sync = 81,                     // sys_sync
+        fsync = 82,                    // sys_fsync
+        fdatasync = 83,                // sys_fdatasync
+        sync_file_range = 84,          // sys_sync_file_range
+        timerfd_create = 85,           // sys_timerfd_create
+        timerfd_settime = 86,          // sys_timerfd_settime
+        timerfd_gettime = 87,          // sys_timerfd_gettime
+        utimensat = 88,                // sys_utimensat
+        acct = 89,                     // sys_acct
+        capget = 90,                   // sys_capget
+        capset = 91,                   // sys_capset
+        personality = 92,              // sys_personality
+        exit = 93,                     // sys_exit
+        exit_group = 94,               // sys_exit_group
+        waitid = 95,                   // sys_waitid
+        set_tid_address = 96,          // sys_set_tid_address
+        unshare = 97,                  // sys_unshare
+        futex = 98,                    // sys_futex
+        set_robust_list = 99,          // sys_set_robust_list
+        get_robust_list = 100,         // sys_get_robust_list
+        nanosleep = 101,               // sys_nanosleep
+        getitimer = 102,               // sys_getitimer
+        setitimer = 103,               // sys_setitimer
+        kexec_load = 104,              // sys_kexec_load
+        init_module = 105,             // sys_init_module
+        delete_module = 106,           // sys_delete_module
+        timer_create = 107,            // sys_timer_create
+        timer_gettime = 108,           // sys_timer_gettime
+        timer_getoverrun = 109,        // sys_timer_getoverrun
+        timer_settime = 110,           // sys_timer_settime
+        timer_delete = 111,            // sys_timer_delete
+        clock_settime = 112,           // sys_clock_settime
+        clock_gettime = 113,           // sys_clock_gettime
+        clock_getres = 114,            // sys_clock_getres
+        clock_nanosleep = 115,         // sys_clock_nanosleep
+        syslog = 116,                  // sys_syslog
+        ptrace = 117,                  // sys_ptrace
+        sched_setparam = 118,          // sys_sched_setparam
+        sched_setscheduler = 119,      // sys_sched_setscheduler
+        sched_getscheduler = 120,      // sys_sched_getscheduler
+        sched_getparam = 121,          // sys_sched_getparam
+        sched_setaffinity = 122,       // sys_sched_setaffinity
+        sched_getaffinity = 123,       // sys_sched_getaffinity
+        sched_yield = 124,             // sys_sched_yield
+        sched_get_priority_max = 125,  // sys_sched_get_priority_max
+        sched_get_priority_min = 126,  // sys_sched_get_priority_min
+        sched_rr_get_interval = 127,   // sys_sched_rr_get_interval
+        restart_syscall = 128,         // sys_restart_syscall
+        kill = 129,                    // sys_kill
+        tkill = 130,                   // sys_tkill
+        tgkill = 131,                  // sys_tgkill
+        sigaltstack = 132,             // sys_sigaltstack
+        rt_sigsuspend = 133,           // sys_rt_sigsuspend
+        rt_sigaction = 134,            // sys_rt_sigaction
+        rt_sigprocmask = 135,          // sys_rt_sigprocmask
+        rt_sigpending = 136,           // sys_rt_sigpending
+        rt_sigtimedwait = 137,         // sys_rt_sigtimedwait
+        rt_sigqueueinfo = 138,         // sys_rt_sigqueueinfo
+        rt_sigreturn = 139,            // sys_rt_sigreturn
+        setpriority = 140,             // sys_setpriority
+        getpriority = 141,             // sys_getpriority
+        reboot = 142,                  // sys_reboot
+        setregid = 143,                // sys_setregid
+        setgid = 144,                  // sys_setgid
+        setreuid = 145,                // sys_setreuid
+        setuid = 146,                  // sys_setuid
+        setresuid = 147,               // sys_setresuid
+        getresuid = 148,               // sys_getresuid
+        setresgid = 149,               // sys_setresgid
+        getresgid = 150,               // sys_getresgid
+        setfsuid = 151,                // sys_setfsuid
+        setfsgid = 152,                // sys_setfsgid
+        times = 153,                   // sys_times
+        setpgid = 154,                 // sys_setpgid
+        getpgid = 155,                 // sys_getpgid
+        getsid = 156,                  // sys_getsid
+        setsid = 157,                  // sys_setsid
+        getgroups = 158,               // sys_getgroups
+        setgroups = 159,               // sys_setgroups
+        uname = 160,                   // sys_newuname
+        sethostname = 161,             // sys_sethostname
+        setdomainname = 162,           // sys_setdomainname
+        getrlimit = 163,               // sys_getrlimit
+        setrlimit = 164,               // sys_setrlimit
+        getrusage = 165,               // sys_getrusage
+        umask = 166,                   // sys_umask
+        prctl = 167,                   // sys_prctl
+        getcpu = 168,                  // sys_getcpu
+        gettimeofday = 169,            // sys_gettimeofday
+        settimeofday = 170,            // sys_settimeofday
+        adjtimex = 171,                // sys_adjtimex
+        getpid = 172,                  // sys_getpid
+        getppid = 173,                 // sys_getppid
+        getuid = 174,                  // sys_getuid
+        geteuid = 175,                 // sys_geteuid
+        getgid = 176,                  // sys_getgid
+        getegid = 177,                 // sys_getegid
+        gettid = 178,                  // sys_gettid
+        sysinfo = 179,                 // sys_sysinfo
+        mq_open = 180,                 // sys_mq_open
+        mq_unlink = 181,               // sys_mq_unlink
+        mq_timedsend = 182,            // sys_mq_timedsend
+        mq_timedreceive = 183,         // sys_mq_timedreceive
+        mq_notify = 184,               // sys_mq_notify
+        mq_getsetattr = 185,           // sys_mq_getsetattr
+        msgget = 186,                  // sys_msgget
+        msgctl = 187,                  // sys_msgctl
+        msgrcv = 188,                  // sys_msgrcv
+        msgsnd = 189,                  // sys_msgsnd
+        semget = 190,                  // sys_semget
+        semctl = 191,                  // sys_semctl
+        semtimedop = 192,              // sys_semtimedop
+        semop = 193,                   // sys_semop
+        shmget = 194,                  // sys_shmget
+        shmctl = 195,                  // sys_shmctl
+        shmat = 196,                   // sys_shmat
+        shmdt = 197,                   // sys_shmdt
+        socket = 198,                  // sys_socket
+        socketpair = 199,              // sys_socketpair
+        bind = 200,                    // sys_bind
+        listen = 201,                  // sys_listen
+        accept = 202,                  // sys_accept
+        connect = 203,                 // sys_connect
+        getsockname = 204,             // sys_getsockname
+        getpeername = 205,             // sys_getpeername
+        sendto = 206,                  // sys_sendto
+        recvfrom = 207,                // sys_recvfrom
+        setsockopt = 208,              // sys_setsockopt
+        getsockopt = 209,              // sys_getsockopt
+        shutdown = 210,                // sys_shutdown
+        sendmsg = 211,                 // sys_sendmsg
+        recvmsg = 212,                 // sys_recvmsg
+        readahead = 213,               // sys_readahead
+        brk = 214,                     // sys_brk
+        munmap = 215,                  // sys_munmap
+        mremap = 216,                  // sys_mremap
+        add_key = 217,                 // sys_add_key
+        request_key = 218,             // sys_request_key
+        keyctl = 219,                  // sys_keyctl
+        clone = 220,                   // sys_clone
+        execve = 221,                  // sys_execve
+        mmap = 222,                    // sys_mmap
+        fadvise64 = 223,               // sys_fadvise64_64
+        swapon = 224,                  // sys_swapon
+        swapoff = 225,                 // sys_swapoff
+        mprotect = 226,                // sys_mprotect
+        msync = 227,                   // sys_msync
+        mlock = 228,                   // sys_mlock
+        munlock = 229,                 // sys_munlock
+        mlockall = 230,                // sys_mlockall
+        munlockall = 231,              // sys_munlockall
+        mincore = 232,                 // sys_mincore
+        madvise = 233,                 // sys_madvise
+        remap_file_pages = 234,        // sys_remap_file_pages
+        mbind = 235,                   // sys_mbind
+        get_mempolicy = 236,           // sys_get_mempolicy
+        set_mempolicy = 237,           // sys_set_mempolicy
+        migrate_pages = 238,           // sys_migrate_pages
+        move_pages = 239,              // sys_move_pages
+        rt_tgsigqueueinfo = 240,       // sys_rt_tgsigqueueinfo
+        perf_event_open = 241,         // sys_perf_event_open
+        accept4 = 242,                 // sys_accept4
+        recvmmsg = 243,                // sys_recvmmsg
+        // 244 - 259 are reserved for the architecture specific syscalls,
+        // and aarch64 does not define any of them.
+        wait4 = 260,                   // sys_wait4
+        prlimit64 = 261,               // sys_prlimit64
+        fanotify_init = 262,           // sys_fanotify_init
+        fanotify_mark = 263,           // sys_fanotify_mark
+        name_to_handle_at = 264,       // sys_name_to_handle_at
+        open_by_handle_at = 265,       // sys_open_by_handle_at
+        clock_adjtime = 266,           // sys_clock_adjtime
+        syncfs = 267,                  // sys_syncfs
+        setns = 268,                   // sys_setns
+        sendmmsg = 269,                // sys_sendmmsg
+        process_vm_readv = 270,        // sys_process_vm_readv
+        process_vm_writev = 271,       // sys_process_vm_writev
+        kcmp = 272,                    // sys_kcmp
+        finit_module = 273,            // sys_finit_module
+        sched_setattr = 274,           // sys_sched_setattr
+        sched_getattr = 275,           // sys_sched_getattr
+        renameat2 = 276,               // sys_renameat2
+        seccomp = 277,                 // sys_seccomp
+        getrandom = 278,               // sys_getrandom
+        memfd_create = 279,            // sys_memfd_create
+        bpf = 280,                     // sys_bpf
+        execveat = 281,                // sys_execveat
+        userfaultfd = 282,             // sys_userfaultfd
+        membarrier = 283,              // sys_membarrier
+        mlock2 = 284,                  // sys_mlock2
+        copy_file_range = 285,         // sys_copy_file_range
+        preadv2 = 286,                 // sys_preadv2
+        pwritev2 = 287,                // sys_pwritev2
+        pkey_mprotect = 288,           // sys_pkey_mprotect
+        pkey_alloc = 289,              // sys_pkey_alloc
+        pkey_free = 290,               // sys_pkey_free
+        statx = 291,                   // sys_statx
+        io_pgetevents = 292,           // sys_io_pgetevents
+        rseq = 293,                    // sys_rseq
+        kexec_file_load = 294,         // sys_kexec_file_load
+        // 295 - 423 are the 32-bit time64 syscalls and they are
+        // not available on 64-bit architectures.
+        pidfd_send_signal = 424,       // sys_pidfd_send_signal
+        io_uring_setup = 425,          // sys_io_uring_setup
+        io_uring_enter = 426,          // sys_io_uring_enter
+        io_uring_register = 427,       // sys_io_uring_register
+        open_tree = 428,               // sys_open_tree
+        move_mount = 429,              // sys_move_mount
+        fsopen = 430,                  // sys_fsopen
+        fsconfig = 431,                // sys_fsconfig
+        fsmount = 432,                 // sys_fsmount
+        fspick = 433,                  // sys_fspick
+        pidfd_open = 434,              // sys_pidfd_open
+        clone3 = 435,                  // sys_clone3
+        close_range = 436,             // sys_close_range
+        openat2 = 437,                 // sys_openat2
+        pidfd_getfd = 438,             // sys_pidfd_getfd
+        faccessat2 = 439,              // sys_faccessat2
+        process_madvise = 440,         // sys_process_madvise
+        epoll_pwait2 = 441,            // sys_epoll_pwait2
+        mount_setattr = 442,           // sys_mount_setattr
+        quotactl_fd = 443,             // sys_quotactl_fd
+        landlock_create_ruleset = 444, // sys_landlock_create_ruleset
+        landlock_add_rule = 445,       // sys_landlock_add_rule
+        landlock_restrict_self = 446,  // sys_landlock_restrict_self
+        memfd_secret = 447,            // sys_memfd_secret
+        process_mrelease = 448,        // sys_process_mrelease
+        futex_waitv = 449,             // sys_futex_waitv
+        set_mempolicy_home_node = 450, // sys_set_mempolicy_home_node
+        cachestat = 451,               // sys_cachestat
+        fchmodat2 = 452,               // sys_fchmodat2
+        map_shadow_stack = 453,        // sys_map_shadow_stack
+        futex_wake = 454,              // sys_futex_wake
+        futex_wait = 455,              // sys_futex_wait
+        futex_requeue = 456,           // sys_futex_requeue
+        statmount = 457,               // sys_statmount
+        listmount = 458,               // sys_listmount
+        lsm_get_self_attr = 459,       // sys_lsm_get_self_attr
+        lsm_set_self_attr = 460,       // sys_lsm_set_self_attr
+        lsm_list_modules = 461,        // sys_lsm_list_modules
+        mseal = 462,                   // sys_mseal
+        setxattrat = 463,              // sys_setxattrat
+        getxattrat = 464,              // sys_getxattrat
+        listxattrat = 465,             // sys_listxattrat
+        removexattrat = 466,           // sys_removexattrat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::arch::aarch64::number::SysCallNum;
+
+    #[test]
+    fn test_lookup_syscall_num() {
+        assert_eq!(SysCallNum::from_number(56), Some(SysCallNum::openat));
+        assert_eq!(SysCallNum::from_number(63), Some(SysCallNum::read));
+        assert_eq!(SysCallNum::from_number(172), Some(SysCallNum::getpid));
+        assert_eq!(SysCallNum::from_number(221), Some(SysCallNum::execve));
+        assert_eq!(SysCallNum::from_number(435), Some(SysCallNum::clone3));
+        assert_eq!(SysCallNum::from_number(250), None);
+        assert_eq!(SysCallNum::from_number(300), None);
+
+        assert!(SysCallNum::from_str("open").is_err());
+        assert!(SysCallNum::from_str("fork").is_err());
+        assert_eq!(SysCallNum::from_str("renameat"), Ok(SysCallNum::renameat));
+
+        for num in SysCallNum::iter() {
+            assert_eq!(SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(SysCallNum::from_str(num.name()), Ok(num));
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+#[cfg(target_arch = "riscv64")]
+pub mod call;
+pub mod flags;
+pub mod number;
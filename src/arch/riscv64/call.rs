@@ -0,0 +1,271 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// riscv64 ABI/calling convention of syscall
+//
+// | register | usage    |
+// |----------|----------|
+// | a7       | call num |
+// | a0       | 1st      | also use for store the return value.
+// | a1       | 2nd      |
+// | a2       | 3rd      |
+// | a3       | 4th      |
+// | a4       | 5th      |
+// | a5       | 6th      |
+//
+// the syscall is invoked by the instruction `ecall`, and all
+// registers except `a0` are preserved by the kernel.
+//
+// syscall example: print "hello world" to stdout
+//
+// ```asm
+// li      a7, 64           # SYS_write
+// li      a0, 1            # STDOUT_FILENO
+// la      a1, message
+// li      a2, 13           # message length
+// ecall
+// ```
+//
+// ref:
+// - https://man7.org/linux/man-pages/man2/syscall.2.html
+// - https://github.com/riscv-non-isa/riscv-elf-psabi-doc
+// - Rust inline assembly
+//   https://doc.rust-lang.org/stable/reference/inline-assembly.html
+use std::arch::asm;
+
+use crate::arch::MAX_ERRNO;
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_without_args(num: usize) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        lateout("a0") result,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_1_arg(num: usize, arg1: usize) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        inlateout("a0") arg1 => result,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_2_args(num: usize, arg1: usize, arg2: usize) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        inlateout("a0") arg1 => result,
+        in("a1") arg2,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_3_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        inlateout("a0") arg1 => result,
+        in("a1") arg2,
+        in("a2") arg3,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_4_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        inlateout("a0") arg1 => result,
+        in("a1") arg2,
+        in("a2") arg3,
+        in("a3") arg4,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_5_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        inlateout("a0") arg1 => result,
+        in("a1") arg2,
+        in("a2") arg3,
+        in("a3") arg4,
+        in("a4") arg5,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_6_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> Result<usize, usize> {
+    let mut result: isize;
+    asm!(
+        "ecall",
+        in("a7") num,
+        inlateout("a0") arg1 => result,
+        in("a1") arg2,
+        in("a2") arg3,
+        in("a3") arg4,
+        in("a4") arg5,
+        in("a5") arg6,
+        options(nostack, preserves_flags)
+    );
+    convert_raw_return_code_from_a0(result)
+}
+
+// for the syscalls which never return on success, e.g. `exit`, `exit_group` and
+// `rt_sigreturn`, the caller must ensure that the syscall does not return (i.e. it
+// can not fail).
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_without_args(num: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") num,
+        options(noreturn, nostack)
+    );
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_with_1_arg(num: usize, arg1: usize) -> ! {
+    asm!(
+        "ecall",
+        in("a7") num,
+        in("a0") arg1,
+        options(noreturn, nostack)
+    );
+}
+
+// only the values in the range of `-MAX_ERRNO..=-1` are errors, see `arch::MAX_ERRNO`.
+#[inline(always)]
+fn convert_raw_return_code_from_a0(raw_code: isize) -> Result<usize, usize> {
+    if (-(MAX_ERRNO as isize)..=-1).contains(&raw_code) {
+        Err((-raw_code) as usize)
+    } else {
+        Ok(raw_code as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        arch::riscv64::{
+            call::{
+                convert_raw_return_code_from_a0, syscall_with_1_arg, syscall_with_4_args,
+                syscall_without_args,
+            },
+            number::SysCallNum,
+        },
+        errno::Errno,
+    };
+
+    #[test]
+    fn test_syscall() {
+        let result0 = unsafe { syscall_without_args(SysCallNum::getpid as usize) };
+        assert!(matches!(result0, Ok(pid) if pid > 0));
+
+        // AT_FDCWD = -100
+        let at_fdcwd = -100isize as usize;
+        let file_path0 = b"/this/file/should/not/exist\0";
+        let result1 = unsafe {
+            syscall_with_4_args(
+                SysCallNum::openat as usize,
+                at_fdcwd,
+                file_path0.as_ptr() as usize,
+                0,
+                0,
+            )
+        };
+        assert!(matches!(result1, Err(errno) if errno == Errno::ENOENT as usize));
+
+        let file_path1 = b"/dev/zero\0";
+        let result2 = unsafe {
+            syscall_with_4_args(
+                SysCallNum::openat as usize,
+                at_fdcwd,
+                file_path1.as_ptr() as usize,
+                0,
+                0,
+            )
+        };
+        let fd = result2.unwrap();
+
+        let result3 = unsafe { syscall_with_1_arg(SysCallNum::close as usize, fd) };
+        assert!(matches!(result3, Ok(0)));
+    }
+
+    #[test]
+    fn test_convert_raw_return_code() {
+        assert_eq!(convert_raw_return_code_from_a0(0), Ok(0));
+        assert_eq!(convert_raw_return_code_from_a0(-1), Err(1));
+        assert_eq!(convert_raw_return_code_from_a0(-4095), Err(4095));
+
+        // the values below -4095 are not errors, e.g. an address returned by `mmap`
+        assert_eq!(
+            convert_raw_return_code_from_a0(-4096),
+            Ok(usize::MAX - 4095)
+        );
+        assert_eq!(
+            convert_raw_return_code_from_a0(isize::MIN),
+            Ok(isize::MIN as usize)
+        );
+    }
+}
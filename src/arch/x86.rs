@@ -0,0 +1,15 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the 32-bit x86 (i386) syscalls.
+//
+// the module `call` invokes the i386 syscalls through the IA32 emulation
+// (compat mode) of the x86_64 kernel by the instruction `int 0x80`, it
+// is used for testing the syscall filters (e.g. seccomp) and the tracers
+// of 32-bit processes, it is not intended for the regular syscalls.
+#[cfg(target_arch = "x86_64")]
+pub mod call;
+pub mod number;
@@ -0,0 +1,413 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// i386 ABI/calling convention of syscall (`int 0x80`)
+//
+// | register | usage    |
+// |----------|----------|
+// | eax      | call num | also use for store the return value.
+// | ebx      | 1st      |
+// | ecx      | 2nd      |
+// | edx      | 3rd      |
+// | esi      | 4th      |
+// | edi      | 5th      |
+// | ebp      | 6th      |
+//
+// this module invokes the i386 syscalls from a x86_64 process through the
+// IA32 emulation (compat mode) of the kernel, the kernel must be built
+// with `CONFIG_IA32_EMULATION` and it must not be disabled by the boot
+// parameter `ia32_emulation=false`, otherwise every call fails with
+// the signal SIGSEGV.
+//
+// note:
+//
+// - only the lower 32 bits of the syscall number and the arguments are
+//   passed to the kernel, so the pointer arguments MUST point to the memory
+//   below 4GB, e.g. the memory allocated by `mmap` with the flag `MAP_32BIT`.
+//   the arguments are kept as `usize` to keep the same signatures as the
+//   module `arch::x86_64::call`.
+// - `rbx` and `rbp` can not be used as the operands of the Rust inline ASM
+//   (they are reserved by LLVM), so the 1st argument is swapped into `rbx` by
+//   `xchg`, and `rbp` is saved on the stack when passing the 6th argument.
+// - the kernel does not preserve `r8`-`r11` for `int 0x80` from a 64-bit
+//   process, they are marked as 'out' in the inline ASM.
+//
+// ref:
+// - https://man7.org/linux/man-pages/man2/syscall.2.html
+// - https://www.kernel.org/doc/html/latest/arch/x86/entry_64.html
+use std::arch::asm;
+
+use crate::arch::MAX_ERRNO;
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_without_args(num: usize) -> Result<usize, usize> {
+    let mut result: u32;
+    asm!(
+        "int 0x80",
+        inlateout("eax") num as u32 => result,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+        options(nostack)
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_1_arg(num: usize, arg1: usize) -> Result<usize, usize> {
+    let mut result: u32;
+    asm!(
+        "xchg {arg1}, rbx",
+        "int 0x80",
+        "xchg {arg1}, rbx",
+        arg1 = in(reg) arg1,
+        inlateout("eax") num as u32 => result,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+        options(nostack)
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_2_args(num: usize, arg1: usize, arg2: usize) -> Result<usize, usize> {
+    let mut result: u32;
+    asm!(
+        "xchg {arg1}, rbx",
+        "int 0x80",
+        "xchg {arg1}, rbx",
+        arg1 = in(reg) arg1,
+        inlateout("eax") num as u32 => result,
+        in("ecx") arg2 as u32,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+        options(nostack)
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_3_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> Result<usize, usize> {
+    let mut result: u32;
+    asm!(
+        "xchg {arg1}, rbx",
+        "int 0x80",
+        "xchg {arg1}, rbx",
+        arg1 = in(reg) arg1,
+        inlateout("eax") num as u32 => result,
+        in("ecx") arg2 as u32,
+        in("edx") arg3 as u32,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+        options(nostack)
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_4_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> Result<usize, usize> {
+    let mut result: u32;
+    asm!(
+        "xchg {arg1}, rbx",
+        "int 0x80",
+        "xchg {arg1}, rbx",
+        arg1 = in(reg) arg1,
+        inlateout("eax") num as u32 => result,
+        in("ecx") arg2 as u32,
+        in("edx") arg3 as u32,
+        in("esi") arg4 as u32,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+        options(nostack)
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_5_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> Result<usize, usize> {
+    let mut result: u32;
+    asm!(
+        "xchg {arg1}, rbx",
+        "int 0x80",
+        "xchg {arg1}, rbx",
+        arg1 = in(reg) arg1,
+        inlateout("eax") num as u32 => result,
+        in("ecx") arg2 as u32,
+        in("edx") arg3 as u32,
+        in("esi") arg4 as u32,
+        in("edi") arg5 as u32,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+        options(nostack)
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_6_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> Result<usize, usize> {
+    let mut result: u32;
+
+    // `rbp` is pushed onto the stack, so the option `nostack` is not applicable.
+    asm!(
+        "xchg {arg1}, rbx",
+        "push rbp",
+        "mov ebp, {arg6:e}",
+        "int 0x80",
+        "pop rbp",
+        "xchg {arg1}, rbx",
+        arg1 = in(reg) arg1,
+        arg6 = in(reg) arg6,
+        inlateout("eax") num as u32 => result,
+        in("ecx") arg2 as u32,
+        in("edx") arg3 as u32,
+        in("esi") arg4 as u32,
+        in("edi") arg5 as u32,
+        out("r8") _,
+        out("r9") _,
+        out("r10") _,
+        out("r11") _,
+    );
+    convert_raw_return_code_from_eax(result)
+}
+
+// for the syscalls which never return on success, e.g. `exit`, `exit_group` and
+// `rt_sigreturn`, the caller must ensure that the syscall does not return (i.e. it
+// can not fail).
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_without_args(num: usize) -> ! {
+    asm!(
+        "int 0x80",
+        in("eax") num as u32,
+        options(noreturn, nostack)
+    );
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_with_1_arg(num: usize, arg1: usize) -> ! {
+    // `rbx` does not need to be restored since the function never returns.
+    asm!(
+        "mov ebx, {arg1:e}",
+        "int 0x80",
+        arg1 = in(reg) arg1,
+        in("eax") num as u32,
+        options(noreturn, nostack)
+    );
+}
+
+// only the values in the range of `-MAX_ERRNO..=-1` are errors, see `arch::MAX_ERRNO`,
+// the other values (including the addresses above 2GB returned by e.g. `mmap2`)
+// are the successful results.
+#[inline(always)]
+fn convert_raw_return_code_from_eax(raw_code: u32) -> Result<usize, usize> {
+    let signed_code = raw_code as i32;
+    if (-(MAX_ERRNO as i32)..=-1).contains(&signed_code) {
+        Err((-signed_code) as usize)
+    } else {
+        Ok(raw_code as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        arch::{
+            x86::{
+                call::{
+                    convert_raw_return_code_from_eax, syscall_with_1_arg, syscall_with_2_args,
+                    syscall_with_3_args, syscall_with_6_args, syscall_without_args,
+                },
+                number::SysCallNum,
+            },
+            x86_64,
+        },
+        errno::Errno,
+    };
+
+    // `int 0x80` raises SIGSEGV when the IA32 emulation is not available,
+    // which kills the whole test process rather than failing one test, so
+    // probe it in a child process first.
+    fn ia32_emulation_available() -> bool {
+        let pid = unsafe {
+            x86_64::call::syscall_without_args(x86_64::number::SysCallNum::fork as usize)
+        }
+        .unwrap();
+
+        if pid == 0 {
+            // the child only invokes the raw syscalls, and never returns
+            // to the test harness.
+            let code = match unsafe { syscall_without_args(SysCallNum::getpid as usize) } {
+                Ok(_) => 0,
+                Err(_) => 1,
+            };
+            unsafe {
+                let _ = x86_64::call::syscall_with_1_arg(
+                    x86_64::number::SysCallNum::exit_group as usize,
+                    code,
+                );
+            }
+            unreachable!();
+        }
+
+        let mut status: i32 = -1;
+        unsafe {
+            x86_64::call::syscall_with_4_args(
+                x86_64::number::SysCallNum::wait4 as usize,
+                pid,
+                &mut status as *mut i32 as usize,
+                0,
+                0,
+            )
+        }
+        .unwrap();
+
+        // i.e. `WIFEXITED(status) && WEXITSTATUS(status) == 0`
+        status == 0
+    }
+
+    #[test]
+    fn test_syscall() {
+        if !ia32_emulation_available() {
+            eprintln!("IA32 emulation is not available, skip testing `int 0x80`");
+            return;
+        }
+
+        let pid = unsafe {
+            x86_64::call::syscall_without_args(x86_64::number::SysCallNum::getpid as usize)
+        };
+        let result0 = unsafe { syscall_without_args(SysCallNum::getpid as usize) };
+        assert_eq!(result0, pid);
+
+        // fd -1 is never valid
+        let result1 = unsafe { syscall_with_1_arg(SysCallNum::close as usize, -1isize as usize) };
+        assert!(matches!(result1, Err(errno) if errno == Errno::EBADF as usize));
+
+        // the path must be located below 4GB, allocate it by the x86_64 `mmap`
+        // with flags `MAP_PRIVATE | MAP_ANONYMOUS | MAP_32BIT`.
+        let buf = unsafe {
+            x86_64::call::syscall_with_6_args(
+                x86_64::number::SysCallNum::mmap as usize,
+                0,
+                4096,
+                0x1 | 0x2,
+                0x02 | 0x20 | 0x40,
+                -1isize as usize,
+                0,
+            )
+        }
+        .unwrap();
+        assert!(buf < (1 << 32));
+
+        let file_path = b"/dev/zero\0";
+        unsafe {
+            std::ptr::copy_nonoverlapping(file_path.as_ptr(), buf as *mut u8, file_path.len());
+        }
+
+        let result2 = unsafe { syscall_with_3_args(SysCallNum::open as usize, buf, 0, 0) };
+        let fd = result2.unwrap();
+
+        let result3 = unsafe { syscall_with_1_arg(SysCallNum::close as usize, fd) };
+        assert!(matches!(result3, Ok(0)));
+
+        unsafe {
+            x86_64::call::syscall_with_2_args(
+                x86_64::number::SysCallNum::munmap as usize,
+                buf,
+                4096,
+            )
+        }
+        .unwrap();
+    }
+
+    #[test]
+    fn test_syscall_with_6_args() {
+        if !ia32_emulation_available() {
+            eprintln!("IA32 emulation is not available, skip testing `int 0x80`");
+            return;
+        }
+
+        // mmap2 takes 6 arguments, the last one (the offset in pages) is passed by `ebp`
+        let addr = unsafe {
+            syscall_with_6_args(
+                SysCallNum::mmap2 as usize,
+                0,
+                4096,
+                0x1 | 0x2,
+                0x02 | 0x20,
+                -1isize as usize,
+                0,
+            )
+        }
+        .unwrap();
+        assert!(addr > 0 && addr < (1 << 32));
+
+        unsafe {
+            (addr as *mut u32).write(0xcafe);
+            assert_eq!((addr as *const u32).read(), 0xcafe);
+        }
+
+        let result = unsafe { syscall_with_2_args(SysCallNum::munmap as usize, addr, 4096) };
+        assert!(matches!(result, Ok(0)));
+    }
+
+    #[test]
+    fn test_convert_raw_return_code() {
+        assert_eq!(convert_raw_return_code_from_eax(0), Ok(0));
+        assert_eq!(convert_raw_return_code_from_eax(-1i32 as u32), Err(1));
+        assert_eq!(convert_raw_return_code_from_eax(-4095i32 as u32), Err(4095));
+
+        // the addresses above 2GB, e.g. returned by `mmap2`
+        assert_eq!(
+            convert_raw_return_code_from_eax(0xf7ff_0000),
+            Ok(0xf7ff_0000)
+        );
+    }
+}
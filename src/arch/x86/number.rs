@@ -0,0 +1,1003 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// THIS FILE IS GENERATED BY 'examples/gen_syscall_num.rs', DO NOT EDIT.
+//
+// the following definition come from Linux (kernel 6.14) source file:
+// 'arch/x86/entry/syscalls/syscall_32.tbl'
+// (a copy of the file is located at 'src/arch/x86/syscall_32.tbl')
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_syscall_num -- x86 src/arch/x86/syscall_32.tbl 6.14 \
+//       > src/arch/x86/number.rs
+// ```
+//
+// ref:
+// - https://man7.org/linux/man-pages/man2/syscall.2.html
+// - https://syscalls.mebeim.net/?table=x86/32/ia32/latest
+//
+// the macro `syscall_num!` generates the enum `SysCallNum` as well as
+// the functions for looking up syscall by number and name, see 'src/macros.rs'.
+
+use crate::arch::SysCallAbi;
+
+// the syscalls of the i386 ABI, i.e. the 32-bit x86 and the IA32
+// emulation (compat mode) on x86_64 which is invoked by `int 0x80`.
+syscall_num! {
+    pub enum SysCallNum {
+        restart_syscall = 0,                // i386    sys_restart_syscall
+        exit = 1,                           // i386    sys_exit
+        fork = 2,                           // i386    sys_fork
+        read = 3,                           // i386    sys_read
+        write = 4,                          // i386    sys_write
+        open = 5,                           // i386    sys_open
+        close = 6,                          // i386    sys_close
+        waitpid = 7,                        // i386    sys_waitpid
+        creat = 8,                          // i386    sys_creat
+        link = 9,                           // i386    sys_link
+        unlink = 10,                        // i386    sys_unlink
+        execve = 11,                        // i386    sys_execve
+        chdir = 12,                         // i386    sys_chdir
+        time = 13,                          // i386    sys_time32
+        mknod = 14,                         // i386    sys_mknod
+        chmod = 15,                         // i386    sys_chmod
+        lchown = 16,                        // i386    sys_lchown16
+        #[name = "break"]
+        break_ = 17,                        // i386
+        oldstat = 18,                       // i386    sys_stat
+        lseek = 19,                         // i386    sys_lseek
+        getpid = 20,                        // i386    sys_getpid
+        mount = 21,                         // i386    sys_mount
+        umount = 22,                        // i386    sys_oldumount
+        setuid = 23,                        // i386    sys_setuid16
+        getuid = 24,                        // i386    sys_getuid16
+        stime = 25,                         // i386    sys_stime32
+        ptrace = 26,                        // i386    sys_ptrace
+        alarm = 27,                         // i386    sys_alarm
+        oldfstat = 28,                      // i386    sys_fstat
+        pause = 29,                         // i386    sys_pause
+        utime = 30,                         // i386    sys_utime32
+        stty = 31,                          // i386
+        gtty = 32,                          // i386
+        access = 33,                        // i386    sys_access
+        nice = 34,                          // i386    sys_nice
+        ftime = 35,                         // i386
+        sync = 36,                          // i386    sys_sync
+        kill = 37,                          // i386    sys_kill
+        rename = 38,                        // i386    sys_rename
+        mkdir = 39,                         // i386    sys_mkdir
+        rmdir = 40,                         // i386    sys_rmdir
+        dup = 41,                           // i386    sys_dup
+        pipe = 42,                          // i386    sys_pipe
+        times = 43,                         // i386    sys_times
+        prof = 44,                          // i386
+        brk = 45,                           // i386    sys_brk
+        setgid = 46,                        // i386    sys_setgid16
+        getgid = 47,                        // i386    sys_getgid16
+        signal = 48,                        // i386    sys_signal
+        geteuid = 49,                       // i386    sys_geteuid16
+        getegid = 50,                       // i386    sys_getegid16
+        acct = 51,                          // i386    sys_acct
+        umount2 = 52,                       // i386    sys_umount
+        lock = 53,                          // i386
+        ioctl = 54,                         // i386    sys_ioctl
+        fcntl = 55,                         // i386    sys_fcntl
+        mpx = 56,                           // i386
+        setpgid = 57,                       // i386    sys_setpgid
+        ulimit = 58,                        // i386
+        oldolduname = 59,                   // i386    sys_olduname
+        umask = 60,                         // i386    sys_umask
+        chroot = 61,                        // i386    sys_chroot
+        ustat = 62,                         // i386    sys_ustat
+        dup2 = 63,                          // i386    sys_dup2
+        getppid = 64,                       // i386    sys_getppid
+        getpgrp = 65,                       // i386    sys_getpgrp
+        setsid = 66,                        // i386    sys_setsid
+        sigaction = 67,                     // i386    sys_sigaction
+        sgetmask = 68,                      // i386    sys_sgetmask
+        ssetmask = 69,                      // i386    sys_ssetmask
+        setreuid = 70,                      // i386    sys_setreuid16
+        setregid = 71,                      // i386    sys_setregid16
+        sigsuspend = 72,                    // i386    sys_sigsuspend
+        sigpending = 73,                    // i386    sys_sigpending
+        sethostname = 74,                   // i386    sys_sethostname
+        setrlimit = 75,                     // i386    sys_setrlimit
+        getrlimit = 76,                     // i386    sys_old_getrlimit
+        getrusage = 77,                     // i386    sys_getrusage
+        gettimeofday = 78,                  // i386    sys_gettimeofday
+        settimeofday = 79,                  // i386    sys_settimeofday
+        getgroups = 80,                     // i386    sys_getgroups16
+        setgroups = 81,                     // i386    sys_setgroups16
+        select = 82,                        // i386    sys_old_select
+        symlink = 83,                       // i386    sys_symlink
+        oldlstat = 84,                      // i386    sys_lstat
+        readlink = 85,                      // i386    sys_readlink
+        uselib = 86,                        // i386    sys_uselib
+        swapon = 87,                        // i386    sys_swapon
+        reboot = 88,                        // i386    sys_reboot
+        readdir = 89,                       // i386    sys_old_readdir
+        mmap = 90,                          // i386    sys_old_mmap
+        munmap = 91,                        // i386    sys_munmap
+        truncate = 92,                      // i386    sys_truncate
+        ftruncate = 93,                     // i386    sys_ftruncate
+        fchmod = 94,                        // i386    sys_fchmod
+        fchown = 95,                        // i386    sys_fchown16
+        getpriority = 96,                   // i386    sys_getpriority
+        setpriority = 97,                   // i386    sys_setpriority
+        profil = 98,                        // i386
+        statfs = 99,                        // i386    sys_statfs
+        fstatfs = 100,                      // i386    sys_fstatfs
+        ioperm = 101,                       // i386    sys_ioperm
+        socketcall = 102,                   // i386    sys_socketcall
+        syslog = 103,                       // i386    sys_syslog
+        setitimer = 104,                    // i386    sys_setitimer
+        getitimer = 105,                    // i386    sys_getitimer
+        stat = 106,                         // i386    sys_newstat
+        lstat = 107,                        // i386    sys_newlstat
+        fstat = 108,                        // i386    sys_newfstat
+        olduname = 109,                     // i386    sys_uname
+        iopl = 110,                         // i386    sys_iopl
+        vhangup = 111,                      // i386    sys_vhangup
+        idle = 112,                         // i386
+        vm86old = 113,                      // i386    sys_vm86old
+        wait4 = 114,                        // i386    sys_wait4
+        swapoff = 115,                      // i386    sys_swapoff
+        sysinfo = 116,                      // i386    sys_sysinfo
+        ipc = 117,                          // i386    sys_ipc
+        fsync = 118,                        // i386    sys_fsync
+        sigreturn = 119,                    // i386    sys_sigreturn
+        clone = 120,                        // i386    sys_clone
+        setdomainname = 121,                // i386    sys_setdomainname
+        uname = 122,                        // i386    sys_newuname
+        modify_ldt = 123,                   // i386    sys_modify_ldt
+        adjtimex = 124,                     // i386    sys_adjtimex_time32
+        mprotect = 125,                     // i386    sys_mprotect
+        sigprocmask = 126,                  // i386    sys_sigprocmask
+        create_module = 127,                // i386
+        init_module = 128,                  // i386    sys_init_module
+        delete_module = 129,                // i386    sys_delete_module
+        get_kernel_syms = 130,              // i386
+        quotactl = 131,                     // i386    sys_quotactl
+        getpgid = 132,                      // i386    sys_getpgid
+        fchdir = 133,                       // i386    sys_fchdir
+        bdflush = 134,                      // i386
+        sysfs = 135,                        // i386    sys_sysfs
+        personality = 136,                  // i386    sys_personality
+        afs_syscall = 137,                  // i386
+        setfsuid = 138,                     // i386    sys_setfsuid16
+        setfsgid = 139,                     // i386    sys_setfsgid16
+        #[name = "_llseek"]
+        llseek_ = 140,                      // i386    sys_llseek
+        getdents = 141,                     // i386    sys_getdents
+        #[name = "_newselect"]
+        newselect_ = 142,                   // i386    sys_select
+        flock = 143,                        // i386    sys_flock
+        msync = 144,                        // i386    sys_msync
+        readv = 145,                        // i386    sys_readv
+        writev = 146,                       // i386    sys_writev
+        getsid = 147,                       // i386    sys_getsid
+        fdatasync = 148,                    // i386    sys_fdatasync
+        #[name = "_sysctl"]
+        sysctl_ = 149,                      // i386    sys_ni_syscall
+        mlock = 150,                        // i386    sys_mlock
+        munlock = 151,                      // i386    sys_munlock
+        mlockall = 152,                     // i386    sys_mlockall
+        munlockall = 153,                   // i386    sys_munlockall
+        sched_setparam = 154,               // i386    sys_sched_setparam
+        sched_getparam = 155,               // i386    sys_sched_getparam
+        sched_setscheduler = 156,           // i386    sys_sched_setscheduler
+        sched_getscheduler = 157,           // i386    sys_sched_getscheduler
+        sched_yield = 158,                  // i386    sys_sched_yield
+        sched_get_priority_max = 159,       // i386    sys_sched_get_priority_max
+        sched_get_priority_min = 160,       // i386    sys_sched_get_priority_min
+        sched_rr_get_interval = 161,        // i386    sys_sched_rr_get_interval_time32
+        nanosleep = 162,                    // i386    sys_nanosleep_time32
+        mremap = 163,                       // i386    sys_mremap
+        setresuid = 164,                    // i386    sys_setresuid16
+        getresuid = 165,                    // i386    sys_getresuid16
+        vm86 = 166,                         // i386    sys_vm86
+        query_module = 167,                 // i386
+        poll = 168,                         // i386    sys_poll
+        nfsservctl = 169,                   // i386
+        setresgid = 170,                    // i386    sys_setresgid16
+        getresgid = 171,                    // i386    sys_getresgid16
+        prctl = 172,                        // i386    sys_prctl
+        rt_sigreturn = 173,                 // i386    sys_rt_sigreturn
+        rt_sigaction = 174,                 // i386    sys_rt_sigaction
+        rt_sigprocmask = 175,               // i386    sys_rt_sigprocmask
+        rt_sigpending = 176,                // i386    sys_rt_sigpending
+        rt_sigtimedwait = 177,              // i386    sys_rt_sigtimedwait_time32
+        rt_sigqueueinfo = 178,              // i386    sys_rt_sigqueueinfo
+        rt_sigsuspend = 179,                // i386    sys_rt_sigsuspend
+        pread64 = 180,                      // i386    sys_ia32_pread64
+        pwrite64 = 181,                     // i386    sys_ia32_pwrite64
+        chown = 182,                        // i386    sys_chown16
+        getcwd = 183,                       // i386    sys_getcwd
+        capget = 184,                       // i386    sys_capget
+        capset = 185,                       // i386    sys_capset
+        sigaltstack = 186,                  // i386    sys_sigaltstack
+        sendfile = 187,                     // i386    sys_sendfile
+        getpmsg = 188,                      // i386
+        putpmsg = 189,                      // i386
+        vfork = 190,                        // i386    sys_vfork
+        ugetrlimit = 191,                   // i386    sys_getrlimit
+        mmap2 = 192,                        // i386    sys_mmap_pgoff
+        truncate64 = 193,                   // i386    sys_ia32_truncate64
+        ftruncate64 = 194,                  // i386    sys_ia32_ftruncate64
+        stat64 = 195,                       // i386    sys_stat64
+        lstat64 = 196,                      // i386    sys_lstat64
+        fstat64 = 197,                      // i386    sys_fstat64
+        lchown32 = 198,                     // i386    sys_lchown
+        getuid32 = 199,                     // i386    sys_getuid
+        getgid32 = 200,                     // i386    sys_getgid
+        geteuid32 = 201,                    // i386    sys_geteuid
+        getegid32 = 202,                    // i386    sys_getegid
+        setreuid32 = 203,                   // i386    sys_setreuid
+        setregid32 = 204,                   // i386    sys_setregid
+        getgroups32 = 205,                  // i386    sys_getgroups
+        setgroups32 = 206,                  // i386    sys_setgroups
+        fchown32 = 207,                     // i386    sys_fchown
+        setresuid32 = 208,                  // i386    sys_setresuid
+        getresuid32 = 209,                  // i386    sys_getresuid
+        setresgid32 = 210,                  // i386    sys_setresgid
+        getresgid32 = 211,                  // i386    sys_getresgid
+        chown32 = 212,                      // i386    sys_chown
+        setuid32 = 213,                     // i386    sys_setuid
+        setgid32 = 214,                     // i386    sys_setgid
+        setfsuid32 = 215,                   // i386    sys_setfsuid
+        setfsgid32 = 216,                   // i386    sys_setfsgid
+        pivot_root = 217,                   // i386    sys_pivot_root
+        mincore = 218,                      // i386    sys_mincore
+        madvise = 219,                      // i386    sys_madvise
+        getdents64 = 220,                   // i386    sys_getdents64
+        fcntl64 = 221,                      // i386    sys_fcntl64
+        // 222 - 223 are not used
+        gettid = 224,                       // i386    sys_gettid
+        readahead = 225,                    // i386    sys_ia32_readahead
+        setxattr = 226,                     // i386    sys_setxattr
+        lsetxattr = 227,                    // i386    sys_lsetxattr
+        fsetxattr = 228,                    // i386    sys_fsetxattr
+        getxattr = 229,                     // i386    sys_getxattr
+        lgetxattr = 230,                    // i386    sys_lgetxattr
+        fgetxattr = 231,                    // i386    sys_fgetxattr
+        listxattr = 232,                    // i386    sys_listxattr
+        llistxattr = 233,                   // i386    sys_llistxattr
+        flistxattr = 234,                   // i386    sys_flistxattr
+        removexattr = 235,                  // i386    sys_removexattr
+        lremovexattr = 236,                 // i386    sys_lremovexattr
+        fremovexattr = 237,                 // i386    sys_fremovexattr
+        tkill = 238,                        // i386    sys_tkill
+        sendfile64 = 239,                   // i386    sys_sendfile64
+        futex = 240,                        // i386    sys_futex_time32
+        sched_setaffinity = 241,            // i386    sys_sched_setaffinity
+        sched_getaffinity = 242,            // i386    sys_sched_getaffinity
+        set_thread_area = 243,              // i386    sys_set_thread_area
+        get_thread_area = 244,              // i386    sys_get_thread_area
+        io_setup = 245,                     // i386    sys_io_setup
+        io_destroy = 246,                   // i386    sys_io_destroy
+        io_getevents = 247,                 // i386    sys_io_getevents_time32
+        io_submit = 248,                    // i386    sys_io_submit
+        io_cancel = 249,                    // i386    sys_io_cancel
+        fadvise64 = 250,                    // i386    sys_ia32_fadvise64
+        // 251 - 251 are not used
+        exit_group = 252,                   // i386    sys_exit_group
+        lookup_dcookie = 253,               // i386
+        epoll_create = 254,                 // i386    sys_epoll_create
+        epoll_ctl = 255,                    // i386    sys_epoll_ctl
+        epoll_wait = 256,                   // i386    sys_epoll_wait
+        remap_file_pages = 257,             // i386    sys_remap_file_pages
+        set_tid_address = 258,              // i386    sys_set_tid_address
+        timer_create = 259,                 // i386    sys_timer_create
+        timer_settime = 260,                // i386    sys_timer_settime32
+        timer_gettime = 261,                // i386    sys_timer_gettime32
+        timer_getoverrun = 262,             // i386    sys_timer_getoverrun
+        timer_delete = 263,                 // i386    sys_timer_delete
+        clock_settime = 264,                // i386    sys_clock_settime32
+        clock_gettime = 265,                // i386    sys_clock_gettime32
+        clock_getres = 266,                 // i386    sys_clock_getres_time32
+        clock_nanosleep = 267,              // i386    sys_clock_nanosleep_time32
+        statfs64 = 268,                     // i386    sys_statfs64
+        fstatfs64 = 269,                    // i386    sys_fstatfs64
+        tgkill = 270,                       // i386    sys_tgkill
+        utimes = 271,                       // i386    sys_utimes_time32
+        fadvise64_64 = 272,                 // i386    sys_ia32_fadvise64_64
+        vserver = 273,                      // i386
+        mbind = 274,                        // i386    sys_mbind
+        get_mempolicy = 275,                // i386    sys_get_mempolicy
+        set_mempolicy = 276,                // i386    sys_set_mempolicy
+        mq_open = 277,                      // i386    sys_mq_open
+        mq_unlink = 278,                    // i386    sys_mq_unlink
+        mq_timedsend = 279,                 // i386    sys_mq_timedsend_time32
+        mq_timedreceive = 280,              // i386    sys_mq_timedreceive_time32
+        mq_notify = 281,                    // i386    sys_mq_notify
+        mq_getsetattr = 282,                // i386    sys_mq_getsetattr
+        kexec_load = 283,                   // i386    sys_kexec_load
+        waitid = 284,                       // i386    sys_waitid
+        // 285 - 285 are not used
+        add_key = 286,                      // i386    sys_add_key
+        request_key = 287,                  // i386    sys_request_key
+        keyctl = 288,                       // i386    sys_keyctl
+        ioprio_set = 289,                   // i386    sys_ioprio_set
+        ioprio_get = 290,                   // i386    sys_ioprio_get
+        inotify_init = 291,                 // i386    sys_inotify_init
+        inotify_add_watch = 292,            // i386    sys_inotify_add_watch
+        inotify_rm_watch = 293,             // i386    sys_inotify_rm_watch
+        migrate_pages = 294,                // i386    sys_migrate_pages
+        openat = 295,                       // i386    sys_openat
+        mkdirat = 296,                      // i386    sys_mkdirat
+        mknodat = 297,                      // i386    sys_mknodat
+        fchownat = 298,                     // i386    sys_fchownat
+        futimesat = 299,                    // i386    sys_futimesat_time32
+        fstatat64 = 300,                    // i386    sys_fstatat64
+        unlinkat = 301,                     // i386    sys_unlinkat
+        renameat = 302,                     // i386    sys_renameat
+        linkat = 303,                       // i386    sys_linkat
+        symlinkat = 304,                    // i386    sys_symlinkat
+        readlinkat = 305,                   // i386    sys_readlinkat
+        fchmodat = 306,                     // i386    sys_fchmodat
+        faccessat = 307,                    // i386    sys_faccessat
+        pselect6 = 308,                     // i386    sys_pselect6_time32
+        ppoll = 309,                        // i386    sys_ppoll_time32
+        unshare = 310,                      // i386    sys_unshare
+        set_robust_list = 311,              // i386    sys_set_robust_list
+        get_robust_list = 312,              // i386    sys_get_robust_list
+        splice = 313,                       // i386    sys_splice
+        sync_file_range = 314,              // i386    sys_ia32_sync_file_range
+        tee = 315,                          // i386    sys_tee
+        vmsplice = 316,                     // i386    sys_vmsplice
+        move_pages = 317,                   // i386    sys_move_pages
+        getcpu = 318,                       // i386    sys_getcpu
+        epoll_pwait = 319,                  // i386    sys_epoll_pwait
+        utimensat = 320,                    // i386    sys_utimensat_time32
+        signalfd = 321,                     // i386    sys_signalfd
+        timerfd_create = 322,               // i386    sys_timerfd_create
+        eventfd = 323,                      // i386    sys_eventfd
+        fallocate = 324,                    // i386    sys_ia32_fallocate
+        timerfd_settime = 325,              // i386    sys_timerfd_settime32
+        timerfd_gettime = 326,              // i386    sys_timerfd_gettime32
+        signalfd4 = 327,                    // i386    sys_signalfd4
+        eventfd2 = 328,                     // i386    sys_eventfd2
+        epoll_create1 = 329,                // i386    sys_epoll_create1
+        dup3 = 330,                         // i386    sys_dup3
+        pipe2 = 331,                        // i386    sys_pipe2
+        inotify_init1 = 332,                // i386    sys_inotify_init1
+        preadv = 333,                       // i386    sys_preadv
+        pwritev = 334,                      // i386    sys_pwritev
+        rt_tgsigqueueinfo = 335,            // i386    sys_rt_tgsigqueueinfo
+        perf_event_open = 336,              // i386    sys_perf_event_open
+        recvmmsg = 337,                     // i386    sys_recvmmsg_time32
+        fanotify_init = 338,                // i386    sys_fanotify_init
+        fanotify_mark = 339,                // i386    sys_fanotify_mark
+        prlimit64 = 340,                    // i386    sys_prlimit64
+        name_to_handle_at = 341,            // i386    sys_name_to_handle_at
+        open_by_handle_at = 342,            // i386    sys_open_by_handle_at
+        clock_adjtime = 343,                // i386    sys_clock_adjtime32
+        syncfs = 344,                       // i386    sys_syncfs
+        sendmmsg = 345,                     // i386    sys_sendmmsg
+        setns = 346,                        // i386    sys_setns
+        process_vm_readv = 347,             // i386    sys_process_vm_readv
+        process_vm_writev = 348,            // i386    sys_process_vm_writev
+        kcmp = 349,                         // i386    sys_kcmp
+        finit_module = 350,                 // i386    sys_finit_module
+        sched_setattr = 351,                // i386    sys_sched_setattr
+        sched_getattr = 352,                // i386    sys_sched_getattr
+        renameat2 = 353,                    // i386    sys_renameat2
+        seccomp = 354,                      // i386    sys_seccomp
+        getrandom = 355,                    // i386    sys_getrandom
+        memfd_create = 356,                 // i386    sys_memfd_create
+        bpf = 357,                          // i386    sys_bpf
+        execveat = 358,                     // i386    sys_execveat
+        socket = 359,                       // i386    sys_socket
+        socketpair = 360,                   // i386    sys_socketpair
+        bind = 361,                         // i386    sys_bind
+        connect = 362,                      // i386    sys_connect
+        listen = 363,                       // i386    sys_listen
+        accept4 = 364,                      // i386    sys_accept4
+        getsockopt = 365,                   // i386    sys_getsockopt
+        setsockopt = 366,                   // i386    sys_setsockopt
+        getsockname = 367,                  // i386    sys_getsockname
+        getpeername = 368,                  // i386    sys_getpeername
+        sendto = 369,                       // i386    sys_sendto
+        recvfrom = 370,                     // i386    sys_recvfrom
+        sendmsg = 371,                      // i386    sys_sendmsg
+        recvmsg = 372,                      // i386    sys_recvmsg
+        shutdown = 373,                     // i386    sys_shutdown
+        userfaultfd = 374,                  // i386    sys_userfaultfd
+        membarrier = 375,                   // i386    sys_membarrier
+        mlock2 = 376,                       // i386    sys_mlock2
+        copy_file_range = 377,              // i386    sys_copy_file_range
+        preadv2 = 378,                      // i386    sys_preadv2
+        pwritev2 = 379,                     // i386    sys_pwritev2
+        pkey_mprotect = 380,                // i386    sys_pkey_mprotect
+        pkey_alloc = 381,                   // i386    sys_pkey_alloc
+        pkey_free = 382,                    // i386    sys_pkey_free
+        statx = 383,                        // i386    sys_statx
+        arch_prctl = 384,                   // i386    sys_arch_prctl
+        io_pgetevents = 385,                // i386    sys_io_pgetevents_time32
+        rseq = 386,                         // i386    sys_rseq
+        // 387 - 392 are not used
+        semget = 393,                       // i386    sys_semget
+        semctl = 394,                       // i386    sys_semctl
+        shmget = 395,                       // i386    sys_shmget
+        shmctl = 396,                       // i386    sys_shmctl
+        shmat = 397,                        // i386    sys_shmat
+        shmdt = 398,                        // i386    sys_shmdt
+        msgget = 399,                       // i386    sys_msgget
+        msgsnd = 400,                       // i386    sys_msgsnd
+        msgrcv = 401,                       // i386    sys_msgrcv
+        msgctl = 402,                       // i386    sys_msgctl
+        clock_gettime64 = 403,              // i386    sys_clock_gettime
+        clock_settime64 = 404,              // i386    sys_clock_settime
+        clock_adjtime64 = 405,              // i386    sys_clock_adjtime
+        clock_getres_time64 = 406,          // i386    sys_clock_getres
+        clock_nanosleep_time64 = 407,       // i386    sys_clock_nanosleep
+        timer_gettime64 = 408,              // i386    sys_timer_gettime
+        timer_settime64 = 409,              // i386    sys_timer_settime
+        timerfd_gettime64 = 410,            // i386    sys_timerfd_gettime
+        timerfd_settime64 = 411,            // i386    sys_timerfd_settime
+        utimensat_time64 = 412,             // i386    sys_utimensat
+        pselect6_time64 = 413,              // i386    sys_pselect6
+        ppoll_time64 = 414,                 // i386    sys_ppoll
+        // 415 - 415 are not used
+        io_pgetevents_time64 = 416,         // i386    sys_io_pgetevents
+        recvmmsg_time64 = 417,              // i386    sys_recvmmsg
+        mq_timedsend_time64 = 418,          // i386    sys_mq_timedsend
+        mq_timedreceive_time64 = 419,       // i386    sys_mq_timedreceive
+        semtimedop_time64 = 420,            // i386    sys_semtimedop
+        rt_sigtimedwait_time64 = 421,       // i386    sys_rt_sigtimedwait
+        futex_time64 = 422,                 // i386    sys_futex
+        sched_rr_get_interval_time64 = 423, // i386    sys_sched_rr_get_interval
+        pidfd_send_signal = 424,            // i386    sys_pidfd_send_signal
+        io_uring_setup = 425,               // i386    sys_io_uring_setup
+        io_uring_enter = 426,               // i386    sys_io_uring_enter
+        io_uring_register = 427,            // i386    sys_io_uring_register
+        open_tree = 428,                    // i386    sys_open_tree
+        move_mount = 429,                   // i386    sys_move_mount
+        fsopen = 430,                       // i386    sys_fsopen
+        fsconfig = 431,                     // i386    sys_fsconfig
+        fsmount = 432,                      // i386    sys_fsmount
+        fspick = 433,                       // i386    sys_fspick
+        pidfd_open = 434,                   // i386    sys_pidfd_open
+        clone3 = 435,                       // i386    sys_clone3
+        close_range = 436,                  // i386    sys_close_range
+        openat2 = 437,                      // i386    sys_openat2
+        pidfd_getfd = 438,                  // i386    sys_pidfd_getfd
+        faccessat2 = 439,                   // i386    sys_faccessat2
+        process_madvise = 440,              // i386    sys_process_madvise
+        epoll_pwait2 = 441,                 // i386    sys_epoll_pwait2
+        mount_setattr = 442,                // i386    sys_mount_setattr
+        quotactl_fd = 443,                  // i386    sys_quotactl_fd
+        landlock_create_ruleset = 444,      // i386    sys_landlock_create_ruleset
+        landlock_add_rule = 445,            // i386    sys_landlock_add_rule
+        landlock_restrict_self = 446,       // i386    sys_landlock_restrict_self
+        memfd_secret = 447,                 // i386    sys_memfd_secret
+        process_mrelease = 448,             // i386    sys_process_mrelease
+        futex_waitv = 449,                  // i386    sys_futex_waitv
+        set_mempolicy_home_node = 450,      // i386    sys_set_mempolicy_home_node
+        cachestat = 451,                    // i386    sys_cachestat
+        fchmodat2 = 452,                    // i386    sys_fchmodat2
+        // 453 - 453 are not used
+        futex_wake = 454,                   // i386    sys_futex_wake
+        futex_wait = 455,                   // i386    sys_futex_wait
+        futex_requeue = 456,                // i386    sys_futex_requeue
+        statmount = 457,                    // i386    sys_statmount
+        listmount = 458,                    // i386    sys_listmount
+        lsm_get_self_attr = 459,            // i386    sys_lsm_get_self_attr
+        lsm_set_self_attr = 460,            // i386    sys_lsm_set_self_attr
+        lsm_list_modules = 461,             // i386    sys_lsm_list_modules
+        mseal = 462,                        // i386    sys_mseal
+        setxattrat = 463,                   // i386    sys_setxattrat
+        getxattrat = 464,                   // i386    sys_getxattrat
+        listxattrat = 465,                  // i386    sys_listxattrat
+        removexattrat = 466,                // i386    sys_removexattrat
+    }
+}
+
+impl SysCallNum {
+    // the ABI column of the syscall table.
+    pub fn abi(&self) -> SysCallAbi {
+        SysCallAbi::I386
+    }
+
+    // the name of the kernel function which implements the syscall,
+    // e.g. "sys_read", or `None` if the syscall is not implemented.
+    pub fn entry_point(&self) -> Option<&'static str> {
+        match self {
+            SysCallNum::restart_syscall => Some("sys_restart_syscall"),
+            SysCallNum::exit => Some("sys_exit"),
+            SysCallNum::fork => Some("sys_fork"),
+            SysCallNum::read => Some("sys_read"),
+            SysCallNum::write => Some("sys_write"),
+            SysCallNum::open => Some("sys_open"),
+            SysCallNum::close => Some("sys_close"),
+            SysCallNum::waitpid => Some("sys_waitpid"),
+            SysCallNum::creat => Some("sys_creat"),
+            SysCallNum::link => Some("sys_link"),
+            SysCallNum::unlink => Some("sys_unlink"),
+            SysCallNum::execve => Some("sys_execve"),
+            SysCallNum::chdir => Some("sys_chdir"),
+            SysCallNum::time => Some("sys_time32"),
+            SysCallNum::mknod => Some("sys_mknod"),
+            SysCallNum::chmod => Some("sys_chmod"),
+            SysCallNum::lchown => Some("sys_lchown16"),
+            SysCallNum::oldstat => Some("sys_stat"),
+            SysCallNum::lseek => Some("sys_lseek"),
+            SysCallNum::getpid => Some("sys_getpid"),
+            SysCallNum::mount => Some("sys_mount"),
+            SysCallNum::umount => Some("sys_oldumount"),
+            SysCallNum::setuid => Some("sys_setuid16"),
+            SysCallNum::getuid => Some("sys_getuid16"),
+            SysCallNum::stime => Some("sys_stime32"),
+            SysCallNum::ptrace => Some("sys_ptrace"),
+            SysCallNum::alarm => Some("sys_alarm"),
+            SysCallNum::oldfstat => Some("sys_fstat"),
+            SysCallNum::pause => Some("sys_pause"),
+            SysCallNum::utime => Some("sys_utime32"),
+            SysCallNum::access => Some("sys_access"),
+            SysCallNum::nice => Some("sys_nice"),
+            SysCallNum::sync => Some("sys_sync"),
+            SysCallNum::kill => Some("sys_kill"),
+            SysCallNum::rename => Some("sys_rename"),
+            SysCallNum::mkdir => Some("sys_mkdir"),
+            SysCallNum::rmdir => Some("sys_rmdir"),
+            SysCallNum::dup => Some("sys_dup"),
+            SysCallNum::pipe => Some("sys_pipe"),
+            SysCallNum::times => Some("sys_times"),
+            SysCallNum::brk => Some("sys_brk"),
+            SysCallNum::setgid => Some("sys_setgid16"),
+            SysCallNum::getgid => Some("sys_getgid16"),
+            SysCallNum::signal => Some("sys_signal"),
+            SysCallNum::geteuid => Some("sys_geteuid16"),
+            SysCallNum::getegid => Some("sys_getegid16"),
+            SysCallNum::acct => Some("sys_acct"),
+            SysCallNum::umount2 => Some("sys_umount"),
+            SysCallNum::ioctl => Some("sys_ioctl"),
+            SysCallNum::fcntl => Some("sys_fcntl"),
+            SysCallNum::setpgid => Some("sys_setpgid"),
+            SysCallNum::oldolduname => Some("sys_olduname"),
+            SysCallNum::umask => Some("sys_umask"),
+            SysCallNum::chroot => Some("sys_chroot"),
+            SysCallNum::ustat => Some("sys_ustat"),
+            SysCallNum::dup2 => Some("sys_dup2"),
+            SysCallNum::getppid => Some("sys_getppid"),
+            SysCallNum::getpgrp => Some("sys_getpgrp"),
+            SysCallNum::setsid => Some("sys_setsid"),
+            SysCallNum::sigaction => Some("sys_sigaction"),
+            SysCallNum::sgetmask => Some("sys_sgetmask"),
+            SysCallNum::ssetmask => Some("sys_ssetmask"),
+            SysCallNum::setreuid => Some("sys_setreuid16"),
+            SysCallNum::setregid => Some("sys_setregid16"),
+            SysCallNum::sigsuspend => Some("sys_sigsuspend"),
+            SysCallNum::sigpending => Some("sys_sigpending"),
+            SysCallNum::sethostname => Some("sys_sethostname"),
+            SysCallNum::setrlimit => Some("sys_setrlimit"),
+            SysCallNum::getrlimit => Some("sys_old_getrlimit"),
+            SysCallNum::getrusage => Some("sys_getrusage"),
+            SysCallNum::gettimeofday => Some("sys_gettimeofday"),
+            SysCallNum::settimeofday => Some("sys_settimeofday"),
+            SysCallNum::getgroups => Some("sys_getgroups16"),
+            SysCallNum::setgroups => Some("sys_setgroups16"),
+            SysCallNum::select => Some("sys_old_select"),
+            SysCallNum::symlink => Some("sys_symlink"),
+            SysCallNum::oldlstat => Some("sys_lstat"),
+            SysCallNum::readlink => Some("sys_readlink"),
+            SysCallNum::uselib => Some("sys_uselib"),
+            SysCallNum::swapon => Some("sys_swapon"),
+            SysCallNum::reboot => Some("sys_reboot"),
+            SysCallNum::readdir => Some("sys_old_readdir"),
+            SysCallNum::mmap => Some("sys_old_mmap"),
+            SysCallNum::munmap => Some("sys_munmap"),
+            SysCallNum::truncate => Some("sys_truncate"),
+            SysCallNum::ftruncate => Some("sys_ftruncate"),
+            SysCallNum::fchmod => Some("sys_fchmod"),
+            SysCallNum::fchown => Some("sys_fchown16"),
+            SysCallNum::getpriority => Some("sys_getpriority"),
+            SysCallNum::setpriority => Some("sys_setpriority"),
+            SysCallNum::statfs => Some("sys_statfs"),
+            SysCallNum::fstatfs => Some("sys_fstatfs"),
+            SysCallNum::ioperm => Some("sys_ioperm"),
+            SysCallNum::socketcall => Some("sys_socketcall"),
+            SysCallNum::syslog => Some("sys_syslog"),
+            SysCallNum::setitimer => Some("sys_setitimer"),
+            SysCallNum::getitimer => Some("sys_getitimer"),
+            SysCallNum::stat => Some("sys_newstat"),
+            SysCallNum::lstat => Some("sys_newlstat"),
+            SysCallNum::fstat => Some("sys_newfstat"),
+            SysCallNum::olduname => Some("sys_uname"),
+            SysCallNum::iopl => Some("sys_iopl"),
+            SysCallNum::vhangup => Some("sys_vhangup"),
+            SysCallNum::vm86old => Some("sys_vm86old"),
+            SysCallNum::wait4 => Some("sys_wait4"),
+            SysCallNum::swapoff => Some("sys_swapoff"),
+            SysCallNum::sysinfo => Some("sys_sysinfo"),
+            SysCallNum::ipc => Some("sys_ipc"),
+            SysCallNum::fsync => Some("sys_fsync"),
+            SysCallNum::sigreturn => Some("sys_sigreturn"),
+            SysCallNum::clone => Some("sys_clone"),
+            SysCallNum::setdomainname => Some("sys_setdomainname"),
+            SysCallNum::uname => Some("sys_newuname"),
+            SysCallNum::modify_ldt => Some("sys_modify_ldt"),
+            SysCallNum::adjtimex => Some("sys_adjtimex_time32"),
+            SysCallNum::mprotect => Some("sys_mprotect"),
+            SysCallNum::sigprocmask => Some("sys_sigprocmask"),
+            SysCallNum::init_module => Some("sys_init_module"),
+            SysCallNum::delete_module => Some("sys_delete_module"),
+            SysCallNum::quotactl => Some("sys_quotactl"),
+            SysCallNum::getpgid => Some("sys_getpgid"),
+            SysCallNum::fchdir => Some("sys_fchdir"),
+            SysCallNum::sysfs => Some("sys_sysfs"),
+            SysCallNum::personality => Some("sys_personality"),
+            SysCallNum::setfsuid => Some("sys_setfsuid16"),
+            SysCallNum::setfsgid => Some("sys_setfsgid16"),
+            SysCallNum::llseek_ => Some("sys_llseek"),
+            SysCallNum::getdents => Some("sys_getdents"),
+            SysCallNum::newselect_ => Some("sys_select"),
+            SysCallNum::flock => Some("sys_flock"),
+            SysCallNum::msync => Some("sys_msync"),
+            SysCallNum::readv => Some("sys_readv"),
+            SysCallNum::writev => Some("sys_writev"),
+            SysCallNum::getsid => Some("sys_getsid"),
+            SysCallNum::fdatasync => Some("sys_fdatasync"),
+            SysCallNum::sysctl_ => Some("sys_ni_syscall"),
+            SysCallNum::mlock => Some("sys_mlock"),
+            SysCallNum::munlock => Some("sys_munlock"),
+            SysCallNum::mlockall => Some("sys_mlockall"),
+            SysCallNum::munlockall => Some("sys_munlockall"),
+            SysCallNum::sched_setparam => Some("sys_sched_setparam"),
+            SysCallNum::sched_getparam => Some("sys_sched_getparam"),
+            SysCallNum::sched_setscheduler => Some("sys_sched_setscheduler"),
+            SysCallNum::sched_getscheduler => Some("sys_sched_getscheduler"),
+            SysCallNum::sched_yield => Some("sys_sched_yield"),
+            SysCallNum::sched_get_priority_max => Some("sys_sched_get_priority_max"),
+            SysCallNum::sched_get_priority_min => Some("sys_sched_get_priority_min"),
+            SysCallNum::sched_rr_get_interval => Some("sys_sched_rr_get_interval_time32"),
+            SysCallNum::nanosleep => Some("sys_nanosleep_time32"),
+            SysCallNum::mremap => Some("sys_mremap"),
+            SysCallNum::setresuid => Some("sys_setresuid16"),
+            SysCallNum::getresuid => Some("sys_getresuid16"),
+            SysCallNum::vm86 => Some("sys_vm86"),
+            SysCallNum::poll => Some("sys_poll"),
+            SysCallNum::setresgid => Some("sys_setresgid16"),
+            SysCallNum::getresgid => Some("sys_getresgid16"),
+            SysCallNum::prctl => Some("sys_prctl"),
+            SysCallNum::rt_sigreturn => Some("sys_rt_sigreturn"),
+            SysCallNum::rt_sigaction => Some("sys_rt_sigaction"),
+            SysCallNum::rt_sigprocmask => Some("sys_rt_sigprocmask"),
+            SysCallNum::rt_sigpending => Some("sys_rt_sigpending"),
+            SysCallNum::rt_sigtimedwait => Some("sys_rt_sigtimedwait_time32"),
+            SysCallNum::rt_sigqueueinfo => Some("sys_rt_sigqueueinfo"),
+            SysCallNum::rt_sigsuspend => Some("sys_rt_sigsuspend"),
+            SysCallNum::pread64 => Some("sys_ia32_pread64"),
+            SysCallNum::pwrite64 => Some("sys_ia32_pwrite64"),
+            SysCallNum::chown => Some("sys_chown16"),
+            SysCallNum::getcwd => Some("sys_getcwd"),
+            SysCallNum::capget => Some("sys_capget"),
+            SysCallNum::capset => Some("sys_capset"),
+            SysCallNum::sigaltstack => Some("sys_sigaltstack"),
+            SysCallNum::sendfile => Some("sys_sendfile"),
+            SysCallNum::vfork => Some("sys_vfork"),
+            SysCallNum::ugetrlimit => Some("sys_getrlimit"),
+            SysCallNum::mmap2 => Some("sys_mmap_pgoff"),
+            SysCallNum::truncate64 => Some("sys_ia32_truncate64"),
+            SysCallNum::ftruncate64 => Some("sys_ia32_ftruncate64"),
+            SysCallNum::stat64 => Some("sys_stat64"),
+            SysCallNum::lstat64 => Some("sys_lstat64"),
+            SysCallNum::fstat64 => Some("sys_fstat64"),
+            SysCallNum::lchown32 => Some("sys_lchown"),
+            SysCallNum::getuid32 => Some("sys_getuid"),
+            SysCallNum::getgid32 => Some("sys_getgid"),
+            SysCallNum::geteuid32 => Some("sys_geteuid"),
+            SysCallNum::getegid32 => Some("sys_getegid"),
+            SysCallNum::setreuid32 => Some("sys_setreuid"),
+            SysCallNum::setregid32 => Some("sys_setregid"),
+            SysCallNum::getgroups32 => Some("sys_getgroups"),
+            SysCallNum::setgroups32 => Some("sys_setgroups"),
+            SysCallNum::fchown32 => Some("sys_fchown"),
+            SysCallNum::setresuid32 => Some("sys_setresuid"),
+            SysCallNum::getresuid32 => Some("sys_getresuid"),
+            SysCallNum::setresgid32 => Some("sys_setresgid"),
+            SysCallNum::getresgid32 => Some("sys_getresgid"),
+            SysCallNum::chown32 => Some("sys_chown"),
+            SysCallNum::setuid32 => Some("sys_setuid"),
+            SysCallNum::setgid32 => Some("sys_setgid"),
+            SysCallNum::setfsuid32 => Some("sys_setfsuid"),
+            SysCallNum::setfsgid32 => Some("sys_setfsgid"),
+            SysCallNum::pivot_root => Some("sys_pivot_root"),
+            SysCallNum::mincore => Some("sys_mincore"),
+            SysCallNum::madvise => Some("sys_madvise"),
+            SysCallNum::getdents64 => Some("sys_getdents64"),
+            SysCallNum::fcntl64 => Some("sys_fcntl64"),
+            SysCallNum::gettid => Some("sys_gettid"),
+            SysCallNum::readahead => Some("sys_ia32_readahead"),
+            SysCallNum::setxattr => Some("sys_setxattr"),
+            SysCallNum::lsetxattr => Some("sys_lsetxattr"),
+            SysCallNum::fsetxattr => Some("sys_fsetxattr"),
+            SysCallNum::getxattr => Some("sys_getxattr"),
+            SysCallNum::lgetxattr => Some("sys_lgetxattr"),
+            SysCallNum::fgetxattr => Some("sys_fgetxattr"),
+            SysCallNum::listxattr => Some("sys_listxattr"),
+            SysCallNum::llistxattr => Some("sys_llistxattr"),
+            SysCallNum::flistxattr => Some("sys_flistxattr"),
+            SysCallNum::removexattr => Some("sys_removexattr"),
+            SysCallNum::lremovexattr => Some("sys_lremovexattr"),
+            SysCallNum::fremovexattr => Some("sys_fremovexattr"),
+            SysCallNum::tkill => Some("sys_tkill"),
+            SysCallNum::sendfile64 => Some("sys_sendfile64"),
+            SysCallNum::futex => Some("sys_futex_time32"),
+            SysCallNum::sched_setaffinity => Some("sys_sched_setaffinity"),
+            SysCallNum::sched_getaffinity => Some("sys_sched_getaffinity"),
+            SysCallNum::set_thread_area => Some("sys_set_thread_area"),
+            SysCallNum::get_thread_area => Some("sys_get_thread_area"),
+            SysCallNum::io_setup => Some("sys_io_setup"),
+            SysCallNum::io_destroy => Some("sys_io_destroy"),
+            SysCallNum::io_getevents => Some("sys_io_getevents_time32"),
+            SysCallNum::io_submit => Some("sys_io_submit"),
+            SysCallNum::io_cancel => Some("sys_io_cancel"),
+            SysCallNum::fadvise64 => Some("sys_ia32_fadvise64"),
+            SysCallNum::exit_group => Some("sys_exit_group"),
+            SysCallNum::epoll_create => Some("sys_epoll_create"),
+            SysCallNum::epoll_ctl => Some("sys_epoll_ctl"),
+            SysCallNum::epoll_wait => Some("sys_epoll_wait"),
+            SysCallNum::remap_file_pages => Some("sys_remap_file_pages"),
+            SysCallNum::set_tid_address => Some("sys_set_tid_address"),
+            SysCallNum::timer_create => Some("sys_timer_create"),
+            SysCallNum::timer_settime => Some("sys_timer_settime32"),
+            SysCallNum::timer_gettime => Some("sys_timer_gettime32"),
+            SysCallNum::timer_getoverrun => Some("sys_timer_getoverrun"),
+            SysCallNum::timer_delete => Some("sys_timer_delete"),
+            SysCallNum::clock_settime => Some("sys_clock_settime32"),
+            SysCallNum::clock_gettime => Some("sys_clock_gettime32"),
+            SysCallNum::clock_getres => Some("sys_clock_getres_time32"),
+            SysCallNum::clock_nanosleep => Some("sys_clock_nanosleep_time32"),
+            SysCallNum::statfs64 => Some("sys_statfs64"),
+            SysCallNum::fstatfs64 => Some("sys_fstatfs64"),
+            SysCallNum::tgkill => Some("sys_tgkill"),
+            SysCallNum::utimes => Some("sys_utimes_time32"),
+            SysCallNum::fadvise64_64 => Some("sys_ia32_fadvise64_64"),
+            SysCallNum::mbind => Some("sys_mbind"),
+            SysCallNum::get_mempolicy => Some("sys_get_mempolicy"),
+            SysCallNum::set_mempolicy => Some("sys_set_mempolicy"),
+            SysCallNum::mq_open => Some("sys_mq_open"),
+            SysCallNum::mq_unlink => Some("sys_mq_unlink"),
+            SysCallNum::mq_timedsend => Some("sys_mq_timedsend_time32"),
+            SysCallNum::mq_timedreceive => Some("sys_mq_timedreceive_time32"),
+            SysCallNum::mq_notify => Some("sys_mq_notify"),
+            SysCallNum::mq_getsetattr => Some("sys_mq_getsetattr"),
+            SysCallNum::kexec_load => Some("sys_kexec_load"),
+            SysCallNum::waitid => Some("sys_waitid"),
+            SysCallNum::add_key => Some("sys_add_key"),
+            SysCallNum::request_key => Some("sys_request_key"),
+            SysCallNum::keyctl => Some("sys_keyctl"),
+            SysCallNum::ioprio_set => Some("sys_ioprio_set"),
+            SysCallNum::ioprio_get => Some("sys_ioprio_get"),
+            SysCallNum::inotify_init => Some("sys_inotify_init"),
+            SysCallNum::inotify_add_watch => Some("sys_inotify_add_watch"),
+            SysCallNum::inotify_rm_watch => Some("sys_inotify_rm_watch"),
+            SysCallNum::migrate_pages => Some("sys_migrate_pages"),
+            SysCallNum::openat => Some("sys_openat"),
+            SysCallNum::mkdirat => Some("sys_mkdirat"),
+            SysCallNum::mknodat => Some("sys_mknodat"),
+            SysCallNum::fchownat => Some("sys_fchownat"),
+            SysCallNum::futimesat => Some("sys_futimesat_time32"),
+            SysCallNum::fstatat64 => Some("sys_fstatat64"),
+            SysCallNum::unlinkat => Some("sys_unlinkat"),
+            SysCallNum::renameat => Some("sys_renameat"),
+            SysCallNum::linkat => Some("sys_linkat"),
+            SysCallNum::symlinkat => Some("sys_symlinkat"),
+            SysCallNum::readlinkat => Some("sys_readlinkat"),
+            SysCallNum::fchmodat => Some("sys_fchmodat"),
+            SysCallNum::faccessat => Some("sys_faccessat"),
+            SysCallNum::pselect6 => Some("sys_pselect6_time32"),
+            SysCallNum::ppoll => Some("sys_ppoll_time32"),
+            SysCallNum::unshare => Some("sys_unshare"),
+            SysCallNum::set_robust_list => Some("sys_set_robust_list"),
+            SysCallNum::get_robust_list => Some("sys_get_robust_list"),
+            SysCallNum::splice => Some("sys_splice"),
+            SysCallNum::sync_file_range => Some("sys_ia32_sync_file_range"),
+            SysCallNum::tee => Some("sys_tee"),
+            SysCallNum::vmsplice => Some("sys_vmsplice"),
+            SysCallNum::move_pages => Some("sys_move_pages"),
+            SysCallNum::getcpu => Some("sys_getcpu"),
+            SysCallNum::epoll_pwait => Some("sys_epoll_pwait"),
+            SysCallNum::utimensat => Some("sys_utimensat_time32"),
+            SysCallNum::signalfd => Some("sys_signalfd"),
+            SysCallNum::timerfd_create => Some("sys_timerfd_create"),
+            SysCallNum::eventfd => Some("sys_eventfd"),
+            SysCallNum::fallocate => Some("sys_ia32_fallocate"),
+            SysCallNum::timerfd_settime => Some("sys_timerfd_settime32"),
+            SysCallNum::timerfd_gettime => Some("sys_timerfd_gettime32"),
+            SysCallNum::signalfd4 => Some("sys_signalfd4"),
+            SysCallNum::eventfd2 => Some("sys_eventfd2"),
+            SysCallNum::epoll_create1 => Some("sys_epoll_create1"),
+            SysCallNum::dup3 => Some("sys_dup3"),
+            SysCallNum::pipe2 => Some("sys_pipe2"),
+            SysCallNum::inotify_init1 => Some("sys_inotify_init1"),
+            SysCallNum::preadv => Some("sys_preadv"),
+            SysCallNum::pwritev => Some("sys_pwritev"),
+            SysCallNum::rt_tgsigqueueinfo => Some("sys_rt_tgsigqueueinfo"),
+            SysCallNum::perf_event_open => Some("sys_perf_event_open"),
+            SysCallNum::recvmmsg => Some("sys_recvmmsg_time32"),
+            SysCallNum::fanotify_init => Some("sys_fanotify_init"),
+            SysCallNum::fanotify_mark => Some("sys_fanotify_mark"),
+            SysCallNum::prlimit64 => Some("sys_prlimit64"),
+            SysCallNum::name_to_handle_at => Some("sys_name_to_handle_at"),
+            SysCallNum::open_by_handle_at => Some("sys_open_by_handle_at"),
+            SysCallNum::clock_adjtime => Some("sys_clock_adjtime32"),
+            SysCallNum::syncfs => Some("sys_syncfs"),
+            SysCallNum::sendmmsg => Some("sys_sendmmsg"),
+            SysCallNum::setns => Some("sys_setns"),
+            SysCallNum::process_vm_readv => Some("sys_process_vm_readv"),
+            SysCallNum::process_vm_writev => Some("sys_process_vm_writev"),
+            SysCallNum::kcmp => Some("sys_kcmp"),
+            SysCallNum::finit_module => Some("sys_finit_module"),
+            SysCallNum::sched_setattr => Some("sys_sched_setattr"),
+            SysCallNum::sched_getattr => Some("sys_sched_getattr"),
+            SysCallNum::renameat2 => Some("sys_renameat2"),
+            SysCallNum::seccomp => Some("sys_seccomp"),
+            SysCallNum::getrandom => Some("sys_getrandom"),
+            SysCallNum::memfd_create => Some("sys_memfd_create"),
+            SysCallNum::bpf => Some("sys_bpf"),
+            SysCallNum::execveat => Some("sys_execveat"),
+            SysCallNum::socket => Some("sys_socket"),
+            SysCallNum::socketpair => Some("sys_socketpair"),
+            SysCallNum::bind => Some("sys_bind"),
+            SysCallNum::connect => Some("sys_connect"),
+            SysCallNum::listen => Some("sys_listen"),
+            SysCallNum::accept4 => Some("sys_accept4"),
+            SysCallNum::getsockopt => Some("sys_getsockopt"),
+            SysCallNum::setsockopt => Some("sys_setsockopt"),
+            SysCallNum::getsockname => Some("sys_getsockname"),
+            SysCallNum::getpeername => Some("sys_getpeername"),
+            SysCallNum::sendto => Some("sys_sendto"),
+            SysCallNum::recvfrom => Some("sys_recvfrom"),
+            SysCallNum::sendmsg => Some("sys_sendmsg"),
+            SysCallNum::recvmsg => Some("sys_recvmsg"),
+            SysCallNum::shutdown => Some("sys_shutdown"),
+            SysCallNum::userfaultfd => Some("sys_userfaultfd"),
+            SysCallNum::membarrier => Some("sys_membarrier"),
+            SysCallNum::mlock2 => Some("sys_mlock2"),
+            SysCallNum::copy_file_range => Some("sys_copy_file_range"),
+            SysCallNum::preadv2 => Some("sys_preadv2"),
+            SysCallNum::pwritev2 => Some("sys_pwritev2"),
+            SysCallNum::pkey_mprotect => Some("sys_pkey_mprotect"),
+            SysCallNum::pkey_alloc => Some("sys_pkey_alloc"),
+            SysCallNum::pkey_free => Some("sys_pkey_free"),
+            SysCallNum::statx => Some("sys_statx"),
+            SysCallNum::arch_prctl => Some("sys_arch_prctl"),
+            SysCallNum::io_pgetevents => Some("sys_io_pgetevents_time32"),
+            SysCallNum::rseq => Some("sys_rseq"),
+            SysCallNum::semget => Some("sys_semget"),
+            SysCallNum::semctl => Some("sys_semctl"),
+            SysCallNum::shmget => Some("sys_shmget"),
+            SysCallNum::shmctl => Some("sys_shmctl"),
+            SysCallNum::shmat => Some("sys_shmat"),
+            SysCallNum::shmdt => Some("sys_shmdt"),
+            SysCallNum::msgget => Some("sys_msgget"),
+            SysCallNum::msgsnd => Some("sys_msgsnd"),
+            SysCallNum::msgrcv => Some("sys_msgrcv"),
+            SysCallNum::msgctl => Some("sys_msgctl"),
+            SysCallNum::clock_gettime64 => Some("sys_clock_gettime"),
+            SysCallNum::clock_settime64 => Some("sys_clock_settime"),
+            SysCallNum::clock_adjtime64 => Some("sys_clock_adjtime"),
+            SysCallNum::clock_getres_time64 => Some("sys_clock_getres"),
+            SysCallNum::clock_nanosleep_time64 => Some("sys_clock_nanosleep"),
+            SysCallNum::timer_gettime64 => Some("sys_timer_gettime"),
+            SysCallNum::timer_settime64 => Some("sys_timer_settime"),
+            SysCallNum::timerfd_gettime64 => Some("sys_timerfd_gettime"),
+            SysCallNum::timerfd_settime64 => Some("sys_timerfd_settime"),
+            SysCallNum::utimensat_time64 => Some("sys_utimensat"),
+            SysCallNum::pselect6_time64 => Some("sys_pselect6"),
+            SysCallNum::ppoll_time64 => Some("sys_ppoll"),
+            SysCallNum::io_pgetevents_time64 => Some("sys_io_pgetevents"),
+            SysCallNum::recvmmsg_time64 => Some("sys_recvmmsg"),
+            SysCallNum::mq_timedsend_time64 => Some("sys_mq_timedsend"),
+            SysCallNum::mq_timedreceive_time64 => Some("sys_mq_timedreceive"),
+            SysCallNum::semtimedop_time64 => Some("sys_semtimedop"),
+            SysCallNum::rt_sigtimedwait_time64 => Some("sys_rt_sigtimedwait"),
+            SysCallNum::futex_time64 => Some("sys_futex"),
+            SysCallNum::sched_rr_get_interval_time64 => Some("sys_sched_rr_get_interval"),
+            SysCallNum::pidfd_send_signal => Some("sys_pidfd_send_signal"),
+            SysCallNum::io_uring_setup => Some("sys_io_uring_setup"),
+            SysCallNum::io_uring_enter => Some("sys_io_uring_enter"),
+            SysCallNum::io_uring_register => Some("sys_io_uring_register"),
+            SysCallNum::open_tree => Some("sys_open_tree"),
+            SysCallNum::move_mount => Some("sys_move_mount"),
+            SysCallNum::fsopen => Some("sys_fsopen"),
+            SysCallNum::fsconfig => Some("sys_fsconfig"),
+            SysCallNum::fsmount => Some("sys_fsmount"),
+            SysCallNum::fspick => Some("sys_fspick"),
+            SysCallNum::pidfd_open => Some("sys_pidfd_open"),
+            SysCallNum::clone3 => Some("sys_clone3"),
+            SysCallNum::close_range => Some("sys_close_range"),
+            SysCallNum::openat2 => Some("sys_openat2"),
+            SysCallNum::pidfd_getfd => Some("sys_pidfd_getfd"),
+            SysCallNum::faccessat2 => Some("sys_faccessat2"),
+            SysCallNum::process_madvise => Some("sys_process_madvise"),
+            SysCallNum::epoll_pwait2 => Some("sys_epoll_pwait2"),
+            SysCallNum::mount_setattr => Some("sys_mount_setattr"),
+            SysCallNum::quotactl_fd => Some("sys_quotactl_fd"),
+            SysCallNum::landlock_create_ruleset => Some("sys_landlock_create_ruleset"),
+            SysCallNum::landlock_add_rule => Some("sys_landlock_add_rule"),
+            SysCallNum::landlock_restrict_self => Some("sys_landlock_restrict_self"),
+            SysCallNum::memfd_secret => Some("sys_memfd_secret"),
+            SysCallNum::process_mrelease => Some("sys_process_mrelease"),
+            SysCallNum::futex_waitv => Some("sys_futex_waitv"),
+            SysCallNum::set_mempolicy_home_node => Some("sys_set_mempolicy_home_node"),
+            SysCallNum::cachestat => Some("sys_cachestat"),
+            SysCallNum::fchmodat2 => Some("sys_fchmodat2"),
+            SysCallNum::futex_wake => Some("sys_futex_wake"),
+            SysCallNum::futex_wait => Some("sys_futex_wait"),
+            SysCallNum::futex_requeue => Some("sys_futex_requeue"),
+            SysCallNum::statmount => Some("sys_statmount"),
+            SysCallNum::listmount => Some("sys_listmount"),
+            SysCallNum::lsm_get_self_attr => Some("sys_lsm_get_self_attr"),
+            SysCallNum::lsm_set_self_attr => Some("sys_lsm_set_self_attr"),
+            SysCallNum::lsm_list_modules => Some("sys_lsm_list_modules"),
+            SysCallNum::mseal => Some("sys_mseal"),
+            SysCallNum::setxattrat => Some("sys_setxattrat"),
+            SysCallNum::getxattrat => Some("sys_getxattrat"),
+            SysCallNum::listxattrat => Some("sys_listxattrat"),
+            SysCallNum::removexattrat => Some("sys_removexattrat"),
+            _ => None,
+        }
+    }
+
+    // whether the syscall never returns (e.g. `exit` and `exit_group`).
+    pub fn is_noreturn(&self) -> bool {
+        matches!(self, SysCallNum::exit | SysCallNum::exit_group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::arch::{x86::number::SysCallNum, SysCallAbi};
+
+    #[test]
+    fn test_lookup_syscall_num() {
+        // the names and the numbers are unique
+        for num in SysCallNum::iter() {
+            assert_eq!(SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(SysCallNum::from_str(num.name()), Ok(num));
+            assert_eq!(num.abi(), SysCallAbi::I386);
+        }
+
+        assert_eq!(SysCallNum::from_number(usize::MAX), None);
+        assert!(SysCallNum::from_str("foo").is_err());
+
+        // the renamed syscalls keep the names of the kernel syscall table
+        assert_eq!(SysCallNum::break_.name(), "break");
+        assert_eq!(SysCallNum::from_str("_llseek"), Ok(SysCallNum::llseek_));
+        assert_eq!(SysCallNum::from_str("_sysctl"), Ok(SysCallNum::sysctl_));
+    }
+
+    #[test]
+    fn test_syscall_table() {
+        // checks the generated enum against the kernel syscall table
+        let text = include_str!("syscall_32.tbl");
+        let mut count = 0;
+
+        for line in text.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let number = fields[0].parse::<usize>().unwrap();
+
+            let num = SysCallNum::from_number(number).unwrap();
+            assert_eq!(num.name(), fields[2]);
+            assert_eq!(num.entry_point(), fields.get(3).copied());
+            assert_eq!(num.is_noreturn(), fields.get(5) == Some(&"noreturn"));
+            count += 1;
+        }
+
+        assert_eq!(count, SysCallNum::ALL.len());
+    }
+}
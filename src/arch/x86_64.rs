@@ -4,5 +4,7 @@
 // the Mozilla Public License version 2.0 and additional exceptions,
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
+#[cfg(target_arch = "x86_64")]
 pub mod call;
+pub mod flags;
 pub mod number;
@@ -83,6 +83,8 @@
 //   https://doc.rust-lang.org/stable/reference/inline-assembly.html
 use std::arch::asm;
 
+use crate::arch::{x86_64::number::SysCallNum, MAX_ERRNO};
+
 #[allow(clippy::missing_safety_doc)]
 #[inline]
 pub unsafe fn syscall_without_args(num: usize) -> Result<usize, usize> {
@@ -235,11 +237,78 @@ pub unsafe fn syscall_with_6_args(
     convert_raw_return_code_from_rax(result)
 }
 
+// for the syscalls which never return on success, e.g. `exit`, `exit_group` and
+// `rt_sigreturn`, the caller must ensure that the syscall does not return (i.e. it
+// can not fail).
+//
+// note that `rt_sigreturn` finds the signal frame by the stack pointer, so it
+// can not be invoked by these functions from a signal handler, see
+// `rt_sigreturn_trampoline`.
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_without_args(num: usize) -> ! {
+    asm!(
+        "syscall",
+        in("rax") num,
+        options(noreturn, nostack)
+    );
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_noreturn_with_1_arg(num: usize, arg1: usize) -> ! {
+    asm!(
+        "syscall",
+        in("rax") num,
+        in("rdi") arg1,
+        options(noreturn, nostack)
+    );
+}
+
+// the trampoline which is set to `sa_restorer` of `rt_sigaction` (with the flag
+// `SA_RESTORER`, which is required on x86_64), the signal handler returns to it,
+// and it invokes `rt_sigreturn` to restore the context saved in the signal frame.
+//
+// it must be a naked function since the stack pointer must not be changed
+// before the syscall.
+#[allow(clippy::missing_safety_doc)]
+#[unsafe(naked)]
+pub unsafe extern "C" fn rt_sigreturn_trampoline() -> ! {
+    std::arch::naked_asm!(
+        "mov eax, {num}",
+        "syscall",
+        num = const SysCallNum::rt_sigreturn as usize,
+    )
+}
+
+// only the values in the range of `-MAX_ERRNO..=-1` are errors, see `arch::MAX_ERRNO`.
 #[inline(always)]
 fn convert_raw_return_code_from_rax(raw_code: isize) -> Result<usize, usize> {
-    if raw_code < 0 {
+    if (-(MAX_ERRNO as isize)..=-1).contains(&raw_code) {
         Err((-raw_code) as usize)
     } else {
         Ok(raw_code as usize)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::arch::x86_64::call::convert_raw_return_code_from_rax;
+
+    #[test]
+    fn test_convert_raw_return_code() {
+        assert_eq!(convert_raw_return_code_from_rax(0), Ok(0));
+        assert_eq!(convert_raw_return_code_from_rax(-1), Err(1));
+        assert_eq!(convert_raw_return_code_from_rax(-4095), Err(4095));
+
+        // the values below -4095 are not errors, e.g. an address returned by `mmap`
+        assert_eq!(
+            convert_raw_return_code_from_rax(-4096),
+            Ok(usize::MAX - 4095)
+        );
+        assert_eq!(
+            convert_raw_return_code_from_rax(isize::MIN),
+            Ok(isize::MIN as usize)
+        );
+    }
+}
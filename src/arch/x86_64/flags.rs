@@ -0,0 +1,753 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// THIS FILE IS GENERATED BY 'examples/gen_flags.rs', DO NOT EDIT.
+//
+// the kernel constants of x86_64, see 'src/flags.rs'.
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_flags -- x86_64 > src/arch/x86_64/flags.rs
+// ```
+//
+// ref:
+// - 'include/uapi/asm-generic/fcntl.h'
+// - 'include/uapi/linux/fcntl.h'
+// - 'include/uapi/asm-generic/mman-common.h'
+// - 'arch/x86/include/uapi/asm/mman.h'
+// - 'include/uapi/linux/mman.h'
+// - 'include/uapi/linux/memfd.h'
+// - 'include/uapi/linux/sched.h'
+// - 'include/uapi/asm-generic/signal.h'
+// - 'include/uapi/asm-generic/signal-defs.h'
+// - 'arch/x86/include/uapi/asm/signal.h'
+// - 'include/uapi/linux/wait.h'
+// - 'include/uapi/linux/signalfd.h'
+// - 'include/uapi/linux/eventfd.h'
+// - 'include/uapi/linux/timerfd.h'
+// - 'include/uapi/linux/time.h'
+// - 'include/linux/socket.h'
+// - 'include/linux/net.h'
+// - 'include/uapi/asm-generic/socket.h'
+// - 'include/uapi/linux/in.h'
+// - 'include/uapi/linux/in6.h'
+// - 'include/uapi/linux/tcp.h'
+// - 'include/uapi/asm-generic/poll.h'
+// - 'include/uapi/linux/eventpoll.h'
+// - 'include/uapi/linux/fs.h'
+// - 'include/uapi/linux/io_uring.h'
+
+// the flags of `open`, `openat`, `pipe2` and `dup3` etc.
+bitflags! {
+    pub struct OpenFlags: u32 {
+        // the access modes, `O_RDONLY` is 0, so `contains(O_RDONLY)` is always true.
+        const O_RDONLY = 0;
+        const O_WRONLY = 0o1;
+        const O_RDWR = 0o2;
+
+        const O_CREAT = 0o100;
+        const O_EXCL = 0o200;
+        const O_NOCTTY = 0o400;
+        const O_TRUNC = 0o1000;
+        const O_APPEND = 0o2000;
+        const O_NONBLOCK = 0o4000;
+
+        // `O_SYNC` is `__O_SYNC | O_DSYNC`
+        const O_SYNC = 0o4010000;
+        const O_DSYNC = 0o10000;
+        const FASYNC = 0o20000;
+
+        const O_DIRECT = 0o40000;
+        const O_LARGEFILE = 0o100000;
+
+        // `O_TMPFILE` is `__O_TMPFILE | O_DIRECTORY`
+        const O_TMPFILE = 0o20200000;
+        const O_DIRECTORY = 0o200000;
+        const O_NOFOLLOW = 0o400000;
+
+        const O_NOATIME = 0o1000000;
+        const O_CLOEXEC = 0o2000000;
+        const O_PATH = 0o10000000;
+    }
+}
+
+// the flags of the `*at` syscalls, e.g. `newfstatat`, `unlinkat` and `statx`.
+bitflags! {
+    pub struct AtFlags: u32 {
+        const AT_SYMLINK_NOFOLLOW = 0x100;
+
+        // `AT_REMOVEDIR` (for `unlinkat`) and `AT_EACCESS` (for `faccessat2`)
+        // have the same value.
+        const AT_REMOVEDIR = 0x200;
+        const AT_EACCESS = 0x200;
+
+        const AT_SYMLINK_FOLLOW = 0x400;
+        const AT_NO_AUTOMOUNT = 0x800;
+        const AT_EMPTY_PATH = 0x1000;
+
+        // for `statx`, see also `AtFlags::AT_STATX_SYNC_AS_STAT`
+        const AT_STATX_FORCE_SYNC = 0x2000;
+        const AT_STATX_DONT_SYNC = 0x4000;
+
+        const AT_RECURSIVE = 0x8000;
+    }
+}
+
+impl AtFlags {
+    // the default synchronization of `statx` (i.e. does whatever `stat` does),
+    // it is 0, so it is not one of the flags and it is never printed by `Debug`.
+    pub const AT_STATX_SYNC_AS_STAT: AtFlags = AtFlags::empty();
+}
+
+// the memory protection of `mmap` and `mprotect`.
+bitflags! {
+    pub struct ProtFlags: u32 {
+        const PROT_NONE = 0;
+        const PROT_READ = 0x1;
+        const PROT_WRITE = 0x2;
+        const PROT_EXEC = 0x4;
+        const PROT_SEM = 0x8;
+        const PROT_GROWSDOWN = 0x01000000;
+        const PROT_GROWSUP = 0x02000000;
+    }
+}
+
+// the flags of `mmap`.
+bitflags! {
+    pub struct MapFlags: u32 {
+        // the mapping types, `MAP_SHARED_VALIDATE` is `MAP_SHARED | MAP_PRIVATE`
+        const MAP_SHARED_VALIDATE = 0x03;
+        const MAP_SHARED = 0x01;
+        const MAP_PRIVATE = 0x02;
+
+        const MAP_FIXED = 0x10;
+        const MAP_ANONYMOUS = 0x20;
+
+        // maps into the first 2GB of the address space
+        const MAP_32BIT = 0x40;
+
+        const MAP_GROWSDOWN = 0x0100;
+        const MAP_DENYWRITE = 0x0800;
+        const MAP_EXECUTABLE = 0x1000;
+        const MAP_LOCKED = 0x2000;
+        const MAP_NORESERVE = 0x4000;
+        const MAP_POPULATE = 0x8000;
+        const MAP_NONBLOCK = 0x10000;
+        const MAP_STACK = 0x20000;
+        const MAP_HUGETLB = 0x40000;
+        const MAP_SYNC = 0x80000;
+        const MAP_FIXED_NOREPLACE = 0x100000;
+        const MAP_UNINITIALIZED = 0x4000000;
+    }
+}
+
+// the flags of `mremap`.
+bitflags! {
+    pub struct MremapFlags: u32 {
+        const MREMAP_MAYMOVE = 0x1;
+        const MREMAP_FIXED = 0x2;
+        const MREMAP_DONTUNMAP = 0x4;
+    }
+}
+
+// the flags of `mlock2`.
+bitflags! {
+    pub struct MlockFlags: u32 {
+        // locks the pages when they are faulted in, instead of populating them
+        const MLOCK_ONFAULT = 0x1;
+    }
+}
+
+// the argument `advice` of `madvise`.
+value_enum! {
+    pub enum Advice: u32 {
+        MADV_NORMAL = 0,
+        MADV_RANDOM = 1,
+        MADV_SEQUENTIAL = 2,
+        MADV_WILLNEED = 3,
+        MADV_DONTNEED = 4,
+        MADV_FREE = 8,
+        MADV_REMOVE = 9,
+        MADV_DONTFORK = 10,
+        MADV_DOFORK = 11,
+        MADV_MERGEABLE = 12,
+        MADV_UNMERGEABLE = 13,
+        MADV_HUGEPAGE = 14,
+        MADV_NOHUGEPAGE = 15,
+        MADV_DONTDUMP = 16,
+        MADV_DODUMP = 17,
+        MADV_WIPEONFORK = 18,
+        MADV_KEEPONFORK = 19,
+        MADV_COLD = 20,
+        MADV_PAGEOUT = 21,
+        MADV_POPULATE_READ = 22,
+        MADV_POPULATE_WRITE = 23,
+        MADV_DONTNEED_LOCKED = 24,
+        MADV_COLLAPSE = 25,
+    }
+}
+
+// the flags of `memfd_create`.
+bitflags! {
+    pub struct MemfdFlags: u32 {
+        const MFD_CLOEXEC = 0x1;
+        const MFD_ALLOW_SEALING = 0x2;
+        const MFD_HUGETLB = 0x4;
+        const MFD_NOEXEC_SEAL = 0x8;
+        const MFD_EXEC = 0x10;
+    }
+}
+
+// the flags of `clone`, `clone3` and `unshare`.
+//
+// the lowest byte of the flags of `clone` is the signal sent to the parent
+// when the child exits (e.g. `SIGCHLD`), it is printed in hex by `Debug`.
+bitflags! {
+    pub struct CloneFlags: u64 {
+        const CLONE_NEWTIME = 0x80;
+        const CLONE_VM = 0x100;
+        const CLONE_FS = 0x200;
+        const CLONE_FILES = 0x400;
+        const CLONE_SIGHAND = 0x800;
+        const CLONE_PIDFD = 0x1000;
+        const CLONE_PTRACE = 0x2000;
+        const CLONE_VFORK = 0x4000;
+        const CLONE_PARENT = 0x8000;
+        const CLONE_THREAD = 0x10000;
+        const CLONE_NEWNS = 0x20000;
+        const CLONE_SYSVSEM = 0x40000;
+        const CLONE_SETTLS = 0x80000;
+        const CLONE_PARENT_SETTID = 0x100000;
+        const CLONE_CHILD_CLEARTID = 0x200000;
+        const CLONE_DETACHED = 0x400000;
+        const CLONE_UNTRACED = 0x800000;
+        const CLONE_CHILD_SETTID = 0x01000000;
+        const CLONE_NEWCGROUP = 0x02000000;
+        const CLONE_NEWUTS = 0x04000000;
+        const CLONE_NEWIPC = 0x08000000;
+        const CLONE_NEWUSER = 0x10000000;
+        const CLONE_NEWPID = 0x20000000;
+        const CLONE_NEWNET = 0x40000000;
+        const CLONE_IO = 0x80000000;
+
+        // the following flags are only available for `clone3`
+        const CLONE_CLEAR_SIGHAND = 0x100000000;
+        const CLONE_INTO_CGROUP = 0x200000000;
+    }
+}
+
+// the options of `wait4` and `waitid`.
+bitflags! {
+    pub struct WaitOptions: u32 {
+        const WNOHANG = 0x1;
+
+        // `WUNTRACED` (for `wait4`) and `WSTOPPED` (for `waitid`) have the same value.
+        const WUNTRACED = 0x2;
+        const WSTOPPED = 0x2;
+
+        const WEXITED = 0x4;
+        const WCONTINUED = 0x8;
+        const WNOWAIT = 0x01000000;
+
+        const __WNOTHREAD = 0x20000000;
+        const __WALL = 0x40000000;
+        const __WCLONE = 0x80000000;
+    }
+}
+
+// the standard signals, the real-time signals (`SIGRTMIN..=SIGRTMAX`)
+// are not listed since they are only numbers.
+value_enum! {
+    pub enum Signal: i32 {
+        SIGHUP = 1,
+        SIGINT = 2,
+        SIGQUIT = 3,
+        SIGILL = 4,
+        SIGTRAP = 5,
+        SIGABRT = 6,
+        SIGBUS = 7,
+        SIGFPE = 8,
+        SIGKILL = 9,
+        SIGUSR1 = 10,
+        SIGSEGV = 11,
+        SIGUSR2 = 12,
+        SIGPIPE = 13,
+        SIGALRM = 14,
+        SIGTERM = 15,
+        SIGSTKFLT = 16,
+        SIGCHLD = 17,
+        SIGCONT = 18,
+        SIGSTOP = 19,
+        SIGTSTP = 20,
+        SIGTTIN = 21,
+        SIGTTOU = 22,
+        SIGURG = 23,
+        SIGXCPU = 24,
+        SIGXFSZ = 25,
+        SIGVTALRM = 26,
+        SIGPROF = 27,
+        SIGWINCH = 28,
+        SIGIO = 29,
+        SIGPWR = 30,
+        SIGSYS = 31,
+    }
+}
+
+impl Signal {
+    // the range of the real-time signals of the kernel, note that glibc
+    // reserves the first 2 or 3 real-time signals for the internal use.
+    pub const SIGRTMIN: i32 = 32;
+    pub const SIGRTMAX: i32 = 64;
+}
+
+// the flags of `rt_sigaction`.
+bitflags! {
+    pub struct SigactionFlags: u64 {
+        const SA_NOCLDSTOP = 0x00000001;
+        const SA_NOCLDWAIT = 0x00000002;
+        const SA_SIGINFO = 0x00000004;
+        const SA_UNSUPPORTED = 0x00000400;
+        const SA_EXPOSE_TAGBITS = 0x00000800;
+        // the obsolete flag which is not defined by the new architectures (e.g. riscv64)
+        const SA_RESTORER = 0x04000000;
+        const SA_ONSTACK = 0x08000000;
+        const SA_RESTART = 0x10000000;
+        const SA_NODEFER = 0x40000000;
+        const SA_RESETHAND = 0x80000000;
+    }
+}
+
+// the argument `how` of `rt_sigprocmask`.
+value_enum! {
+    pub enum SigmaskHow: u32 {
+        SIG_BLOCK = 0,
+        SIG_UNBLOCK = 1,
+        SIG_SETMASK = 2,
+    }
+}
+
+// the flags of `sigaltstack`.
+bitflags! {
+    pub struct SigaltstackFlags: u32 {
+        const SS_ONSTACK = 1;
+        const SS_DISABLE = 2;
+        const SS_AUTODISARM = 1 << 31;
+    }
+}
+
+// the address families (domains) of `socket`.
+value_enum! {
+    pub enum AddressFamily: u16 {
+        AF_UNSPEC = 0,
+        AF_UNIX = 1,
+        AF_INET = 2,
+        AF_INET6 = 10,
+        AF_NETLINK = 16,
+        AF_PACKET = 17,
+        AF_ALG = 38,
+        AF_VSOCK = 40,
+        AF_XDP = 44,
+    }
+}
+
+// the socket types of `socket`, the type can be combined with
+// `SocketFlags` in the argument `type` of `socket` and `socketpair`.
+value_enum! {
+    pub enum SocketType: u32 {
+        SOCK_STREAM = 1,
+        SOCK_DGRAM = 2,
+        SOCK_RAW = 3,
+        SOCK_RDM = 4,
+        SOCK_SEQPACKET = 5,
+        SOCK_DCCP = 6,
+        SOCK_PACKET = 10,
+    }
+}
+
+// the flags of `socket`, `socketpair` and `accept4`.
+bitflags! {
+    pub struct SocketFlags: u32 {
+        const SOCK_NONBLOCK = 0o4000;
+        const SOCK_CLOEXEC = 0o2000000;
+    }
+}
+
+// the flags of `sendto`, `recvfrom`, `sendmsg` and `recvmsg`.
+bitflags! {
+    pub struct MsgFlags: u32 {
+        const MSG_OOB = 0x1;
+        // receives the data without removing it from the queue
+        const MSG_PEEK = 0x2;
+        const MSG_DONTROUTE = 0x4;
+        const MSG_CTRUNC = 0x8;
+        const MSG_TRUNC = 0x20;
+        const MSG_DONTWAIT = 0x40;
+        const MSG_EOR = 0x80;
+        const MSG_WAITALL = 0x100;
+        const MSG_ERRQUEUE = 0x2000;
+        // does not raise `SIGPIPE` when the peer has closed the connection
+        const MSG_NOSIGNAL = 0x4000;
+        const MSG_MORE = 0x8000;
+        const MSG_CMSG_CLOEXEC = 0x40000000;
+    }
+}
+
+// the argument `how` of `shutdown`.
+value_enum! {
+    pub enum ShutdownHow: u32 {
+        SHUT_RD = 0,
+        SHUT_WR = 1,
+        SHUT_RDWR = 2,
+    }
+}
+
+// the options of the level `SOL_SOCKET`.
+//
+// `SO_RCVTIMEO` and `SO_SNDTIMEO` are the `*_OLD` values, which take
+// `struct timeval` of 64-bit `time_t` on the 64-bit architectures.
+value_enum! {
+    pub enum SocketOption: i32 {
+        SO_DEBUG = 1,
+        SO_REUSEADDR = 2,
+        SO_TYPE = 3,
+        SO_ERROR = 4,
+        SO_DONTROUTE = 5,
+        SO_BROADCAST = 6,
+        SO_SNDBUF = 7,
+        SO_RCVBUF = 8,
+        SO_KEEPALIVE = 9,
+        SO_OOBINLINE = 10,
+        SO_LINGER = 13,
+        SO_REUSEPORT = 15,
+        SO_PASSCRED = 16,
+        SO_PEERCRED = 17,
+        SO_RCVLOWAT = 18,
+        SO_SNDLOWAT = 19,
+        SO_RCVTIMEO = 20,
+        SO_SNDTIMEO = 21,
+        SO_ACCEPTCONN = 30,
+        SO_PROTOCOL = 38,
+        SO_DOMAIN = 39,
+    }
+}
+
+// the options of the level `IPPROTO_TCP`.
+value_enum! {
+    pub enum TcpOption: i32 {
+        TCP_NODELAY = 1,
+        TCP_MAXSEG = 2,
+        TCP_CORK = 3,
+        TCP_KEEPIDLE = 4,
+        TCP_KEEPINTVL = 5,
+        TCP_KEEPCNT = 6,
+        TCP_QUICKACK = 12,
+        TCP_USER_TIMEOUT = 18,
+        TCP_FASTOPEN = 23,
+    }
+}
+
+// the options of the level `IPPROTO_IP`.
+value_enum! {
+    pub enum IpOption: i32 {
+        IP_TOS = 1,
+        IP_TTL = 2,
+        IP_MULTICAST_TTL = 33,
+        IP_MULTICAST_LOOP = 34,
+    }
+}
+
+// the options of the level `IPPROTO_IPV6`.
+value_enum! {
+    pub enum Ipv6Option: i32 {
+        IPV6_UNICAST_HOPS = 16,
+        IPV6_MULTICAST_HOPS = 18,
+        IPV6_MULTICAST_LOOP = 19,
+        IPV6_V6ONLY = 26,
+    }
+}
+
+// the events of `poll` and `ppoll`, i.e. the type of the field `short events`.
+bitflags! {
+    pub struct PollEvents: i16 {
+        const POLLIN = 0x0001;
+        const POLLPRI = 0x0002;
+        const POLLOUT = 0x0004;
+        const POLLERR = 0x0008;
+        const POLLHUP = 0x0010;
+        const POLLNVAL = 0x0020;
+        const POLLRDNORM = 0x0040;
+        const POLLRDBAND = 0x0080;
+        const POLLWRNORM = 0x0100;
+        const POLLWRBAND = 0x0200;
+        const POLLMSG = 0x0400;
+        const POLLREMOVE = 0x1000;
+        const POLLRDHUP = 0x2000;
+    }
+}
+
+// the flags of `epoll_create1`, the value is the same as `O_CLOEXEC`.
+bitflags! {
+    pub struct EpollFlags: u32 {
+        const EPOLL_CLOEXEC = 0o2000000;
+    }
+}
+
+// the operations of `epoll_ctl`.
+value_enum! {
+    pub enum EpollOp: u32 {
+        EPOLL_CTL_ADD = 1,
+        EPOLL_CTL_DEL = 2,
+        EPOLL_CTL_MOD = 3,
+    }
+}
+
+// the events of `epoll_ctl` and `epoll_wait`.
+bitflags! {
+    pub struct EpollEvents: u32 {
+        const EPOLLIN = 0x00000001;
+        const EPOLLPRI = 0x00000002;
+        const EPOLLOUT = 0x00000004;
+        const EPOLLERR = 0x00000008;
+        const EPOLLHUP = 0x00000010;
+        const EPOLLNVAL = 0x00000020;
+        const EPOLLRDNORM = 0x00000040;
+        const EPOLLRDBAND = 0x00000080;
+        const EPOLLWRNORM = 0x00000100;
+        const EPOLLWRBAND = 0x00000200;
+        const EPOLLMSG = 0x00000400;
+        const EPOLLRDHUP = 0x00002000;
+        const EPOLLEXCLUSIVE = 1 << 28;
+        const EPOLLWAKEUP = 1 << 29;
+        const EPOLLONESHOT = 1 << 30;
+        const EPOLLET = 1 << 31;
+    }
+}
+
+// the flags of `signalfd4`, the values are the same as `O_NONBLOCK` and `O_CLOEXEC`.
+bitflags! {
+    pub struct SfdFlags: u32 {
+        const SFD_NONBLOCK = 0o4000;
+        const SFD_CLOEXEC = 0o2000000;
+    }
+}
+
+// the flags of `eventfd2`.
+bitflags! {
+    pub struct EfdFlags: u32 {
+        // reads 1 at a time instead of the whole counter
+        const EFD_SEMAPHORE = 0x1;
+        const EFD_NONBLOCK = 0o4000;
+        const EFD_CLOEXEC = 0o2000000;
+    }
+}
+
+// the flags of `timerfd_create`.
+bitflags! {
+    pub struct TfdFlags: u32 {
+        const TFD_NONBLOCK = 0o4000;
+        const TFD_CLOEXEC = 0o2000000;
+    }
+}
+
+// the flags of `timerfd_settime`.
+bitflags! {
+    pub struct TfdTimerFlags: u32 {
+        const TFD_TIMER_ABSTIME = 0x1;
+        // for `CLOCK_REALTIME`, the timer is canceled if the clock is changed
+        const TFD_TIMER_CANCEL_ON_SET = 0x2;
+    }
+}
+
+// the clocks of `clock_gettime`, `timerfd_create` and so on.
+value_enum! {
+    pub enum ClockId: i32 {
+        CLOCK_REALTIME = 0,
+        CLOCK_MONOTONIC = 1,
+        CLOCK_PROCESS_CPUTIME_ID = 2,
+        CLOCK_THREAD_CPUTIME_ID = 3,
+        CLOCK_MONOTONIC_RAW = 4,
+        CLOCK_REALTIME_COARSE = 5,
+        CLOCK_MONOTONIC_COARSE = 6,
+        CLOCK_BOOTTIME = 7,
+        CLOCK_REALTIME_ALARM = 8,
+        CLOCK_BOOTTIME_ALARM = 9,
+        CLOCK_TAI = 11,
+    }
+}
+
+// the flags of `io_uring_setup`.
+bitflags! {
+    pub struct IoUringSetupFlags: u32 {
+        // busy-polling for the completions (for `O_DIRECT` files only)
+        const IORING_SETUP_IOPOLL = 1 << 0;
+        // the submission queue is polled by a kernel thread
+        const IORING_SETUP_SQPOLL = 1 << 1;
+        const IORING_SETUP_SQ_AFF = 1 << 2;
+        // the size of the completion queue is `io_uring_params.cq_entries`
+        const IORING_SETUP_CQSIZE = 1 << 3;
+        const IORING_SETUP_CLAMP = 1 << 4;
+        const IORING_SETUP_ATTACH_WQ = 1 << 5;
+        const IORING_SETUP_R_DISABLED = 1 << 6;
+        const IORING_SETUP_SUBMIT_ALL = 1 << 7;
+        const IORING_SETUP_COOP_TASKRUN = 1 << 8;
+        const IORING_SETUP_TASKRUN_FLAG = 1 << 9;
+        const IORING_SETUP_SQE128 = 1 << 10;
+        const IORING_SETUP_CQE32 = 1 << 11;
+        const IORING_SETUP_SINGLE_ISSUER = 1 << 12;
+        const IORING_SETUP_DEFER_TASKRUN = 1 << 13;
+        const IORING_SETUP_NO_MMAP = 1 << 14;
+        const IORING_SETUP_REGISTERED_FD_ONLY = 1 << 15;
+        const IORING_SETUP_NO_SQARRAY = 1 << 16;
+    }
+}
+
+// the features of io_uring, i.e. `io_uring_params.features`.
+bitflags! {
+    pub struct IoUringFeatures: u32 {
+        // the submission and completion queue rings share one mapping
+        const IORING_FEAT_SINGLE_MMAP = 1 << 0;
+        const IORING_FEAT_NODROP = 1 << 1;
+        const IORING_FEAT_SUBMIT_STABLE = 1 << 2;
+        const IORING_FEAT_RW_CUR_POS = 1 << 3;
+        const IORING_FEAT_CUR_PERSONALITY = 1 << 4;
+        const IORING_FEAT_FAST_POLL = 1 << 5;
+        const IORING_FEAT_POLL_32BITS = 1 << 6;
+        const IORING_FEAT_SQPOLL_NONFIXED = 1 << 7;
+        const IORING_FEAT_EXT_ARG = 1 << 8;
+        const IORING_FEAT_NATIVE_WORKERS = 1 << 9;
+        const IORING_FEAT_RSRC_TAGS = 1 << 10;
+        const IORING_FEAT_CQE_SKIP = 1 << 11;
+        const IORING_FEAT_LINKED_FILE = 1 << 12;
+    }
+}
+
+// the flags of `io_uring_enter`.
+bitflags! {
+    pub struct IoUringEnterFlags: u32 {
+        // waits for `min_complete` completions
+        const IORING_ENTER_GETEVENTS = 1 << 0;
+        const IORING_ENTER_SQ_WAKEUP = 1 << 1;
+        const IORING_ENTER_SQ_WAIT = 1 << 2;
+        const IORING_ENTER_EXT_ARG = 1 << 3;
+        const IORING_ENTER_REGISTERED_RING = 1 << 4;
+    }
+}
+
+// the flags of the submission queue entry, i.e. `io_uring_sqe.flags`.
+bitflags! {
+    pub struct SqeFlags: u8 {
+        // `io_uring_sqe.fd` is the index of the registered files
+        const IOSQE_FIXED_FILE = 1 << 0;
+        // starts after all previous entries are completed
+        const IOSQE_IO_DRAIN = 1 << 1;
+        // the next entry starts after this entry is completed successfully
+        const IOSQE_IO_LINK = 1 << 2;
+        // same as `IOSQE_IO_LINK`, but the chain is not broken by the failure
+        const IOSQE_IO_HARDLINK = 1 << 3;
+        const IOSQE_ASYNC = 1 << 4;
+        const IOSQE_BUFFER_SELECT = 1 << 5;
+        const IOSQE_CQE_SKIP_SUCCESS = 1 << 6;
+    }
+}
+
+// the flags of the timeout entry.
+bitflags! {
+    pub struct TimeoutFlags: u32 {
+        const IORING_TIMEOUT_ABS = 1 << 0;
+        const IORING_TIMEOUT_UPDATE = 1 << 1;
+        const IORING_TIMEOUT_BOOTTIME = 1 << 2;
+        const IORING_TIMEOUT_REALTIME = 1 << 3;
+        const IORING_LINK_TIMEOUT_UPDATE = 1 << 4;
+        const IORING_TIMEOUT_ETIME_SUCCESS = 1 << 5;
+        const IORING_TIMEOUT_MULTISHOT = 1 << 6;
+    }
+}
+
+// the operations of the submission queue entry, i.e. `io_uring_sqe.opcode`.
+value_enum! {
+    pub enum IoUringOp: u8 {
+        IORING_OP_NOP = 0,
+        IORING_OP_READV = 1,
+        IORING_OP_WRITEV = 2,
+        IORING_OP_FSYNC = 3,
+        IORING_OP_READ_FIXED = 4,
+        IORING_OP_WRITE_FIXED = 5,
+        IORING_OP_POLL_ADD = 6,
+        IORING_OP_POLL_REMOVE = 7,
+        IORING_OP_SYNC_FILE_RANGE = 8,
+        IORING_OP_SENDMSG = 9,
+        IORING_OP_RECVMSG = 10,
+        IORING_OP_TIMEOUT = 11,
+        IORING_OP_TIMEOUT_REMOVE = 12,
+        IORING_OP_ACCEPT = 13,
+        IORING_OP_ASYNC_CANCEL = 14,
+        IORING_OP_LINK_TIMEOUT = 15,
+        IORING_OP_CONNECT = 16,
+        IORING_OP_FALLOCATE = 17,
+        IORING_OP_OPENAT = 18,
+        IORING_OP_CLOSE = 19,
+        IORING_OP_FILES_UPDATE = 20,
+        IORING_OP_STATX = 21,
+        IORING_OP_READ = 22,
+        IORING_OP_WRITE = 23,
+        IORING_OP_FADVISE = 24,
+        IORING_OP_MADVISE = 25,
+        IORING_OP_SEND = 26,
+        IORING_OP_RECV = 27,
+        IORING_OP_OPENAT2 = 28,
+        IORING_OP_EPOLL_CTL = 29,
+        IORING_OP_SPLICE = 30,
+        IORING_OP_PROVIDE_BUFFERS = 31,
+        IORING_OP_REMOVE_BUFFERS = 32,
+        IORING_OP_TEE = 33,
+        IORING_OP_SHUTDOWN = 34,
+        IORING_OP_RENAMEAT = 35,
+        IORING_OP_UNLINKAT = 36,
+        IORING_OP_MKDIRAT = 37,
+        IORING_OP_SYMLINKAT = 38,
+        IORING_OP_LINKAT = 39,
+        IORING_OP_MSG_RING = 40,
+        IORING_OP_FSETXATTR = 41,
+        IORING_OP_SETXATTR = 42,
+        IORING_OP_FGETXATTR = 43,
+        IORING_OP_GETXATTR = 44,
+        IORING_OP_SOCKET = 45,
+        IORING_OP_URING_CMD = 46,
+        IORING_OP_SEND_ZC = 47,
+        IORING_OP_SENDMSG_ZC = 48,
+    }
+}
+
+// the operations of `io_uring_register`.
+value_enum! {
+    pub enum IoUringRegisterOp: u32 {
+        IORING_REGISTER_BUFFERS = 0,
+        IORING_UNREGISTER_BUFFERS = 1,
+        IORING_REGISTER_FILES = 2,
+        IORING_UNREGISTER_FILES = 3,
+        IORING_REGISTER_EVENTFD = 4,
+        IORING_UNREGISTER_EVENTFD = 5,
+        IORING_REGISTER_FILES_UPDATE = 6,
+        IORING_REGISTER_EVENTFD_ASYNC = 7,
+        IORING_REGISTER_PROBE = 8,
+        IORING_REGISTER_PERSONALITY = 9,
+        IORING_UNREGISTER_PERSONALITY = 10,
+        IORING_REGISTER_RESTRICTIONS = 11,
+        IORING_REGISTER_ENABLE_RINGS = 12,
+    }
+}
+
+// the argument `whence` of `lseek`.
+value_enum! {
+    pub enum Whence: u32 {
+        SEEK_SET = 0,
+        SEEK_CUR = 1,
+        SEEK_END = 2,
+        SEEK_DATA = 3,
+        SEEK_HOLE = 4,
+    }
+}
@@ -4,8 +4,18 @@
 // the Mozilla Public License version 2.0 and additional exceptions,
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
-// the following definition come from Linux (kernel 6.3.3) source file:
+// THIS FILE IS GENERATED BY 'examples/gen_syscall_num.rs', DO NOT EDIT.
+//
+// the following definition come from Linux (kernel 6.14) source file:
 // 'arch/x86/entry/syscalls/syscall_64.tbl'
+// (a copy of the file is located at 'src/arch/x86_64/syscall_64.tbl')
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_syscall_num -- x86_64 src/arch/x86_64/syscall_64.tbl 6.14 \
+//       > src/arch/x86_64/number.rs
+// ```
 //
 // for other arch such as aarch64 and newer arch such as riscv64, checkout
 // 'include/uapi/asm-generic/unistd.h' and
@@ -15,420 +25,1746 @@
 // - https://www.chromium.org/chromium-os/developer-library/reference/linux-constants/syscalls/
 // - https://man7.org/linux/man-pages/man2/syscall.2.html
 // - https://syscalls.mebeim.net/?table=x86/64/x64/latest
+//
+// the macro `syscall_num!` generates the enum `SysCallNum` as well as
+// the functions for looking up syscall by number and name, see 'src/macros.rs'.
 
-#[repr(usize)]
-#[derive(Debug, PartialEq, Clone, Copy)]
-#[allow(non_camel_case_types)]
-pub enum SysCallNum {
-    read = 0,                     //	common				sys_read
-    write = 1,                    //	common				sys_write
-    open = 2,                     //	common				sys_open
-    close = 3,                    //	common				sys_close
-    stat = 4,                     //	common				sys_newstat
-    fstat = 5,                    //	common				sys_newfstat
-    lstat = 6,                    //	common				sys_newlstat
-    poll = 7,                     //	common				sys_poll
-    lseek = 8,                    //	common				sys_lseek
-    mmap = 9,                     //	common				sys_mmap
-    mprotect = 10,                //	common			    sys_mprotect
-    munmap = 11,                  //	common				sys_munmap
-    brk = 12,                     //	common				sys_brk
-    rt_sigaction = 13,            //	64			        sys_rt_sigaction
-    rt_sigprocmask = 14,          //	common			    sys_rt_sigprocmask
-    rt_sigreturn = 15,            //	64			        sys_rt_sigreturn
-    ioctl = 16,                   //	64				    sys_ioctl
-    pread64 = 17,                 //	common				sys_pread64
-    pwrite64 = 18,                //	common			    sys_pwrite64
-    readv = 19,                   //	64				    sys_readv
-    writev = 20,                  //	64				    sys_writev
-    access = 21,                  //	common				sys_access
-    pipe = 22,                    //	common				sys_pipe
-    select = 23,                  //	common				sys_select
-    sched_yield = 24,             //	common			    sys_sched_yield
-    mremap = 25,                  //	common				sys_mremap
-    msync = 26,                   //	common				sys_msync
-    mincore = 27,                 //	common				sys_mincore
-    madvise = 28,                 //	common				sys_madvise
-    shmget = 29,                  //	common				sys_shmget
-    shmat = 30,                   //	common				sys_shmat
-    shmctl = 31,                  //	common				sys_shmctl
-    dup = 32,                     //	common				sys_dup
-    dup2 = 33,                    //	common				sys_dup2
-    pause = 34,                   //	common				sys_pause
-    nanosleep = 35,               //	common			    sys_nanosleep
-    getitimer = 36,               //	common			    sys_getitimer
-    alarm = 37,                   //	common				sys_alarm
-    setitimer = 38,               //	common			    sys_setitimer
-    getpid = 39,                  //	common				sys_getpid
-    sendfile = 40,                //	common			    sys_sendfile64
-    socket = 41,                  //	common				sys_socket
-    connect = 42,                 //	common				sys_connect
-    accept = 43,                  //	common				sys_accept
-    sendto = 44,                  //	common				sys_sendto
-    recvfrom = 45,                //	64			        sys_recvfrom
-    sendmsg = 46,                 //	64				    sys_sendmsg
-    recvmsg = 47,                 //	64				    sys_recvmsg
-    shutdown = 48,                //	common			    sys_shutdown
-    bind = 49,                    //	common				sys_bind
-    listen = 50,                  //	common				sys_listen
-    getsockname = 51,             //	common			    sys_getsockname
-    getpeername = 52,             //	common			    sys_getpeername
-    socketpair = 53,              //	common			    sys_socketpair
-    setsockopt = 54,              //	64			        sys_setsockopt
-    getsockopt = 55,              //	64			        sys_getsockopt
-    clone = 56,                   //	common				sys_clone
-    fork = 57,                    //	common				sys_fork
-    vfork = 58,                   //	common				sys_vfork
-    execve = 59,                  //	64				    sys_execve
-    exit = 60,                    //	common				sys_exit
-    wait4 = 61,                   //	common				sys_wait4
-    kill = 62,                    //	common				sys_kill
-    uname = 63,                   //	common				sys_newuname
-    semget = 64,                  //	common				sys_semget
-    semop = 65,                   //	common				sys_semop
-    semctl = 66,                  //	common				sys_semctl
-    shmdt = 67,                   //	common				sys_shmdt
-    msgget = 68,                  //	common				sys_msgget
-    msgsnd = 69,                  //	common				sys_msgsnd
-    msgrcv = 70,                  //	common				sys_msgrcv
-    msgctl = 71,                  //	common				sys_msgctl
-    fcntl = 72,                   //	common				sys_fcntl
-    flock = 73,                   //	common				sys_flock
-    fsync = 74,                   //	common				sys_fsync
-    fdatasync = 75,               //	common			    sys_fdatasync
-    truncate = 76,                //	common			    sys_truncate
-    ftruncate = 77,               //	common			    sys_ftruncate
-    getdents = 78,                //	common			    sys_getdents
-    getcwd = 79,                  //	common				sys_getcwd
-    chdir = 80,                   //	common				sys_chdir
-    fchdir = 81,                  //	common				sys_fchdir
-    rename = 82,                  //	common				sys_rename
-    mkdir = 83,                   //	common				sys_mkdir
-    rmdir = 84,                   //	common				sys_rmdir
-    creat = 85,                   //	common				sys_creat
-    link = 86,                    //	common				sys_link
-    unlink = 87,                  //	common				sys_unlink
-    symlink = 88,                 //	common				sys_symlink
-    readlink = 89,                //	common			    sys_readlink
-    chmod = 90,                   //	common				sys_chmod
-    fchmod = 91,                  //	common				sys_fchmod
-    chown = 92,                   //	common				sys_chown
-    fchown = 93,                  //	common				sys_fchown
-    lchown = 94,                  //	common				sys_lchown
-    umask = 95,                   //	common				sys_umask
-    gettimeofday = 96,            //	common			    sys_gettimeofday
-    getrlimit = 97,               //	common			    sys_getrlimit
-    getrusage = 98,               //	common			    sys_getrusage
-    sysinfo = 99,                 //	common				sys_sysinfo
-    times = 100,                  //	common				sys_times
-    ptrace = 101,                 //	64				    sys_ptrace
-    getuid = 102,                 //	common				sys_getuid
-    syslog = 103,                 //	common				sys_syslog
-    getgid = 104,                 //	common				sys_getgid
-    setuid = 105,                 //	common				sys_setuid
-    setgid = 106,                 //	common				sys_setgid
-    geteuid = 107,                //	common				sys_geteuid
-    getegid = 108,                //	common				sys_getegid
-    setpgid = 109,                //	common				sys_setpgid
-    getppid = 110,                //	common				sys_getppid
-    getpgrp = 111,                //	common				sys_getpgrp
-    setsid = 112,                 //	common				sys_setsid
-    setreuid = 113,               //	common			    sys_setreuid
-    setregid = 114,               //	common			    sys_setregid
-    getgroups = 115,              //	common			    sys_getgroups
-    setgroups = 116,              //	common			    sys_setgroups
-    setresuid = 117,              //	common			    sys_setresuid
-    getresuid = 118,              //	common			    sys_getresuid
-    setresgid = 119,              //	common			    sys_setresgid
-    getresgid = 120,              //	common			    sys_getresgid
-    getpgid = 121,                //	common				sys_getpgid
-    setfsuid = 122,               //	common			    sys_setfsuid
-    setfsgid = 123,               //	common			    sys_setfsgid
-    getsid = 124,                 //	common				sys_getsid
-    capget = 125,                 //	common				sys_capget
-    capset = 126,                 //	common				sys_capset
-    rt_sigpending = 127,          //	64			        sys_rt_sigpending
-    rt_sigtimedwait = 128,        //	64			        sys_rt_sigtimedwait
-    rt_sigqueueinfo = 129,        //	64			        sys_rt_sigqueueinfo
-    rt_sigsuspend = 130,          //	common			    sys_rt_sigsuspend
-    sigaltstack = 131,            //	64			        sys_sigaltstack
-    utime = 132,                  //	common				sys_utime
-    mknod = 133,                  //	common				sys_mknod
-    uselib = 134,                 //	64
-    personality = 135,            //	common			    sys_personality
-    ustat = 136,                  //	common				sys_ustat
-    statfs = 137,                 //	common				sys_statfs
-    fstatfs = 138,                //	common				sys_fstatfs
-    sysfs = 139,                  //	common				sys_sysfs
-    getpriority = 140,            //	common			    sys_getpriority
-    setpriority = 141,            //	common			    sys_setpriority
-    sched_setparam = 142,         //	common			    sys_sched_setparam
-    sched_getparam = 143,         //	common			    sys_sched_getparam
-    sched_setscheduler = 144,     //	common		        sys_sched_setscheduler
-    sched_getscheduler = 145,     //	common		        sys_sched_getscheduler
-    sched_get_priority_max = 146, //	common		        sys_sched_get_priority_max
-    sched_get_priority_min = 147, //	common		        sys_sched_get_priority_min
-    sched_rr_get_interval = 148,  //	common		        sys_sched_rr_get_interval
-    mlock = 149,                  //	common				sys_mlock
-    munlock = 150,                //	common				sys_munlock
-    mlockall = 151,               //	common			    sys_mlockall
-    munlockall = 152,             //	common			    sys_munlockall
-    vhangup = 153,                //	common				sys_vhangup
-    modify_ldt = 154,             //	common			    sys_modify_ldt
-    pivot_root = 155,             //	common			    sys_pivot_root
-    sysctl_ = 156,                //	64				    sys_ni_syscall
-    prctl = 157,                  //	common				sys_prctl
-    arch_prctl = 158,             //	common			    sys_arch_prctl
-    adjtimex = 159,               //	common			    sys_adjtimex
-    setrlimit = 160,              //	common			    sys_setrlimit
-    chroot = 161,                 //	common				sys_chroot
-    sync = 162,                   //	common				sys_sync
-    acct = 163,                   //	common				sys_acct
-    settimeofday = 164,           //	common			    sys_settimeofday
-    mount = 165,                  //	common				sys_mount
-    umount2 = 166,                //	common				sys_umount
-    swapon = 167,                 //	common				sys_swapon
-    swapoff = 168,                //	common				sys_swapoff
-    reboot = 169,                 //	common				sys_reboot
-    sethostname = 170,            //	common			    sys_sethostname
-    setdomainname = 171,          //	common			    sys_setdomainname
-    iopl = 172,                   //	common				sys_iopl
-    ioperm = 173,                 //	common				sys_ioperm
-    create_module = 174,          //	64
-    init_module = 175,            //	common			    sys_init_module
-    delete_module = 176,          //	common			    sys_delete_module
-    get_kernel_syms = 177,        //	64
-    query_module = 178,           //	64
-    quotactl = 179,               //	common			    sys_quotactl
-    nfsservctl = 180,             //	64
-    getpmsg = 181,                //	common
-    putpmsg = 182,                //	common
-    afs_syscall = 183,            //	common
-    tuxcall = 184,                //	common
-    security = 185,               //	common
-    gettid = 186,                 //	common				sys_gettid
-    readahead = 187,              //	common			    sys_readahead
-    setxattr = 188,               //	common			    sys_setxattr
-    lsetxattr = 189,              //	common			    sys_lsetxattr
-    fsetxattr = 190,              //	common			    sys_fsetxattr
-    getxattr = 191,               //	common			    sys_getxattr
-    lgetxattr = 192,              //	common			    sys_lgetxattr
-    fgetxattr = 193,              //	common			    sys_fgetxattr
-    listxattr = 194,              //	common			    sys_listxattr
-    llistxattr = 195,             //	common			    sys_llistxattr
-    flistxattr = 196,             //	common			    sys_flistxattr
-    removexattr = 197,            //	common			    sys_removexattr
-    lremovexattr = 198,           //	common			    sys_lremovexattr
-    fremovexattr = 199,           //	common			    sys_fremovexattr
-    tkill = 200,                  //	common				sys_tkill
-    time = 201,                   //	common				sys_time
-    futex = 202,                  //	common				sys_futex
-    sched_setaffinity = 203,      //	common		        sys_sched_setaffinity
-    sched_getaffinity = 204,      //	common		        sys_sched_getaffinity
-    set_thread_area = 205,        //	64
-    io_setup = 206,               //	64			        sys_io_setup
-    io_destroy = 207,             //	common			    sys_io_destroy
-    io_getevents = 208,           //	common			    sys_io_getevents
-    io_submit = 209,              //	64			        sys_io_submit
-    io_cancel = 210,              //	common			    sys_io_cancel
-    get_thread_area = 211,        //	64
-    lookup_dcookie = 212,         //	common			    sys_lookup_dcookie
-    epoll_create = 213,           //	common			    sys_epoll_create
-    epoll_ctl_old = 214,          //	64
-    epoll_wait_old = 215,         //	64
-    remap_file_pages = 216,       //	common		        sys_remap_file_pages
-    getdents64 = 217,             //	common			    sys_getdents64
-    set_tid_address = 218,        //	common			    sys_set_tid_address
-    restart_syscall = 219,        //	common			    sys_restart_syscall
-    semtimedop = 220,             //	common			    sys_semtimedop
-    fadvise64 = 221,              //	common			    sys_fadvise64
-    timer_create = 222,           //	64			        sys_timer_create
-    timer_settime = 223,          //	common			    sys_timer_settime
-    timer_gettime = 224,          //	common			    sys_timer_gettime
-    timer_getoverrun = 225,       //	common		        sys_timer_getoverrun
-    timer_delete = 226,           //	common			    sys_timer_delete
-    clock_settime = 227,          //	common			    sys_clock_settime
-    clock_gettime = 228,          //	common			    sys_clock_gettime
-    clock_getres = 229,           //	common			    sys_clock_getres
-    clock_nanosleep = 230,        //	common			    sys_clock_nanosleep
-    exit_group = 231,             //	common			    sys_exit_group
-    epoll_wait = 232,             //	common			    sys_epoll_wait
-    epoll_ctl = 233,              //	common			    sys_epoll_ctl
-    tgkill = 234,                 //	common				sys_tgkill
-    utimes = 235,                 //	common				sys_utimes
-    vserver = 236,                //	64
-    mbind = 237,                  //	common				sys_mbind
-    set_mempolicy = 238,          //	common			    sys_set_mempolicy
-    get_mempolicy = 239,          //	common			    sys_get_mempolicy
-    mq_open = 240,                //	common				sys_mq_open
-    mq_unlink = 241,              //	common			    sys_mq_unlink
-    mq_timedsend = 242,           //	common			    sys_mq_timedsend
-    mq_timedreceive = 243,        //	common			    sys_mq_timedreceive
-    mq_notify = 244,              //	64			        sys_mq_notify
-    mq_getsetattr = 245,          //	common			    sys_mq_getsetattr
-    kexec_load = 246,             //	64			        sys_kexec_load
-    waitid = 247,                 //	64				    sys_waitid
-    add_key = 248,                //	common				sys_add_key
-    request_key = 249,            //	common			    sys_request_key
-    keyctl = 250,                 //	common				sys_keyctl
-    ioprio_set = 251,             //	common			    sys_ioprio_set
-    ioprio_get = 252,             //	common			    sys_ioprio_get
-    inotify_init = 253,           //	common			    sys_inotify_init
-    inotify_add_watch = 254,      //	common		        sys_inotify_add_watch
-    inotify_rm_watch = 255,       //	common		        sys_inotify_rm_watch
-    migrate_pages = 256,          //	common			    sys_migrate_pages
-    openat = 257,                 //	common				sys_openat
-    mkdirat = 258,                //	common				sys_mkdirat
-    mknodat = 259,                //	common				sys_mknodat
-    fchownat = 260,               //	common			    sys_fchownat
-    futimesat = 261,              //	common			    sys_futimesat
-    newfstatat = 262,             //	common			    sys_newfstatat
-    unlinkat = 263,               //	common			    sys_unlinkat
-    renameat = 264,               //	common			    sys_renameat
-    linkat = 265,                 //	common				sys_linkat
-    symlinkat = 266,              //	common			    sys_symlinkat
-    readlinkat = 267,             //	common			    sys_readlinkat
-    fchmodat = 268,               //	common			    sys_fchmodat
-    faccessat = 269,              //	common			    sys_faccessat
-    pselect6 = 270,               //	common			    sys_pselect6
-    ppoll = 271,                  //	common				sys_ppoll
-    unshare = 272,                //	common				sys_unshare
-    set_robust_list = 273,        //	64			        sys_set_robust_list
-    get_robust_list = 274,        //	64			        sys_get_robust_list
-    splice = 275,                 //	common				sys_splice
-    tee = 276,                    //	common				sys_tee
-    sync_file_range = 277,        //	common			    sys_sync_file_range
-    vmsplice = 278,               //	64			        sys_vmsplice
-    move_pages = 279,             //	64			        sys_move_pages
-    utimensat = 280,              //	common			    sys_utimensat
-    epoll_pwait = 281,            //	common			    sys_epoll_pwait
-    signalfd = 282,               //	common			    sys_signalfd
-    timerfd_create = 283,         //	common			    sys_timerfd_create
-    eventfd = 284,                //	common				sys_eventfd
-    fallocate = 285,              //	common			    sys_fallocate
-    timerfd_settime = 286,        //	common			    sys_timerfd_settime
-    timerfd_gettime = 287,        //	common			    sys_timerfd_gettime
-    accept4 = 288,                //	common				sys_accept4
-    signalfd4 = 289,              //	common			    sys_signalfd4
-    eventfd2 = 290,               //	common			    sys_eventfd2
-    epoll_create1 = 291,          //	common			    sys_epoll_create1
-    dup3 = 292,                   //	common				sys_dup3
-    pipe2 = 293,                  //	common				sys_pipe2
-    inotify_init1 = 294,          //	common			    sys_inotify_init1
-    preadv = 295,                 //	64				    sys_preadv
-    pwritev = 296,                //	64				    sys_pwritev
-    rt_tgsigqueueinfo = 297,      //	64		            sys_rt_tgsigqueueinfo
-    perf_event_open = 298,        //	common			    sys_perf_event_open
-    recvmmsg = 299,               //	64			        sys_recvmmsg
-    fanotify_init = 300,          //	common			    sys_fanotify_init
-    fanotify_mark = 301,          //	common			    sys_fanotify_mark
-    prlimit64 = 302,              //	common			    sys_prlimit64
-    name_to_handle_at = 303,      //	common		        sys_name_to_handle_at
-    open_by_handle_at = 304,      //	common		        sys_open_by_handle_at
-    clock_adjtime = 305,          //	common			    sys_clock_adjtime
-    syncfs = 306,                 //	common				sys_syncfs
-    sendmmsg = 307,               //	64			        sys_sendmmsg
-    setns = 308,                  //	common				sys_setns
-    getcpu = 309,                 //	common				sys_getcpu
-    process_vm_readv = 310,       //	64		            sys_process_vm_readv
-    process_vm_writev = 311,      //	64		            sys_process_vm_writev
-    kcmp = 312,                   //	common				sys_kcmp
-    finit_module = 313,           //	common			    sys_finit_module
-    sched_setattr = 314,          //	common			    sys_sched_setattr
-    sched_getattr = 315,          //	common			    sys_sched_getattr
-    renameat2 = 316,              //	common			    sys_renameat2
-    seccomp = 317,                //	common				sys_seccomp
-    getrandom = 318,              //	common			    sys_getrandom
-    memfd_create = 319,           //	common			    sys_memfd_create
-    kexec_file_load = 320,        //	common			    sys_kexec_file_load
-    bpf = 321,                    //	common				sys_bpf
-    execveat = 322,               //	64			        sys_execveat
-    userfaultfd = 323,            //	common			    sys_userfaultfd
-    membarrier = 324,             //	common			    sys_membarrier
-    mlock2 = 325,                 //	common				sys_mlock2
-    copy_file_range = 326,        //	common			    sys_copy_file_range
-    preadv2 = 327,                //	64				    sys_preadv2
-    pwritev2 = 328,               //	64			        sys_pwritev2
-    pkey_mprotect = 329,          //	common			    sys_pkey_mprotect
-    pkey_alloc = 330,             //	common			    sys_pkey_alloc
-    pkey_free = 331,              //	common			    sys_pkey_free
-    statx = 332,                  //	common				sys_statx
-    io_pgetevents = 333,          //	common			    sys_io_pgetevents
-    rseq = 334,                   //	common				sys_rseq
-    // # don't use numbers 387 through 423, add new calls after the last
-    // # 'common' entry
-    pidfd_send_signal = 424,       //	common		        sys_pidfd_send_signal
-    io_uring_setup = 425,          //	common			    sys_io_uring_setup
-    io_uring_enter = 426,          //	common			    sys_io_uring_enter
-    io_uring_register = 427,       //	common		        sys_io_uring_register
-    open_tree = 428,               //	common			    sys_open_tree
-    move_mount = 429,              //	common			    sys_move_mount
-    fsopen = 430,                  //	common				sys_fsopen
-    fsconfig = 431,                //	common			    sys_fsconfig
-    fsmount = 432,                 //	common				sys_fsmount
-    fspick = 433,                  //	common				sys_fspick
-    pidfd_open = 434,              //	common			    sys_pidfd_open
-    clone3 = 435,                  //	common				sys_clone3
-    close_range = 436,             //	common			    sys_close_range
-    openat2 = 437,                 //	common				sys_openat2
-    pidfd_getfd = 438,             //	common			    sys_pidfd_getfd
-    faccessat2 = 439,              //	common			    sys_faccessat2
-    process_madvise = 440,         //	common			    sys_process_madvise
-    epoll_pwait2 = 441,            //	common			    sys_epoll_pwait2
-    mount_setattr = 442,           //	common			    sys_mount_setattr
-    quotactl_fd = 443,             //	common			    sys_quotactl_fd
-    landlock_create_ruleset = 444, //	common		        sys_landlock_create_ruleset
-    landlock_add_rule = 445,       //	common		        sys_landlock_add_rule
-    landlock_restrict_self = 446,  //	common		        sys_landlock_restrict_self
-    memfd_secret = 447,            //	common			    sys_memfd_secret
-    process_mrelease = 448,        //	common		        sys_process_mrelease
-    futex_waitv = 449,             //	common			    sys_futex_waitv
-    set_mempolicy_home_node = 450, //	common		        sys_set_mempolicy_home_node
+use crate::arch::SysCallAbi;
+
+// the syscalls of the x86_64 ABI (the ABI "common" and "64").
+syscall_num! {
+    pub enum SysCallNum {
+        read = 0,                      // common  sys_read
+        write = 1,                     // common  sys_write
+        open = 2,                      // common  sys_open
+        close = 3,                     // common  sys_close
+        stat = 4,                      // common  sys_newstat
+        fstat = 5,                     // common  sys_newfstat
+        lstat = 6,                     // common  sys_newlstat
+        poll = 7,                      // common  sys_poll
+        lseek = 8,                     // common  sys_lseek
+        mmap = 9,                      // common  sys_mmap
+        mprotect = 10,                 // common  sys_mprotect
+        munmap = 11,                   // common  sys_munmap
+        brk = 12,                      // common  sys_brk
+        rt_sigaction = 13,             // 64      sys_rt_sigaction
+        rt_sigprocmask = 14,           // common  sys_rt_sigprocmask
+        rt_sigreturn = 15,             // 64      sys_rt_sigreturn
+        ioctl = 16,                    // 64      sys_ioctl
+        pread64 = 17,                  // common  sys_pread64
+        pwrite64 = 18,                 // common  sys_pwrite64
+        readv = 19,                    // 64      sys_readv
+        writev = 20,                   // 64      sys_writev
+        access = 21,                   // common  sys_access
+        pipe = 22,                     // common  sys_pipe
+        select = 23,                   // common  sys_select
+        sched_yield = 24,              // common  sys_sched_yield
+        mremap = 25,                   // common  sys_mremap
+        msync = 26,                    // common  sys_msync
+        mincore = 27,                  // common  sys_mincore
+        madvise = 28,                  // common  sys_madvise
+        shmget = 29,                   // common  sys_shmget
+        shmat = 30,                    // common  sys_shmat
+        shmctl = 31,                   // common  sys_shmctl
+        dup = 32,                      // common  sys_dup
+        dup2 = 33,                     // common  sys_dup2
+        pause = 34,                    // common  sys_pause
+        nanosleep = 35,                // common  sys_nanosleep
+        getitimer = 36,                // common  sys_getitimer
+        alarm = 37,                    // common  sys_alarm
+        setitimer = 38,                // common  sys_setitimer
+        getpid = 39,                   // common  sys_getpid
+        sendfile = 40,                 // common  sys_sendfile64
+        socket = 41,                   // common  sys_socket
+        connect = 42,                  // common  sys_connect
+        accept = 43,                   // common  sys_accept
+        sendto = 44,                   // common  sys_sendto
+        recvfrom = 45,                 // 64      sys_recvfrom
+        sendmsg = 46,                  // 64      sys_sendmsg
+        recvmsg = 47,                  // 64      sys_recvmsg
+        shutdown = 48,                 // common  sys_shutdown
+        bind = 49,                     // common  sys_bind
+        listen = 50,                   // common  sys_listen
+        getsockname = 51,              // common  sys_getsockname
+        getpeername = 52,              // common  sys_getpeername
+        socketpair = 53,               // common  sys_socketpair
+        setsockopt = 54,               // 64      sys_setsockopt
+        getsockopt = 55,               // 64      sys_getsockopt
+        clone = 56,                    // common  sys_clone
+        fork = 57,                     // common  sys_fork
+        vfork = 58,                    // common  sys_vfork
+        execve = 59,                   // 64      sys_execve
+        exit = 60,                     // common  sys_exit
+        wait4 = 61,                    // common  sys_wait4
+        kill = 62,                     // common  sys_kill
+        uname = 63,                    // common  sys_newuname
+        semget = 64,                   // common  sys_semget
+        semop = 65,                    // common  sys_semop
+        semctl = 66,                   // common  sys_semctl
+        shmdt = 67,                    // common  sys_shmdt
+        msgget = 68,                   // common  sys_msgget
+        msgsnd = 69,                   // common  sys_msgsnd
+        msgrcv = 70,                   // common  sys_msgrcv
+        msgctl = 71,                   // common  sys_msgctl
+        fcntl = 72,                    // common  sys_fcntl
+        flock = 73,                    // common  sys_flock
+        fsync = 74,                    // common  sys_fsync
+        fdatasync = 75,                // common  sys_fdatasync
+        truncate = 76,                 // common  sys_truncate
+        ftruncate = 77,                // common  sys_ftruncate
+        getdents = 78,                 // common  sys_getdents
+        getcwd = 79,                   // common  sys_getcwd
+        chdir = 80,                    // common  sys_chdir
+        fchdir = 81,                   // common  sys_fchdir
+        rename = 82,                   // common  sys_rename
+        mkdir = 83,                    // common  sys_mkdir
+        rmdir = 84,                    // common  sys_rmdir
+        creat = 85,                    // common  sys_creat
+        link = 86,                     // common  sys_link
+        unlink = 87,                   // common  sys_unlink
+        symlink = 88,                  // common  sys_symlink
+        readlink = 89,                 // common  sys_readlink
+        chmod = 90,                    // common  sys_chmod
+        fchmod = 91,                   // common  sys_fchmod
+        chown = 92,                    // common  sys_chown
+        fchown = 93,                   // common  sys_fchown
+        lchown = 94,                   // common  sys_lchown
+        umask = 95,                    // common  sys_umask
+        gettimeofday = 96,             // common  sys_gettimeofday
+        getrlimit = 97,                // common  sys_getrlimit
+        getrusage = 98,                // common  sys_getrusage
+        sysinfo = 99,                  // common  sys_sysinfo
+        times = 100,                   // common  sys_times
+        ptrace = 101,                  // 64      sys_ptrace
+        getuid = 102,                  // common  sys_getuid
+        syslog = 103,                  // common  sys_syslog
+        getgid = 104,                  // common  sys_getgid
+        setuid = 105,                  // common  sys_setuid
+        setgid = 106,                  // common  sys_setgid
+        geteuid = 107,                 // common  sys_geteuid
+        getegid = 108,                 // common  sys_getegid
+        setpgid = 109,                 // common  sys_setpgid
+        getppid = 110,                 // common  sys_getppid
+        getpgrp = 111,                 // common  sys_getpgrp
+        setsid = 112,                  // common  sys_setsid
+        setreuid = 113,                // common  sys_setreuid
+        setregid = 114,                // common  sys_setregid
+        getgroups = 115,               // common  sys_getgroups
+        setgroups = 116,               // common  sys_setgroups
+        setresuid = 117,               // common  sys_setresuid
+        getresuid = 118,               // common  sys_getresuid
+        setresgid = 119,               // common  sys_setresgid
+        getresgid = 120,               // common  sys_getresgid
+        getpgid = 121,                 // common  sys_getpgid
+        setfsuid = 122,                // common  sys_setfsuid
+        setfsgid = 123,                // common  sys_setfsgid
+        getsid = 124,                  // common  sys_getsid
+        capget = 125,                  // common  sys_capget
+        capset = 126,                  // common  sys_capset
+        rt_sigpending = 127,           // 64      sys_rt_sigpending
+        rt_sigtimedwait = 128,         // 64      sys_rt_sigtimedwait
+        rt_sigqueueinfo = 129,         // 64      sys_rt_sigqueueinfo
+        rt_sigsuspend = 130,           // common  sys_rt_sigsuspend
+        sigaltstack = 131,             // 64      sys_sigaltstack
+        utime = 132,                   // common  sys_utime
+        mknod = 133,                   // common  sys_mknod
+        uselib = 134,                  // 64
+        personality = 135,             // common  sys_personality
+        ustat = 136,                   // common  sys_ustat
+        statfs = 137,                  // common  sys_statfs
+        fstatfs = 138,                 // common  sys_fstatfs
+        sysfs = 139,                   // common  sys_sysfs
+        getpriority = 140,             // common  sys_getpriority
+        setpriority = 141,             // common  sys_setpriority
+        sched_setparam = 142,          // common  sys_sched_setparam
+        sched_getparam = 143,          // common  sys_sched_getparam
+        sched_setscheduler = 144,      // common  sys_sched_setscheduler
+        sched_getscheduler = 145,      // common  sys_sched_getscheduler
+        sched_get_priority_max = 146,  // common  sys_sched_get_priority_max
+        sched_get_priority_min = 147,  // common  sys_sched_get_priority_min
+        sched_rr_get_interval = 148,   // common  sys_sched_rr_get_interval
+        mlock = 149,                   // common  sys_mlock
+        munlock = 150,                 // common  sys_munlock
+        mlockall = 151,                // common  sys_mlockall
+        munlockall = 152,              // common  sys_munlockall
+        vhangup = 153,                 // common  sys_vhangup
+        modify_ldt = 154,              // common  sys_modify_ldt
+        pivot_root = 155,              // common  sys_pivot_root
+        #[name = "_sysctl"]
+        sysctl_ = 156,                 // 64      sys_ni_syscall
+        prctl = 157,                   // common  sys_prctl
+        arch_prctl = 158,              // common  sys_arch_prctl
+        adjtimex = 159,                // common  sys_adjtimex
+        setrlimit = 160,               // common  sys_setrlimit
+        chroot = 161,                  // common  sys_chroot
+        sync = 162,                    // common  sys_sync
+        acct = 163,                    // common  sys_acct
+        settimeofday = 164,            // common  sys_settimeofday
+        mount = 165,                   // common  sys_mount
+        umount2 = 166,                 // common  sys_umount
+        swapon = 167,                  // common  sys_swapon
+        swapoff = 168,                 // common  sys_swapoff
+        reboot = 169,                  // common  sys_reboot
+        sethostname = 170,             // common  sys_sethostname
+        setdomainname = 171,           // common  sys_setdomainname
+        iopl = 172,                    // common  sys_iopl
+        ioperm = 173,                  // common  sys_ioperm
+        create_module = 174,           // 64
+        init_module = 175,             // common  sys_init_module
+        delete_module = 176,           // common  sys_delete_module
+        get_kernel_syms = 177,         // 64
+        query_module = 178,            // 64
+        quotactl = 179,                // common  sys_quotactl
+        nfsservctl = 180,              // 64
+        getpmsg = 181,                 // common
+        putpmsg = 182,                 // common
+        afs_syscall = 183,             // common
+        tuxcall = 184,                 // common
+        security = 185,                // common
+        gettid = 186,                  // common  sys_gettid
+        readahead = 187,               // common  sys_readahead
+        setxattr = 188,                // common  sys_setxattr
+        lsetxattr = 189,               // common  sys_lsetxattr
+        fsetxattr = 190,               // common  sys_fsetxattr
+        getxattr = 191,                // common  sys_getxattr
+        lgetxattr = 192,               // common  sys_lgetxattr
+        fgetxattr = 193,               // common  sys_fgetxattr
+        listxattr = 194,               // common  sys_listxattr
+        llistxattr = 195,              // common  sys_llistxattr
+        flistxattr = 196,              // common  sys_flistxattr
+        removexattr = 197,             // common  sys_removexattr
+        lremovexattr = 198,            // common  sys_lremovexattr
+        fremovexattr = 199,            // common  sys_fremovexattr
+        tkill = 200,                   // common  sys_tkill
+        time = 201,                    // common  sys_time
+        futex = 202,                   // common  sys_futex
+        sched_setaffinity = 203,       // common  sys_sched_setaffinity
+        sched_getaffinity = 204,       // common  sys_sched_getaffinity
+        set_thread_area = 205,         // 64
+        io_setup = 206,                // 64      sys_io_setup
+        io_destroy = 207,              // common  sys_io_destroy
+        io_getevents = 208,            // common  sys_io_getevents
+        io_submit = 209,               // 64      sys_io_submit
+        io_cancel = 210,               // common  sys_io_cancel
+        get_thread_area = 211,         // 64
+        lookup_dcookie = 212,          // common
+        epoll_create = 213,            // common  sys_epoll_create
+        epoll_ctl_old = 214,           // 64
+        epoll_wait_old = 215,          // 64
+        remap_file_pages = 216,        // common  sys_remap_file_pages
+        getdents64 = 217,              // common  sys_getdents64
+        set_tid_address = 218,         // common  sys_set_tid_address
+        restart_syscall = 219,         // common  sys_restart_syscall
+        semtimedop = 220,              // common  sys_semtimedop
+        fadvise64 = 221,               // common  sys_fadvise64
+        timer_create = 222,            // 64      sys_timer_create
+        timer_settime = 223,           // common  sys_timer_settime
+        timer_gettime = 224,           // common  sys_timer_gettime
+        timer_getoverrun = 225,        // common  sys_timer_getoverrun
+        timer_delete = 226,            // common  sys_timer_delete
+        clock_settime = 227,           // common  sys_clock_settime
+        clock_gettime = 228,           // common  sys_clock_gettime
+        clock_getres = 229,            // common  sys_clock_getres
+        clock_nanosleep = 230,         // common  sys_clock_nanosleep
+        exit_group = 231,              // common  sys_exit_group
+        epoll_wait = 232,              // common  sys_epoll_wait
+        epoll_ctl = 233,               // common  sys_epoll_ctl
+        tgkill = 234,                  // common  sys_tgkill
+        utimes = 235,                  // common  sys_utimes
+        vserver = 236,                 // 64
+        mbind = 237,                   // common  sys_mbind
+        set_mempolicy = 238,           // common  sys_set_mempolicy
+        get_mempolicy = 239,           // common  sys_get_mempolicy
+        mq_open = 240,                 // common  sys_mq_open
+        mq_unlink = 241,               // common  sys_mq_unlink
+        mq_timedsend = 242,            // common  sys_mq_timedsend
+        mq_timedreceive = 243,         // common  sys_mq_timedreceive
+        mq_notify = 244,               // 64      sys_mq_notify
+        mq_getsetattr = 245,           // common  sys_mq_getsetattr
+        kexec_load = 246,              // 64      sys_kexec_load
+        waitid = 247,                  // 64      sys_waitid
+        add_key = 248,                 // common  sys_add_key
+        request_key = 249,             // common  sys_request_key
+        keyctl = 250,                  // common  sys_keyctl
+        ioprio_set = 251,              // common  sys_ioprio_set
+        ioprio_get = 252,              // common  sys_ioprio_get
+        inotify_init = 253,            // common  sys_inotify_init
+        inotify_add_watch = 254,       // common  sys_inotify_add_watch
+        inotify_rm_watch = 255,        // common  sys_inotify_rm_watch
+        migrate_pages = 256,           // common  sys_migrate_pages
+        openat = 257,                  // common  sys_openat
+        mkdirat = 258,                 // common  sys_mkdirat
+        mknodat = 259,                 // common  sys_mknodat
+        fchownat = 260,                // common  sys_fchownat
+        futimesat = 261,               // common  sys_futimesat
+        newfstatat = 262,              // common  sys_newfstatat
+        unlinkat = 263,                // common  sys_unlinkat
+        renameat = 264,                // common  sys_renameat
+        linkat = 265,                  // common  sys_linkat
+        symlinkat = 266,               // common  sys_symlinkat
+        readlinkat = 267,              // common  sys_readlinkat
+        fchmodat = 268,                // common  sys_fchmodat
+        faccessat = 269,               // common  sys_faccessat
+        pselect6 = 270,                // common  sys_pselect6
+        ppoll = 271,                   // common  sys_ppoll
+        unshare = 272,                 // common  sys_unshare
+        set_robust_list = 273,         // 64      sys_set_robust_list
+        get_robust_list = 274,         // 64      sys_get_robust_list
+        splice = 275,                  // common  sys_splice
+        tee = 276,                     // common  sys_tee
+        sync_file_range = 277,         // common  sys_sync_file_range
+        vmsplice = 278,                // 64      sys_vmsplice
+        move_pages = 279,              // 64      sys_move_pages
+        utimensat = 280,               // common  sys_utimensat
+        epoll_pwait = 281,             // common  sys_epoll_pwait
+        signalfd = 282,                // common  sys_signalfd
+        timerfd_create = 283,          // common  sys_timerfd_create
+        eventfd = 284,                 // common  sys_eventfd
+        fallocate = 285,               // common  sys_fallocate
+        timerfd_settime = 286,         // common  sys_timerfd_settime
+        timerfd_gettime = 287,         // common  sys_timerfd_gettime
+        accept4 = 288,                 // common  sys_accept4
+        signalfd4 = 289,               // common  sys_signalfd4
+        eventfd2 = 290,                // common  sys_eventfd2
+        epoll_create1 = 291,           // common  sys_epoll_create1
+        dup3 = 292,                    // common  sys_dup3
+        pipe2 = 293,                   // common  sys_pipe2
+        inotify_init1 = 294,           // common  sys_inotify_init1
+        preadv = 295,                  // 64      sys_preadv
+        pwritev = 296,                 // 64      sys_pwritev
+        rt_tgsigqueueinfo = 297,       // 64      sys_rt_tgsigqueueinfo
+        perf_event_open = 298,         // common  sys_perf_event_open
+        recvmmsg = 299,                // 64      sys_recvmmsg
+        fanotify_init = 300,           // common  sys_fanotify_init
+        fanotify_mark = 301,           // common  sys_fanotify_mark
+        prlimit64 = 302,               // common  sys_prlimit64
+        name_to_handle_at = 303,       // common  sys_name_to_handle_at
+        open_by_handle_at = 304,       // common  sys_open_by_handle_at
+        clock_adjtime = 305,           // common  sys_clock_adjtime
+        syncfs = 306,                  // common  sys_syncfs
+        sendmmsg = 307,                // 64      sys_sendmmsg
+        setns = 308,                   // common  sys_setns
+        getcpu = 309,                  // common  sys_getcpu
+        process_vm_readv = 310,        // 64      sys_process_vm_readv
+        process_vm_writev = 311,       // 64      sys_process_vm_writev
+        kcmp = 312,                    // common  sys_kcmp
+        finit_module = 313,            // common  sys_finit_module
+        sched_setattr = 314,           // common  sys_sched_setattr
+        sched_getattr = 315,           // common  sys_sched_getattr
+        renameat2 = 316,               // common  sys_renameat2
+        seccomp = 317,                 // common  sys_seccomp
+        getrandom = 318,               // common  sys_getrandom
+        memfd_create = 319,            // common  sys_memfd_create
+        kexec_file_load = 320,         // common  sys_kexec_file_load
+        bpf = 321,                     // common  sys_bpf
+        execveat = 322,                // 64      sys_execveat
+        userfaultfd = 323,             // common  sys_userfaultfd
+        membarrier = 324,              // common  sys_membarrier
+        mlock2 = 325,                  // common  sys_mlock2
+        copy_file_range = 326,         // common  sys_copy_file_range
+        preadv2 = 327,                 // 64      sys_preadv2
+        pwritev2 = 328,                // 64      sys_pwritev2
+        pkey_mprotect = 329,           // common  sys_pkey_mprotect
+        pkey_alloc = 330,              // common  sys_pkey_alloc
+        pkey_free = 331,               // common  sys_pkey_free
+        statx = 332,                   // common  sys_statx
+        io_pgetevents = 333,           // common  sys_io_pgetevents
+        rseq = 334,                    // common  sys_rseq
+        uretprobe = 335,               // common  sys_uretprobe
+        // 336 - 423 are not used
+        pidfd_send_signal = 424,       // common  sys_pidfd_send_signal
+        io_uring_setup = 425,          // common  sys_io_uring_setup
+        io_uring_enter = 426,          // common  sys_io_uring_enter
+        io_uring_register = 427,       // common  sys_io_uring_register
+        open_tree = 428,               // common  sys_open_tree
+        move_mount = 429,              // common  sys_move_mount
+        fsopen = 430,                  // common  sys_fsopen
+        fsconfig = 431,                // common  sys_fsconfig
+        fsmount = 432,                 // common  sys_fsmount
+        fspick = 433,                  // common  sys_fspick
+        pidfd_open = 434,              // common  sys_pidfd_open
+        clone3 = 435,                  // common  sys_clone3
+        close_range = 436,             // common  sys_close_range
+        openat2 = 437,                 // common  sys_openat2
+        pidfd_getfd = 438,             // common  sys_pidfd_getfd
+        faccessat2 = 439,              // common  sys_faccessat2
+        process_madvise = 440,         // common  sys_process_madvise
+        epoll_pwait2 = 441,            // common  sys_epoll_pwait2
+        mount_setattr = 442,           // common  sys_mount_setattr
+        quotactl_fd = 443,             // common  sys_quotactl_fd
+        landlock_create_ruleset = 444, // common  sys_landlock_create_ruleset
+        landlock_add_rule = 445,       // common  sys_landlock_add_rule
+        landlock_restrict_self = 446,  // common  sys_landlock_restrict_self
+        memfd_secret = 447,            // common  sys_memfd_secret
+        process_mrelease = 448,        // common  sys_process_mrelease
+        futex_waitv = 449,             // common  sys_futex_waitv
+        set_mempolicy_home_node = 450, // common  sys_set_mempolicy_home_node
+        cachestat = 451,               // common  sys_cachestat
+        fchmodat2 = 452,               // common  sys_fchmodat2
+        map_shadow_stack = 453,        // 64      sys_map_shadow_stack
+        futex_wake = 454,              // common  sys_futex_wake
+        futex_wait = 455,              // common  sys_futex_wait
+        futex_requeue = 456,           // common  sys_futex_requeue
+        statmount = 457,               // common  sys_statmount
+        listmount = 458,               // common  sys_listmount
+        lsm_get_self_attr = 459,       // common  sys_lsm_get_self_attr
+        lsm_set_self_attr = 460,       // common  sys_lsm_set_self_attr
+        lsm_list_modules = 461,        // common  sys_lsm_list_modules
+        mseal = 462,                   // common  sys_mseal
+        setxattrat = 463,              // common  sys_setxattrat
+        getxattrat = 464,              // common  sys_getxattrat
+        listxattrat = 465,             // common  sys_listxattrat
+        removexattrat = 466,           // common  sys_removexattrat
+    }
 }
 
-// # Due to a historical design error, certain syscalls are numbered differently
-// # in x32 as compared to native x86_64.
-// # These syscalls have numbers 512-547.
-// # Do not add new syscalls to this range.
-// # Numbers 548 and above are available for non-x32 use.
+impl SysCallNum {
+    // the ABI column of the syscall table.
+    pub fn abi(&self) -> SysCallAbi {
+        match self {
+            SysCallNum::rt_sigaction
+            | SysCallNum::rt_sigreturn
+            | SysCallNum::ioctl
+            | SysCallNum::readv
+            | SysCallNum::writev
+            | SysCallNum::recvfrom
+            | SysCallNum::sendmsg
+            | SysCallNum::recvmsg
+            | SysCallNum::setsockopt
+            | SysCallNum::getsockopt
+            | SysCallNum::execve
+            | SysCallNum::ptrace
+            | SysCallNum::rt_sigpending
+            | SysCallNum::rt_sigtimedwait
+            | SysCallNum::rt_sigqueueinfo
+            | SysCallNum::sigaltstack
+            | SysCallNum::uselib
+            | SysCallNum::sysctl_
+            | SysCallNum::create_module
+            | SysCallNum::get_kernel_syms
+            | SysCallNum::query_module
+            | SysCallNum::nfsservctl
+            | SysCallNum::set_thread_area
+            | SysCallNum::io_setup
+            | SysCallNum::io_submit
+            | SysCallNum::get_thread_area
+            | SysCallNum::epoll_ctl_old
+            | SysCallNum::epoll_wait_old
+            | SysCallNum::timer_create
+            | SysCallNum::vserver
+            | SysCallNum::mq_notify
+            | SysCallNum::kexec_load
+            | SysCallNum::waitid
+            | SysCallNum::set_robust_list
+            | SysCallNum::get_robust_list
+            | SysCallNum::vmsplice
+            | SysCallNum::move_pages
+            | SysCallNum::preadv
+            | SysCallNum::pwritev
+            | SysCallNum::rt_tgsigqueueinfo
+            | SysCallNum::recvmmsg
+            | SysCallNum::sendmmsg
+            | SysCallNum::process_vm_readv
+            | SysCallNum::process_vm_writev
+            | SysCallNum::execveat
+            | SysCallNum::preadv2
+            | SysCallNum::pwritev2
+            | SysCallNum::map_shadow_stack => SysCallAbi::X64,
+            _ => SysCallAbi::Common,
+        }
+    }
+
+    // the name of the kernel function which implements the syscall,
+    // e.g. "sys_read", or `None` if the syscall is not implemented.
+    pub fn entry_point(&self) -> Option<&'static str> {
+        match self {
+            SysCallNum::read => Some("sys_read"),
+            SysCallNum::write => Some("sys_write"),
+            SysCallNum::open => Some("sys_open"),
+            SysCallNum::close => Some("sys_close"),
+            SysCallNum::stat => Some("sys_newstat"),
+            SysCallNum::fstat => Some("sys_newfstat"),
+            SysCallNum::lstat => Some("sys_newlstat"),
+            SysCallNum::poll => Some("sys_poll"),
+            SysCallNum::lseek => Some("sys_lseek"),
+            SysCallNum::mmap => Some("sys_mmap"),
+            SysCallNum::mprotect => Some("sys_mprotect"),
+            SysCallNum::munmap => Some("sys_munmap"),
+            SysCallNum::brk => Some("sys_brk"),
+            SysCallNum::rt_sigaction => Some("sys_rt_sigaction"),
+            SysCallNum::rt_sigprocmask => Some("sys_rt_sigprocmask"),
+            SysCallNum::rt_sigreturn => Some("sys_rt_sigreturn"),
+            SysCallNum::ioctl => Some("sys_ioctl"),
+            SysCallNum::pread64 => Some("sys_pread64"),
+            SysCallNum::pwrite64 => Some("sys_pwrite64"),
+            SysCallNum::readv => Some("sys_readv"),
+            SysCallNum::writev => Some("sys_writev"),
+            SysCallNum::access => Some("sys_access"),
+            SysCallNum::pipe => Some("sys_pipe"),
+            SysCallNum::select => Some("sys_select"),
+            SysCallNum::sched_yield => Some("sys_sched_yield"),
+            SysCallNum::mremap => Some("sys_mremap"),
+            SysCallNum::msync => Some("sys_msync"),
+            SysCallNum::mincore => Some("sys_mincore"),
+            SysCallNum::madvise => Some("sys_madvise"),
+            SysCallNum::shmget => Some("sys_shmget"),
+            SysCallNum::shmat => Some("sys_shmat"),
+            SysCallNum::shmctl => Some("sys_shmctl"),
+            SysCallNum::dup => Some("sys_dup"),
+            SysCallNum::dup2 => Some("sys_dup2"),
+            SysCallNum::pause => Some("sys_pause"),
+            SysCallNum::nanosleep => Some("sys_nanosleep"),
+            SysCallNum::getitimer => Some("sys_getitimer"),
+            SysCallNum::alarm => Some("sys_alarm"),
+            SysCallNum::setitimer => Some("sys_setitimer"),
+            SysCallNum::getpid => Some("sys_getpid"),
+            SysCallNum::sendfile => Some("sys_sendfile64"),
+            SysCallNum::socket => Some("sys_socket"),
+            SysCallNum::connect => Some("sys_connect"),
+            SysCallNum::accept => Some("sys_accept"),
+            SysCallNum::sendto => Some("sys_sendto"),
+            SysCallNum::recvfrom => Some("sys_recvfrom"),
+            SysCallNum::sendmsg => Some("sys_sendmsg"),
+            SysCallNum::recvmsg => Some("sys_recvmsg"),
+            SysCallNum::shutdown => Some("sys_shutdown"),
+            SysCallNum::bind => Some("sys_bind"),
+            SysCallNum::listen => Some("sys_listen"),
+            SysCallNum::getsockname => Some("sys_getsockname"),
+            SysCallNum::getpeername => Some("sys_getpeername"),
+            SysCallNum::socketpair => Some("sys_socketpair"),
+            SysCallNum::setsockopt => Some("sys_setsockopt"),
+            SysCallNum::getsockopt => Some("sys_getsockopt"),
+            SysCallNum::clone => Some("sys_clone"),
+            SysCallNum::fork => Some("sys_fork"),
+            SysCallNum::vfork => Some("sys_vfork"),
+            SysCallNum::execve => Some("sys_execve"),
+            SysCallNum::exit => Some("sys_exit"),
+            SysCallNum::wait4 => Some("sys_wait4"),
+            SysCallNum::kill => Some("sys_kill"),
+            SysCallNum::uname => Some("sys_newuname"),
+            SysCallNum::semget => Some("sys_semget"),
+            SysCallNum::semop => Some("sys_semop"),
+            SysCallNum::semctl => Some("sys_semctl"),
+            SysCallNum::shmdt => Some("sys_shmdt"),
+            SysCallNum::msgget => Some("sys_msgget"),
+            SysCallNum::msgsnd => Some("sys_msgsnd"),
+            SysCallNum::msgrcv => Some("sys_msgrcv"),
+            SysCallNum::msgctl => Some("sys_msgctl"),
+            SysCallNum::fcntl => Some("sys_fcntl"),
+            SysCallNum::flock => Some("sys_flock"),
+            SysCallNum::fsync => Some("sys_fsync"),
+            SysCallNum::fdatasync => Some("sys_fdatasync"),
+            SysCallNum::truncate => Some("sys_truncate"),
+            SysCallNum::ftruncate => Some("sys_ftruncate"),
+            SysCallNum::getdents => Some("sys_getdents"),
+            SysCallNum::getcwd => Some("sys_getcwd"),
+            SysCallNum::chdir => Some("sys_chdir"),
+            SysCallNum::fchdir => Some("sys_fchdir"),
+            SysCallNum::rename => Some("sys_rename"),
+            SysCallNum::mkdir => Some("sys_mkdir"),
+            SysCallNum::rmdir => Some("sys_rmdir"),
+            SysCallNum::creat => Some("sys_creat"),
+            SysCallNum::link => Some("sys_link"),
+            SysCallNum::unlink => Some("sys_unlink"),
+            SysCallNum::symlink => Some("sys_symlink"),
+            SysCallNum::readlink => Some("sys_readlink"),
+            SysCallNum::chmod => Some("sys_chmod"),
+            SysCallNum::fchmod => Some("sys_fchmod"),
+            SysCallNum::chown => Some("sys_chown"),
+            SysCallNum::fchown => Some("sys_fchown"),
+            SysCallNum::lchown => Some("sys_lchown"),
+            SysCallNum::umask => Some("sys_umask"),
+            SysCallNum::gettimeofday => Some("sys_gettimeofday"),
+            SysCallNum::getrlimit => Some("sys_getrlimit"),
+            SysCallNum::getrusage => Some("sys_getrusage"),
+            SysCallNum::sysinfo => Some("sys_sysinfo"),
+            SysCallNum::times => Some("sys_times"),
+            SysCallNum::ptrace => Some("sys_ptrace"),
+            SysCallNum::getuid => Some("sys_getuid"),
+            SysCallNum::syslog => Some("sys_syslog"),
+            SysCallNum::getgid => Some("sys_getgid"),
+            SysCallNum::setuid => Some("sys_setuid"),
+            SysCallNum::setgid => Some("sys_setgid"),
+            SysCallNum::geteuid => Some("sys_geteuid"),
+            SysCallNum::getegid => Some("sys_getegid"),
+            SysCallNum::setpgid => Some("sys_setpgid"),
+            SysCallNum::getppid => Some("sys_getppid"),
+            SysCallNum::getpgrp => Some("sys_getpgrp"),
+            SysCallNum::setsid => Some("sys_setsid"),
+            SysCallNum::setreuid => Some("sys_setreuid"),
+            SysCallNum::setregid => Some("sys_setregid"),
+            SysCallNum::getgroups => Some("sys_getgroups"),
+            SysCallNum::setgroups => Some("sys_setgroups"),
+            SysCallNum::setresuid => Some("sys_setresuid"),
+            SysCallNum::getresuid => Some("sys_getresuid"),
+            SysCallNum::setresgid => Some("sys_setresgid"),
+            SysCallNum::getresgid => Some("sys_getresgid"),
+            SysCallNum::getpgid => Some("sys_getpgid"),
+            SysCallNum::setfsuid => Some("sys_setfsuid"),
+            SysCallNum::setfsgid => Some("sys_setfsgid"),
+            SysCallNum::getsid => Some("sys_getsid"),
+            SysCallNum::capget => Some("sys_capget"),
+            SysCallNum::capset => Some("sys_capset"),
+            SysCallNum::rt_sigpending => Some("sys_rt_sigpending"),
+            SysCallNum::rt_sigtimedwait => Some("sys_rt_sigtimedwait"),
+            SysCallNum::rt_sigqueueinfo => Some("sys_rt_sigqueueinfo"),
+            SysCallNum::rt_sigsuspend => Some("sys_rt_sigsuspend"),
+            SysCallNum::sigaltstack => Some("sys_sigaltstack"),
+            SysCallNum::utime => Some("sys_utime"),
+            SysCallNum::mknod => Some("sys_mknod"),
+            SysCallNum::personality => Some("sys_personality"),
+            SysCallNum::ustat => Some("sys_ustat"),
+            SysCallNum::statfs => Some("sys_statfs"),
+            SysCallNum::fstatfs => Some("sys_fstatfs"),
+            SysCallNum::sysfs => Some("sys_sysfs"),
+            SysCallNum::getpriority => Some("sys_getpriority"),
+            SysCallNum::setpriority => Some("sys_setpriority"),
+            SysCallNum::sched_setparam => Some("sys_sched_setparam"),
+            SysCallNum::sched_getparam => Some("sys_sched_getparam"),
+            SysCallNum::sched_setscheduler => Some("sys_sched_setscheduler"),
+            SysCallNum::sched_getscheduler => Some("sys_sched_getscheduler"),
+            SysCallNum::sched_get_priority_max => Some("sys_sched_get_priority_max"),
+            SysCallNum::sched_get_priority_min => Some("sys_sched_get_priority_min"),
+            SysCallNum::sched_rr_get_interval => Some("sys_sched_rr_get_interval"),
+            SysCallNum::mlock => Some("sys_mlock"),
+            SysCallNum::munlock => Some("sys_munlock"),
+            SysCallNum::mlockall => Some("sys_mlockall"),
+            SysCallNum::munlockall => Some("sys_munlockall"),
+            SysCallNum::vhangup => Some("sys_vhangup"),
+            SysCallNum::modify_ldt => Some("sys_modify_ldt"),
+            SysCallNum::pivot_root => Some("sys_pivot_root"),
+            SysCallNum::sysctl_ => Some("sys_ni_syscall"),
+            SysCallNum::prctl => Some("sys_prctl"),
+            SysCallNum::arch_prctl => Some("sys_arch_prctl"),
+            SysCallNum::adjtimex => Some("sys_adjtimex"),
+            SysCallNum::setrlimit => Some("sys_setrlimit"),
+            SysCallNum::chroot => Some("sys_chroot"),
+            SysCallNum::sync => Some("sys_sync"),
+            SysCallNum::acct => Some("sys_acct"),
+            SysCallNum::settimeofday => Some("sys_settimeofday"),
+            SysCallNum::mount => Some("sys_mount"),
+            SysCallNum::umount2 => Some("sys_umount"),
+            SysCallNum::swapon => Some("sys_swapon"),
+            SysCallNum::swapoff => Some("sys_swapoff"),
+            SysCallNum::reboot => Some("sys_reboot"),
+            SysCallNum::sethostname => Some("sys_sethostname"),
+            SysCallNum::setdomainname => Some("sys_setdomainname"),
+            SysCallNum::iopl => Some("sys_iopl"),
+            SysCallNum::ioperm => Some("sys_ioperm"),
+            SysCallNum::init_module => Some("sys_init_module"),
+            SysCallNum::delete_module => Some("sys_delete_module"),
+            SysCallNum::quotactl => Some("sys_quotactl"),
+            SysCallNum::gettid => Some("sys_gettid"),
+            SysCallNum::readahead => Some("sys_readahead"),
+            SysCallNum::setxattr => Some("sys_setxattr"),
+            SysCallNum::lsetxattr => Some("sys_lsetxattr"),
+            SysCallNum::fsetxattr => Some("sys_fsetxattr"),
+            SysCallNum::getxattr => Some("sys_getxattr"),
+            SysCallNum::lgetxattr => Some("sys_lgetxattr"),
+            SysCallNum::fgetxattr => Some("sys_fgetxattr"),
+            SysCallNum::listxattr => Some("sys_listxattr"),
+            SysCallNum::llistxattr => Some("sys_llistxattr"),
+            SysCallNum::flistxattr => Some("sys_flistxattr"),
+            SysCallNum::removexattr => Some("sys_removexattr"),
+            SysCallNum::lremovexattr => Some("sys_lremovexattr"),
+            SysCallNum::fremovexattr => Some("sys_fremovexattr"),
+            SysCallNum::tkill => Some("sys_tkill"),
+            SysCallNum::time => Some("sys_time"),
+            SysCallNum::futex => Some("sys_futex"),
+            SysCallNum::sched_setaffinity => Some("sys_sched_setaffinity"),
+            SysCallNum::sched_getaffinity => Some("sys_sched_getaffinity"),
+            SysCallNum::io_setup => Some("sys_io_setup"),
+            SysCallNum::io_destroy => Some("sys_io_destroy"),
+            SysCallNum::io_getevents => Some("sys_io_getevents"),
+            SysCallNum::io_submit => Some("sys_io_submit"),
+            SysCallNum::io_cancel => Some("sys_io_cancel"),
+            SysCallNum::epoll_create => Some("sys_epoll_create"),
+            SysCallNum::remap_file_pages => Some("sys_remap_file_pages"),
+            SysCallNum::getdents64 => Some("sys_getdents64"),
+            SysCallNum::set_tid_address => Some("sys_set_tid_address"),
+            SysCallNum::restart_syscall => Some("sys_restart_syscall"),
+            SysCallNum::semtimedop => Some("sys_semtimedop"),
+            SysCallNum::fadvise64 => Some("sys_fadvise64"),
+            SysCallNum::timer_create => Some("sys_timer_create"),
+            SysCallNum::timer_settime => Some("sys_timer_settime"),
+            SysCallNum::timer_gettime => Some("sys_timer_gettime"),
+            SysCallNum::timer_getoverrun => Some("sys_timer_getoverrun"),
+            SysCallNum::timer_delete => Some("sys_timer_delete"),
+            SysCallNum::clock_settime => Some("sys_clock_settime"),
+            SysCallNum::clock_gettime => Some("sys_clock_gettime"),
+            SysCallNum::clock_getres => Some("sys_clock_getres"),
+            SysCallNum::clock_nanosleep => Some("sys_clock_nanosleep"),
+            SysCallNum::exit_group => Some("sys_exit_group"),
+            SysCallNum::epoll_wait => Some("sys_epoll_wait"),
+            SysCallNum::epoll_ctl => Some("sys_epoll_ctl"),
+            SysCallNum::tgkill => Some("sys_tgkill"),
+            SysCallNum::utimes => Some("sys_utimes"),
+            SysCallNum::mbind => Some("sys_mbind"),
+            SysCallNum::set_mempolicy => Some("sys_set_mempolicy"),
+            SysCallNum::get_mempolicy => Some("sys_get_mempolicy"),
+            SysCallNum::mq_open => Some("sys_mq_open"),
+            SysCallNum::mq_unlink => Some("sys_mq_unlink"),
+            SysCallNum::mq_timedsend => Some("sys_mq_timedsend"),
+            SysCallNum::mq_timedreceive => Some("sys_mq_timedreceive"),
+            SysCallNum::mq_notify => Some("sys_mq_notify"),
+            SysCallNum::mq_getsetattr => Some("sys_mq_getsetattr"),
+            SysCallNum::kexec_load => Some("sys_kexec_load"),
+            SysCallNum::waitid => Some("sys_waitid"),
+            SysCallNum::add_key => Some("sys_add_key"),
+            SysCallNum::request_key => Some("sys_request_key"),
+            SysCallNum::keyctl => Some("sys_keyctl"),
+            SysCallNum::ioprio_set => Some("sys_ioprio_set"),
+            SysCallNum::ioprio_get => Some("sys_ioprio_get"),
+            SysCallNum::inotify_init => Some("sys_inotify_init"),
+            SysCallNum::inotify_add_watch => Some("sys_inotify_add_watch"),
+            SysCallNum::inotify_rm_watch => Some("sys_inotify_rm_watch"),
+            SysCallNum::migrate_pages => Some("sys_migrate_pages"),
+            SysCallNum::openat => Some("sys_openat"),
+            SysCallNum::mkdirat => Some("sys_mkdirat"),
+            SysCallNum::mknodat => Some("sys_mknodat"),
+            SysCallNum::fchownat => Some("sys_fchownat"),
+            SysCallNum::futimesat => Some("sys_futimesat"),
+            SysCallNum::newfstatat => Some("sys_newfstatat"),
+            SysCallNum::unlinkat => Some("sys_unlinkat"),
+            SysCallNum::renameat => Some("sys_renameat"),
+            SysCallNum::linkat => Some("sys_linkat"),
+            SysCallNum::symlinkat => Some("sys_symlinkat"),
+            SysCallNum::readlinkat => Some("sys_readlinkat"),
+            SysCallNum::fchmodat => Some("sys_fchmodat"),
+            SysCallNum::faccessat => Some("sys_faccessat"),
+            SysCallNum::pselect6 => Some("sys_pselect6"),
+            SysCallNum::ppoll => Some("sys_ppoll"),
+            SysCallNum::unshare => Some("sys_unshare"),
+            SysCallNum::set_robust_list => Some("sys_set_robust_list"),
+            SysCallNum::get_robust_list => Some("sys_get_robust_list"),
+            SysCallNum::splice => Some("sys_splice"),
+            SysCallNum::tee => Some("sys_tee"),
+            SysCallNum::sync_file_range => Some("sys_sync_file_range"),
+            SysCallNum::vmsplice => Some("sys_vmsplice"),
+            SysCallNum::move_pages => Some("sys_move_pages"),
+            SysCallNum::utimensat => Some("sys_utimensat"),
+            SysCallNum::epoll_pwait => Some("sys_epoll_pwait"),
+            SysCallNum::signalfd => Some("sys_signalfd"),
+            SysCallNum::timerfd_create => Some("sys_timerfd_create"),
+            SysCallNum::eventfd => Some("sys_eventfd"),
+            SysCallNum::fallocate => Some("sys_fallocate"),
+            SysCallNum::timerfd_settime => Some("sys_timerfd_settime"),
+            SysCallNum::timerfd_gettime => Some("sys_timerfd_gettime"),
+            SysCallNum::accept4 => Some("sys_accept4"),
+            SysCallNum::signalfd4 => Some("sys_signalfd4"),
+            SysCallNum::eventfd2 => Some("sys_eventfd2"),
+            SysCallNum::epoll_create1 => Some("sys_epoll_create1"),
+            SysCallNum::dup3 => Some("sys_dup3"),
+            SysCallNum::pipe2 => Some("sys_pipe2"),
+            SysCallNum::inotify_init1 => Some("sys_inotify_init1"),
+            SysCallNum::preadv => Some("sys_preadv"),
+            SysCallNum::pwritev => Some("sys_pwritev"),
+            SysCallNum::rt_tgsigqueueinfo => Some("sys_rt_tgsigqueueinfo"),
+            SysCallNum::perf_event_open => Some("sys_perf_event_open"),
+            SysCallNum::recvmmsg => Some("sys_recvmmsg"),
+            SysCallNum::fanotify_init => Some("sys_fanotify_init"),
+            SysCallNum::fanotify_mark => Some("sys_fanotify_mark"),
+            SysCallNum::prlimit64 => Some("sys_prlimit64"),
+            SysCallNum::name_to_handle_at => Some("sys_name_to_handle_at"),
+            SysCallNum::open_by_handle_at => Some("sys_open_by_handle_at"),
+            SysCallNum::clock_adjtime => Some("sys_clock_adjtime"),
+            SysCallNum::syncfs => Some("sys_syncfs"),
+            SysCallNum::sendmmsg => Some("sys_sendmmsg"),
+            SysCallNum::setns => Some("sys_setns"),
+            SysCallNum::getcpu => Some("sys_getcpu"),
+            SysCallNum::process_vm_readv => Some("sys_process_vm_readv"),
+            SysCallNum::process_vm_writev => Some("sys_process_vm_writev"),
+            SysCallNum::kcmp => Some("sys_kcmp"),
+            SysCallNum::finit_module => Some("sys_finit_module"),
+            SysCallNum::sched_setattr => Some("sys_sched_setattr"),
+            SysCallNum::sched_getattr => Some("sys_sched_getattr"),
+            SysCallNum::renameat2 => Some("sys_renameat2"),
+            SysCallNum::seccomp => Some("sys_seccomp"),
+            SysCallNum::getrandom => Some("sys_getrandom"),
+            SysCallNum::memfd_create => Some("sys_memfd_create"),
+            SysCallNum::kexec_file_load => Some("sys_kexec_file_load"),
+            SysCallNum::bpf => Some("sys_bpf"),
+            SysCallNum::execveat => Some("sys_execveat"),
+            SysCallNum::userfaultfd => Some("sys_userfaultfd"),
+            SysCallNum::membarrier => Some("sys_membarrier"),
+            SysCallNum::mlock2 => Some("sys_mlock2"),
+            SysCallNum::copy_file_range => Some("sys_copy_file_range"),
+            SysCallNum::preadv2 => Some("sys_preadv2"),
+            SysCallNum::pwritev2 => Some("sys_pwritev2"),
+            SysCallNum::pkey_mprotect => Some("sys_pkey_mprotect"),
+            SysCallNum::pkey_alloc => Some("sys_pkey_alloc"),
+            SysCallNum::pkey_free => Some("sys_pkey_free"),
+            SysCallNum::statx => Some("sys_statx"),
+            SysCallNum::io_pgetevents => Some("sys_io_pgetevents"),
+            SysCallNum::rseq => Some("sys_rseq"),
+            SysCallNum::uretprobe => Some("sys_uretprobe"),
+            SysCallNum::pidfd_send_signal => Some("sys_pidfd_send_signal"),
+            SysCallNum::io_uring_setup => Some("sys_io_uring_setup"),
+            SysCallNum::io_uring_enter => Some("sys_io_uring_enter"),
+            SysCallNum::io_uring_register => Some("sys_io_uring_register"),
+            SysCallNum::open_tree => Some("sys_open_tree"),
+            SysCallNum::move_mount => Some("sys_move_mount"),
+            SysCallNum::fsopen => Some("sys_fsopen"),
+            SysCallNum::fsconfig => Some("sys_fsconfig"),
+            SysCallNum::fsmount => Some("sys_fsmount"),
+            SysCallNum::fspick => Some("sys_fspick"),
+            SysCallNum::pidfd_open => Some("sys_pidfd_open"),
+            SysCallNum::clone3 => Some("sys_clone3"),
+            SysCallNum::close_range => Some("sys_close_range"),
+            SysCallNum::openat2 => Some("sys_openat2"),
+            SysCallNum::pidfd_getfd => Some("sys_pidfd_getfd"),
+            SysCallNum::faccessat2 => Some("sys_faccessat2"),
+            SysCallNum::process_madvise => Some("sys_process_madvise"),
+            SysCallNum::epoll_pwait2 => Some("sys_epoll_pwait2"),
+            SysCallNum::mount_setattr => Some("sys_mount_setattr"),
+            SysCallNum::quotactl_fd => Some("sys_quotactl_fd"),
+            SysCallNum::landlock_create_ruleset => Some("sys_landlock_create_ruleset"),
+            SysCallNum::landlock_add_rule => Some("sys_landlock_add_rule"),
+            SysCallNum::landlock_restrict_self => Some("sys_landlock_restrict_self"),
+            SysCallNum::memfd_secret => Some("sys_memfd_secret"),
+            SysCallNum::process_mrelease => Some("sys_process_mrelease"),
+            SysCallNum::futex_waitv => Some("sys_futex_waitv"),
+            SysCallNum::set_mempolicy_home_node => Some("sys_set_mempolicy_home_node"),
+            SysCallNum::cachestat => Some("sys_cachestat"),
+            SysCallNum::fchmodat2 => Some("sys_fchmodat2"),
+            SysCallNum::map_shadow_stack => Some("sys_map_shadow_stack"),
+            SysCallNum::futex_wake => Some("sys_futex_wake"),
+            SysCallNum::futex_wait => Some("sys_futex_wait"),
+            SysCallNum::futex_requeue => Some("sys_futex_requeue"),
+            SysCallNum::statmount => Some("sys_statmount"),
+            SysCallNum::listmount => Some("sys_listmount"),
+            SysCallNum::lsm_get_self_attr => Some("sys_lsm_get_self_attr"),
+            SysCallNum::lsm_set_self_attr => Some("sys_lsm_set_self_attr"),
+            SysCallNum::lsm_list_modules => Some("sys_lsm_list_modules"),
+            SysCallNum::mseal => Some("sys_mseal"),
+            SysCallNum::setxattrat => Some("sys_setxattrat"),
+            SysCallNum::getxattrat => Some("sys_getxattrat"),
+            SysCallNum::listxattrat => Some("sys_listxattrat"),
+            SysCallNum::removexattrat => Some("sys_removexattrat"),
+            _ => None,
+        }
+    }
+
+    // whether the syscall never returns (e.g. `exit` and `exit_group`).
+    pub fn is_noreturn(&self) -> bool {
+        matches!(self, SysCallNum::exit | SysCallNum::exit_group)
+    }
+}
+
+// the bit which is added to the syscall numbers of the x32 ABI,
+// i.e. the value of `rax` is `__X32_SYSCALL_BIT | <number in table>`.
 //
-// 512	x32	rt_sigaction		compat_sys_rt_sigaction
-// 513	x32	rt_sigreturn		compat_sys_x32_rt_sigreturn
-// 514	x32	ioctl			compat_sys_ioctl
-// 515	x32	readv			sys_readv
-// 516	x32	writev			sys_writev
-// 517	x32	recvfrom		compat_sys_recvfrom
-// 518	x32	sendmsg			compat_sys_sendmsg
-// 519	x32	recvmsg			compat_sys_recvmsg
-// 520	x32	execve			compat_sys_execve
-// 521	x32	ptrace			compat_sys_ptrace
-// 522	x32	rt_sigpending		compat_sys_rt_sigpending
-// 523	x32	rt_sigtimedwait		compat_sys_rt_sigtimedwait_time64
-// 524	x32	rt_sigqueueinfo		compat_sys_rt_sigqueueinfo
-// 525	x32	sigaltstack		compat_sys_sigaltstack
-// 526	x32	timer_create		compat_sys_timer_create
-// 527	x32	mq_notify		compat_sys_mq_notify
-// 528	x32	kexec_load		compat_sys_kexec_load
-// 529	x32	waitid			compat_sys_waitid
-// 530	x32	set_robust_list		compat_sys_set_robust_list
-// 531	x32	get_robust_list		compat_sys_get_robust_list
-// 532	x32	vmsplice		sys_vmsplice
-// 533	x32	move_pages		sys_move_pages
-// 534	x32	preadv			compat_sys_preadv64
-// 535	x32	pwritev			compat_sys_pwritev64
-// 536	x32	rt_tgsigqueueinfo	compat_sys_rt_tgsigqueueinfo
-// 537	x32	recvmmsg		compat_sys_recvmmsg_time64
-// 538	x32	sendmmsg		compat_sys_sendmmsg
-// 539	x32	process_vm_readv	sys_process_vm_readv
-// 540	x32	process_vm_writev	sys_process_vm_writev
-// 541	x32	setsockopt		sys_setsockopt
-// 542	x32	getsockopt		sys_getsockopt
-// 543	x32	io_setup		compat_sys_io_setup
-// 544	x32	io_submit		compat_sys_io_submit
-// 545	x32	execveat		compat_sys_execveat
-// 546	x32	preadv2			compat_sys_preadv64v2
-// 547	x32	pwritev2		compat_sys_pwritev64v2
+// see 'arch/x86/include/uapi/asm/unistd.h'
+pub const X32_SYSCALL_BIT: usize = 0x40000000;
+
+// the syscalls of the x32 ABI (the ABI "common" and "x32"),
+// the numbers include the `X32_SYSCALL_BIT`.
 //
-// # This is the end of the legacy x32 range.
-// # Numbers 548 and above are
-// # not special and are not to be used for x32-specific syscalls.
+// due to a historical design error, certain syscalls are numbered differently
+// in x32 as compared to native x86_64, these syscalls have numbers 512-547,
+// and the x86_64 (ABI "64") version of these syscalls are not available for x32.
+
+syscall_num! {
+    pub enum X32SysCallNum {
+        read = 0x40000000,                    // 0    common  sys_read
+        write = 0x40000001,                   // 1    common  sys_write
+        open = 0x40000002,                    // 2    common  sys_open
+        close = 0x40000003,                   // 3    common  sys_close
+        stat = 0x40000004,                    // 4    common  sys_newstat
+        fstat = 0x40000005,                   // 5    common  sys_newfstat
+        lstat = 0x40000006,                   // 6    common  sys_newlstat
+        poll = 0x40000007,                    // 7    common  sys_poll
+        lseek = 0x40000008,                   // 8    common  sys_lseek
+        mmap = 0x40000009,                    // 9    common  sys_mmap
+        mprotect = 0x4000000a,                // 10   common  sys_mprotect
+        munmap = 0x4000000b,                  // 11   common  sys_munmap
+        brk = 0x4000000c,                     // 12   common  sys_brk
+        // 13 - 13 are not used
+        rt_sigprocmask = 0x4000000e,          // 14   common  sys_rt_sigprocmask
+        // 15 - 16 are not used
+        pread64 = 0x40000011,                 // 17   common  sys_pread64
+        pwrite64 = 0x40000012,                // 18   common  sys_pwrite64
+        // 19 - 20 are not used
+        access = 0x40000015,                  // 21   common  sys_access
+        pipe = 0x40000016,                    // 22   common  sys_pipe
+        select = 0x40000017,                  // 23   common  sys_select
+        sched_yield = 0x40000018,             // 24   common  sys_sched_yield
+        mremap = 0x40000019,                  // 25   common  sys_mremap
+        msync = 0x4000001a,                   // 26   common  sys_msync
+        mincore = 0x4000001b,                 // 27   common  sys_mincore
+        madvise = 0x4000001c,                 // 28   common  sys_madvise
+        shmget = 0x4000001d,                  // 29   common  sys_shmget
+        shmat = 0x4000001e,                   // 30   common  sys_shmat
+        shmctl = 0x4000001f,                  // 31   common  sys_shmctl
+        dup = 0x40000020,                     // 32   common  sys_dup
+        dup2 = 0x40000021,                    // 33   common  sys_dup2
+        pause = 0x40000022,                   // 34   common  sys_pause
+        nanosleep = 0x40000023,               // 35   common  sys_nanosleep
+        getitimer = 0x40000024,               // 36   common  sys_getitimer
+        alarm = 0x40000025,                   // 37   common  sys_alarm
+        setitimer = 0x40000026,               // 38   common  sys_setitimer
+        getpid = 0x40000027,                  // 39   common  sys_getpid
+        sendfile = 0x40000028,                // 40   common  sys_sendfile64
+        socket = 0x40000029,                  // 41   common  sys_socket
+        connect = 0x4000002a,                 // 42   common  sys_connect
+        accept = 0x4000002b,                  // 43   common  sys_accept
+        sendto = 0x4000002c,                  // 44   common  sys_sendto
+        // 45 - 47 are not used
+        shutdown = 0x40000030,                // 48   common  sys_shutdown
+        bind = 0x40000031,                    // 49   common  sys_bind
+        listen = 0x40000032,                  // 50   common  sys_listen
+        getsockname = 0x40000033,             // 51   common  sys_getsockname
+        getpeername = 0x40000034,             // 52   common  sys_getpeername
+        socketpair = 0x40000035,              // 53   common  sys_socketpair
+        // 54 - 55 are not used
+        clone = 0x40000038,                   // 56   common  sys_clone
+        fork = 0x40000039,                    // 57   common  sys_fork
+        vfork = 0x4000003a,                   // 58   common  sys_vfork
+        // 59 - 59 are not used
+        exit = 0x4000003c,                    // 60   common  sys_exit
+        wait4 = 0x4000003d,                   // 61   common  sys_wait4
+        kill = 0x4000003e,                    // 62   common  sys_kill
+        uname = 0x4000003f,                   // 63   common  sys_newuname
+        semget = 0x40000040,                  // 64   common  sys_semget
+        semop = 0x40000041,                   // 65   common  sys_semop
+        semctl = 0x40000042,                  // 66   common  sys_semctl
+        shmdt = 0x40000043,                   // 67   common  sys_shmdt
+        msgget = 0x40000044,                  // 68   common  sys_msgget
+        msgsnd = 0x40000045,                  // 69   common  sys_msgsnd
+        msgrcv = 0x40000046,                  // 70   common  sys_msgrcv
+        msgctl = 0x40000047,                  // 71   common  sys_msgctl
+        fcntl = 0x40000048,                   // 72   common  sys_fcntl
+        flock = 0x40000049,                   // 73   common  sys_flock
+        fsync = 0x4000004a,                   // 74   common  sys_fsync
+        fdatasync = 0x4000004b,               // 75   common  sys_fdatasync
+        truncate = 0x4000004c,                // 76   common  sys_truncate
+        ftruncate = 0x4000004d,               // 77   common  sys_ftruncate
+        getdents = 0x4000004e,                // 78   common  sys_getdents
+        getcwd = 0x4000004f,                  // 79   common  sys_getcwd
+        chdir = 0x40000050,                   // 80   common  sys_chdir
+        fchdir = 0x40000051,                  // 81   common  sys_fchdir
+        rename = 0x40000052,                  // 82   common  sys_rename
+        mkdir = 0x40000053,                   // 83   common  sys_mkdir
+        rmdir = 0x40000054,                   // 84   common  sys_rmdir
+        creat = 0x40000055,                   // 85   common  sys_creat
+        link = 0x40000056,                    // 86   common  sys_link
+        unlink = 0x40000057,                  // 87   common  sys_unlink
+        symlink = 0x40000058,                 // 88   common  sys_symlink
+        readlink = 0x40000059,                // 89   common  sys_readlink
+        chmod = 0x4000005a,                   // 90   common  sys_chmod
+        fchmod = 0x4000005b,                  // 91   common  sys_fchmod
+        chown = 0x4000005c,                   // 92   common  sys_chown
+        fchown = 0x4000005d,                  // 93   common  sys_fchown
+        lchown = 0x4000005e,                  // 94   common  sys_lchown
+        umask = 0x4000005f,                   // 95   common  sys_umask
+        gettimeofday = 0x40000060,            // 96   common  sys_gettimeofday
+        getrlimit = 0x40000061,               // 97   common  sys_getrlimit
+        getrusage = 0x40000062,               // 98   common  sys_getrusage
+        sysinfo = 0x40000063,                 // 99   common  sys_sysinfo
+        times = 0x40000064,                   // 100  common  sys_times
+        // 101 - 101 are not used
+        getuid = 0x40000066,                  // 102  common  sys_getuid
+        syslog = 0x40000067,                  // 103  common  sys_syslog
+        getgid = 0x40000068,                  // 104  common  sys_getgid
+        setuid = 0x40000069,                  // 105  common  sys_setuid
+        setgid = 0x4000006a,                  // 106  common  sys_setgid
+        geteuid = 0x4000006b,                 // 107  common  sys_geteuid
+        getegid = 0x4000006c,                 // 108  common  sys_getegid
+        setpgid = 0x4000006d,                 // 109  common  sys_setpgid
+        getppid = 0x4000006e,                 // 110  common  sys_getppid
+        getpgrp = 0x4000006f,                 // 111  common  sys_getpgrp
+        setsid = 0x40000070,                  // 112  common  sys_setsid
+        setreuid = 0x40000071,                // 113  common  sys_setreuid
+        setregid = 0x40000072,                // 114  common  sys_setregid
+        getgroups = 0x40000073,               // 115  common  sys_getgroups
+        setgroups = 0x40000074,               // 116  common  sys_setgroups
+        setresuid = 0x40000075,               // 117  common  sys_setresuid
+        getresuid = 0x40000076,               // 118  common  sys_getresuid
+        setresgid = 0x40000077,               // 119  common  sys_setresgid
+        getresgid = 0x40000078,               // 120  common  sys_getresgid
+        getpgid = 0x40000079,                 // 121  common  sys_getpgid
+        setfsuid = 0x4000007a,                // 122  common  sys_setfsuid
+        setfsgid = 0x4000007b,                // 123  common  sys_setfsgid
+        getsid = 0x4000007c,                  // 124  common  sys_getsid
+        capget = 0x4000007d,                  // 125  common  sys_capget
+        capset = 0x4000007e,                  // 126  common  sys_capset
+        // 127 - 129 are not used
+        rt_sigsuspend = 0x40000082,           // 130  common  sys_rt_sigsuspend
+        // 131 - 131 are not used
+        utime = 0x40000084,                   // 132  common  sys_utime
+        mknod = 0x40000085,                   // 133  common  sys_mknod
+        // 134 - 134 are not used
+        personality = 0x40000087,             // 135  common  sys_personality
+        ustat = 0x40000088,                   // 136  common  sys_ustat
+        statfs = 0x40000089,                  // 137  common  sys_statfs
+        fstatfs = 0x4000008a,                 // 138  common  sys_fstatfs
+        sysfs = 0x4000008b,                   // 139  common  sys_sysfs
+        getpriority = 0x4000008c,             // 140  common  sys_getpriority
+        setpriority = 0x4000008d,             // 141  common  sys_setpriority
+        sched_setparam = 0x4000008e,          // 142  common  sys_sched_setparam
+        sched_getparam = 0x4000008f,          // 143  common  sys_sched_getparam
+        sched_setscheduler = 0x40000090,      // 144  common  sys_sched_setscheduler
+        sched_getscheduler = 0x40000091,      // 145  common  sys_sched_getscheduler
+        sched_get_priority_max = 0x40000092,  // 146  common  sys_sched_get_priority_max
+        sched_get_priority_min = 0x40000093,  // 147  common  sys_sched_get_priority_min
+        sched_rr_get_interval = 0x40000094,   // 148  common  sys_sched_rr_get_interval
+        mlock = 0x40000095,                   // 149  common  sys_mlock
+        munlock = 0x40000096,                 // 150  common  sys_munlock
+        mlockall = 0x40000097,                // 151  common  sys_mlockall
+        munlockall = 0x40000098,              // 152  common  sys_munlockall
+        vhangup = 0x40000099,                 // 153  common  sys_vhangup
+        modify_ldt = 0x4000009a,              // 154  common  sys_modify_ldt
+        pivot_root = 0x4000009b,              // 155  common  sys_pivot_root
+        // 156 - 156 are not used
+        prctl = 0x4000009d,                   // 157  common  sys_prctl
+        arch_prctl = 0x4000009e,              // 158  common  sys_arch_prctl
+        adjtimex = 0x4000009f,                // 159  common  sys_adjtimex
+        setrlimit = 0x400000a0,               // 160  common  sys_setrlimit
+        chroot = 0x400000a1,                  // 161  common  sys_chroot
+        sync = 0x400000a2,                    // 162  common  sys_sync
+        acct = 0x400000a3,                    // 163  common  sys_acct
+        settimeofday = 0x400000a4,            // 164  common  sys_settimeofday
+        mount = 0x400000a5,                   // 165  common  sys_mount
+        umount2 = 0x400000a6,                 // 166  common  sys_umount
+        swapon = 0x400000a7,                  // 167  common  sys_swapon
+        swapoff = 0x400000a8,                 // 168  common  sys_swapoff
+        reboot = 0x400000a9,                  // 169  common  sys_reboot
+        sethostname = 0x400000aa,             // 170  common  sys_sethostname
+        setdomainname = 0x400000ab,           // 171  common  sys_setdomainname
+        iopl = 0x400000ac,                    // 172  common  sys_iopl
+        ioperm = 0x400000ad,                  // 173  common  sys_ioperm
+        // 174 - 174 are not used
+        init_module = 0x400000af,             // 175  common  sys_init_module
+        delete_module = 0x400000b0,           // 176  common  sys_delete_module
+        // 177 - 178 are not used
+        quotactl = 0x400000b3,                // 179  common  sys_quotactl
+        // 180 - 180 are not used
+        getpmsg = 0x400000b5,                 // 181  common
+        putpmsg = 0x400000b6,                 // 182  common
+        afs_syscall = 0x400000b7,             // 183  common
+        tuxcall = 0x400000b8,                 // 184  common
+        security = 0x400000b9,                // 185  common
+        gettid = 0x400000ba,                  // 186  common  sys_gettid
+        readahead = 0x400000bb,               // 187  common  sys_readahead
+        setxattr = 0x400000bc,                // 188  common  sys_setxattr
+        lsetxattr = 0x400000bd,               // 189  common  sys_lsetxattr
+        fsetxattr = 0x400000be,               // 190  common  sys_fsetxattr
+        getxattr = 0x400000bf,                // 191  common  sys_getxattr
+        lgetxattr = 0x400000c0,               // 192  common  sys_lgetxattr
+        fgetxattr = 0x400000c1,               // 193  common  sys_fgetxattr
+        listxattr = 0x400000c2,               // 194  common  sys_listxattr
+        llistxattr = 0x400000c3,              // 195  common  sys_llistxattr
+        flistxattr = 0x400000c4,              // 196  common  sys_flistxattr
+        removexattr = 0x400000c5,             // 197  common  sys_removexattr
+        lremovexattr = 0x400000c6,            // 198  common  sys_lremovexattr
+        fremovexattr = 0x400000c7,            // 199  common  sys_fremovexattr
+        tkill = 0x400000c8,                   // 200  common  sys_tkill
+        time = 0x400000c9,                    // 201  common  sys_time
+        futex = 0x400000ca,                   // 202  common  sys_futex
+        sched_setaffinity = 0x400000cb,       // 203  common  sys_sched_setaffinity
+        sched_getaffinity = 0x400000cc,       // 204  common  sys_sched_getaffinity
+        // 205 - 206 are not used
+        io_destroy = 0x400000cf,              // 207  common  sys_io_destroy
+        io_getevents = 0x400000d0,            // 208  common  sys_io_getevents
+        // 209 - 209 are not used
+        io_cancel = 0x400000d2,               // 210  common  sys_io_cancel
+        // 211 - 211 are not used
+        lookup_dcookie = 0x400000d4,          // 212  common
+        epoll_create = 0x400000d5,            // 213  common  sys_epoll_create
+        // 214 - 215 are not used
+        remap_file_pages = 0x400000d8,        // 216  common  sys_remap_file_pages
+        getdents64 = 0x400000d9,              // 217  common  sys_getdents64
+        set_tid_address = 0x400000da,         // 218  common  sys_set_tid_address
+        restart_syscall = 0x400000db,         // 219  common  sys_restart_syscall
+        semtimedop = 0x400000dc,              // 220  common  sys_semtimedop
+        fadvise64 = 0x400000dd,               // 221  common  sys_fadvise64
+        // 222 - 222 are not used
+        timer_settime = 0x400000df,           // 223  common  sys_timer_settime
+        timer_gettime = 0x400000e0,           // 224  common  sys_timer_gettime
+        timer_getoverrun = 0x400000e1,        // 225  common  sys_timer_getoverrun
+        timer_delete = 0x400000e2,            // 226  common  sys_timer_delete
+        clock_settime = 0x400000e3,           // 227  common  sys_clock_settime
+        clock_gettime = 0x400000e4,           // 228  common  sys_clock_gettime
+        clock_getres = 0x400000e5,            // 229  common  sys_clock_getres
+        clock_nanosleep = 0x400000e6,         // 230  common  sys_clock_nanosleep
+        exit_group = 0x400000e7,              // 231  common  sys_exit_group
+        epoll_wait = 0x400000e8,              // 232  common  sys_epoll_wait
+        epoll_ctl = 0x400000e9,               // 233  common  sys_epoll_ctl
+        tgkill = 0x400000ea,                  // 234  common  sys_tgkill
+        utimes = 0x400000eb,                  // 235  common  sys_utimes
+        // 236 - 236 are not used
+        mbind = 0x400000ed,                   // 237  common  sys_mbind
+        set_mempolicy = 0x400000ee,           // 238  common  sys_set_mempolicy
+        get_mempolicy = 0x400000ef,           // 239  common  sys_get_mempolicy
+        mq_open = 0x400000f0,                 // 240  common  sys_mq_open
+        mq_unlink = 0x400000f1,               // 241  common  sys_mq_unlink
+        mq_timedsend = 0x400000f2,            // 242  common  sys_mq_timedsend
+        mq_timedreceive = 0x400000f3,         // 243  common  sys_mq_timedreceive
+        // 244 - 244 are not used
+        mq_getsetattr = 0x400000f5,           // 245  common  sys_mq_getsetattr
+        // 246 - 247 are not used
+        add_key = 0x400000f8,                 // 248  common  sys_add_key
+        request_key = 0x400000f9,             // 249  common  sys_request_key
+        keyctl = 0x400000fa,                  // 250  common  sys_keyctl
+        ioprio_set = 0x400000fb,              // 251  common  sys_ioprio_set
+        ioprio_get = 0x400000fc,              // 252  common  sys_ioprio_get
+        inotify_init = 0x400000fd,            // 253  common  sys_inotify_init
+        inotify_add_watch = 0x400000fe,       // 254  common  sys_inotify_add_watch
+        inotify_rm_watch = 0x400000ff,        // 255  common  sys_inotify_rm_watch
+        migrate_pages = 0x40000100,           // 256  common  sys_migrate_pages
+        openat = 0x40000101,                  // 257  common  sys_openat
+        mkdirat = 0x40000102,                 // 258  common  sys_mkdirat
+        mknodat = 0x40000103,                 // 259  common  sys_mknodat
+        fchownat = 0x40000104,                // 260  common  sys_fchownat
+        futimesat = 0x40000105,               // 261  common  sys_futimesat
+        newfstatat = 0x40000106,              // 262  common  sys_newfstatat
+        unlinkat = 0x40000107,                // 263  common  sys_unlinkat
+        renameat = 0x40000108,                // 264  common  sys_renameat
+        linkat = 0x40000109,                  // 265  common  sys_linkat
+        symlinkat = 0x4000010a,               // 266  common  sys_symlinkat
+        readlinkat = 0x4000010b,              // 267  common  sys_readlinkat
+        fchmodat = 0x4000010c,                // 268  common  sys_fchmodat
+        faccessat = 0x4000010d,               // 269  common  sys_faccessat
+        pselect6 = 0x4000010e,                // 270  common  sys_pselect6
+        ppoll = 0x4000010f,                   // 271  common  sys_ppoll
+        unshare = 0x40000110,                 // 272  common  sys_unshare
+        // 273 - 274 are not used
+        splice = 0x40000113,                  // 275  common  sys_splice
+        tee = 0x40000114,                     // 276  common  sys_tee
+        sync_file_range = 0x40000115,         // 277  common  sys_sync_file_range
+        // 278 - 279 are not used
+        utimensat = 0x40000118,               // 280  common  sys_utimensat
+        epoll_pwait = 0x40000119,             // 281  common  sys_epoll_pwait
+        signalfd = 0x4000011a,                // 282  common  sys_signalfd
+        timerfd_create = 0x4000011b,          // 283  common  sys_timerfd_create
+        eventfd = 0x4000011c,                 // 284  common  sys_eventfd
+        fallocate = 0x4000011d,               // 285  common  sys_fallocate
+        timerfd_settime = 0x4000011e,         // 286  common  sys_timerfd_settime
+        timerfd_gettime = 0x4000011f,         // 287  common  sys_timerfd_gettime
+        accept4 = 0x40000120,                 // 288  common  sys_accept4
+        signalfd4 = 0x40000121,               // 289  common  sys_signalfd4
+        eventfd2 = 0x40000122,                // 290  common  sys_eventfd2
+        epoll_create1 = 0x40000123,           // 291  common  sys_epoll_create1
+        dup3 = 0x40000124,                    // 292  common  sys_dup3
+        pipe2 = 0x40000125,                   // 293  common  sys_pipe2
+        inotify_init1 = 0x40000126,           // 294  common  sys_inotify_init1
+        // 295 - 297 are not used
+        perf_event_open = 0x4000012a,         // 298  common  sys_perf_event_open
+        // 299 - 299 are not used
+        fanotify_init = 0x4000012c,           // 300  common  sys_fanotify_init
+        fanotify_mark = 0x4000012d,           // 301  common  sys_fanotify_mark
+        prlimit64 = 0x4000012e,               // 302  common  sys_prlimit64
+        name_to_handle_at = 0x4000012f,       // 303  common  sys_name_to_handle_at
+        open_by_handle_at = 0x40000130,       // 304  common  sys_open_by_handle_at
+        clock_adjtime = 0x40000131,           // 305  common  sys_clock_adjtime
+        syncfs = 0x40000132,                  // 306  common  sys_syncfs
+        // 307 - 307 are not used
+        setns = 0x40000134,                   // 308  common  sys_setns
+        getcpu = 0x40000135,                  // 309  common  sys_getcpu
+        // 310 - 311 are not used
+        kcmp = 0x40000138,                    // 312  common  sys_kcmp
+        finit_module = 0x40000139,            // 313  common  sys_finit_module
+        sched_setattr = 0x4000013a,           // 314  common  sys_sched_setattr
+        sched_getattr = 0x4000013b,           // 315  common  sys_sched_getattr
+        renameat2 = 0x4000013c,               // 316  common  sys_renameat2
+        seccomp = 0x4000013d,                 // 317  common  sys_seccomp
+        getrandom = 0x4000013e,               // 318  common  sys_getrandom
+        memfd_create = 0x4000013f,            // 319  common  sys_memfd_create
+        kexec_file_load = 0x40000140,         // 320  common  sys_kexec_file_load
+        bpf = 0x40000141,                     // 321  common  sys_bpf
+        // 322 - 322 are not used
+        userfaultfd = 0x40000143,             // 323  common  sys_userfaultfd
+        membarrier = 0x40000144,              // 324  common  sys_membarrier
+        mlock2 = 0x40000145,                  // 325  common  sys_mlock2
+        copy_file_range = 0x40000146,         // 326  common  sys_copy_file_range
+        // 327 - 328 are not used
+        pkey_mprotect = 0x40000149,           // 329  common  sys_pkey_mprotect
+        pkey_alloc = 0x4000014a,              // 330  common  sys_pkey_alloc
+        pkey_free = 0x4000014b,               // 331  common  sys_pkey_free
+        statx = 0x4000014c,                   // 332  common  sys_statx
+        io_pgetevents = 0x4000014d,           // 333  common  sys_io_pgetevents
+        rseq = 0x4000014e,                    // 334  common  sys_rseq
+        uretprobe = 0x4000014f,               // 335  common  sys_uretprobe
+        // 336 - 423 are not used
+        pidfd_send_signal = 0x400001a8,       // 424  common  sys_pidfd_send_signal
+        io_uring_setup = 0x400001a9,          // 425  common  sys_io_uring_setup
+        io_uring_enter = 0x400001aa,          // 426  common  sys_io_uring_enter
+        io_uring_register = 0x400001ab,       // 427  common  sys_io_uring_register
+        open_tree = 0x400001ac,               // 428  common  sys_open_tree
+        move_mount = 0x400001ad,              // 429  common  sys_move_mount
+        fsopen = 0x400001ae,                  // 430  common  sys_fsopen
+        fsconfig = 0x400001af,                // 431  common  sys_fsconfig
+        fsmount = 0x400001b0,                 // 432  common  sys_fsmount
+        fspick = 0x400001b1,                  // 433  common  sys_fspick
+        pidfd_open = 0x400001b2,              // 434  common  sys_pidfd_open
+        clone3 = 0x400001b3,                  // 435  common  sys_clone3
+        close_range = 0x400001b4,             // 436  common  sys_close_range
+        openat2 = 0x400001b5,                 // 437  common  sys_openat2
+        pidfd_getfd = 0x400001b6,             // 438  common  sys_pidfd_getfd
+        faccessat2 = 0x400001b7,              // 439  common  sys_faccessat2
+        process_madvise = 0x400001b8,         // 440  common  sys_process_madvise
+        epoll_pwait2 = 0x400001b9,            // 441  common  sys_epoll_pwait2
+        mount_setattr = 0x400001ba,           // 442  common  sys_mount_setattr
+        quotactl_fd = 0x400001bb,             // 443  common  sys_quotactl_fd
+        landlock_create_ruleset = 0x400001bc, // 444  common  sys_landlock_create_ruleset
+        landlock_add_rule = 0x400001bd,       // 445  common  sys_landlock_add_rule
+        landlock_restrict_self = 0x400001be,  // 446  common  sys_landlock_restrict_self
+        memfd_secret = 0x400001bf,            // 447  common  sys_memfd_secret
+        process_mrelease = 0x400001c0,        // 448  common  sys_process_mrelease
+        futex_waitv = 0x400001c1,             // 449  common  sys_futex_waitv
+        set_mempolicy_home_node = 0x400001c2, // 450  common  sys_set_mempolicy_home_node
+        cachestat = 0x400001c3,               // 451  common  sys_cachestat
+        fchmodat2 = 0x400001c4,               // 452  common  sys_fchmodat2
+        // 453 - 453 are not used
+        futex_wake = 0x400001c6,              // 454  common  sys_futex_wake
+        futex_wait = 0x400001c7,              // 455  common  sys_futex_wait
+        futex_requeue = 0x400001c8,           // 456  common  sys_futex_requeue
+        statmount = 0x400001c9,               // 457  common  sys_statmount
+        listmount = 0x400001ca,               // 458  common  sys_listmount
+        lsm_get_self_attr = 0x400001cb,       // 459  common  sys_lsm_get_self_attr
+        lsm_set_self_attr = 0x400001cc,       // 460  common  sys_lsm_set_self_attr
+        lsm_list_modules = 0x400001cd,        // 461  common  sys_lsm_list_modules
+        mseal = 0x400001ce,                   // 462  common  sys_mseal
+        setxattrat = 0x400001cf,              // 463  common  sys_setxattrat
+        getxattrat = 0x400001d0,              // 464  common  sys_getxattrat
+        listxattrat = 0x400001d1,             // 465  common  sys_listxattrat
+        removexattrat = 0x400001d2,           // 466  common  sys_removexattrat
+        // 467 - 511 are not used
+        rt_sigaction = 0x40000200,            // 512  x32     compat_sys_rt_sigaction
+        rt_sigreturn = 0x40000201,            // 513  x32     compat_sys_x32_rt_sigreturn
+        ioctl = 0x40000202,                   // 514  x32     compat_sys_ioctl
+        readv = 0x40000203,                   // 515  x32     sys_readv
+        writev = 0x40000204,                  // 516  x32     sys_writev
+        recvfrom = 0x40000205,                // 517  x32     compat_sys_recvfrom
+        sendmsg = 0x40000206,                 // 518  x32     compat_sys_sendmsg
+        recvmsg = 0x40000207,                 // 519  x32     compat_sys_recvmsg
+        execve = 0x40000208,                  // 520  x32     compat_sys_execve
+        ptrace = 0x40000209,                  // 521  x32     compat_sys_ptrace
+        rt_sigpending = 0x4000020a,           // 522  x32     compat_sys_rt_sigpending
+        rt_sigtimedwait = 0x4000020b,         // 523  x32     compat_sys_rt_sigtimedwait_time64
+        rt_sigqueueinfo = 0x4000020c,         // 524  x32     compat_sys_rt_sigqueueinfo
+        sigaltstack = 0x4000020d,             // 525  x32     compat_sys_sigaltstack
+        timer_create = 0x4000020e,            // 526  x32     compat_sys_timer_create
+        mq_notify = 0x4000020f,               // 527  x32     compat_sys_mq_notify
+        kexec_load = 0x40000210,              // 528  x32     compat_sys_kexec_load
+        waitid = 0x40000211,                  // 529  x32     compat_sys_waitid
+        set_robust_list = 0x40000212,         // 530  x32     compat_sys_set_robust_list
+        get_robust_list = 0x40000213,         // 531  x32     compat_sys_get_robust_list
+        vmsplice = 0x40000214,                // 532  x32     sys_vmsplice
+        move_pages = 0x40000215,              // 533  x32     sys_move_pages
+        preadv = 0x40000216,                  // 534  x32     compat_sys_preadv64
+        pwritev = 0x40000217,                 // 535  x32     compat_sys_pwritev64
+        rt_tgsigqueueinfo = 0x40000218,       // 536  x32     compat_sys_rt_tgsigqueueinfo
+        recvmmsg = 0x40000219,                // 537  x32     compat_sys_recvmmsg_time64
+        sendmmsg = 0x4000021a,                // 538  x32     compat_sys_sendmmsg
+        process_vm_readv = 0x4000021b,        // 539  x32     sys_process_vm_readv
+        process_vm_writev = 0x4000021c,       // 540  x32     sys_process_vm_writev
+        setsockopt = 0x4000021d,              // 541  x32     sys_setsockopt
+        getsockopt = 0x4000021e,              // 542  x32     sys_getsockopt
+        io_setup = 0x4000021f,                // 543  x32     compat_sys_io_setup
+        io_submit = 0x40000220,               // 544  x32     compat_sys_io_submit
+        execveat = 0x40000221,                // 545  x32     compat_sys_execveat
+        preadv2 = 0x40000222,                 // 546  x32     compat_sys_preadv64v2
+        pwritev2 = 0x40000223,                // 547  x32     compat_sys_pwritev64v2
+    }
+}
+
+impl X32SysCallNum {
+    // the ABI column of the syscall table.
+    pub fn abi(&self) -> SysCallAbi {
+        match self {
+            X32SysCallNum::rt_sigaction
+            | X32SysCallNum::rt_sigreturn
+            | X32SysCallNum::ioctl
+            | X32SysCallNum::readv
+            | X32SysCallNum::writev
+            | X32SysCallNum::recvfrom
+            | X32SysCallNum::sendmsg
+            | X32SysCallNum::recvmsg
+            | X32SysCallNum::execve
+            | X32SysCallNum::ptrace
+            | X32SysCallNum::rt_sigpending
+            | X32SysCallNum::rt_sigtimedwait
+            | X32SysCallNum::rt_sigqueueinfo
+            | X32SysCallNum::sigaltstack
+            | X32SysCallNum::timer_create
+            | X32SysCallNum::mq_notify
+            | X32SysCallNum::kexec_load
+            | X32SysCallNum::waitid
+            | X32SysCallNum::set_robust_list
+            | X32SysCallNum::get_robust_list
+            | X32SysCallNum::vmsplice
+            | X32SysCallNum::move_pages
+            | X32SysCallNum::preadv
+            | X32SysCallNum::pwritev
+            | X32SysCallNum::rt_tgsigqueueinfo
+            | X32SysCallNum::recvmmsg
+            | X32SysCallNum::sendmmsg
+            | X32SysCallNum::process_vm_readv
+            | X32SysCallNum::process_vm_writev
+            | X32SysCallNum::setsockopt
+            | X32SysCallNum::getsockopt
+            | X32SysCallNum::io_setup
+            | X32SysCallNum::io_submit
+            | X32SysCallNum::execveat
+            | X32SysCallNum::preadv2
+            | X32SysCallNum::pwritev2 => SysCallAbi::X32,
+            _ => SysCallAbi::Common,
+        }
+    }
+
+    // the name of the kernel function which implements the syscall,
+    // e.g. "sys_read", or `None` if the syscall is not implemented.
+    pub fn entry_point(&self) -> Option<&'static str> {
+        match self {
+            X32SysCallNum::read => Some("sys_read"),
+            X32SysCallNum::write => Some("sys_write"),
+            X32SysCallNum::open => Some("sys_open"),
+            X32SysCallNum::close => Some("sys_close"),
+            X32SysCallNum::stat => Some("sys_newstat"),
+            X32SysCallNum::fstat => Some("sys_newfstat"),
+            X32SysCallNum::lstat => Some("sys_newlstat"),
+            X32SysCallNum::poll => Some("sys_poll"),
+            X32SysCallNum::lseek => Some("sys_lseek"),
+            X32SysCallNum::mmap => Some("sys_mmap"),
+            X32SysCallNum::mprotect => Some("sys_mprotect"),
+            X32SysCallNum::munmap => Some("sys_munmap"),
+            X32SysCallNum::brk => Some("sys_brk"),
+            X32SysCallNum::rt_sigprocmask => Some("sys_rt_sigprocmask"),
+            X32SysCallNum::pread64 => Some("sys_pread64"),
+            X32SysCallNum::pwrite64 => Some("sys_pwrite64"),
+            X32SysCallNum::access => Some("sys_access"),
+            X32SysCallNum::pipe => Some("sys_pipe"),
+            X32SysCallNum::select => Some("sys_select"),
+            X32SysCallNum::sched_yield => Some("sys_sched_yield"),
+            X32SysCallNum::mremap => Some("sys_mremap"),
+            X32SysCallNum::msync => Some("sys_msync"),
+            X32SysCallNum::mincore => Some("sys_mincore"),
+            X32SysCallNum::madvise => Some("sys_madvise"),
+            X32SysCallNum::shmget => Some("sys_shmget"),
+            X32SysCallNum::shmat => Some("sys_shmat"),
+            X32SysCallNum::shmctl => Some("sys_shmctl"),
+            X32SysCallNum::dup => Some("sys_dup"),
+            X32SysCallNum::dup2 => Some("sys_dup2"),
+            X32SysCallNum::pause => Some("sys_pause"),
+            X32SysCallNum::nanosleep => Some("sys_nanosleep"),
+            X32SysCallNum::getitimer => Some("sys_getitimer"),
+            X32SysCallNum::alarm => Some("sys_alarm"),
+            X32SysCallNum::setitimer => Some("sys_setitimer"),
+            X32SysCallNum::getpid => Some("sys_getpid"),
+            X32SysCallNum::sendfile => Some("sys_sendfile64"),
+            X32SysCallNum::socket => Some("sys_socket"),
+            X32SysCallNum::connect => Some("sys_connect"),
+            X32SysCallNum::accept => Some("sys_accept"),
+            X32SysCallNum::sendto => Some("sys_sendto"),
+            X32SysCallNum::shutdown => Some("sys_shutdown"),
+            X32SysCallNum::bind => Some("sys_bind"),
+            X32SysCallNum::listen => Some("sys_listen"),
+            X32SysCallNum::getsockname => Some("sys_getsockname"),
+            X32SysCallNum::getpeername => Some("sys_getpeername"),
+            X32SysCallNum::socketpair => Some("sys_socketpair"),
+            X32SysCallNum::clone => Some("sys_clone"),
+            X32SysCallNum::fork => Some("sys_fork"),
+            X32SysCallNum::vfork => Some("sys_vfork"),
+            X32SysCallNum::exit => Some("sys_exit"),
+            X32SysCallNum::wait4 => Some("sys_wait4"),
+            X32SysCallNum::kill => Some("sys_kill"),
+            X32SysCallNum::uname => Some("sys_newuname"),
+            X32SysCallNum::semget => Some("sys_semget"),
+            X32SysCallNum::semop => Some("sys_semop"),
+            X32SysCallNum::semctl => Some("sys_semctl"),
+            X32SysCallNum::shmdt => Some("sys_shmdt"),
+            X32SysCallNum::msgget => Some("sys_msgget"),
+            X32SysCallNum::msgsnd => Some("sys_msgsnd"),
+            X32SysCallNum::msgrcv => Some("sys_msgrcv"),
+            X32SysCallNum::msgctl => Some("sys_msgctl"),
+            X32SysCallNum::fcntl => Some("sys_fcntl"),
+            X32SysCallNum::flock => Some("sys_flock"),
+            X32SysCallNum::fsync => Some("sys_fsync"),
+            X32SysCallNum::fdatasync => Some("sys_fdatasync"),
+            X32SysCallNum::truncate => Some("sys_truncate"),
+            X32SysCallNum::ftruncate => Some("sys_ftruncate"),
+            X32SysCallNum::getdents => Some("sys_getdents"),
+            X32SysCallNum::getcwd => Some("sys_getcwd"),
+            X32SysCallNum::chdir => Some("sys_chdir"),
+            X32SysCallNum::fchdir => Some("sys_fchdir"),
+            X32SysCallNum::rename => Some("sys_rename"),
+            X32SysCallNum::mkdir => Some("sys_mkdir"),
+            X32SysCallNum::rmdir => Some("sys_rmdir"),
+            X32SysCallNum::creat => Some("sys_creat"),
+            X32SysCallNum::link => Some("sys_link"),
+            X32SysCallNum::unlink => Some("sys_unlink"),
+            X32SysCallNum::symlink => Some("sys_symlink"),
+            X32SysCallNum::readlink => Some("sys_readlink"),
+            X32SysCallNum::chmod => Some("sys_chmod"),
+            X32SysCallNum::fchmod => Some("sys_fchmod"),
+            X32SysCallNum::chown => Some("sys_chown"),
+            X32SysCallNum::fchown => Some("sys_fchown"),
+            X32SysCallNum::lchown => Some("sys_lchown"),
+            X32SysCallNum::umask => Some("sys_umask"),
+            X32SysCallNum::gettimeofday => Some("sys_gettimeofday"),
+            X32SysCallNum::getrlimit => Some("sys_getrlimit"),
+            X32SysCallNum::getrusage => Some("sys_getrusage"),
+            X32SysCallNum::sysinfo => Some("sys_sysinfo"),
+            X32SysCallNum::times => Some("sys_times"),
+            X32SysCallNum::getuid => Some("sys_getuid"),
+            X32SysCallNum::syslog => Some("sys_syslog"),
+            X32SysCallNum::getgid => Some("sys_getgid"),
+            X32SysCallNum::setuid => Some("sys_setuid"),
+            X32SysCallNum::setgid => Some("sys_setgid"),
+            X32SysCallNum::geteuid => Some("sys_geteuid"),
+            X32SysCallNum::getegid => Some("sys_getegid"),
+            X32SysCallNum::setpgid => Some("sys_setpgid"),
+            X32SysCallNum::getppid => Some("sys_getppid"),
+            X32SysCallNum::getpgrp => Some("sys_getpgrp"),
+            X32SysCallNum::setsid => Some("sys_setsid"),
+            X32SysCallNum::setreuid => Some("sys_setreuid"),
+            X32SysCallNum::setregid => Some("sys_setregid"),
+            X32SysCallNum::getgroups => Some("sys_getgroups"),
+            X32SysCallNum::setgroups => Some("sys_setgroups"),
+            X32SysCallNum::setresuid => Some("sys_setresuid"),
+            X32SysCallNum::getresuid => Some("sys_getresuid"),
+            X32SysCallNum::setresgid => Some("sys_setresgid"),
+            X32SysCallNum::getresgid => Some("sys_getresgid"),
+            X32SysCallNum::getpgid => Some("sys_getpgid"),
+            X32SysCallNum::setfsuid => Some("sys_setfsuid"),
+            X32SysCallNum::setfsgid => Some("sys_setfsgid"),
+            X32SysCallNum::getsid => Some("sys_getsid"),
+            X32SysCallNum::capget => Some("sys_capget"),
+            X32SysCallNum::capset => Some("sys_capset"),
+            X32SysCallNum::rt_sigsuspend => Some("sys_rt_sigsuspend"),
+            X32SysCallNum::utime => Some("sys_utime"),
+            X32SysCallNum::mknod => Some("sys_mknod"),
+            X32SysCallNum::personality => Some("sys_personality"),
+            X32SysCallNum::ustat => Some("sys_ustat"),
+            X32SysCallNum::statfs => Some("sys_statfs"),
+            X32SysCallNum::fstatfs => Some("sys_fstatfs"),
+            X32SysCallNum::sysfs => Some("sys_sysfs"),
+            X32SysCallNum::getpriority => Some("sys_getpriority"),
+            X32SysCallNum::setpriority => Some("sys_setpriority"),
+            X32SysCallNum::sched_setparam => Some("sys_sched_setparam"),
+            X32SysCallNum::sched_getparam => Some("sys_sched_getparam"),
+            X32SysCallNum::sched_setscheduler => Some("sys_sched_setscheduler"),
+            X32SysCallNum::sched_getscheduler => Some("sys_sched_getscheduler"),
+            X32SysCallNum::sched_get_priority_max => Some("sys_sched_get_priority_max"),
+            X32SysCallNum::sched_get_priority_min => Some("sys_sched_get_priority_min"),
+            X32SysCallNum::sched_rr_get_interval => Some("sys_sched_rr_get_interval"),
+            X32SysCallNum::mlock => Some("sys_mlock"),
+            X32SysCallNum::munlock => Some("sys_munlock"),
+            X32SysCallNum::mlockall => Some("sys_mlockall"),
+            X32SysCallNum::munlockall => Some("sys_munlockall"),
+            X32SysCallNum::vhangup => Some("sys_vhangup"),
+            X32SysCallNum::modify_ldt => Some("sys_modify_ldt"),
+            X32SysCallNum::pivot_root => Some("sys_pivot_root"),
+            X32SysCallNum::prctl => Some("sys_prctl"),
+            X32SysCallNum::arch_prctl => Some("sys_arch_prctl"),
+            X32SysCallNum::adjtimex => Some("sys_adjtimex"),
+            X32SysCallNum::setrlimit => Some("sys_setrlimit"),
+            X32SysCallNum::chroot => Some("sys_chroot"),
+            X32SysCallNum::sync => Some("sys_sync"),
+            X32SysCallNum::acct => Some("sys_acct"),
+            X32SysCallNum::settimeofday => Some("sys_settimeofday"),
+            X32SysCallNum::mount => Some("sys_mount"),
+            X32SysCallNum::umount2 => Some("sys_umount"),
+            X32SysCallNum::swapon => Some("sys_swapon"),
+            X32SysCallNum::swapoff => Some("sys_swapoff"),
+            X32SysCallNum::reboot => Some("sys_reboot"),
+            X32SysCallNum::sethostname => Some("sys_sethostname"),
+            X32SysCallNum::setdomainname => Some("sys_setdomainname"),
+            X32SysCallNum::iopl => Some("sys_iopl"),
+            X32SysCallNum::ioperm => Some("sys_ioperm"),
+            X32SysCallNum::init_module => Some("sys_init_module"),
+            X32SysCallNum::delete_module => Some("sys_delete_module"),
+            X32SysCallNum::quotactl => Some("sys_quotactl"),
+            X32SysCallNum::gettid => Some("sys_gettid"),
+            X32SysCallNum::readahead => Some("sys_readahead"),
+            X32SysCallNum::setxattr => Some("sys_setxattr"),
+            X32SysCallNum::lsetxattr => Some("sys_lsetxattr"),
+            X32SysCallNum::fsetxattr => Some("sys_fsetxattr"),
+            X32SysCallNum::getxattr => Some("sys_getxattr"),
+            X32SysCallNum::lgetxattr => Some("sys_lgetxattr"),
+            X32SysCallNum::fgetxattr => Some("sys_fgetxattr"),
+            X32SysCallNum::listxattr => Some("sys_listxattr"),
+            X32SysCallNum::llistxattr => Some("sys_llistxattr"),
+            X32SysCallNum::flistxattr => Some("sys_flistxattr"),
+            X32SysCallNum::removexattr => Some("sys_removexattr"),
+            X32SysCallNum::lremovexattr => Some("sys_lremovexattr"),
+            X32SysCallNum::fremovexattr => Some("sys_fremovexattr"),
+            X32SysCallNum::tkill => Some("sys_tkill"),
+            X32SysCallNum::time => Some("sys_time"),
+            X32SysCallNum::futex => Some("sys_futex"),
+            X32SysCallNum::sched_setaffinity => Some("sys_sched_setaffinity"),
+            X32SysCallNum::sched_getaffinity => Some("sys_sched_getaffinity"),
+            X32SysCallNum::io_destroy => Some("sys_io_destroy"),
+            X32SysCallNum::io_getevents => Some("sys_io_getevents"),
+            X32SysCallNum::io_cancel => Some("sys_io_cancel"),
+            X32SysCallNum::epoll_create => Some("sys_epoll_create"),
+            X32SysCallNum::remap_file_pages => Some("sys_remap_file_pages"),
+            X32SysCallNum::getdents64 => Some("sys_getdents64"),
+            X32SysCallNum::set_tid_address => Some("sys_set_tid_address"),
+            X32SysCallNum::restart_syscall => Some("sys_restart_syscall"),
+            X32SysCallNum::semtimedop => Some("sys_semtimedop"),
+            X32SysCallNum::fadvise64 => Some("sys_fadvise64"),
+            X32SysCallNum::timer_settime => Some("sys_timer_settime"),
+            X32SysCallNum::timer_gettime => Some("sys_timer_gettime"),
+            X32SysCallNum::timer_getoverrun => Some("sys_timer_getoverrun"),
+            X32SysCallNum::timer_delete => Some("sys_timer_delete"),
+            X32SysCallNum::clock_settime => Some("sys_clock_settime"),
+            X32SysCallNum::clock_gettime => Some("sys_clock_gettime"),
+            X32SysCallNum::clock_getres => Some("sys_clock_getres"),
+            X32SysCallNum::clock_nanosleep => Some("sys_clock_nanosleep"),
+            X32SysCallNum::exit_group => Some("sys_exit_group"),
+            X32SysCallNum::epoll_wait => Some("sys_epoll_wait"),
+            X32SysCallNum::epoll_ctl => Some("sys_epoll_ctl"),
+            X32SysCallNum::tgkill => Some("sys_tgkill"),
+            X32SysCallNum::utimes => Some("sys_utimes"),
+            X32SysCallNum::mbind => Some("sys_mbind"),
+            X32SysCallNum::set_mempolicy => Some("sys_set_mempolicy"),
+            X32SysCallNum::get_mempolicy => Some("sys_get_mempolicy"),
+            X32SysCallNum::mq_open => Some("sys_mq_open"),
+            X32SysCallNum::mq_unlink => Some("sys_mq_unlink"),
+            X32SysCallNum::mq_timedsend => Some("sys_mq_timedsend"),
+            X32SysCallNum::mq_timedreceive => Some("sys_mq_timedreceive"),
+            X32SysCallNum::mq_getsetattr => Some("sys_mq_getsetattr"),
+            X32SysCallNum::add_key => Some("sys_add_key"),
+            X32SysCallNum::request_key => Some("sys_request_key"),
+            X32SysCallNum::keyctl => Some("sys_keyctl"),
+            X32SysCallNum::ioprio_set => Some("sys_ioprio_set"),
+            X32SysCallNum::ioprio_get => Some("sys_ioprio_get"),
+            X32SysCallNum::inotify_init => Some("sys_inotify_init"),
+            X32SysCallNum::inotify_add_watch => Some("sys_inotify_add_watch"),
+            X32SysCallNum::inotify_rm_watch => Some("sys_inotify_rm_watch"),
+            X32SysCallNum::migrate_pages => Some("sys_migrate_pages"),
+            X32SysCallNum::openat => Some("sys_openat"),
+            X32SysCallNum::mkdirat => Some("sys_mkdirat"),
+            X32SysCallNum::mknodat => Some("sys_mknodat"),
+            X32SysCallNum::fchownat => Some("sys_fchownat"),
+            X32SysCallNum::futimesat => Some("sys_futimesat"),
+            X32SysCallNum::newfstatat => Some("sys_newfstatat"),
+            X32SysCallNum::unlinkat => Some("sys_unlinkat"),
+            X32SysCallNum::renameat => Some("sys_renameat"),
+            X32SysCallNum::linkat => Some("sys_linkat"),
+            X32SysCallNum::symlinkat => Some("sys_symlinkat"),
+            X32SysCallNum::readlinkat => Some("sys_readlinkat"),
+            X32SysCallNum::fchmodat => Some("sys_fchmodat"),
+            X32SysCallNum::faccessat => Some("sys_faccessat"),
+            X32SysCallNum::pselect6 => Some("sys_pselect6"),
+            X32SysCallNum::ppoll => Some("sys_ppoll"),
+            X32SysCallNum::unshare => Some("sys_unshare"),
+            X32SysCallNum::splice => Some("sys_splice"),
+            X32SysCallNum::tee => Some("sys_tee"),
+            X32SysCallNum::sync_file_range => Some("sys_sync_file_range"),
+            X32SysCallNum::utimensat => Some("sys_utimensat"),
+            X32SysCallNum::epoll_pwait => Some("sys_epoll_pwait"),
+            X32SysCallNum::signalfd => Some("sys_signalfd"),
+            X32SysCallNum::timerfd_create => Some("sys_timerfd_create"),
+            X32SysCallNum::eventfd => Some("sys_eventfd"),
+            X32SysCallNum::fallocate => Some("sys_fallocate"),
+            X32SysCallNum::timerfd_settime => Some("sys_timerfd_settime"),
+            X32SysCallNum::timerfd_gettime => Some("sys_timerfd_gettime"),
+            X32SysCallNum::accept4 => Some("sys_accept4"),
+            X32SysCallNum::signalfd4 => Some("sys_signalfd4"),
+            X32SysCallNum::eventfd2 => Some("sys_eventfd2"),
+            X32SysCallNum::epoll_create1 => Some("sys_epoll_create1"),
+            X32SysCallNum::dup3 => Some("sys_dup3"),
+            X32SysCallNum::pipe2 => Some("sys_pipe2"),
+            X32SysCallNum::inotify_init1 => Some("sys_inotify_init1"),
+            X32SysCallNum::perf_event_open => Some("sys_perf_event_open"),
+            X32SysCallNum::fanotify_init => Some("sys_fanotify_init"),
+            X32SysCallNum::fanotify_mark => Some("sys_fanotify_mark"),
+            X32SysCallNum::prlimit64 => Some("sys_prlimit64"),
+            X32SysCallNum::name_to_handle_at => Some("sys_name_to_handle_at"),
+            X32SysCallNum::open_by_handle_at => Some("sys_open_by_handle_at"),
+            X32SysCallNum::clock_adjtime => Some("sys_clock_adjtime"),
+            X32SysCallNum::syncfs => Some("sys_syncfs"),
+            X32SysCallNum::setns => Some("sys_setns"),
+            X32SysCallNum::getcpu => Some("sys_getcpu"),
+            X32SysCallNum::kcmp => Some("sys_kcmp"),
+            X32SysCallNum::finit_module => Some("sys_finit_module"),
+            X32SysCallNum::sched_setattr => Some("sys_sched_setattr"),
+            X32SysCallNum::sched_getattr => Some("sys_sched_getattr"),
+            X32SysCallNum::renameat2 => Some("sys_renameat2"),
+            X32SysCallNum::seccomp => Some("sys_seccomp"),
+            X32SysCallNum::getrandom => Some("sys_getrandom"),
+            X32SysCallNum::memfd_create => Some("sys_memfd_create"),
+            X32SysCallNum::kexec_file_load => Some("sys_kexec_file_load"),
+            X32SysCallNum::bpf => Some("sys_bpf"),
+            X32SysCallNum::userfaultfd => Some("sys_userfaultfd"),
+            X32SysCallNum::membarrier => Some("sys_membarrier"),
+            X32SysCallNum::mlock2 => Some("sys_mlock2"),
+            X32SysCallNum::copy_file_range => Some("sys_copy_file_range"),
+            X32SysCallNum::pkey_mprotect => Some("sys_pkey_mprotect"),
+            X32SysCallNum::pkey_alloc => Some("sys_pkey_alloc"),
+            X32SysCallNum::pkey_free => Some("sys_pkey_free"),
+            X32SysCallNum::statx => Some("sys_statx"),
+            X32SysCallNum::io_pgetevents => Some("sys_io_pgetevents"),
+            X32SysCallNum::rseq => Some("sys_rseq"),
+            X32SysCallNum::uretprobe => Some("sys_uretprobe"),
+            X32SysCallNum::pidfd_send_signal => Some("sys_pidfd_send_signal"),
+            X32SysCallNum::io_uring_setup => Some("sys_io_uring_setup"),
+            X32SysCallNum::io_uring_enter => Some("sys_io_uring_enter"),
+            X32SysCallNum::io_uring_register => Some("sys_io_uring_register"),
+            X32SysCallNum::open_tree => Some("sys_open_tree"),
+            X32SysCallNum::move_mount => Some("sys_move_mount"),
+            X32SysCallNum::fsopen => Some("sys_fsopen"),
+            X32SysCallNum::fsconfig => Some("sys_fsconfig"),
+            X32SysCallNum::fsmount => Some("sys_fsmount"),
+            X32SysCallNum::fspick => Some("sys_fspick"),
+            X32SysCallNum::pidfd_open => Some("sys_pidfd_open"),
+            X32SysCallNum::clone3 => Some("sys_clone3"),
+            X32SysCallNum::close_range => Some("sys_close_range"),
+            X32SysCallNum::openat2 => Some("sys_openat2"),
+            X32SysCallNum::pidfd_getfd => Some("sys_pidfd_getfd"),
+            X32SysCallNum::faccessat2 => Some("sys_faccessat2"),
+            X32SysCallNum::process_madvise => Some("sys_process_madvise"),
+            X32SysCallNum::epoll_pwait2 => Some("sys_epoll_pwait2"),
+            X32SysCallNum::mount_setattr => Some("sys_mount_setattr"),
+            X32SysCallNum::quotactl_fd => Some("sys_quotactl_fd"),
+            X32SysCallNum::landlock_create_ruleset => Some("sys_landlock_create_ruleset"),
+            X32SysCallNum::landlock_add_rule => Some("sys_landlock_add_rule"),
+            X32SysCallNum::landlock_restrict_self => Some("sys_landlock_restrict_self"),
+            X32SysCallNum::memfd_secret => Some("sys_memfd_secret"),
+            X32SysCallNum::process_mrelease => Some("sys_process_mrelease"),
+            X32SysCallNum::futex_waitv => Some("sys_futex_waitv"),
+            X32SysCallNum::set_mempolicy_home_node => Some("sys_set_mempolicy_home_node"),
+            X32SysCallNum::cachestat => Some("sys_cachestat"),
+            X32SysCallNum::fchmodat2 => Some("sys_fchmodat2"),
+            X32SysCallNum::futex_wake => Some("sys_futex_wake"),
+            X32SysCallNum::futex_wait => Some("sys_futex_wait"),
+            X32SysCallNum::futex_requeue => Some("sys_futex_requeue"),
+            X32SysCallNum::statmount => Some("sys_statmount"),
+            X32SysCallNum::listmount => Some("sys_listmount"),
+            X32SysCallNum::lsm_get_self_attr => Some("sys_lsm_get_self_attr"),
+            X32SysCallNum::lsm_set_self_attr => Some("sys_lsm_set_self_attr"),
+            X32SysCallNum::lsm_list_modules => Some("sys_lsm_list_modules"),
+            X32SysCallNum::mseal => Some("sys_mseal"),
+            X32SysCallNum::setxattrat => Some("sys_setxattrat"),
+            X32SysCallNum::getxattrat => Some("sys_getxattrat"),
+            X32SysCallNum::listxattrat => Some("sys_listxattrat"),
+            X32SysCallNum::removexattrat => Some("sys_removexattrat"),
+            X32SysCallNum::rt_sigaction => Some("compat_sys_rt_sigaction"),
+            X32SysCallNum::rt_sigreturn => Some("compat_sys_x32_rt_sigreturn"),
+            X32SysCallNum::ioctl => Some("compat_sys_ioctl"),
+            X32SysCallNum::readv => Some("sys_readv"),
+            X32SysCallNum::writev => Some("sys_writev"),
+            X32SysCallNum::recvfrom => Some("compat_sys_recvfrom"),
+            X32SysCallNum::sendmsg => Some("compat_sys_sendmsg"),
+            X32SysCallNum::recvmsg => Some("compat_sys_recvmsg"),
+            X32SysCallNum::execve => Some("compat_sys_execve"),
+            X32SysCallNum::ptrace => Some("compat_sys_ptrace"),
+            X32SysCallNum::rt_sigpending => Some("compat_sys_rt_sigpending"),
+            X32SysCallNum::rt_sigtimedwait => Some("compat_sys_rt_sigtimedwait_time64"),
+            X32SysCallNum::rt_sigqueueinfo => Some("compat_sys_rt_sigqueueinfo"),
+            X32SysCallNum::sigaltstack => Some("compat_sys_sigaltstack"),
+            X32SysCallNum::timer_create => Some("compat_sys_timer_create"),
+            X32SysCallNum::mq_notify => Some("compat_sys_mq_notify"),
+            X32SysCallNum::kexec_load => Some("compat_sys_kexec_load"),
+            X32SysCallNum::waitid => Some("compat_sys_waitid"),
+            X32SysCallNum::set_robust_list => Some("compat_sys_set_robust_list"),
+            X32SysCallNum::get_robust_list => Some("compat_sys_get_robust_list"),
+            X32SysCallNum::vmsplice => Some("sys_vmsplice"),
+            X32SysCallNum::move_pages => Some("sys_move_pages"),
+            X32SysCallNum::preadv => Some("compat_sys_preadv64"),
+            X32SysCallNum::pwritev => Some("compat_sys_pwritev64"),
+            X32SysCallNum::rt_tgsigqueueinfo => Some("compat_sys_rt_tgsigqueueinfo"),
+            X32SysCallNum::recvmmsg => Some("compat_sys_recvmmsg_time64"),
+            X32SysCallNum::sendmmsg => Some("compat_sys_sendmmsg"),
+            X32SysCallNum::process_vm_readv => Some("sys_process_vm_readv"),
+            X32SysCallNum::process_vm_writev => Some("sys_process_vm_writev"),
+            X32SysCallNum::setsockopt => Some("sys_setsockopt"),
+            X32SysCallNum::getsockopt => Some("sys_getsockopt"),
+            X32SysCallNum::io_setup => Some("compat_sys_io_setup"),
+            X32SysCallNum::io_submit => Some("compat_sys_io_submit"),
+            X32SysCallNum::execveat => Some("compat_sys_execveat"),
+            X32SysCallNum::preadv2 => Some("compat_sys_preadv64v2"),
+            X32SysCallNum::pwritev2 => Some("compat_sys_pwritev64v2"),
+            _ => None,
+        }
+    }
+
+    // whether the syscall never returns (e.g. `exit` and `exit_group`).
+    pub fn is_noreturn(&self) -> bool {
+        matches!(self, X32SysCallNum::exit | X32SysCallNum::exit_group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::arch::{
+        x86_64::number::{SysCallNum, X32SysCallNum, X32_SYSCALL_BIT},
+        SysCallAbi,
+    };
+
+    #[test]
+    fn test_lookup_syscall_num() {
+        // the names and the numbers are unique
+        for num in SysCallNum::iter() {
+            assert_eq!(SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(SysCallNum::from_str(num.name()), Ok(num));
+        }
+
+        for num in X32SysCallNum::iter() {
+            assert_eq!(X32SysCallNum::from_number(num as usize), Some(num));
+            assert_eq!(X32SysCallNum::from_str(num.name()), Ok(num));
+            assert_ne!(num as usize & X32_SYSCALL_BIT, 0);
+        }
+
+        assert_eq!(SysCallNum::iter().count(), SysCallNum::ALL.len());
+        assert_eq!(SysCallNum::from_number(usize::MAX), None);
+        assert!(SysCallNum::from_str("foo").is_err());
+
+        // the renamed syscalls keep the names of the kernel syscall table
+        assert_eq!(SysCallNum::sysctl_.name(), "_sysctl");
+        assert_eq!(SysCallNum::from_str("_sysctl"), Ok(SysCallNum::sysctl_));
+        assert!(SysCallNum::from_str("sysctl_").is_err());
+    }
+
+    #[test]
+    fn test_syscall_table() {
+        // checks the generated enums against the kernel syscall table
+        let text = include_str!("syscall_64.tbl");
+        let mut x64_count = 0;
+        let mut x32_count = 0;
+
+        for line in text.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let number = fields[0].parse::<usize>().unwrap();
+            let abi = match fields[1] {
+                "common" => SysCallAbi::Common,
+                "64" => SysCallAbi::X64,
+                "x32" => SysCallAbi::X32,
+                _ => continue,
+            };
+            let name = fields[2];
+            let entry_point = fields.get(3).copied();
+            let noreturn = fields.get(5) == Some(&"noreturn");
+
+            if abi != SysCallAbi::X32 {
+                let num = SysCallNum::from_number(number).unwrap();
+                assert_eq!(num.name(), name);
+                assert_eq!(num.abi(), abi);
+                assert_eq!(num.entry_point(), entry_point);
+                assert_eq!(num.is_noreturn(), noreturn);
+                x64_count += 1;
+            }
+
+            if abi != SysCallAbi::X64 {
+                let num = X32SysCallNum::from_number(number | X32_SYSCALL_BIT).unwrap();
+                assert_eq!(num.name(), name);
+                assert_eq!(num.abi(), abi);
+                assert_eq!(num.entry_point(), entry_point);
+                assert_eq!(num.is_noreturn(), noreturn);
+                x32_count += 1;
+            }
+        }
+
+        assert_eq!(x64_count, SysCallNum::ALL.len());
+        assert_eq!(x32_count, X32SysCallNum::ALL.len());
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the dynamic-arity version of the functions in the module `call`.
+//
+// the functions `syscall_without_args`...`syscall_with_6_args` require
+// the amount of arguments to be known at compile time, the function
+// `syscall()` takes the arguments as a slice and routes to the function
+// of the corresponding arity at runtime, e.g.
+//
+// ```rust
+// let args: Vec<usize> = /* the arguments from the bytecode */;
+// let result = unsafe { syscall(num, &args) };
+// ```
+//
+// the macro `syscall!` accepts any mix of integers, pointers and `SysCallNum`, e.g.
+//
+// ```rust
+// let buf = b"hello\n";
+// let result = unsafe { syscall!(SysCallNum::write, 1, buf.as_ptr(), buf.len()) };
+// ```
+
+use crate::{call, errno::Errno, number::SysCallNum};
+
+// the maximum number of syscall arguments.
+pub const MAX_ARGS: usize = 6;
+
+// returns `Err(EINVAL)` if there are more than 6 arguments.
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall(num: usize, args: &[usize]) -> Result<usize, usize> {
+    match *args {
+        [] => call::syscall_without_args(num),
+        [a1] => call::syscall_with_1_arg(num, a1),
+        [a1, a2] => call::syscall_with_2_args(num, a1, a2),
+        [a1, a2, a3] => call::syscall_with_3_args(num, a1, a2, a3),
+        [a1, a2, a3, a4] => call::syscall_with_4_args(num, a1, a2, a3, a4),
+        [a1, a2, a3, a4, a5] => call::syscall_with_5_args(num, a1, a2, a3, a4, a5),
+        [a1, a2, a3, a4, a5, a6] => call::syscall_with_6_args(num, a1, a2, a3, a4, a5, a6),
+        _ => Err(Errno::EINVAL as usize),
+    }
+}
+
+// converts a value into a syscall argument (or the syscall number).
+//
+// the signed integers are sign-extended, so `-1i32` (e.g. an invalid fd)
+// and `-100i32` (i.e. `AT_FDCWD`) are passed to the kernel as expected.
+pub trait SysCallArg {
+    fn into_arg(self) -> usize;
+}
+
+macro_rules! impl_syscall_arg_for_unsigned {
+    ($($t:ty),*) => {
+        $(impl SysCallArg for $t {
+            #[inline(always)]
+            fn into_arg(self) -> usize {
+                self as usize
+            }
+        })*
+    };
+}
+
+macro_rules! impl_syscall_arg_for_signed {
+    ($($t:ty),*) => {
+        $(impl SysCallArg for $t {
+            #[inline(always)]
+            fn into_arg(self) -> usize {
+                self as isize as usize
+            }
+        })*
+    };
+}
+
+impl_syscall_arg_for_unsigned!(u8, u16, u32, u64, usize);
+impl_syscall_arg_for_signed!(i8, i16, i32, i64, isize);
+
+impl SysCallArg for bool {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as usize
+    }
+}
+
+impl<T> SysCallArg for *const T {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as usize
+    }
+}
+
+impl<T> SysCallArg for *mut T {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as usize
+    }
+}
+
+impl SysCallArg for SysCallNum {
+    #[inline(always)]
+    fn into_arg(self) -> usize {
+        self as usize
+    }
+}
+
+// invokes a syscall with any mix of integers, pointers and `SysCallNum`,
+// the first argument is the syscall number.
+//
+// this macro expands to an unsafe function call, so it must be used
+// in an `unsafe` block.
+#[macro_export]
+macro_rules! syscall {
+    ($num:expr $(, $arg:expr)* $(,)?) => {
+        $crate::dispatch::syscall(
+            $crate::dispatch::SysCallArg::into_arg($num),
+            &[$($crate::dispatch::SysCallArg::into_arg($arg)),*],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dispatch::{syscall, MAX_ARGS},
+        errno::Errno,
+        number::SysCallNum,
+    };
+
+    #[test]
+    fn test_dispatch() {
+        let pid = unsafe { crate::call::syscall_without_args(SysCallNum::getpid as usize) };
+        let result0 = unsafe { syscall(SysCallNum::getpid as usize, &[]) };
+        assert_eq!(result0, pid);
+
+        let result1 = unsafe { syscall(SysCallNum::close as usize, &[usize::MAX >> 1]) };
+        assert_eq!(result1, Err(Errno::EBADF as usize));
+
+        // more than 6 arguments
+        let result2 = unsafe { syscall(SysCallNum::getpid as usize, &[0; MAX_ARGS + 1]) };
+        assert_eq!(result2, Err(Errno::EINVAL as usize));
+    }
+
+    #[test]
+    fn test_syscall_macro() {
+        let result0 = unsafe { syscall!(SysCallNum::getpid) };
+        assert!(matches!(result0, Ok(pid) if pid > 0));
+
+        // AT_FDCWD = -100
+        let file_path = b"/dev/zero\0";
+        let result1 = unsafe { syscall!(SysCallNum::openat, -100, file_path.as_ptr(), 0, 0u32) };
+        let fd = result1.unwrap();
+
+        let mut buffer = [1u8; 8];
+        let result2 = unsafe { syscall!(SysCallNum::read, fd, buffer.as_mut_ptr(), buffer.len(),) };
+        assert_eq!(result2, Ok(8));
+        assert_eq!(buffer, [0u8; 8]);
+
+        let result3 = unsafe { syscall!(SysCallNum::close, fd) };
+        assert_eq!(result3, Ok(0));
+
+        // -1 is sign-extended
+        let result4 = unsafe { syscall!(SysCallNum::close, -1i32) };
+        assert_eq!(result4, Err(Errno::EBADF as usize));
+    }
+}
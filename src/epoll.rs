@@ -0,0 +1,403 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the I/O event notification, e.g.
+//
+// ```rust
+// let epoll = Epoll::new(EpollFlags::EPOLL_CLOEXEC)?;
+// epoll.add(read_fd.as_fd(), EpollEvents::EPOLLIN | EpollEvents::EPOLLET, 1)?;
+//
+// let mut buf = [epoll_event::default(); 64];
+// for event in epoll.wait(&mut buf, Some(Duration::from_millis(100)))? {
+//     // `event.data` is the value passed to `add`, i.e. 1
+// }
+// ```
+
+use std::{
+    os::fd::{AsRawFd, RawFd},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use crate::{
+    errno::{Errno, SysCallError},
+    fd::{BorrowedFd, OwnedFd},
+    flags::{EpollEvents, EpollFlags, EpollOp},
+    number::SysCallNum,
+    signal::SIGSET_SIZE,
+    typed::{syscall_with_1_arg, syscall_with_4_args, syscall_with_6_args},
+    types::{epoll_event, timespec},
+};
+
+// `epoll_pwait2` (which takes the timeout in nanoseconds) is available
+// since Linux 5.11, it is set once `ENOSYS` is returned.
+static PWAIT2_UNSUPPORTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug)]
+pub struct Epoll {
+    fd: OwnedFd,
+}
+
+impl Epoll {
+    pub fn new(flags: EpollFlags) -> Result<Self, SysCallError> {
+        let fd = unsafe {
+            syscall_with_1_arg(SysCallNum::epoll_create1 as usize, flags.bits() as usize)
+        }?;
+        Ok(Self {
+            fd: unsafe { OwnedFd::from_raw(fd as RawFd) },
+        })
+    }
+
+    pub fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+
+    pub fn into_fd(self) -> OwnedFd {
+        self.fd
+    }
+
+    // registers `fd` with the interested events, `data` is returned with
+    // the ready events, it is usually the index or the token of `fd`.
+    //
+    // the modes can be added to `events`:
+    // - `EPOLLET`: edge-triggered, i.e. the event is reported only when
+    //   the state changes, instead of while the state is ready.
+    // - `EPOLLONESHOT`: `fd` is disabled after an event is reported,
+    //   until it is re-armed by `modify`.
+    // - `EPOLLEXCLUSIVE`: only one of the epoll instances which wait for the
+    //   same `fd` is woken up, it can only be used with `add`.
+    pub fn add(
+        &self,
+        fd: BorrowedFd<'_>,
+        events: EpollEvents,
+        data: u64,
+    ) -> Result<(), SysCallError> {
+        self.ctl(
+            EpollOp::EPOLL_CTL_ADD,
+            fd,
+            Some(epoll_event {
+                events: events.bits(),
+                data,
+            }),
+        )
+    }
+
+    // changes the interested events and the data of the registered `fd`.
+    pub fn modify(
+        &self,
+        fd: BorrowedFd<'_>,
+        events: EpollEvents,
+        data: u64,
+    ) -> Result<(), SysCallError> {
+        self.ctl(
+            EpollOp::EPOLL_CTL_MOD,
+            fd,
+            Some(epoll_event {
+                events: events.bits(),
+                data,
+            }),
+        )
+    }
+
+    pub fn delete(&self, fd: BorrowedFd<'_>) -> Result<(), SysCallError> {
+        self.ctl(EpollOp::EPOLL_CTL_DEL, fd, None)
+    }
+
+    fn ctl(
+        &self,
+        op: EpollOp,
+        fd: BorrowedFd<'_>,
+        event: Option<epoll_event>,
+    ) -> Result<(), SysCallError> {
+        unsafe {
+            syscall_with_4_args(
+                SysCallNum::epoll_ctl as usize,
+                self.fd.as_raw() as usize,
+                op.raw() as usize,
+                fd.as_raw() as usize,
+                event
+                    .as_ref()
+                    .map_or(0, |event| event as *const epoll_event as usize),
+            )
+        }
+        .map(|_| ())
+    }
+
+    // waits for the events and stores them into `buf`, returns the iterator
+    // over the ready events, which is empty if the timeout expires.
+    //
+    // `timeout` is `None` means waiting forever, and `Some(Duration::ZERO)`
+    // means returning immediately.
+    pub fn wait<'a>(
+        &self,
+        buf: &'a mut [epoll_event],
+        timeout: Option<Duration>,
+    ) -> Result<ReadyEvents<'a>, SysCallError> {
+        let count = if PWAIT2_UNSUPPORTED.load(Ordering::Relaxed) {
+            self.pwait(buf, timeout)?
+        } else {
+            match self.pwait2(buf, timeout) {
+                Err(e) if e == Errno::ENOSYS => {
+                    PWAIT2_UNSUPPORTED.store(true, Ordering::Relaxed);
+                    self.pwait(buf, timeout)?
+                }
+                result => result?,
+            }
+        };
+
+        Ok(ReadyEvents {
+            iter: buf[..count].iter(),
+        })
+    }
+
+    fn pwait2(
+        &self,
+        buf: &mut [epoll_event],
+        timeout: Option<Duration>,
+    ) -> Result<usize, SysCallError> {
+        let timeout = timeout.map(timespec::from);
+        unsafe {
+            syscall_with_6_args(
+                SysCallNum::epoll_pwait2 as usize,
+                self.fd.as_raw() as usize,
+                buf.as_mut_ptr() as usize,
+                buf.len().min(i32::MAX as usize),
+                timeout
+                    .as_ref()
+                    .map_or(0, |timeout| timeout as *const timespec as usize),
+                0,
+                SIGSET_SIZE,
+            )
+        }
+    }
+
+    // the fallback of `pwait2`, it is the same as `epoll_wait` since the
+    // signal mask is NULL (aarch64 and riscv64 do not have `epoll_wait`).
+    //
+    // the timeout is in milliseconds, so it is rounded up to avoid
+    // returning before the timeout expires.
+    fn pwait(
+        &self,
+        buf: &mut [epoll_event],
+        timeout: Option<Duration>,
+    ) -> Result<usize, SysCallError> {
+        let timeout_ms: i32 = match timeout {
+            None => -1,
+            Some(duration) => duration
+                .as_nanos()
+                .div_ceil(1_000_000)
+                .min(i32::MAX as u128) as i32,
+        };
+
+        unsafe {
+            syscall_with_6_args(
+                SysCallNum::epoll_pwait as usize,
+                self.fd.as_raw() as usize,
+                buf.as_mut_ptr() as usize,
+                buf.len().min(i32::MAX as usize),
+                timeout_ms as usize,
+                0,
+                SIGSET_SIZE,
+            )
+        }
+    }
+}
+
+impl AsRawFd for Epoll {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ReadyEvent {
+    pub events: EpollEvents,
+    pub data: u64,
+}
+
+// the iterator over the ready events of `Epoll::wait`.
+#[derive(Debug)]
+pub struct ReadyEvents<'a> {
+    iter: std::slice::Iter<'a, epoll_event>,
+}
+
+impl Iterator for ReadyEvents<'_> {
+    type Item = ReadyEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the fields are copied (instead of being referenced) since
+        // `epoll_event` is packed on x86_64
+        self.iter.next().map(|event| ReadyEvent {
+            events: EpollEvents::from_bits(event.events),
+            data: event.data,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ReadyEvents<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        epoll::{Epoll, ReadyEvent},
+        errno::Errno,
+        event::EventFd,
+        flags::{EfdFlags, EpollEvents, EpollFlags, OpenFlags},
+        io::{pipe2, read, write},
+        types::epoll_event,
+    };
+
+    #[test]
+    fn test_level_triggered() {
+        let epoll = Epoll::new(EpollFlags::EPOLL_CLOEXEC).unwrap();
+        let (read_fd, write_fd) = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+        epoll.add(read_fd.as_fd(), EpollEvents::EPOLLIN, 7).unwrap();
+
+        let mut buf = [epoll_event::default(); 8];
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 0);
+
+        write(write_fd.as_fd(), b"abc").unwrap();
+        let events: Vec<ReadyEvent> = epoll.wait(&mut buf, None).unwrap().collect();
+        assert_eq!(
+            events,
+            vec![ReadyEvent {
+                events: EpollEvents::EPOLLIN,
+                data: 7
+            }]
+        );
+
+        // reported again while the data is not read
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 1);
+
+        // the write end is closed
+        epoll
+            .modify(
+                read_fd.as_fd(),
+                EpollEvents::EPOLLIN | EpollEvents::EPOLLRDHUP,
+                8,
+            )
+            .unwrap();
+        drop(write_fd);
+        let event = epoll.wait(&mut buf, None).unwrap().next().unwrap();
+        assert_eq!(event.data, 8);
+        assert!(event
+            .events
+            .contains(EpollEvents::EPOLLIN | EpollEvents::EPOLLHUP));
+
+        epoll.delete(read_fd.as_fd()).unwrap();
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 0);
+        assert_eq!(epoll.delete(read_fd.as_fd()), Err(Errno::ENOENT.into()));
+    }
+
+    #[test]
+    fn test_edge_triggered_and_oneshot() {
+        let epoll = Epoll::new(EpollFlags::EPOLL_CLOEXEC).unwrap();
+        let (read_fd, write_fd) = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+        let mut buf = [epoll_event::default(); 8];
+
+        // edge-triggered, reported once for each write
+        epoll
+            .add(
+                read_fd.as_fd(),
+                EpollEvents::EPOLLIN | EpollEvents::EPOLLET,
+                1,
+            )
+            .unwrap();
+        write(write_fd.as_fd(), b"a").unwrap();
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 1);
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 0);
+        write(write_fd.as_fd(), b"b").unwrap();
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 1);
+
+        // oneshot, disabled after the first event until it is re-armed
+        epoll
+            .modify(
+                read_fd.as_fd(),
+                EpollEvents::EPOLLIN | EpollEvents::EPOLLONESHOT,
+                2,
+            )
+            .unwrap();
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 1);
+        write(write_fd.as_fd(), b"c").unwrap();
+        assert_eq!(epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().len(), 0);
+        epoll
+            .modify(
+                read_fd.as_fd(),
+                EpollEvents::EPOLLIN | EpollEvents::EPOLLONESHOT,
+                3,
+            )
+            .unwrap();
+        let event = epoll.wait(&mut buf, Some(Duration::ZERO)).unwrap().next();
+        assert_eq!(event.map(|event| event.data), Some(3));
+
+        let mut data = [0u8; 8];
+        assert_eq!(read(read_fd.as_fd(), &mut data), Ok(3));
+    }
+
+    #[test]
+    fn test_exclusive() {
+        let epoll = Epoll::new(EpollFlags::EPOLL_CLOEXEC).unwrap();
+        let event_fd = EventFd::new(0, EfdFlags::EFD_CLOEXEC).unwrap();
+        epoll
+            .add(
+                event_fd.as_fd(),
+                EpollEvents::EPOLLIN | EpollEvents::EPOLLEXCLUSIVE,
+                1,
+            )
+            .unwrap();
+
+        // `EPOLLEXCLUSIVE` can not be modified
+        assert_eq!(
+            epoll.modify(event_fd.as_fd(), EpollEvents::EPOLLIN, 1),
+            Err(Errno::EINVAL.into())
+        );
+
+        // wakes up the waiting thread
+        let mut buf = [epoll_event::default(); 8];
+        std::thread::scope(|scope| {
+            scope.spawn(|| event_fd.notify(1).unwrap());
+            assert_eq!(epoll.wait(&mut buf, None).unwrap().len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_timeout() {
+        let epoll = Epoll::new(EpollFlags::EPOLL_CLOEXEC).unwrap();
+        let mut buf = [epoll_event::default(); 8];
+
+        let start = Instant::now();
+        assert_eq!(
+            epoll
+                .wait(&mut buf, Some(Duration::from_millis(20)))
+                .unwrap()
+                .len(),
+            0
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        // the fallback rounds the timeout up to milliseconds
+        let start = Instant::now();
+        assert_eq!(
+            epoll.pwait(&mut buf, Some(Duration::from_micros(1500))),
+            Ok(0)
+        );
+        assert!(start.elapsed() >= Duration::from_micros(1500));
+
+        // the buffer can not be empty
+        assert_eq!(
+            epoll
+                .wait(&mut [], Some(Duration::ZERO))
+                .map(|events| events.len()),
+            Err(Errno::EINVAL.into())
+        );
+    }
+}
@@ -71,8 +71,10 @@
 // 'include/uapi/asm-generic/errno.h'
 // 'include/uapi/asm-generic/errno-base.h'
 
+use std::fmt::Display;
+
 #[repr(u32)]
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Errno {
     // 'include/uapi/asm-generic/errno-base.h'
     EPERM = 1,    /* Operation not permitted */
@@ -228,3 +230,520 @@ pub enum Errno {
 // alias
 pub const EWOULDBLOCK: Errno = Errno::EAGAIN; /* Operation would block */
 pub const EDEADLOCK: Errno = Errno::EDEADLK;
+
+impl Errno {
+    // decodes an error number that comes from a syscall (i.e. the `-rax`).
+    //
+    // returns `None` if the number is not listed in `Errno`, e.g. the
+    // numbers 41 and 58 which are not used by the kernel, or the
+    // kernel internal numbers such as `ENOTSUPP = 524`.
+    pub fn from_code(code: u32) -> Option<Errno> {
+        match code {
+            41 | 58 => None,
+            // SAFETY: `Errno` is `#[repr(u32)]` and its discriminants are
+            // continuous in the range `1..=133` except 41 and 58.
+            1..=133 => Some(unsafe { std::mem::transmute::<u32, Errno>(code) }),
+            _ => None,
+        }
+    }
+
+    // the name of the error number, e.g. "ENOENT".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Errno::EPERM => "EPERM",
+            Errno::ENOENT => "ENOENT",
+            Errno::ESRCH => "ESRCH",
+            Errno::EINTR => "EINTR",
+            Errno::EIO => "EIO",
+            Errno::ENXIO => "ENXIO",
+            Errno::E2BIG => "E2BIG",
+            Errno::ENOEXEC => "ENOEXEC",
+            Errno::EBADF => "EBADF",
+            Errno::ECHILD => "ECHILD",
+            Errno::EAGAIN => "EAGAIN",
+            Errno::ENOMEM => "ENOMEM",
+            Errno::EACCES => "EACCES",
+            Errno::EFAULT => "EFAULT",
+            Errno::ENOTBLK => "ENOTBLK",
+            Errno::EBUSY => "EBUSY",
+            Errno::EEXIST => "EEXIST",
+            Errno::EXDEV => "EXDEV",
+            Errno::ENODEV => "ENODEV",
+            Errno::ENOTDIR => "ENOTDIR",
+            Errno::EISDIR => "EISDIR",
+            Errno::EINVAL => "EINVAL",
+            Errno::ENFILE => "ENFILE",
+            Errno::EMFILE => "EMFILE",
+            Errno::ENOTTY => "ENOTTY",
+            Errno::ETXTBSY => "ETXTBSY",
+            Errno::EFBIG => "EFBIG",
+            Errno::ENOSPC => "ENOSPC",
+            Errno::ESPIPE => "ESPIPE",
+            Errno::EROFS => "EROFS",
+            Errno::EMLINK => "EMLINK",
+            Errno::EPIPE => "EPIPE",
+            Errno::EDOM => "EDOM",
+            Errno::ERANGE => "ERANGE",
+            Errno::EDEADLK => "EDEADLK",
+            Errno::ENAMETOOLONG => "ENAMETOOLONG",
+            Errno::ENOLCK => "ENOLCK",
+            Errno::ENOSYS => "ENOSYS",
+            Errno::ENOTEMPTY => "ENOTEMPTY",
+            Errno::ELOOP => "ELOOP",
+            Errno::ENOMSG => "ENOMSG",
+            Errno::EIDRM => "EIDRM",
+            Errno::ECHRNG => "ECHRNG",
+            Errno::EL2NSYNC => "EL2NSYNC",
+            Errno::EL3HLT => "EL3HLT",
+            Errno::EL3RST => "EL3RST",
+            Errno::ELNRNG => "ELNRNG",
+            Errno::EUNATCH => "EUNATCH",
+            Errno::ENOCSI => "ENOCSI",
+            Errno::EL2HLT => "EL2HLT",
+            Errno::EBADE => "EBADE",
+            Errno::EBADR => "EBADR",
+            Errno::EXFULL => "EXFULL",
+            Errno::ENOANO => "ENOANO",
+            Errno::EBADRQC => "EBADRQC",
+            Errno::EBADSLT => "EBADSLT",
+            Errno::EBFONT => "EBFONT",
+            Errno::ENOSTR => "ENOSTR",
+            Errno::ENODATA => "ENODATA",
+            Errno::ETIME => "ETIME",
+            Errno::ENOSR => "ENOSR",
+            Errno::ENONET => "ENONET",
+            Errno::ENOPKG => "ENOPKG",
+            Errno::EREMOTE => "EREMOTE",
+            Errno::ENOLINK => "ENOLINK",
+            Errno::EADV => "EADV",
+            Errno::ESRMNT => "ESRMNT",
+            Errno::ECOMM => "ECOMM",
+            Errno::EPROTO => "EPROTO",
+            Errno::EMULTIHOP => "EMULTIHOP",
+            Errno::EDOTDOT => "EDOTDOT",
+            Errno::EBADMSG => "EBADMSG",
+            Errno::EOVERFLOW => "EOVERFLOW",
+            Errno::ENOTUNIQ => "ENOTUNIQ",
+            Errno::EBADFD => "EBADFD",
+            Errno::EREMCHG => "EREMCHG",
+            Errno::ELIBACC => "ELIBACC",
+            Errno::ELIBBAD => "ELIBBAD",
+            Errno::ELIBSCN => "ELIBSCN",
+            Errno::ELIBMAX => "ELIBMAX",
+            Errno::ELIBEXEC => "ELIBEXEC",
+            Errno::EILSEQ => "EILSEQ",
+            Errno::ERESTART => "ERESTART",
+            Errno::ESTRPIPE => "ESTRPIPE",
+            Errno::EUSERS => "EUSERS",
+            Errno::ENOTSOCK => "ENOTSOCK",
+            Errno::EDESTADDRREQ => "EDESTADDRREQ",
+            Errno::EMSGSIZE => "EMSGSIZE",
+            Errno::EPROTOTYPE => "EPROTOTYPE",
+            Errno::ENOPROTOOPT => "ENOPROTOOPT",
+            Errno::EPROTONOSUPPORT => "EPROTONOSUPPORT",
+            Errno::ESOCKTNOSUPPORT => "ESOCKTNOSUPPORT",
+            Errno::EOPNOTSUPP => "EOPNOTSUPP",
+            Errno::EPFNOSUPPORT => "EPFNOSUPPORT",
+            Errno::EAFNOSUPPORT => "EAFNOSUPPORT",
+            Errno::EADDRINUSE => "EADDRINUSE",
+            Errno::EADDRNOTAVAIL => "EADDRNOTAVAIL",
+            Errno::ENETDOWN => "ENETDOWN",
+            Errno::ENETUNREACH => "ENETUNREACH",
+            Errno::ENETRESET => "ENETRESET",
+            Errno::ECONNABORTED => "ECONNABORTED",
+            Errno::ECONNRESET => "ECONNRESET",
+            Errno::ENOBUFS => "ENOBUFS",
+            Errno::EISCONN => "EISCONN",
+            Errno::ENOTCONN => "ENOTCONN",
+            Errno::ESHUTDOWN => "ESHUTDOWN",
+            Errno::ETOOMANYREFS => "ETOOMANYREFS",
+            Errno::ETIMEDOUT => "ETIMEDOUT",
+            Errno::ECONNREFUSED => "ECONNREFUSED",
+            Errno::EHOSTDOWN => "EHOSTDOWN",
+            Errno::EHOSTUNREACH => "EHOSTUNREACH",
+            Errno::EALREADY => "EALREADY",
+            Errno::EINPROGRESS => "EINPROGRESS",
+            Errno::ESTALE => "ESTALE",
+            Errno::EUCLEAN => "EUCLEAN",
+            Errno::ENOTNAM => "ENOTNAM",
+            Errno::ENAVAIL => "ENAVAIL",
+            Errno::EISNAM => "EISNAM",
+            Errno::EREMOTEIO => "EREMOTEIO",
+            Errno::EDQUOT => "EDQUOT",
+            Errno::ENOMEDIUM => "ENOMEDIUM",
+            Errno::EMEDIUMTYPE => "EMEDIUMTYPE",
+            Errno::ECANCELED => "ECANCELED",
+            Errno::ENOKEY => "ENOKEY",
+            Errno::EKEYEXPIRED => "EKEYEXPIRED",
+            Errno::EKEYREVOKED => "EKEYREVOKED",
+            Errno::EKEYREJECTED => "EKEYREJECTED",
+            Errno::EOWNERDEAD => "EOWNERDEAD",
+            Errno::ENOTRECOVERABLE => "ENOTRECOVERABLE",
+            Errno::ERFKILL => "ERFKILL",
+            Errno::EHWPOISON => "EHWPOISON",
+        }
+    }
+
+    // the message of the error number, e.g. "No such file or directory".
+    //
+    // the messages come from the comments in the kernel source files
+    // 'include/uapi/asm-generic/errno.h' and 'errno-base.h'.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Errno::EPERM => "Operation not permitted",
+            Errno::ENOENT => "No such file or directory",
+            Errno::ESRCH => "No such process",
+            Errno::EINTR => "Interrupted system call",
+            Errno::EIO => "I/O error",
+            Errno::ENXIO => "No such device or address",
+            Errno::E2BIG => "Argument list too long",
+            Errno::ENOEXEC => "Exec format error",
+            Errno::EBADF => "Bad file number",
+            Errno::ECHILD => "No child processes",
+            Errno::EAGAIN => "Try again",
+            Errno::ENOMEM => "Out of memory",
+            Errno::EACCES => "Permission denied",
+            Errno::EFAULT => "Bad address",
+            Errno::ENOTBLK => "Block device required",
+            Errno::EBUSY => "Device or resource busy",
+            Errno::EEXIST => "File exists",
+            Errno::EXDEV => "Cross-device link",
+            Errno::ENODEV => "No such device",
+            Errno::ENOTDIR => "Not a directory",
+            Errno::EISDIR => "Is a directory",
+            Errno::EINVAL => "Invalid argument",
+            Errno::ENFILE => "File table overflow",
+            Errno::EMFILE => "Too many open files",
+            Errno::ENOTTY => "Not a typewriter",
+            Errno::ETXTBSY => "Text file busy",
+            Errno::EFBIG => "File too large",
+            Errno::ENOSPC => "No space left on device",
+            Errno::ESPIPE => "Illegal seek",
+            Errno::EROFS => "Read-only file system",
+            Errno::EMLINK => "Too many links",
+            Errno::EPIPE => "Broken pipe",
+            Errno::EDOM => "Math argument out of domain of func",
+            Errno::ERANGE => "Math result not representable",
+            Errno::EDEADLK => "Resource deadlock would occur",
+            Errno::ENAMETOOLONG => "File name too long",
+            Errno::ENOLCK => "No record locks available",
+            Errno::ENOSYS => "Invalid system call number",
+            Errno::ENOTEMPTY => "Directory not empty",
+            Errno::ELOOP => "Too many symbolic links encountered",
+            Errno::ENOMSG => "No message of desired type",
+            Errno::EIDRM => "Identifier removed",
+            Errno::ECHRNG => "Channel number out of range",
+            Errno::EL2NSYNC => "Level 2 not synchronized",
+            Errno::EL3HLT => "Level 3 halted",
+            Errno::EL3RST => "Level 3 reset",
+            Errno::ELNRNG => "Link number out of range",
+            Errno::EUNATCH => "Protocol driver not attached",
+            Errno::ENOCSI => "No CSI structure available",
+            Errno::EL2HLT => "Level 2 halted",
+            Errno::EBADE => "Invalid exchange",
+            Errno::EBADR => "Invalid request descriptor",
+            Errno::EXFULL => "Exchange full",
+            Errno::ENOANO => "No anode",
+            Errno::EBADRQC => "Invalid request code",
+            Errno::EBADSLT => "Invalid slot",
+            Errno::EBFONT => "Bad font file format",
+            Errno::ENOSTR => "Device not a stream",
+            Errno::ENODATA => "No data available",
+            Errno::ETIME => "Timer expired",
+            Errno::ENOSR => "Out of streams resources",
+            Errno::ENONET => "Machine is not on the network",
+            Errno::ENOPKG => "Package not installed",
+            Errno::EREMOTE => "Object is remote",
+            Errno::ENOLINK => "Link has been severed",
+            Errno::EADV => "Advertise error",
+            Errno::ESRMNT => "Srmount error",
+            Errno::ECOMM => "Communication error on send",
+            Errno::EPROTO => "Protocol error",
+            Errno::EMULTIHOP => "Multihop attempted",
+            Errno::EDOTDOT => "RFS specific error",
+            Errno::EBADMSG => "Not a data message",
+            Errno::EOVERFLOW => "Value too large for defined data type",
+            Errno::ENOTUNIQ => "Name not unique on network",
+            Errno::EBADFD => "File descriptor in bad state",
+            Errno::EREMCHG => "Remote address changed",
+            Errno::ELIBACC => "Can not access a needed shared library",
+            Errno::ELIBBAD => "Accessing a corrupted shared library",
+            Errno::ELIBSCN => ".lib section in a.out corrupted",
+            Errno::ELIBMAX => "Attempting to link in too many shared libraries",
+            Errno::ELIBEXEC => "Cannot exec a shared library directly",
+            Errno::EILSEQ => "Illegal byte sequence",
+            Errno::ERESTART => "Interrupted system call should be restarted",
+            Errno::ESTRPIPE => "Streams pipe error",
+            Errno::EUSERS => "Too many users",
+            Errno::ENOTSOCK => "Socket operation on non-socket",
+            Errno::EDESTADDRREQ => "Destination address required",
+            Errno::EMSGSIZE => "Message too long",
+            Errno::EPROTOTYPE => "Protocol wrong type for socket",
+            Errno::ENOPROTOOPT => "Protocol not available",
+            Errno::EPROTONOSUPPORT => "Protocol not supported",
+            Errno::ESOCKTNOSUPPORT => "Socket type not supported",
+            Errno::EOPNOTSUPP => "Operation not supported on transport endpoint",
+            Errno::EPFNOSUPPORT => "Protocol family not supported",
+            Errno::EAFNOSUPPORT => "Address family not supported by protocol",
+            Errno::EADDRINUSE => "Address already in use",
+            Errno::EADDRNOTAVAIL => "Cannot assign requested address",
+            Errno::ENETDOWN => "Network is down",
+            Errno::ENETUNREACH => "Network is unreachable",
+            Errno::ENETRESET => "Network dropped connection because of reset",
+            Errno::ECONNABORTED => "Software caused connection abort",
+            Errno::ECONNRESET => "Connection reset by peer",
+            Errno::ENOBUFS => "No buffer space available",
+            Errno::EISCONN => "Transport endpoint is already connected",
+            Errno::ENOTCONN => "Transport endpoint is not connected",
+            Errno::ESHUTDOWN => "Cannot send after transport endpoint shutdown",
+            Errno::ETOOMANYREFS => "Too many references: cannot splice",
+            Errno::ETIMEDOUT => "Connection timed out",
+            Errno::ECONNREFUSED => "Connection refused",
+            Errno::EHOSTDOWN => "Host is down",
+            Errno::EHOSTUNREACH => "No route to host",
+            Errno::EALREADY => "Operation already in progress",
+            Errno::EINPROGRESS => "Operation now in progress",
+            Errno::ESTALE => "Stale file handle",
+            Errno::EUCLEAN => "Structure needs cleaning",
+            Errno::ENOTNAM => "Not a XENIX named type file",
+            Errno::ENAVAIL => "No XENIX semaphores available",
+            Errno::EISNAM => "Is a named type file",
+            Errno::EREMOTEIO => "Remote I/O error",
+            Errno::EDQUOT => "Quota exceeded",
+            Errno::ENOMEDIUM => "No medium found",
+            Errno::EMEDIUMTYPE => "Wrong medium type",
+            Errno::ECANCELED => "Operation Canceled",
+            Errno::ENOKEY => "Required key not available",
+            Errno::EKEYEXPIRED => "Key has expired",
+            Errno::EKEYREVOKED => "Key has been revoked",
+            Errno::EKEYREJECTED => "Key was rejected by service",
+            Errno::EOWNERDEAD => "Owner died",
+            Errno::ENOTRECOVERABLE => "State not recoverable",
+            Errno::ERFKILL => "Operation not possible due to RF-kill",
+            Errno::EHWPOISON => "Memory page has hardware error",
+        }
+    }
+}
+
+// the error of the typed syscall functions (the module `crate::typed`).
+//
+// the known error numbers are decoded into `Errno`, and the unknown
+// numbers are kept as they are, so that no information is lost.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SysCallError {
+    Errno(Errno),
+    Unknown(u32),
+}
+
+impl SysCallError {
+    pub fn from_code(code: u32) -> SysCallError {
+        match Errno::from_code(code) {
+            Some(errno) => SysCallError::Errno(errno),
+            None => SysCallError::Unknown(code),
+        }
+    }
+
+    // the raw error number, e.g. `2` for `ENOENT`.
+    pub fn code(&self) -> u32 {
+        match self {
+            SysCallError::Errno(errno) => *errno as u32,
+            SysCallError::Unknown(code) => *code,
+        }
+    }
+
+    pub fn errno(&self) -> Option<Errno> {
+        match self {
+            SysCallError::Errno(errno) => Some(*errno),
+            SysCallError::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<Errno> for SysCallError {
+    fn from(errno: Errno) -> Self {
+        SysCallError::Errno(errno)
+    }
+}
+
+impl PartialEq<Errno> for SysCallError {
+    fn eq(&self, other: &Errno) -> bool {
+        matches!(self, SysCallError::Errno(errno) if errno == other)
+    }
+}
+
+// note that the alias `EWOULDBLOCK` and `EDEADLOCK` are the same value as
+// `EAGAIN` and `EDEADLK`, so they are decoded into `Errno::EAGAIN` and
+// `Errno::EDEADLK`.
+impl TryFrom<u32> for Errno {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Errno::from_code(value).ok_or(value)
+    }
+}
+
+impl TryFrom<usize> for Errno {
+    type Error = usize;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u32::try_from(value)
+            .ok()
+            .and_then(Errno::from_code)
+            .ok_or(value)
+    }
+}
+
+// the value of `std::io::Error::raw_os_error()` is an `i32`.
+impl TryFrom<i32> for Errno {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        u32::try_from(value)
+            .ok()
+            .and_then(Errno::from_code)
+            .ok_or(value)
+    }
+}
+
+impl From<Errno> for u32 {
+    fn from(value: Errno) -> Self {
+        value as u32
+    }
+}
+
+impl From<Errno> for std::io::Error {
+    fn from(value: Errno) -> Self {
+        std::io::Error::from_raw_os_error(value as i32)
+    }
+}
+
+impl From<SysCallError> for std::io::Error {
+    fn from(value: SysCallError) -> Self {
+        std::io::Error::from_raw_os_error(value.code() as i32)
+    }
+}
+
+impl Errno {
+    // the `std::io::ErrorKind` of the error number.
+    //
+    // the mapping is the same as `std::io::Error::kind()`, e.g.
+    // `ENOENT` -> `NotFound`, `EAGAIN` -> `WouldBlock`,
+    // and the numbers without a specific kind are mapped to
+    // `Uncategorized` (which is unstable and displayed as "uncategorized error").
+    pub fn kind(&self) -> std::io::ErrorKind {
+        std::io::Error::from(*self).kind()
+    }
+}
+
+impl Display for Errno {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl std::error::Error for Errno {}
+
+impl Display for SysCallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SysCallError::Errno(errno) => Display::fmt(errno, f),
+            SysCallError::Unknown(code) => write!(f, "Unknown error {}", code),
+        }
+    }
+}
+
+impl std::error::Error for SysCallError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::errno::{Errno, SysCallError};
+
+    #[test]
+    fn test_decode_error_number() {
+        assert_eq!(Errno::from_code(1), Some(Errno::EPERM));
+        assert_eq!(Errno::from_code(2), Some(Errno::ENOENT));
+        assert_eq!(Errno::from_code(40), Some(Errno::ELOOP));
+        assert_eq!(Errno::from_code(42), Some(Errno::ENOMSG));
+        assert_eq!(Errno::from_code(59), Some(Errno::EBFONT));
+        assert_eq!(Errno::from_code(133), Some(Errno::EHWPOISON));
+
+        assert_eq!(Errno::from_code(0), None);
+        assert_eq!(Errno::from_code(41), None);
+        assert_eq!(Errno::from_code(58), None);
+        assert_eq!(Errno::from_code(134), None);
+
+        assert_eq!(
+            SysCallError::from_code(2),
+            SysCallError::Errno(Errno::ENOENT)
+        );
+        assert_eq!(SysCallError::from_code(524), SysCallError::Unknown(524));
+        assert_eq!(SysCallError::from_code(524).code(), 524);
+        assert_eq!(SysCallError::from_code(22).code(), 22);
+        assert_eq!(SysCallError::from_code(22), Errno::EINVAL);
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(Errno::ENOENT.name(), "ENOENT");
+        assert_eq!(Errno::ENOENT.description(), "No such file or directory");
+        assert_eq!(Errno::EHWPOISON.name(), "EHWPOISON");
+        assert_eq!(
+            Errno::EHWPOISON.description(),
+            "Memory page has hardware error"
+        );
+
+        assert_eq!(Errno::EINVAL.to_string(), "Invalid argument");
+        assert_eq!(
+            SysCallError::Errno(Errno::EACCES).to_string(),
+            "Permission denied"
+        );
+        assert_eq!(SysCallError::Unknown(524).to_string(), "Unknown error 524");
+
+        // `Errno` can be propagated by the `?` operator
+        fn check() -> Result<(), Box<dyn std::error::Error>> {
+            Err(Errno::EPERM)?;
+            Ok(())
+        }
+        assert_eq!(check().unwrap_err().to_string(), "Operation not permitted");
+    }
+
+    #[test]
+    fn test_convert_error_number() {
+        use std::io::ErrorKind;
+
+        use crate::errno::{EDEADLOCK, EWOULDBLOCK};
+
+        assert_eq!(Errno::try_from(2u32), Ok(Errno::ENOENT));
+        assert_eq!(Errno::try_from(41u32), Err(41));
+        assert_eq!(Errno::try_from(58usize), Err(58));
+        assert_eq!(Errno::try_from(usize::MAX), Err(usize::MAX));
+        assert_eq!(Errno::try_from(11i32), Ok(Errno::EAGAIN));
+        assert_eq!(Errno::try_from(-1i32), Err(-1));
+        assert_eq!(u32::from(Errno::EDEADLK), 35);
+
+        // alias
+        assert_eq!(Errno::try_from(EWOULDBLOCK as u32), Ok(Errno::EAGAIN));
+        assert_eq!(Errno::try_from(EDEADLOCK as u32), Ok(Errno::EDEADLK));
+
+        let e0 = std::io::Error::from(Errno::ENOENT);
+        assert_eq!(e0.raw_os_error(), Some(2));
+        assert_eq!(e0.kind(), ErrorKind::NotFound);
+        assert_eq!(
+            Errno::try_from(e0.raw_os_error().unwrap()),
+            Ok(Errno::ENOENT)
+        );
+
+        let e1 = std::io::Error::from(SysCallError::Unknown(524));
+        assert_eq!(e1.raw_os_error(), Some(524));
+
+        assert_eq!(Errno::EAGAIN.kind(), ErrorKind::WouldBlock);
+        assert_eq!(EWOULDBLOCK.kind(), ErrorKind::WouldBlock);
+        assert_eq!(Errno::EACCES.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(Errno::EPERM.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(Errno::EEXIST.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(Errno::EINVAL.kind(), ErrorKind::InvalidInput);
+        assert_eq!(Errno::EPIPE.kind(), ErrorKind::BrokenPipe);
+        assert_eq!(Errno::ECONNREFUSED.kind(), ErrorKind::ConnectionRefused);
+        assert_eq!(Errno::EINTR.kind(), ErrorKind::Interrupted);
+    }
+}
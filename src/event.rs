@@ -0,0 +1,344 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the file descriptors which turn the signals, the wakeups and the timers
+// into the readable events, so they can be polled by an event loop, e.g.
+//
+// ```rust
+// let timer = TimerFd::new(ClockId::CLOCK_MONOTONIC, TfdFlags::TFD_CLOEXEC)?;
+// timer.set(Deadline::Relative(Duration::from_millis(10)), None)?;
+// let expirations = timer.wait()?;
+// ```
+//
+// all of them own the file descriptor, which is closed when they are dropped.
+
+use std::{
+    os::fd::{AsRawFd, RawFd},
+    time::Duration,
+};
+
+use crate::{
+    errno::SysCallError,
+    fd::{BorrowedFd, OwnedFd},
+    flags::{ClockId, EfdFlags, SfdFlags, TfdFlags, TfdTimerFlags},
+    io::{read, write},
+    number::SysCallNum,
+    signal::SIGSET_SIZE,
+    typed::{syscall_with_2_args, syscall_with_4_args},
+    types::{itimerspec, signalfd_siginfo, sigset_t, timespec},
+};
+
+// implements the accessors of the file descriptor.
+macro_rules! impl_fd_accessors {
+    ($type:ident) => {
+        impl $type {
+            pub fn as_fd(&self) -> BorrowedFd<'_> {
+                self.fd.as_fd()
+            }
+
+            pub fn into_fd(self) -> OwnedFd {
+                self.fd
+            }
+        }
+
+        impl AsRawFd for $type {
+            fn as_raw_fd(&self) -> RawFd {
+                self.fd.as_raw()
+            }
+        }
+    };
+}
+
+// reads an 8-byte counter, it is used by `EventFd` and `TimerFd`.
+fn read_u64(fd: BorrowedFd<'_>) -> Result<u64, SysCallError> {
+    let mut buf = [0u8; 8];
+    read(fd, &mut buf)?;
+    Ok(u64::from_ne_bytes(buf))
+}
+
+// receives the signals in the mask by reading, the signals should be
+// blocked (by `rt_sigprocmask`) so that they are not handled by the
+// default actions or the handlers.
+#[derive(Debug)]
+pub struct SignalFd {
+    fd: OwnedFd,
+}
+
+impl SignalFd {
+    pub fn new(mask: &sigset_t, flags: SfdFlags) -> Result<Self, SysCallError> {
+        let fd = signalfd4(-1, mask, flags)?;
+        Ok(Self {
+            fd: unsafe { OwnedFd::from_raw(fd) },
+        })
+    }
+
+    // replaces the mask of the signals.
+    pub fn set_mask(&self, mask: &sigset_t) -> Result<(), SysCallError> {
+        signalfd4(self.fd.as_raw(), mask, SfdFlags::empty()).map(|_| ())
+    }
+
+    // reads a pending signal, it blocks until a signal arrives, or returns
+    // `EAGAIN` if there is no pending signal and `SFD_NONBLOCK` is set.
+    pub fn read(&self) -> Result<signalfd_siginfo, SysCallError> {
+        let mut info = signalfd_siginfo::default();
+        let buf = unsafe {
+            std::slice::from_raw_parts_mut(
+                &mut info as *mut signalfd_siginfo as *mut u8,
+                std::mem::size_of::<signalfd_siginfo>(),
+            )
+        };
+        read(self.fd.as_fd(), buf)?;
+        Ok(info)
+    }
+}
+
+impl_fd_accessors!(SignalFd);
+
+fn signalfd4(fd: RawFd, mask: &sigset_t, flags: SfdFlags) -> Result<RawFd, SysCallError> {
+    unsafe {
+        syscall_with_4_args(
+            SysCallNum::signalfd4 as usize,
+            fd as usize,
+            mask as *const sigset_t as usize,
+            SIGSET_SIZE,
+            flags.bits() as usize,
+        )
+    }
+    .map(|fd| fd as RawFd)
+}
+
+// a 64-bit counter for the wakeups, `notify` adds to the counter,
+// and `wait` takes the counter and resets it to 0 (or takes 1 and
+// decreases it by 1 if `EFD_SEMAPHORE` is set).
+#[derive(Debug)]
+pub struct EventFd {
+    fd: OwnedFd,
+}
+
+impl EventFd {
+    pub fn new(initial_value: u32, flags: EfdFlags) -> Result<Self, SysCallError> {
+        let fd = unsafe {
+            syscall_with_2_args(
+                SysCallNum::eventfd2 as usize,
+                initial_value as usize,
+                flags.bits() as usize,
+            )
+        }?;
+        Ok(Self {
+            fd: unsafe { OwnedFd::from_raw(fd as RawFd) },
+        })
+    }
+
+    // adds `value` to the counter, it blocks (or returns `EAGAIN` if
+    // `EFD_NONBLOCK` is set) if the counter would overflow.
+    pub fn notify(&self, value: u64) -> Result<(), SysCallError> {
+        write(self.fd.as_fd(), &value.to_ne_bytes()).map(|_| ())
+    }
+
+    // it blocks (or returns `EAGAIN` if `EFD_NONBLOCK` is set) while
+    // the counter is 0.
+    pub fn wait(&self) -> Result<u64, SysCallError> {
+        read_u64(self.fd.as_fd())
+    }
+}
+
+impl_fd_accessors!(EventFd);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Deadline {
+    // the duration from now
+    Relative(Duration),
+    // the time of the clock of the timer, e.g. the duration since the
+    // Unix epoch for `CLOCK_REALTIME`, and the duration since the boot
+    // (excluding the suspended time) for `CLOCK_MONOTONIC`.
+    Absolute(Duration),
+}
+
+// the timer which counts the expirations.
+#[derive(Debug)]
+pub struct TimerFd {
+    fd: OwnedFd,
+}
+
+impl TimerFd {
+    pub fn new(clock: ClockId, flags: TfdFlags) -> Result<Self, SysCallError> {
+        let fd = unsafe {
+            syscall_with_2_args(
+                SysCallNum::timerfd_create as usize,
+                clock.raw() as usize,
+                flags.bits() as usize,
+            )
+        }?;
+        Ok(Self {
+            fd: unsafe { OwnedFd::from_raw(fd as RawFd) },
+        })
+    }
+
+    // arms the timer, it expires at `deadline` first, and then every
+    // `interval` if it is not `None`.
+    //
+    // note that the zero deadline (i.e. `Relative(Duration::ZERO)`) disarms
+    // the timer, and the absolute deadline in the past expires immediately.
+    pub fn set(&self, deadline: Deadline, interval: Option<Duration>) -> Result<(), SysCallError> {
+        let (value, flags) = match deadline {
+            Deadline::Relative(value) => (value, TfdTimerFlags::empty()),
+            Deadline::Absolute(value) => (value, TfdTimerFlags::TFD_TIMER_ABSTIME),
+        };
+        let spec = itimerspec {
+            it_interval: interval.map_or(timespec::default(), timespec::from),
+            it_value: timespec::from(value),
+        };
+        self.settime(&spec, flags)
+    }
+
+    pub fn disarm(&self) -> Result<(), SysCallError> {
+        self.settime(&itimerspec::default(), TfdTimerFlags::empty())
+    }
+
+    // `TFD_TIMER_CANCEL_ON_SET` can be used here with the absolute
+    // `CLOCK_REALTIME` timers.
+    pub fn settime(&self, spec: &itimerspec, flags: TfdTimerFlags) -> Result<(), SysCallError> {
+        unsafe {
+            syscall_with_4_args(
+                SysCallNum::timerfd_settime as usize,
+                self.fd.as_raw() as usize,
+                flags.bits() as usize,
+                spec as *const itimerspec as usize,
+                0,
+            )
+        }
+        .map(|_| ())
+    }
+
+    // returns the remaining time until the next expiration (`it_value`, which is
+    // zero if the timer is disarmed) and the interval (`it_interval`).
+    pub fn gettime(&self) -> Result<itimerspec, SysCallError> {
+        let mut spec = itimerspec::default();
+        unsafe {
+            syscall_with_2_args(
+                SysCallNum::timerfd_gettime as usize,
+                self.fd.as_raw() as usize,
+                &mut spec as *mut itimerspec as usize,
+            )
+        }?;
+        Ok(spec)
+    }
+
+    // returns the number of the expirations since the last `wait`, it blocks
+    // (or returns `EAGAIN` if `TFD_NONBLOCK` is set) until the timer expires.
+    pub fn wait(&self) -> Result<u64, SysCallError> {
+        read_u64(self.fd.as_fd())
+    }
+}
+
+impl_fd_accessors!(TimerFd);
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        errno::Errno,
+        event::{Deadline, EventFd, SignalFd, TimerFd},
+        flags::{ClockId, EfdFlags, SfdFlags, SigmaskHow, Signal, TfdFlags},
+        process::{getpid, gettid, tgkill},
+        signal::{rt_sigprocmask, SI_TKILL},
+        types::{itimerspec, sigset_t, timespec},
+    };
+
+    #[test]
+    fn test_eventfd() {
+        let event = EventFd::new(1, EfdFlags::EFD_CLOEXEC | EfdFlags::EFD_NONBLOCK).unwrap();
+        event.notify(3).unwrap();
+        event.notify(2).unwrap();
+        assert_eq!(event.wait(), Ok(6));
+        assert_eq!(event.wait(), Err(Errno::EAGAIN.into()));
+
+        // the semaphore mode
+        let event = EventFd::new(0, EfdFlags::EFD_SEMAPHORE | EfdFlags::EFD_NONBLOCK).unwrap();
+        event.notify(2).unwrap();
+        assert_eq!(event.wait(), Ok(1));
+        assert_eq!(event.wait(), Ok(1));
+        assert_eq!(event.wait(), Err(Errno::EAGAIN.into()));
+
+        // wakes up another thread
+        let event = EventFd::new(0, EfdFlags::EFD_CLOEXEC).unwrap();
+        std::thread::scope(|scope| {
+            let handle = scope.spawn(|| event.wait());
+            event.notify(1).unwrap();
+            assert_eq!(handle.join().unwrap(), Ok(1));
+        });
+    }
+
+    #[test]
+    fn test_timerfd() {
+        let timer = TimerFd::new(ClockId::CLOCK_MONOTONIC, TfdFlags::TFD_NONBLOCK).unwrap();
+        assert_eq!(timer.wait(), Err(Errno::EAGAIN.into()));
+
+        timer
+            .set(
+                Deadline::Relative(Duration::from_secs(10)),
+                Some(Duration::from_millis(500)),
+            )
+            .unwrap();
+        let spec = timer.gettime().unwrap();
+        assert!(spec.it_value.tv_sec > 0);
+        assert_eq!(
+            spec.it_interval,
+            timespec {
+                tv_sec: 0,
+                tv_nsec: 500_000_000
+            }
+        );
+
+        timer.disarm().unwrap();
+        assert_eq!(timer.gettime(), Ok(itimerspec::default()));
+
+        // the absolute deadline in the past expires immediately
+        timer
+            .set(Deadline::Absolute(Duration::from_nanos(1)), None)
+            .unwrap();
+        assert_eq!(timer.wait(), Ok(1));
+
+        // the periodic timer
+        let timer = TimerFd::new(ClockId::CLOCK_MONOTONIC, TfdFlags::TFD_CLOEXEC).unwrap();
+        timer
+            .set(
+                Deadline::Relative(Duration::from_millis(1)),
+                Some(Duration::from_millis(1)),
+            )
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(timer.wait().unwrap() >= 2);
+    }
+
+    #[test]
+    fn test_signalfd() {
+        let mut mask = sigset_t::empty();
+        mask.add(Signal::SIGWINCH.raw());
+        let old_mask = rt_sigprocmask(SigmaskHow::SIG_BLOCK, Some(&mask)).unwrap();
+
+        let signal_fd =
+            SignalFd::new(&mask, SfdFlags::SFD_NONBLOCK | SfdFlags::SFD_CLOEXEC).unwrap();
+        assert_eq!(signal_fd.read().map(|_| ()), Err(Errno::EAGAIN.into()));
+
+        // the signal is sent to the current thread, which is readable from the signalfd
+        tgkill(getpid(), gettid(), Signal::SIGWINCH).unwrap();
+        let info = signal_fd.read().unwrap();
+        assert_eq!(info.ssi_signo, Signal::SIGWINCH.raw() as u32);
+        assert_eq!(info.ssi_code, SI_TKILL);
+        assert_eq!(info.ssi_pid, getpid() as u32);
+        assert_eq!(signal_fd.read().map(|_| ()), Err(Errno::EAGAIN.into()));
+
+        // the signals out of the mask are not received
+        signal_fd.set_mask(&sigset_t::empty()).unwrap();
+        tgkill(getpid(), gettid(), Signal::SIGWINCH).unwrap();
+        assert_eq!(signal_fd.read().map(|_| ()), Err(Errno::EAGAIN.into()));
+
+        // the pending signal is discarded since `SIGWINCH` is ignored by default
+        rt_sigprocmask(SigmaskHow::SIG_SETMASK, Some(&old_mask)).unwrap();
+    }
+}
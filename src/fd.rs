@@ -0,0 +1,187 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the owned and borrowed file descriptors.
+//
+// `OwnedFd` closes the file descriptor by the raw syscall `close` when it
+// is dropped (instead of the `close` of libc, which is used by
+// `std::os::fd::OwnedFd`), `BorrowedFd` is a file descriptor borrowed
+// from an `OwnedFd` (or other owners), e.g.
+//
+// ```rust
+// let fd: OwnedFd = openat(AT_FDCWD, c"/dev/zero", OpenFlags::O_RDONLY, 0)?;
+// read(fd.as_fd(), &mut buf)?;
+// // `fd` is closed here
+// ```
+//
+// both of them can be converted to and from the types of `std::os::fd`.
+
+use std::{
+    marker::PhantomData,
+    os::fd::{AsRawFd, FromRawFd, IntoRawFd, RawFd},
+};
+
+use crate::{call, number::SysCallNum};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct OwnedFd {
+    fd: RawFd,
+}
+
+impl OwnedFd {
+    // the caller must ensure that `fd` is an open file descriptor and
+    // it is not owned by others.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn from_raw(fd: RawFd) -> Self {
+        debug_assert!(fd >= 0);
+        Self { fd }
+    }
+
+    // takes the ownership of the file descriptor, i.e. it will
+    // not be closed automatically.
+    pub fn into_raw(self) -> RawFd {
+        let fd = self.fd;
+        std::mem::forget(self);
+        fd
+    }
+
+    pub fn as_raw(&self) -> RawFd {
+        self.fd
+    }
+
+    pub fn as_fd(&self) -> BorrowedFd<'_> {
+        BorrowedFd {
+            fd: self.fd,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl Drop for OwnedFd {
+    fn drop(&mut self) {
+        // the error is ignored, the same as `std::os::fd::OwnedFd`, because the
+        // file descriptor is released even if `close` fails.
+        let _ = unsafe { call::syscall_with_1_arg(SysCallNum::close as usize, self.fd as usize) };
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BorrowedFd<'a> {
+    fd: RawFd,
+    _marker: PhantomData<&'a OwnedFd>,
+}
+
+impl BorrowedFd<'_> {
+    // the caller must ensure that `fd` stays open for the lifetime of
+    // the returned value. unlike `OwnedFd::from_raw`, `fd` may be a special
+    // value such as `AT_FDCWD`.
+    #[allow(clippy::missing_safety_doc)]
+    pub const unsafe fn borrow_raw(fd: RawFd) -> Self {
+        Self {
+            fd,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_raw(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl AsRawFd for OwnedFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl IntoRawFd for OwnedFd {
+    fn into_raw_fd(self) -> RawFd {
+        self.into_raw()
+    }
+}
+
+impl FromRawFd for OwnedFd {
+    unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        Self::from_raw(fd)
+    }
+}
+
+impl AsRawFd for BorrowedFd<'_> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl From<OwnedFd> for std::os::fd::OwnedFd {
+    fn from(fd: OwnedFd) -> Self {
+        unsafe { std::os::fd::OwnedFd::from_raw_fd(fd.into_raw()) }
+    }
+}
+
+impl From<std::os::fd::OwnedFd> for OwnedFd {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { OwnedFd::from_raw(fd.into_raw_fd()) }
+    }
+}
+
+impl<'a> From<BorrowedFd<'a>> for std::os::fd::BorrowedFd<'a> {
+    fn from(fd: BorrowedFd<'a>) -> Self {
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(fd.fd) }
+    }
+}
+
+impl<'a> From<std::os::fd::BorrowedFd<'a>> for BorrowedFd<'a> {
+    fn from(fd: std::os::fd::BorrowedFd<'a>) -> Self {
+        unsafe { BorrowedFd::borrow_raw(fd.as_raw_fd()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use crate::{
+        fd::OwnedFd,
+        flags::OpenFlags,
+        io::{pipe2, read, write},
+    };
+
+    #[test]
+    fn test_drop_closes_fd() {
+        let (read_fd, write_fd) = pipe2(OpenFlags::empty()).unwrap();
+        assert_eq!(write(write_fd.as_fd(), b"abc"), Ok(3));
+
+        // the read end gets EOF after the write end is closed
+        drop(write_fd);
+
+        let mut buf = [0u8; 8];
+        assert_eq!(read(read_fd.as_fd(), &mut buf), Ok(3));
+        assert_eq!(read(read_fd.as_fd(), &mut buf), Ok(0));
+    }
+
+    #[test]
+    fn test_into_and_from_raw() {
+        let (read_fd, write_fd) = pipe2(OpenFlags::empty()).unwrap();
+
+        // the fd is not closed by `into_raw`
+        let raw_fd = write_fd.into_raw();
+        let write_fd = unsafe { OwnedFd::from_raw(raw_fd) };
+        assert_eq!(write_fd.as_raw(), raw_fd);
+        assert_eq!(write(write_fd.as_fd(), b"abc"), Ok(3));
+
+        // converts to and from `std::os::fd::OwnedFd`
+        let mut file = std::fs::File::from(std::os::fd::OwnedFd::from(write_fd));
+        file.write_all(b"def").unwrap();
+        let write_fd = OwnedFd::from(std::os::fd::OwnedFd::from(file));
+        assert_eq!(write_fd.as_raw(), raw_fd);
+        drop(write_fd);
+
+        let mut file = std::fs::File::from(std::os::fd::OwnedFd::from(read_fd));
+        let mut text = String::new();
+        file.read_to_string(&mut text).unwrap();
+        assert_eq!(text, "abcdef");
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the kernel constants, e.g.
+//
+// ```rust
+// let flags = OpenFlags::O_RDWR | OpenFlags::O_CREAT | OpenFlags::O_CLOEXEC;
+// assert!(flags.contains(OpenFlags::O_CREAT));
+// assert_eq!(format!("{:?}", flags), "OpenFlags(O_RDWR | O_CREAT | O_CLOEXEC)");
+// ```
+//
+// the bit flags are defined by the macro `bitflags!`, and the values which
+// can not be combined (i.e. the signal numbers, address families, socket
+// types and seek whences) are defined as enums by the macro `value_enum!`,
+// see 'src/macros.rs'.
+//
+// the constants are defined per architecture in 'src/arch/{x86_64,aarch64,riscv64}/flags.rs',
+// which are generated by 'examples/gen_flags.rs' (most of the values are the
+// same, i.e. the values from 'include/uapi/asm-generic'). this module
+// re-exports the constants of the current target, and the constants of the
+// other architectures are available as `arch::<arch>::flags`, e.g. for
+// decoding the arguments of the syscalls of other architectures.
+
+#[cfg(target_arch = "x86_64")]
+pub use crate::arch::x86_64::flags::*;
+
+#[cfg(target_arch = "aarch64")]
+pub use crate::arch::aarch64::flags::*;
+
+#[cfg(target_arch = "riscv64")]
+pub use crate::arch::riscv64::flags::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::flags::{
+        Advice, AtFlags, CloneFlags, EpollEvents, MapFlags, OpenFlags, ProtFlags, Signal,
+        SocketType, Whence,
+    };
+
+    #[test]
+    fn test_bitflags() {
+        let flags = OpenFlags::O_RDWR | OpenFlags::O_CREAT;
+        assert_eq!(flags.bits(), 0o102);
+        assert!(flags.contains(OpenFlags::O_CREAT));
+        assert!(!flags.contains(OpenFlags::O_CREAT | OpenFlags::O_EXCL));
+        assert!(flags.intersects(OpenFlags::O_CREAT | OpenFlags::O_EXCL));
+
+        let mut flags = flags;
+        flags |= OpenFlags::O_CLOEXEC;
+        flags.remove(OpenFlags::O_CREAT);
+        assert_eq!(flags, OpenFlags::O_RDWR | OpenFlags::O_CLOEXEC);
+        assert_eq!(flags & OpenFlags::O_CLOEXEC, OpenFlags::O_CLOEXEC);
+        assert!((flags & !OpenFlags::O_CLOEXEC & !OpenFlags::O_RDWR).is_empty());
+
+        assert_eq!(OpenFlags::O_CLOEXEC.bits(), 0x80000);
+        assert_eq!(OpenFlags::default(), OpenFlags::empty());
+        assert_eq!(EpollEvents::EPOLLET.bits(), 0x80000000);
+    }
+
+    #[test]
+    fn test_bitflags_debug() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                OpenFlags::O_RDWR | OpenFlags::O_CREAT | OpenFlags::O_CLOEXEC
+            ),
+            "OpenFlags(O_RDWR | O_CREAT | O_CLOEXEC)"
+        );
+        // the flags with value 0 are never printed
+        assert_eq!(format!("{:?}", OpenFlags::empty()), "OpenFlags(empty)");
+        assert_eq!(format!("{:?}", OpenFlags::O_RDONLY), "OpenFlags(empty)");
+        assert_eq!(
+            format!("{:?}", ProtFlags::PROT_NONE | ProtFlags::PROT_READ),
+            "ProtFlags(PROT_READ)"
+        );
+
+        // the composed flag
+        assert_eq!(format!("{:?}", OpenFlags::O_SYNC), "OpenFlags(O_SYNC)");
+        assert_eq!(format!("{:?}", OpenFlags::O_DSYNC), "OpenFlags(O_DSYNC)");
+
+        // the unknown bits
+        assert_eq!(
+            format!("{:?}", ProtFlags::PROT_READ | ProtFlags::from_bits(0x100)),
+            "ProtFlags(PROT_READ | 0x100)"
+        );
+        assert_eq!(
+            format!("{:?}", MapFlags::MAP_PRIVATE | MapFlags::MAP_ANONYMOUS),
+            "MapFlags(MAP_PRIVATE | MAP_ANONYMOUS)"
+        );
+        assert_eq!(
+            format!("{:?}", MapFlags::MAP_SHARED | MapFlags::MAP_PRIVATE),
+            "MapFlags(MAP_SHARED_VALIDATE)"
+        );
+
+        // SIGCHLD = 17
+        assert_eq!(
+            format!("{:?}", CloneFlags::CLONE_VM | CloneFlags::from_bits(17)),
+            "CloneFlags(CLONE_VM | 0x11)"
+        );
+        assert_eq!(format!("{:?}", AtFlags::empty()), "AtFlags(empty)");
+        assert_eq!(
+            format!("{:?}", AtFlags::AT_STATX_SYNC_AS_STAT),
+            "AtFlags(empty)"
+        );
+    }
+
+    #[test]
+    fn test_value_enum() {
+        assert_eq!(Signal::SIGKILL.raw(), 9);
+        assert_eq!(Signal::from_raw(17), Some(Signal::SIGCHLD));
+        assert_eq!(Signal::from_raw(Signal::SIGRTMIN), None);
+        assert_eq!(format!("{:?}", Signal::SIGTERM), "SIGTERM");
+        assert_eq!(Signal::SIGSYS.name(), "SIGSYS");
+
+        assert_eq!(SocketType::SOCK_DGRAM as u32, 2);
+        assert_eq!(Whence::from_raw(2), Some(Whence::SEEK_END));
+        assert_eq!(Advice::from_raw(4), Some(Advice::MADV_DONTNEED));
+        assert_eq!(Advice::from_raw(5), None);
+    }
+
+    #[test]
+    fn test_arch_specific_flags() {
+        use crate::arch::{aarch64, riscv64, x86_64};
+
+        assert_eq!(x86_64::flags::OpenFlags::O_DIRECTORY.bits(), 0o200000);
+        assert_eq!(aarch64::flags::OpenFlags::O_DIRECTORY.bits(), 0o40000);
+        assert_eq!(riscv64::flags::OpenFlags::O_DIRECTORY.bits(), 0o200000);
+
+        // the same bits have different names on different architectures
+        assert_eq!(
+            format!("{:?}", x86_64::flags::OpenFlags::from_bits(0o40000)),
+            "OpenFlags(O_DIRECT)"
+        );
+        assert_eq!(
+            format!("{:?}", aarch64::flags::OpenFlags::from_bits(0o40000)),
+            "OpenFlags(O_DIRECTORY)"
+        );
+
+        // `MAP_32BIT` is only available on x86_64, and `SA_RESTORER` is
+        // not available on riscv64
+        assert_eq!(x86_64::flags::MapFlags::MAP_32BIT.bits(), 0x40);
+        assert_eq!(
+            format!("{:?}", aarch64::flags::MapFlags::from_bits(0x40)),
+            "MapFlags(0x40)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                riscv64::flags::SigactionFlags::from_bits(0x04000000)
+            ),
+            "SigactionFlags(0x4000000)"
+        );
+    }
+}
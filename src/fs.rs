@@ -0,0 +1,110 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the safe wrappers of the file system syscalls, e.g.
+//
+// ```rust
+// let fd: OwnedFd = openat(AT_FDCWD, c"/dev/zero", OpenFlags::O_RDONLY, 0)?;
+// ```
+//
+// see also the module `io` for reading and writing the file descriptors.
+
+use std::{ffi::CStr, os::fd::RawFd};
+
+use crate::{
+    errno::SysCallError,
+    fd::{BorrowedFd, OwnedFd},
+    flags::OpenFlags,
+    number::SysCallNum,
+    typed::{syscall_with_1_arg, syscall_with_4_args},
+};
+
+// the special value for the argument `dirfd` of the `*at` syscalls, which
+// indicates the path is relative to the current working directory.
+pub const AT_FDCWD: BorrowedFd<'static> = unsafe { BorrowedFd::borrow_raw(-100) };
+
+// `flags` is the combination of the access mode (`O_RDONLY`, `O_WRONLY` or `O_RDWR`)
+// and the file creation and status flags (e.g. `O_CREAT` and `O_CLOEXEC`), `mode`
+// is the permission of the new file, it is ignored if neither `O_CREAT` nor
+// `O_TMPFILE` is specified.
+pub fn openat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: OpenFlags,
+    mode: u32,
+) -> Result<OwnedFd, SysCallError> {
+    unsafe {
+        syscall_with_4_args(
+            SysCallNum::openat as usize,
+            dirfd.as_raw() as usize,
+            path.as_ptr() as usize,
+            flags.bits() as usize,
+            mode as usize,
+        )
+    }
+    .map(|fd| unsafe { OwnedFd::from_raw(fd as RawFd) })
+}
+
+// flushes the data and metadata of the file to the storage device.
+pub fn fsync(fd: BorrowedFd<'_>) -> Result<(), SysCallError> {
+    unsafe { syscall_with_1_arg(SysCallNum::fsync as usize, fd.as_raw() as usize) }.map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use crate::{
+        errno::Errno,
+        flags::{OpenFlags, Whence},
+        fs::{fsync, openat, AT_FDCWD},
+        io::{close, lseek, pread64, pwrite64, read},
+    };
+
+    #[test]
+    fn test_openat() {
+        let result0 = openat(
+            AT_FDCWD,
+            c"/this/file/should/not/exist",
+            OpenFlags::O_RDONLY,
+            0,
+        );
+        assert_eq!(result0, Err(Errno::ENOENT.into()));
+
+        let fd = openat(AT_FDCWD, c"/dev/zero", OpenFlags::O_RDONLY, 0).unwrap();
+        let mut buf = [1u8; 8];
+        assert_eq!(read(fd.as_fd(), &mut buf), Ok(8));
+        assert_eq!(buf, [0u8; 8]);
+
+        // '/dev/zero' does not support synchronization
+        assert_eq!(fsync(fd.as_fd()), Err(Errno::EINVAL.into()));
+    }
+
+    #[test]
+    fn test_pread_pwrite() {
+        let path = std::env::temp_dir().join(format!("syscall-util-fs-{}", std::process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let flags =
+            OpenFlags::O_RDWR | OpenFlags::O_CREAT | OpenFlags::O_TRUNC | OpenFlags::O_CLOEXEC;
+        let fd = openat(AT_FDCWD, &c_path, flags, 0o600).unwrap();
+
+        assert_eq!(pwrite64(fd.as_fd(), b"hello world", 0), Ok(11));
+        assert_eq!(pwrite64(fd.as_fd(), b"W", 6), Ok(1));
+        assert_eq!(fsync(fd.as_fd()), Ok(()));
+
+        // the file offset is not changed by `pread64` and `pwrite64`
+        assert_eq!(lseek(fd.as_fd(), 0, Whence::SEEK_CUR), Ok(0));
+        assert_eq!(lseek(fd.as_fd(), 0, Whence::SEEK_END), Ok(11));
+
+        let mut buf = [0u8; 5];
+        assert_eq!(pread64(fd.as_fd(), &mut buf, 6), Ok(5));
+        assert_eq!(&buf, b"World");
+
+        assert_eq!(close(fd), Ok(()));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the safe wrappers of the file descriptor I/O syscalls, e.g.
+//
+// ```rust
+// let (read_fd, write_fd) = pipe2(OpenFlags::O_CLOEXEC)?;
+// write(write_fd.as_fd(), b"hello")?;
+//
+// let mut buf = [0u8; 8];
+// let len = read(read_fd.as_fd(), &mut buf)?;
+// ```
+//
+// these functions are built on the module `typed`, the error is
+// `SysCallError`, which can be compared with `Errno` directly.
+
+use std::{
+    io::{IoSlice, IoSliceMut},
+    os::fd::RawFd,
+};
+
+use crate::{
+    errno::SysCallError,
+    fd::{BorrowedFd, OwnedFd},
+    flags::{OpenFlags, Whence},
+    number::SysCallNum,
+    typed::{syscall_with_1_arg, syscall_with_2_args, syscall_with_3_args, syscall_with_4_args},
+};
+
+pub fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> Result<usize, SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::read as usize,
+            fd.as_raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+        )
+    }
+}
+
+pub fn write(fd: BorrowedFd<'_>, buf: &[u8]) -> Result<usize, SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::write as usize,
+            fd.as_raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+        )
+    }
+}
+
+// reads from the specified offset, the file offset is not changed.
+pub fn pread64(fd: BorrowedFd<'_>, buf: &mut [u8], offset: u64) -> Result<usize, SysCallError> {
+    unsafe {
+        syscall_with_4_args(
+            SysCallNum::pread64 as usize,
+            fd.as_raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            offset as usize,
+        )
+    }
+}
+
+// writes to the specified offset, the file offset is not changed.
+pub fn pwrite64(fd: BorrowedFd<'_>, buf: &[u8], offset: u64) -> Result<usize, SysCallError> {
+    unsafe {
+        syscall_with_4_args(
+            SysCallNum::pwrite64 as usize,
+            fd.as_raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+            offset as usize,
+        )
+    }
+}
+
+// `IoSliceMut` and `IoSlice` are ABI compatible with `struct iovec` on Linux.
+pub fn readv(fd: BorrowedFd<'_>, bufs: &mut [IoSliceMut<'_>]) -> Result<usize, SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::readv as usize,
+            fd.as_raw() as usize,
+            bufs.as_mut_ptr() as usize,
+            bufs.len(),
+        )
+    }
+}
+
+pub fn writev(fd: BorrowedFd<'_>, bufs: &[IoSlice<'_>]) -> Result<usize, SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::writev as usize,
+            fd.as_raw() as usize,
+            bufs.as_ptr() as usize,
+            bufs.len(),
+        )
+    }
+}
+
+// returns the resulting offset from the beginning of the file.
+//
+// the offset is unsigned for the files with the flag `FMODE_UNSIGNED_OFFSET`
+// (e.g. '/dev/mem' and '/proc/PID/mem'), so the offsets above `i64::MAX` are
+// returned as they are, except the top 4095 values which can not be told
+// apart from the error numbers.
+pub fn lseek(fd: BorrowedFd<'_>, offset: i64, whence: Whence) -> Result<u64, SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::lseek as usize,
+            fd.as_raw() as usize,
+            offset as usize,
+            whence.raw() as usize,
+        )
+    }
+    .map(|offset| offset as u64)
+}
+
+// closes the file descriptor and reports the error, which is ignored
+// when an `OwnedFd` is dropped.
+//
+// note that the file descriptor is released even if `close` fails
+// (e.g. with `EINTR` or `EIO`), so do NOT retry on failure.
+pub fn close(fd: OwnedFd) -> Result<(), SysCallError> {
+    unsafe { syscall_with_1_arg(SysCallNum::close as usize, fd.into_raw() as usize) }.map(|_| ())
+}
+
+// duplicates `old_fd` to the file descriptor number `new_fd` and returns it,
+// `flags` can be 0 or `O_CLOEXEC`.
+//
+// `new_fd` is a raw number rather than an `OwnedFd`, so that the standard
+// streams 0, 1 and 2 can be redirected (e.g. between `fork` and `execve`).
+// the original file of `new_fd` (if any) is closed silently, and `EINVAL` is
+// returned if `old_fd` equals to `new_fd`.
+pub fn dup3(
+    old_fd: BorrowedFd<'_>,
+    new_fd: RawFd,
+    flags: OpenFlags,
+) -> Result<RawFd, SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::dup3 as usize,
+            old_fd.as_raw() as usize,
+            new_fd as usize,
+            flags.bits() as usize,
+        )
+    }
+    .map(|fd| fd as RawFd)
+}
+
+// `flags` is the combination of `O_CLOEXEC`, `O_DIRECT` and `O_NONBLOCK`.
+//
+// returns `(read end, write end)`.
+pub fn pipe2(flags: OpenFlags) -> Result<(OwnedFd, OwnedFd), SysCallError> {
+    let mut fds: [RawFd; 2] = [-1, -1];
+    unsafe {
+        syscall_with_2_args(
+            SysCallNum::pipe2 as usize,
+            fds.as_mut_ptr() as usize,
+            flags.bits() as usize,
+        )
+    }?;
+    Ok(unsafe { (OwnedFd::from_raw(fds[0]), OwnedFd::from_raw(fds[1])) })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{IoSlice, IoSliceMut};
+
+    use crate::{
+        errno::Errno,
+        flags::{OpenFlags, Whence},
+        io::{close, dup3, lseek, pipe2, read, readv, write, writev},
+    };
+
+    #[test]
+    fn test_pipe_read_write() {
+        let (read_fd, write_fd) = pipe2(OpenFlags::empty()).unwrap();
+
+        assert_eq!(write(write_fd.as_fd(), b"hello"), Ok(5));
+
+        let mut buf = [0u8; 8];
+        assert_eq!(read(read_fd.as_fd(), &mut buf), Ok(5));
+        assert_eq!(&buf[..5], b"hello");
+
+        // pipe is not seekable
+        assert_eq!(
+            lseek(read_fd.as_fd(), 0, Whence::SEEK_SET),
+            Err(Errno::ESPIPE.into())
+        );
+
+        let bufs = [IoSlice::new(b"foo"), IoSlice::new(b"bar")];
+        assert_eq!(writev(write_fd.as_fd(), &bufs), Ok(6));
+
+        let mut buf0 = [0u8; 2];
+        let mut buf1 = [0u8; 4];
+        let mut bufs = [IoSliceMut::new(&mut buf0), IoSliceMut::new(&mut buf1)];
+        assert_eq!(readv(read_fd.as_fd(), &mut bufs), Ok(6));
+        assert_eq!(&buf0, b"fo");
+        assert_eq!(&buf1, b"obar");
+
+        assert_eq!(close(read_fd), Ok(()));
+        assert_eq!(close(write_fd), Ok(()));
+    }
+
+    #[test]
+    fn test_dup3() {
+        let (read_fd, write_fd) = pipe2(OpenFlags::empty()).unwrap();
+
+        let (_other_fd, new_fd) = pipe2(OpenFlags::empty()).unwrap();
+        assert_eq!(
+            dup3(write_fd.as_fd(), new_fd.as_raw(), OpenFlags::O_CLOEXEC),
+            Ok(new_fd.as_raw())
+        );
+
+        // writes through the duplicated fd
+        assert_eq!(write(new_fd.as_fd(), b"abc"), Ok(3));
+        let mut buf = [0u8; 3];
+        assert_eq!(read(read_fd.as_fd(), &mut buf), Ok(3));
+        assert_eq!(&buf, b"abc");
+
+        // the same fd
+        assert_eq!(
+            dup3(write_fd.as_fd(), write_fd.as_raw(), OpenFlags::empty()),
+            Err(Errno::EINVAL.into())
+        );
+    }
+}
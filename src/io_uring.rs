@@ -0,0 +1,956 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the asynchronous I/O interface, e.g.
+//
+// ```rust
+// let mut ring = IoUring::new(8)?;
+//
+// let mut buf = [0u8; 64];
+// let sqe = io_uring_sqe::read(fd.as_raw(), buf.as_mut_ptr(), buf.len() as u32, 0)
+//     .with_user_data(1);
+// unsafe { ring.push(&sqe) }?;
+// ring.submit_and_wait(1)?;
+//
+// let cqe = ring.pop().unwrap();
+// assert_eq!(cqe.user_data, 1);
+// let len = cqe.result()?;
+// ```
+//
+// the submission queue (SQ) and the completion queue (CQ) are the ring
+// buffers which are shared with the kernel by `mmap`. the application
+// writes the entries and then advances the SQ tail, the kernel consumes
+// the entries and advances the SQ head. for the CQ, the kernel is the
+// producer and the application is the consumer.
+//
+// the head and the tail which are written by the other side are loaded
+// with the acquire ordering, and the ones which are written by this side
+// are stored with the release ordering, so that the entries are visible
+// before the index is updated.
+//
+// ref:
+// - 'include/uapi/linux/io_uring.h'
+// - https://kernel.dk/io_uring.pdf
+
+use std::{
+    ffi::{c_char, c_void},
+    mem::size_of,
+    os::fd::{AsRawFd, RawFd},
+    sync::atomic::{fence, AtomicU32, Ordering},
+};
+
+use crate::{
+    errno::{Errno, SysCallError},
+    fd::{BorrowedFd, OwnedFd},
+    flags::{
+        IoUringEnterFlags, IoUringFeatures, IoUringOp, IoUringRegisterOp, IoUringSetupFlags,
+        MapFlags, OpenFlags, ProtFlags, SocketFlags, SqeFlags, TimeoutFlags,
+    },
+    mm::Mapping,
+    number::SysCallNum,
+    signal::SIGSET_SIZE,
+    typed::{syscall_with_2_args, syscall_with_4_args, syscall_with_6_args},
+    types::{
+        io_uring_cqe, io_uring_params, io_uring_probe, io_uring_probe_op, io_uring_sqe, iovec,
+        timespec,
+    },
+};
+
+// the offsets of `mmap` for the rings and the submission queue entries.
+const IORING_OFF_SQ_RING: u64 = 0;
+const IORING_OFF_CQ_RING: u64 = 0x8000000;
+const IORING_OFF_SQES: u64 = 0x10000000;
+
+// the flags of the SQ ring, i.e. the field at `io_sqring_offsets.flags`.
+const IORING_SQ_NEED_WAKEUP: u32 = 1 << 0;
+
+// the flag of `io_uring_probe_op.flags`.
+pub const IO_URING_OP_SUPPORTED: u16 = 1 << 0;
+
+// the flag of the fsync entry.
+pub const IORING_FSYNC_DATASYNC: u32 = 1 << 0;
+
+// the number of the operations which can be probed, the opcode is `u8`.
+const PROBE_OPS_LEN: usize = 256;
+
+#[derive(Debug)]
+pub struct IoUring {
+    fd: OwnedFd,
+    params: io_uring_params,
+
+    // the SQ ring also contains the CQ ring if the kernel supports
+    // `IORING_FEAT_SINGLE_MMAP` (since Linux 5.4).
+    sq_ring: Mapping,
+    cq_ring: Option<Mapping>,
+    sqes: Mapping,
+
+    sq_mask: u32,
+    cq_mask: u32,
+
+    // the local SQ tail, the entries between the shared tail and this
+    // tail are pushed but not submitted.
+    sq_tail: u32,
+}
+
+impl IoUring {
+    pub fn new(entries: u32) -> Result<Self, SysCallError> {
+        Self::with_params(entries, io_uring_params::default())
+    }
+
+    // creates the ring with the flags (and the related fields such as
+    // `cq_entries` and `sq_thread_idle`) in `params`.
+    //
+    // the flags which change the layout of the rings or the entries
+    // (i.e. `IORING_SETUP_SQE128`, `IORING_SETUP_CQE32`, `IORING_SETUP_NO_MMAP`,
+    // `IORING_SETUP_NO_SQARRAY` and `IORING_SETUP_REGISTERED_FD_ONLY`) are
+    // not supported, `EINVAL` is returned.
+    pub fn with_params(entries: u32, mut params: io_uring_params) -> Result<Self, SysCallError> {
+        if IoUringSetupFlags::from_bits(params.flags).intersects(
+            IoUringSetupFlags::IORING_SETUP_SQE128
+                | IoUringSetupFlags::IORING_SETUP_CQE32
+                | IoUringSetupFlags::IORING_SETUP_NO_MMAP
+                | IoUringSetupFlags::IORING_SETUP_NO_SQARRAY
+                | IoUringSetupFlags::IORING_SETUP_REGISTERED_FD_ONLY,
+        ) {
+            return Err(Errno::EINVAL.into());
+        }
+
+        let fd = unsafe {
+            syscall_with_2_args(
+                SysCallNum::io_uring_setup as usize,
+                entries as usize,
+                &mut params as *mut io_uring_params as usize,
+            )
+        }?;
+        let fd = unsafe { OwnedFd::from_raw(fd as RawFd) };
+
+        let sq_ring_len =
+            params.sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+        let cq_ring_len =
+            params.cq_off.cqes as usize + params.cq_entries as usize * size_of::<io_uring_cqe>();
+        let single_mmap = IoUringFeatures::from_bits(params.features)
+            .contains(IoUringFeatures::IORING_FEAT_SINGLE_MMAP);
+
+        let map = |offset: u64, len: usize| {
+            Mapping::file(
+                fd.as_fd(),
+                offset,
+                len,
+                ProtFlags::PROT_READ | ProtFlags::PROT_WRITE,
+                MapFlags::MAP_SHARED | MapFlags::MAP_POPULATE,
+            )
+        };
+
+        let (sq_ring, cq_ring) = if single_mmap {
+            (map(IORING_OFF_SQ_RING, sq_ring_len.max(cq_ring_len))?, None)
+        } else {
+            (
+                map(IORING_OFF_SQ_RING, sq_ring_len)?,
+                Some(map(IORING_OFF_CQ_RING, cq_ring_len)?),
+            )
+        };
+        let sqes = map(
+            IORING_OFF_SQES,
+            params.sq_entries as usize * size_of::<io_uring_sqe>(),
+        )?;
+
+        let mut ring = Self {
+            fd,
+            params,
+            sq_ring,
+            cq_ring,
+            sqes,
+            sq_mask: 0,
+            cq_mask: 0,
+            sq_tail: 0,
+        };
+
+        ring.sq_mask = ring
+            .sq_field(params.sq_off.ring_mask)
+            .load(Ordering::Relaxed);
+        ring.cq_mask = ring
+            .cq_field(params.cq_off.ring_mask)
+            .load(Ordering::Relaxed);
+        ring.sq_tail = ring.sq_field(params.sq_off.tail).load(Ordering::Relaxed);
+
+        // the SQ array (the indices of the entries) is filled once, i.e. the
+        // n-th slot of the ring always refers to the n-th entry.
+        let array = (ring.sq_ring.addr() + params.sq_off.array as usize) as *mut u32;
+        for index in 0..params.sq_entries {
+            unsafe { array.add(index as usize).write(index) };
+        }
+
+        Ok(ring)
+    }
+
+    pub fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+
+    // the parameters which are filled by the kernel, e.g. the actual
+    // number of entries and the features.
+    pub fn params(&self) -> &io_uring_params {
+        &self.params
+    }
+
+    pub fn features(&self) -> IoUringFeatures {
+        IoUringFeatures::from_bits(self.params.features)
+    }
+
+    // the field of the SQ ring at `offset`, all fields are `u32`.
+    fn sq_field(&self, offset: u32) -> &AtomicU32 {
+        unsafe { &*((self.sq_ring.addr() + offset as usize) as *const AtomicU32) }
+    }
+
+    fn cq_ring_addr(&self) -> usize {
+        self.cq_ring.as_ref().unwrap_or(&self.sq_ring).addr()
+    }
+
+    fn cq_field(&self, offset: u32) -> &AtomicU32 {
+        unsafe { &*((self.cq_ring_addr() + offset as usize) as *const AtomicU32) }
+    }
+
+    // the number of the entries which are pushed but not consumed by the kernel.
+    fn sq_pending(&self) -> u32 {
+        let head = self
+            .sq_field(self.params.sq_off.head)
+            .load(Ordering::Acquire);
+        self.sq_tail.wrapping_sub(head)
+    }
+
+    // copies the entry into the SQ, it is not visible to the kernel until
+    // `submit` or `submit_and_wait` is called. `EBUSY` is returned if the
+    // SQ is full.
+    //
+    // the caller must ensure that the memory referenced by the entry
+    // (e.g. the buffer of `read` and the path of `openat`) stays valid
+    // until the entry is completed.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn push(&mut self, sqe: &io_uring_sqe) -> Result<(), SysCallError> {
+        if self.sq_pending() >= self.params.sq_entries {
+            return Err(Errno::EBUSY.into());
+        }
+
+        let index = (self.sq_tail & self.sq_mask) as usize;
+        let entries = self.sqes.as_mut_ptr() as *mut io_uring_sqe;
+        entries.add(index).write(*sqe);
+        self.sq_tail = self.sq_tail.wrapping_add(1);
+        Ok(())
+    }
+
+    // submits the pushed entries, returns the number of the entries
+    // consumed by the kernel.
+    pub fn submit(&mut self) -> Result<usize, SysCallError> {
+        self.submit_and_wait(0)
+    }
+
+    // submits the pushed entries and waits until at least `want` entries
+    // are completed.
+    pub fn submit_and_wait(&mut self, want: u32) -> Result<usize, SysCallError> {
+        self.sq_field(self.params.sq_off.tail)
+            .store(self.sq_tail, Ordering::Release);
+
+        let mut flags = IoUringEnterFlags::empty();
+        if want > 0 {
+            flags |= IoUringEnterFlags::IORING_ENTER_GETEVENTS;
+        }
+
+        let sqpoll = IoUringSetupFlags::from_bits(self.params.flags)
+            .contains(IoUringSetupFlags::IORING_SETUP_SQPOLL);
+        if sqpoll {
+            // the entries are consumed by the kernel thread, the syscall is
+            // only required to wake up the idle thread or to wait. the fence
+            // orders the store of the tail before the load of the flags.
+            fence(Ordering::SeqCst);
+            let sq_flags = self
+                .sq_field(self.params.sq_off.flags)
+                .load(Ordering::Relaxed);
+            if sq_flags & IORING_SQ_NEED_WAKEUP != 0 {
+                flags |= IoUringEnterFlags::IORING_ENTER_SQ_WAKEUP;
+            }
+        }
+
+        let to_submit = self.sq_pending();
+        if flags.is_empty() && (sqpoll || to_submit == 0) {
+            return Ok(to_submit as usize);
+        }
+
+        self.enter(to_submit, want, flags)
+    }
+
+    fn enter(
+        &self,
+        to_submit: u32,
+        min_complete: u32,
+        flags: IoUringEnterFlags,
+    ) -> Result<usize, SysCallError> {
+        unsafe {
+            syscall_with_6_args(
+                SysCallNum::io_uring_enter as usize,
+                self.fd.as_raw() as usize,
+                to_submit as usize,
+                min_complete as usize,
+                flags.bits() as usize,
+                0,
+                SIGSET_SIZE,
+            )
+        }
+    }
+
+    // takes the next completion entry, returns `None` if the CQ is empty.
+    pub fn pop(&mut self) -> Option<io_uring_cqe> {
+        let head_field = self.cq_field(self.params.cq_off.head);
+        let head = head_field.load(Ordering::Relaxed);
+        let tail = self
+            .cq_field(self.params.cq_off.tail)
+            .load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+
+        let cqes = (self.cq_ring_addr() + self.params.cq_off.cqes as usize) as *const io_uring_cqe;
+        let cqe = unsafe { cqes.add((head & self.cq_mask) as usize).read() };
+
+        // releases the slot to the kernel after the entry is read
+        head_field.store(head.wrapping_add(1), Ordering::Release);
+        Some(cqe)
+    }
+
+    // the iterator over the completion entries which are available now.
+    pub fn completions(&mut self) -> Completions<'_> {
+        Completions { ring: self }
+    }
+
+    // registers the files, so that the entries with the flag
+    // `IOSQE_FIXED_FILE` can refer to them by the index (i.e. `io_uring_sqe.fd`
+    // is the index of `fds`), which saves the cost of looking up the file
+    // for each operation. `-1` in `fds` is an empty slot.
+    pub fn register_files(&self, fds: &[RawFd]) -> Result<(), SysCallError> {
+        self.register(
+            IoUringRegisterOp::IORING_REGISTER_FILES,
+            fds.as_ptr() as usize,
+            fds.len() as u32,
+        )
+        .map(|_| ())
+    }
+
+    pub fn unregister_files(&self) -> Result<(), SysCallError> {
+        self.register(IoUringRegisterOp::IORING_UNREGISTER_FILES, 0, 0)
+            .map(|_| ())
+    }
+
+    // registers the buffers for `read_fixed` and `write_fixed`, the pages
+    // are pinned by the kernel, and the entries refer to the buffers by
+    // the index of `bufs`.
+    //
+    // the caller must ensure that the buffers stay valid until they
+    // are unregistered (or the ring is dropped).
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn register_buffers(&self, bufs: &[iovec]) -> Result<(), SysCallError> {
+        self.register(
+            IoUringRegisterOp::IORING_REGISTER_BUFFERS,
+            bufs.as_ptr() as usize,
+            bufs.len() as u32,
+        )
+        .map(|_| ())
+    }
+
+    pub fn unregister_buffers(&self) -> Result<(), SysCallError> {
+        self.register(IoUringRegisterOp::IORING_UNREGISTER_BUFFERS, 0, 0)
+            .map(|_| ())
+    }
+
+    // queries the operations which are supported by the kernel (since Linux 5.6).
+    pub fn probe(&self) -> Result<Probe, SysCallError> {
+        #[repr(C)]
+        struct ProbeBuf {
+            header: io_uring_probe,
+            ops: [io_uring_probe_op; PROBE_OPS_LEN],
+        }
+
+        let mut buf = ProbeBuf {
+            header: io_uring_probe::default(),
+            ops: [io_uring_probe_op::default(); PROBE_OPS_LEN],
+        };
+        self.register(
+            IoUringRegisterOp::IORING_REGISTER_PROBE,
+            &mut buf as *mut ProbeBuf as usize,
+            PROBE_OPS_LEN as u32,
+        )?;
+
+        Ok(Probe {
+            last_op: buf.header.last_op,
+            ops: buf.ops[..buf.header.ops_len as usize].to_vec(),
+        })
+    }
+
+    fn register(
+        &self,
+        op: IoUringRegisterOp,
+        arg: usize,
+        nr_args: u32,
+    ) -> Result<usize, SysCallError> {
+        unsafe {
+            syscall_with_4_args(
+                SysCallNum::io_uring_register as usize,
+                self.fd.as_raw() as usize,
+                op.raw() as usize,
+                arg,
+                nr_args as usize,
+            )
+        }
+    }
+}
+
+impl AsRawFd for IoUring {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw()
+    }
+}
+
+// the iterator of `IoUring::completions`.
+#[derive(Debug)]
+pub struct Completions<'a> {
+    ring: &'a mut IoUring,
+}
+
+impl Iterator for Completions<'_> {
+    type Item = io_uring_cqe;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ring.pop()
+    }
+}
+
+// the result of `IoUring::probe`.
+#[derive(Debug, Clone)]
+pub struct Probe {
+    last_op: u8,
+    ops: Vec<io_uring_probe_op>,
+}
+
+impl Probe {
+    // the last opcode which is known by the kernel.
+    pub fn last_op(&self) -> u8 {
+        self.last_op
+    }
+
+    pub fn is_supported(&self, op: IoUringOp) -> bool {
+        self.ops
+            .get(op.raw() as usize)
+            .is_some_and(|op| op.flags & IO_URING_OP_SUPPORTED != 0)
+    }
+}
+
+// the builders of the submission queue entries.
+//
+// `fd` is the index of the registered files if the entry has the
+// flag `IOSQE_FIXED_FILE`, and `offset` of the read and write entries
+// is `u64::MAX` for the current file position (e.g. the pipes and sockets).
+//
+// the pointers are stored in the entry as they are, see `IoUring::push`.
+impl io_uring_sqe {
+    fn new(op: IoUringOp, fd: RawFd) -> Self {
+        Self {
+            opcode: op.raw(),
+            fd,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_user_data(mut self, user_data: u64) -> Self {
+        self.user_data = user_data;
+        self
+    }
+
+    pub fn with_flags(mut self, flags: SqeFlags) -> Self {
+        self.flags = flags.bits();
+        self
+    }
+
+    pub fn nop() -> Self {
+        Self::new(IoUringOp::IORING_OP_NOP, -1)
+    }
+
+    pub fn read(fd: RawFd, buf: *mut u8, len: u32, offset: u64) -> Self {
+        Self {
+            off: offset,
+            addr: buf as u64,
+            len,
+            ..Self::new(IoUringOp::IORING_OP_READ, fd)
+        }
+    }
+
+    pub fn write(fd: RawFd, buf: *const u8, len: u32, offset: u64) -> Self {
+        Self {
+            off: offset,
+            addr: buf as u64,
+            len,
+            ..Self::new(IoUringOp::IORING_OP_WRITE, fd)
+        }
+    }
+
+    // `buf` must be within the registered buffer `buf_index`.
+    pub fn read_fixed(fd: RawFd, buf: *mut u8, len: u32, offset: u64, buf_index: u16) -> Self {
+        Self {
+            off: offset,
+            addr: buf as u64,
+            len,
+            buf_index,
+            ..Self::new(IoUringOp::IORING_OP_READ_FIXED, fd)
+        }
+    }
+
+    pub fn write_fixed(fd: RawFd, buf: *const u8, len: u32, offset: u64, buf_index: u16) -> Self {
+        Self {
+            off: offset,
+            addr: buf as u64,
+            len,
+            buf_index,
+            ..Self::new(IoUringOp::IORING_OP_WRITE_FIXED, fd)
+        }
+    }
+
+    pub fn readv(fd: RawFd, iovecs: *const iovec, count: u32, offset: u64) -> Self {
+        Self {
+            off: offset,
+            addr: iovecs as u64,
+            len: count,
+            ..Self::new(IoUringOp::IORING_OP_READV, fd)
+        }
+    }
+
+    pub fn writev(fd: RawFd, iovecs: *const iovec, count: u32, offset: u64) -> Self {
+        Self {
+            off: offset,
+            addr: iovecs as u64,
+            len: count,
+            ..Self::new(IoUringOp::IORING_OP_WRITEV, fd)
+        }
+    }
+
+    // `datasync` is the same as `fdatasync`.
+    pub fn fsync(fd: RawFd, datasync: bool) -> Self {
+        Self {
+            rw_flags: if datasync { IORING_FSYNC_DATASYNC } else { 0 },
+            ..Self::new(IoUringOp::IORING_OP_FSYNC, fd)
+        }
+    }
+
+    // the result is the new file descriptor, which is owned by the caller.
+    pub fn openat(dirfd: RawFd, path: *const c_char, flags: OpenFlags, mode: u32) -> Self {
+        Self {
+            addr: path as u64,
+            len: mode,
+            rw_flags: flags.bits(),
+            ..Self::new(IoUringOp::IORING_OP_OPENAT, dirfd)
+        }
+    }
+
+    pub fn close(fd: RawFd) -> Self {
+        Self::new(IoUringOp::IORING_OP_CLOSE, fd)
+    }
+
+    // completes with `ETIME` when the timeout expires, or with 0 when
+    // `count` other entries are completed (0 means only the timeout).
+    pub fn timeout(ts: *const timespec, count: u32, flags: TimeoutFlags) -> Self {
+        Self {
+            off: count as u64,
+            addr: ts as u64,
+            len: 1,
+            rw_flags: flags.bits(),
+            ..Self::new(IoUringOp::IORING_OP_TIMEOUT, -1)
+        }
+    }
+
+    // `addr` and `addrlen` can be null if the peer address is not required,
+    // the result is the file descriptor of the connection.
+    pub fn accept(fd: RawFd, addr: *mut c_void, addrlen: *mut u32, flags: SocketFlags) -> Self {
+        Self {
+            off: addrlen as u64,
+            addr: addr as u64,
+            rw_flags: flags.bits(),
+            ..Self::new(IoUringOp::IORING_OP_ACCEPT, fd)
+        }
+    }
+
+    pub fn connect(fd: RawFd, addr: *const c_void, addrlen: u32) -> Self {
+        Self {
+            off: addrlen as u64,
+            addr: addr as u64,
+            ..Self::new(IoUringOp::IORING_OP_CONNECT, fd)
+        }
+    }
+
+    // `flags` is the `MSG_*` flags of `sendto`.
+    pub fn send(fd: RawFd, buf: *const u8, len: u32, flags: u32) -> Self {
+        Self {
+            addr: buf as u64,
+            len,
+            rw_flags: flags,
+            ..Self::new(IoUringOp::IORING_OP_SEND, fd)
+        }
+    }
+
+    // `flags` is the `MSG_*` flags of `recvfrom`.
+    pub fn recv(fd: RawFd, buf: *mut u8, len: u32, flags: u32) -> Self {
+        Self {
+            addr: buf as u64,
+            len,
+            rw_flags: flags,
+            ..Self::new(IoUringOp::IORING_OP_RECV, fd)
+        }
+    }
+
+    // cancels the in-flight entry which has `user_data`, the canceled
+    // entry completes with `ECANCELED`, and this entry completes with 0,
+    // or `ENOENT` if the entry is not found, or `EALREADY` if the entry
+    // is running.
+    pub fn cancel(user_data: u64) -> Self {
+        Self {
+            addr: user_data,
+            ..Self::new(IoUringOp::IORING_OP_ASYNC_CANCEL, -1)
+        }
+    }
+}
+
+impl io_uring_cqe {
+    // converts `res` to the result of the operation.
+    pub fn result(&self) -> Result<u32, SysCallError> {
+        if self.res < 0 {
+            Err(SysCallError::from_code(self.res.unsigned_abs()))
+        } else {
+            Ok(self.res as u32)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::{c_void, CString},
+        net::TcpListener,
+        os::fd::{AsRawFd, RawFd},
+        ptr::null_mut,
+        time::Duration,
+    };
+
+    use crate::{
+        errno::{Errno, SysCallError},
+        fd::OwnedFd,
+        flags::{
+            AddressFamily, IoUringOp, OpenFlags, SocketFlags, SocketType, SqeFlags, TimeoutFlags,
+        },
+        fs::AT_FDCWD,
+        io::{pipe2, read, write},
+        io_uring::IoUring,
+        number::SysCallNum,
+        typed::syscall_with_3_args,
+        types::{io_uring_cqe, io_uring_sqe, iovec, timespec},
+    };
+
+    // io_uring may be disabled, e.g. by `kernel.io_uring_disabled` or seccomp.
+    fn new_ring(entries: u32) -> Option<IoUring> {
+        match IoUring::new(entries) {
+            Err(e) if e == Errno::ENOSYS || e == Errno::EPERM => {
+                eprintln!("io_uring is not available, skip the test");
+                None
+            }
+            result => Some(result.unwrap()),
+        }
+    }
+
+    // collects the completions sorted by `user_data`.
+    fn collect_sorted(ring: &mut IoUring) -> Vec<io_uring_cqe> {
+        let mut cqes: Vec<io_uring_cqe> = ring.completions().collect();
+        cqes.sort_by_key(|cqe| cqe.user_data);
+        cqes
+    }
+
+    #[test]
+    fn test_nop() {
+        let Some(mut ring) = new_ring(3) else {
+            return;
+        };
+
+        // the number of entries is rounded up to the power of 2
+        assert_eq!(ring.params().sq_entries, 4);
+        assert_eq!(ring.submit(), Ok(0));
+        assert_eq!(ring.pop(), None);
+
+        for user_data in 0..4 {
+            unsafe { ring.push(&io_uring_sqe::nop().with_user_data(user_data)) }.unwrap();
+        }
+        assert_eq!(
+            unsafe { ring.push(&io_uring_sqe::nop()) },
+            Err(Errno::EBUSY.into())
+        );
+
+        assert_eq!(ring.submit_and_wait(4), Ok(4));
+        let cqes = collect_sorted(&mut ring);
+        assert_eq!(
+            cqes.iter().map(|cqe| cqe.user_data).collect::<Vec<u64>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert!(cqes.iter().all(|cqe| cqe.result() == Ok(0)));
+        assert_eq!(ring.pop(), None);
+
+        // the slots are reusable
+        for _ in 0..2 {
+            unsafe { ring.push(&io_uring_sqe::nop().with_user_data(9)) }.unwrap();
+            assert_eq!(ring.submit_and_wait(1), Ok(1));
+            assert_eq!(ring.pop().map(|cqe| cqe.user_data), Some(9));
+        }
+    }
+
+    #[test]
+    fn test_file_io() {
+        let Some(mut ring) = new_ring(8) else {
+            return;
+        };
+
+        let path =
+            std::env::temp_dir().join(format!("syscall-util-io-uring-{}", std::process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let flags =
+            OpenFlags::O_RDWR | OpenFlags::O_CREAT | OpenFlags::O_TRUNC | OpenFlags::O_CLOEXEC;
+        let sqe = io_uring_sqe::openat(AT_FDCWD.as_raw(), c_path.as_ptr(), flags, 0o600);
+        unsafe { ring.push(&sqe) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        let fd = ring.pop().unwrap().result().unwrap() as RawFd;
+
+        // the linked entries are executed in order
+        let data = b"hello io_uring";
+        let mut head = [0u8; 5];
+        let mut tail = [0u8; 16];
+        let iovecs = [
+            iovec {
+                iov_base: head.as_mut_ptr() as *mut c_void,
+                iov_len: head.len(),
+            },
+            iovec {
+                iov_base: tail.as_mut_ptr() as *mut c_void,
+                iov_len: tail.len(),
+            },
+        ];
+        let link = SqeFlags::IOSQE_IO_LINK;
+        unsafe {
+            ring.push(
+                &io_uring_sqe::write(fd, data.as_ptr(), data.len() as u32, 0)
+                    .with_flags(link)
+                    .with_user_data(1),
+            )
+            .unwrap();
+            ring.push(
+                &io_uring_sqe::fsync(fd, true)
+                    .with_flags(link)
+                    .with_user_data(2),
+            )
+            .unwrap();
+            ring.push(&io_uring_sqe::readv(fd, iovecs.as_ptr(), 2, 0).with_user_data(3))
+                .unwrap();
+        }
+        assert_eq!(ring.submit_and_wait(3), Ok(3));
+
+        let results: Vec<Result<u32, SysCallError>> = collect_sorted(&mut ring)
+            .iter()
+            .map(|cqe| cqe.result())
+            .collect();
+        assert_eq!(results, vec![Ok(14), Ok(0), Ok(14)]);
+        assert_eq!(&head, b"hello");
+        assert_eq!(&tail[..9], b" io_uring");
+
+        unsafe { ring.push(&io_uring_sqe::close(fd)) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        assert_eq!(ring.pop().unwrap().result(), Ok(0));
+
+        unsafe { ring.push(&io_uring_sqe::close(fd)) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        assert_eq!(ring.pop().unwrap().result(), Err(Errno::EBADF.into()));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_fixed_files_and_buffers() {
+        let Some(mut ring) = new_ring(4) else {
+            return;
+        };
+
+        let (read_fd, write_fd) = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+        ring.register_files(&[-1, read_fd.as_raw(), write_fd.as_raw()])
+            .unwrap();
+
+        let mut buf = vec![0u8; 4096];
+        buf[..5].copy_from_slice(b"fixed");
+        let bufs = [iovec {
+            iov_base: buf.as_mut_ptr() as *mut c_void,
+            iov_len: buf.len(),
+        }];
+        unsafe { ring.register_buffers(&bufs) }.unwrap();
+
+        // `fd` is the index of the registered files
+        unsafe {
+            ring.push(
+                &io_uring_sqe::write_fixed(2, buf.as_ptr(), 5, u64::MAX, 0)
+                    .with_flags(SqeFlags::IOSQE_FIXED_FILE | SqeFlags::IOSQE_IO_LINK)
+                    .with_user_data(1),
+            )
+            .unwrap();
+            ring.push(
+                &io_uring_sqe::read_fixed(1, buf.as_mut_ptr().add(100), 64, u64::MAX, 0)
+                    .with_flags(SqeFlags::IOSQE_FIXED_FILE)
+                    .with_user_data(2),
+            )
+            .unwrap();
+        }
+        assert_eq!(ring.submit_and_wait(2), Ok(2));
+        let results: Vec<Result<u32, SysCallError>> = collect_sorted(&mut ring)
+            .iter()
+            .map(|cqe| cqe.result())
+            .collect();
+        assert_eq!(results, vec![Ok(5), Ok(5)]);
+        assert_eq!(&buf[100..105], b"fixed");
+
+        // the buffer must be within the registered buffer
+        let mut other = [0u8; 8];
+        unsafe {
+            ring.push(&io_uring_sqe::read_fixed(
+                read_fd.as_raw(),
+                other.as_mut_ptr(),
+                8,
+                u64::MAX,
+                0,
+            ))
+        }
+        .unwrap();
+        ring.submit_and_wait(1).unwrap();
+        assert_eq!(ring.pop().unwrap().result(), Err(Errno::EFAULT.into()));
+
+        assert_eq!(ring.unregister_buffers(), Ok(()));
+        assert_eq!(ring.unregister_files(), Ok(()));
+        assert_eq!(ring.unregister_files(), Err(Errno::ENXIO.into()));
+    }
+
+    #[test]
+    fn test_timeout_and_cancel() {
+        let Some(mut ring) = new_ring(4) else {
+            return;
+        };
+
+        let short = timespec::from(Duration::from_millis(10));
+        unsafe { ring.push(&io_uring_sqe::timeout(&short, 0, TimeoutFlags::empty())) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        assert_eq!(ring.pop().unwrap().result(), Err(Errno::ETIME.into()));
+
+        let long = timespec::from(Duration::from_secs(10));
+        unsafe {
+            ring.push(&io_uring_sqe::timeout(&long, 0, TimeoutFlags::empty()).with_user_data(7))
+        }
+        .unwrap();
+        ring.submit().unwrap();
+        unsafe { ring.push(&io_uring_sqe::cancel(7).with_user_data(8)) }.unwrap();
+        assert_eq!(ring.submit_and_wait(2), Ok(1));
+
+        let results: Vec<(u64, Result<u32, SysCallError>)> = collect_sorted(&mut ring)
+            .iter()
+            .map(|cqe| (cqe.user_data, cqe.result()))
+            .collect();
+        assert_eq!(results, vec![(7, Err(Errno::ECANCELED.into())), (8, Ok(0))]);
+
+        unsafe { ring.push(&io_uring_sqe::cancel(7)) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        assert_eq!(ring.pop().unwrap().result(), Err(Errno::ENOENT.into()));
+    }
+
+    #[test]
+    fn test_socket() {
+        let Some(mut ring) = new_ring(4) else {
+            return;
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let fd = unsafe {
+            syscall_with_3_args(
+                SysCallNum::socket as usize,
+                AddressFamily::AF_INET.raw() as usize,
+                SocketType::SOCK_STREAM.raw() as usize | SocketFlags::SOCK_CLOEXEC.bits() as usize,
+                0,
+            )
+        }
+        .unwrap();
+        let client = unsafe { OwnedFd::from_raw(fd as RawFd) };
+
+        // i.e. `struct sockaddr_in` of 127.0.0.1
+        let mut addr = [0u8; 16];
+        addr[..2].copy_from_slice(&AddressFamily::AF_INET.raw().to_ne_bytes());
+        addr[2..4].copy_from_slice(&port.to_be_bytes());
+        addr[4..8].copy_from_slice(&[127, 0, 0, 1]);
+
+        unsafe {
+            ring.push(
+                &io_uring_sqe::accept(
+                    listener.as_raw_fd(),
+                    null_mut(),
+                    null_mut(),
+                    SocketFlags::SOCK_CLOEXEC,
+                )
+                .with_user_data(1),
+            )
+            .unwrap();
+            ring.push(
+                &io_uring_sqe::connect(client.as_raw(), addr.as_ptr() as *const c_void, 16)
+                    .with_user_data(2),
+            )
+            .unwrap();
+        }
+        assert_eq!(ring.submit_and_wait(2), Ok(2));
+        let cqes = collect_sorted(&mut ring);
+        assert_eq!(cqes[1].result(), Ok(0));
+        let server = unsafe { OwnedFd::from_raw(cqes[0].result().unwrap() as RawFd) };
+
+        let mut buf = [0u8; 16];
+        unsafe {
+            ring.push(
+                &io_uring_sqe::send(client.as_raw(), b"ping".as_ptr(), 4, 0)
+                    .with_flags(SqeFlags::IOSQE_IO_LINK)
+                    .with_user_data(1),
+            )
+            .unwrap();
+            ring.push(
+                &io_uring_sqe::recv(server.as_raw(), buf.as_mut_ptr(), 16, 0).with_user_data(2),
+            )
+            .unwrap();
+        }
+        assert_eq!(ring.submit_and_wait(2), Ok(2));
+        let results: Vec<Result<u32, SysCallError>> = collect_sorted(&mut ring)
+            .iter()
+            .map(|cqe| cqe.result())
+            .collect();
+        assert_eq!(results, vec![Ok(4), Ok(4)]);
+        assert_eq!(&buf[..4], b"ping");
+
+        // the connection works with the normal syscalls as well
+        write(server.as_fd(), b"pong").unwrap();
+        assert_eq!(read(client.as_fd(), &mut buf), Ok(4));
+        assert_eq!(&buf[..4], b"pong");
+    }
+
+    #[test]
+    fn test_probe() {
+        let Some(ring) = new_ring(1) else {
+            return;
+        };
+
+        let probe = ring.probe().unwrap();
+        assert!(probe.last_op() >= IoUringOp::IORING_OP_RECV.raw());
+        assert!(probe.is_supported(IoUringOp::IORING_OP_NOP));
+        assert!(probe.is_supported(IoUringOp::IORING_OP_READ));
+        assert!(probe.is_supported(IoUringOp::IORING_OP_ASYNC_CANCEL));
+    }
+}
@@ -11,20 +11,70 @@
 // if you need a syscall library, please refer to:
 // - https://github.com/jasonwhite/syscalls.git
 
-mod arch;
+#[macro_use]
+mod macros;
+
+pub mod arch;
 
 #[cfg(target_arch = "x86_64")]
 pub use arch::x86_64::*;
 
+#[cfg(target_arch = "aarch64")]
+pub use arch::aarch64::*;
+
+#[cfg(target_arch = "riscv64")]
+pub use arch::riscv64::*;
+
+// the following modules are built on the syscall entries (`call`) and the
+// syscall numbers (`number`) of the host architecture, so they are only
+// available on the supported architectures, the same as `pub use arch::*`.
+macro_rules! supported_arch {
+    ($($item:item)*) => {
+        $(
+            #[cfg(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "riscv64"
+            ))]
+            $item
+        )*
+    };
+}
+
+supported_arch! {
+    pub mod dispatch;
+    pub mod epoll;
+    pub mod event;
+    pub mod fd;
+    pub mod flags;
+    pub mod fs;
+    pub mod io;
+    pub mod io_uring;
+    pub mod mm;
+    pub mod net;
+    pub mod portable;
+    pub mod process;
+    pub mod signal;
+    pub mod signature;
+    pub mod typed;
+}
+
 pub mod errno;
+pub mod types;
 
-#[cfg(test)]
+// the following tests use the legacy syscall `open` which is only
+// available on x86_64.
+#[cfg(all(test, target_arch = "x86_64"))]
 mod tests {
+    use std::os::fd::RawFd;
+
     use crate::{
         call::{
             syscall_with_1_arg, syscall_with_2_args, syscall_with_3_args, syscall_without_args,
         },
         errno::Errno,
+        fd::OwnedFd,
+        flags::OpenFlags,
         number::SysCallNum,
     };
 
@@ -105,16 +155,13 @@ mod tests {
         let file_path0 = b"/this/file/should/not/exist\0";
         let file_path1 = b"/dev/zero\0";
 
-        const O_RDONLY: u32 = 0x0;
-        const O_CLOEXEC: u32 = 0x80000;
-
-        let flags = O_RDONLY | O_CLOEXEC;
+        let flags = OpenFlags::O_RDONLY | OpenFlags::O_CLOEXEC;
 
         let result0 = unsafe {
             syscall_with_2_args(
                 SysCallNum::open as usize,
                 file_path0.as_ptr() as usize,
-                flags as usize,
+                flags.bits() as usize,
             )
         };
         assert!(matches!(result0, Err(errno) if errno == Errno::ENOENT as usize));
@@ -123,9 +170,12 @@ mod tests {
             syscall_with_2_args(
                 SysCallNum::open as usize,
                 file_path1.as_ptr() as usize,
-                flags as usize,
+                flags.bits() as usize,
             )
         };
         assert!(matches!(result1, Ok(fd) if fd > 0));
+
+        // closes the file by dropping the owned fd
+        let _fd = unsafe { OwnedFd::from_raw(result1.unwrap() as RawFd) };
     }
 }
@@ -0,0 +1,309 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// defines a syscall number enum and the lookup functions, e.g.
+//
+// ```rust
+// syscall_num! {
+//     pub enum SysCallNum {
+//         read = 0,
+//         write = 1,
+//     }
+// }
+// ```
+//
+// the name of a syscall is the name of the variant, unless the name in
+// the kernel syscall table is not a valid (or not a conventional) Rust
+// identifier, in which case the variant is renamed and the original name
+// is given by the attribute `name`, e.g.
+//
+// ```rust
+//         #[name = "_sysctl"]
+//         sysctl_ = 156,
+// ```
+//
+// generates:
+//
+// - `SysCallNum::ALL`, all variants in the order of definition
+// - `SysCallNum::iter()`
+// - `SysCallNum::from_number(usize) -> Option<SysCallNum>`
+// - `SysCallNum::name() -> &'static str`, the name in the kernel syscall table
+// - `impl FromStr for SysCallNum`, parses the name in the kernel syscall table
+macro_rules! syscall_num {
+    (@name $name:ident $kernel_name:literal) => {
+        $kernel_name
+    };
+    (@name $name:ident) => {
+        stringify!($name)
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $enum_name:ident {
+            $($(#[name = $kernel_name:literal])? $name:ident = $num:literal,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(usize)]
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+        #[allow(non_camel_case_types)]
+        $vis enum $enum_name {
+            $($name = $num,)*
+        }
+
+        impl $enum_name {
+            pub const ALL: &'static [$enum_name] = &[$($enum_name::$name,)*];
+
+            pub fn iter() -> impl Iterator<Item = $enum_name> {
+                Self::ALL.iter().copied()
+            }
+
+            pub fn from_number(number: usize) -> Option<$enum_name> {
+                match number {
+                    $($num => Some($enum_name::$name),)*
+                    _ => None,
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($enum_name::$name => syscall_num!(@name $name $($kernel_name)?),)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for $enum_name {
+            type Err = $crate::arch::ParseSysCallNumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::iter()
+                    .find(|item| item.name() == s)
+                    .ok_or_else(|| $crate::arch::ParseSysCallNumError::new(s))
+            }
+        }
+    };
+}
+
+// defines a bit flags type, e.g.
+//
+// ```rust
+// bitflags! {
+//     pub struct ProtFlags: u32 {
+//         const PROT_NONE = 0;
+//         const PROT_READ = 0x1;
+//         const PROT_WRITE = 0x2;
+//     }
+// }
+// ```
+//
+// generates:
+//
+// - the associated constants `ProtFlags::PROT_READ` etc.
+// - `empty()`, `from_bits()`, `bits()`, `contains()`, `intersects()`,
+//   `is_empty()`, `insert()` and `remove()`
+// - the operators `|`, `|=`, `&`, `&=` and `!`
+// - `impl Debug`, which prints the names of the flags, e.g.
+//   `ProtFlags(PROT_READ | PROT_WRITE)`, the unknown bits are printed in hex.
+//   the flags with value 0 (e.g. `PROT_NONE`) never match, the empty value
+//   is printed as `ProtFlags(empty)`.
+//
+// when a flag is composed of other flags (e.g. `O_SYNC` is `__O_SYNC | O_DSYNC`),
+// it should be defined before its components so that `Debug` prints
+// the composed name.
+macro_rules! bitflags {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $type_name:ident: $t:ty {
+            $(
+                $(#[$flag_attr:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Eq, Hash, Clone, Copy, Default)]
+        $vis struct $type_name($t);
+
+        #[allow(non_upper_case_globals)]
+        impl $type_name {
+            $(
+                $(#[$flag_attr])*
+                pub const $flag: $type_name = $type_name($value);
+            )*
+
+            // the (name, value) of all flags, for `Debug`.
+            const NAMED_FLAGS: &'static [(&'static str, $type_name)] = &[
+                $(
+                    $(#[$flag_attr])*
+                    (stringify!($flag), $type_name::$flag),
+                )*
+            ];
+
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            // the unknown bits are retained.
+            pub const fn from_bits(bits: $t) -> Self {
+                Self(bits)
+            }
+
+            pub const fn bits(&self) -> $t {
+                self.0
+            }
+
+            // returns true if all bits of `other` are set.
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            // returns true if any bit of `other` is set.
+            pub const fn intersects(&self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl std::ops::BitOr for $type_name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $type_name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl std::ops::BitAnd for $type_name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl std::ops::BitAndAssign for $type_name {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl std::ops::Not for $type_name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        impl std::fmt::Debug for $type_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut names: Vec<String> = vec![];
+
+                let mut remaining = self.0;
+                for (name, flag) in Self::NAMED_FLAGS {
+                    if flag.0 != 0 && remaining & flag.0 == flag.0 {
+                        names.push(name.to_string());
+                        remaining &= !flag.0;
+                    }
+                }
+
+                if remaining != 0 {
+                    names.push(format!("{:#x}", remaining));
+                }
+
+                if names.is_empty() {
+                    write!(f, "{}(empty)", stringify!($type_name))
+                } else {
+                    write!(f, "{}({})", stringify!($type_name), names.join(" | "))
+                }
+            }
+        }
+    };
+}
+
+// defines an enum of the constant values which can not be combined
+// (e.g. the signal numbers), e.g.
+//
+// ```rust
+// value_enum! {
+//     pub enum Whence: u32 {
+//         SEEK_SET = 0,
+//         SEEK_CUR = 1,
+//     }
+// }
+// ```
+//
+// generates:
+//
+// - `Whence::ALL`, all variants in the order of definition
+// - `Whence::from_raw($t) -> Option<Whence>`
+// - `Whence::raw() -> $t`
+// - `Whence::name() -> &'static str`
+//
+// `Debug` prints the name of the variant, e.g. `SEEK_SET`.
+macro_rules! value_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $enum_name:ident: $t:ty {
+            $(
+                $(#[$variant_attr:meta])*
+                $name:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+        #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+        #[repr($t)]
+        $vis enum $enum_name {
+            $(
+                $(#[$variant_attr])*
+                $name = $value,
+            )*
+        }
+
+        impl $enum_name {
+            pub const ALL: &'static [$enum_name] = &[
+                $(
+                    $(#[$variant_attr])*
+                    $enum_name::$name,
+                )*
+            ];
+
+            pub fn from_raw(value: $t) -> Option<$enum_name> {
+                Self::ALL.iter().copied().find(|item| *item as $t == value)
+            }
+
+            pub const fn raw(self) -> $t {
+                self as $t
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        $(#[$variant_attr])*
+                        $enum_name::$name => stringify!($name),
+                    )*
+                }
+            }
+        }
+    };
+}
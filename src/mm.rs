@@ -0,0 +1,509 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the memory mappings, e.g.
+//
+// ```rust
+// // reserves 1GB address space without allocating any memory, and
+// // commits the first 64KB, the rest pages are guard pages.
+// let mut heap = Mapping::reserve(1 << 30)?;
+// heap.commit(0, 64 * 1024)?;
+// heap.as_mut_ptr().write(42);
+//
+// // the mapping is unmapped here
+// ```
+//
+// `Mapping` owns the mapped pages and unmaps them by the raw syscall `munmap`
+// when it is dropped. the offsets and lengths of the methods are relative
+// to the start of the mapping, the offsets must be aligned to the page size
+// (see `page_size()`), and the ranges out of the mapping are rejected with
+// `EINVAL`, so that a `Mapping` can never change the pages it does not own.
+
+use std::sync::OnceLock;
+
+use crate::{
+    errno::{Errno, SysCallError},
+    fd::BorrowedFd,
+    flags::{Advice, MapFlags, MlockFlags, MremapFlags, ProtFlags},
+    number::SysCallNum,
+    typed::{syscall_with_2_args, syscall_with_3_args, syscall_with_5_args, syscall_with_6_args},
+};
+
+// the type of the entry of the auxiliary vector which holds the page size,
+// see 'include/uapi/linux/auxvec.h'.
+const AT_PAGESZ: usize = 6;
+
+// the page size is 4KB on x86_64, but it can be 16KB or 64KB on aarch64
+// and riscv64, so it is read from the auxiliary vector (i.e. '/proc/self/auxv')
+// instead of being hardcoded.
+//
+// when '/proc' is not available (e.g. in a chroot or under seccomp), the
+// page size is probed by `probe_page_size()` except on x86_64 (which
+// always uses 4KB pages), because a wrong page size breaks every length
+// and alignment of `Mapping`.
+pub fn page_size() -> usize {
+    static PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+
+    *PAGE_SIZE.get_or_init(|| {
+        const WORD_SIZE: usize = std::mem::size_of::<usize>();
+
+        let auxv = std::fs::read("/proc/self/auxv").unwrap_or_default();
+        let page_size = auxv
+            .chunks_exact(WORD_SIZE * 2)
+            .map(|entry| {
+                let (key, value) = entry.split_at(WORD_SIZE);
+                (
+                    usize::from_ne_bytes(key.try_into().unwrap()),
+                    usize::from_ne_bytes(value.try_into().unwrap()),
+                )
+            })
+            .find(|(key, _)| *key == AT_PAGESZ)
+            .map(|(_, value)| value);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            page_size.unwrap_or(4096)
+        }
+
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            page_size.or_else(probe_page_size).expect(
+                "failed to determine the page size, '/proc/self/auxv' is unavailable and probing failed",
+            )
+        }
+    })
+}
+
+// the page sizes supported by the architectures, from 4KB to 64KB.
+#[cfg(any(test, not(target_arch = "x86_64")))]
+const PAGE_SIZE_CANDIDATES: [usize; 5] = [4096, 8192, 16384, 32768, 65536];
+
+// probes the page size by changing the protection at the offsets of a
+// fresh mapping, the kernel rejects the unaligned address with `EINVAL`,
+// so the smallest offset which is accepted is the page size.
+#[cfg(any(test, not(target_arch = "x86_64")))]
+fn probe_page_size() -> Option<usize> {
+    let max_size = PAGE_SIZE_CANDIDATES[PAGE_SIZE_CANDIDATES.len() - 1];
+
+    // the address of the mapping is aligned to the page size, and the
+    // mapping contains at least one page at every candidate offset.
+    let mut mapping = Mapping::reserve(max_size * 2).ok()?;
+
+    PAGE_SIZE_CANDIDATES
+        .into_iter()
+        .find(|size| mapping.protect(*size, *size, ProtFlags::PROT_NONE).is_ok())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mapping {
+    addr: usize,
+    len: usize,
+}
+
+impl Mapping {
+    // maps the private anonymous memory which is filled with zero.
+    pub fn anonymous(len: usize, prot: ProtFlags) -> Result<Self, SysCallError> {
+        Self::map(
+            len,
+            prot,
+            MapFlags::MAP_PRIVATE | MapFlags::MAP_ANONYMOUS,
+            None,
+            0,
+        )
+    }
+
+    // maps the file from `offset` (which must be aligned to the page size),
+    // `flags` should contain one of the mapping types, i.e. `MAP_SHARED`,
+    // `MAP_SHARED_VALIDATE` or `MAP_PRIVATE`.
+    //
+    // the mapping keeps a reference to the file, so `fd` can be closed
+    // after mapping.
+    pub fn file(
+        fd: BorrowedFd<'_>,
+        offset: u64,
+        len: usize,
+        prot: ProtFlags,
+        flags: MapFlags,
+    ) -> Result<Self, SysCallError> {
+        // `MAP_FIXED` would replace the existing mappings silently
+        if flags.intersects(MapFlags::MAP_FIXED | MapFlags::MAP_ANONYMOUS) {
+            return Err(Errno::EINVAL.into());
+        }
+
+        Self::map(len, prot, flags, Some(fd), offset)
+    }
+
+    // reserves the address space without committing any memory, all pages
+    // are inaccessible (i.e. `PROT_NONE`) until they are committed by `commit`,
+    // so the uncommitted pages act as guard pages.
+    pub fn reserve(len: usize) -> Result<Self, SysCallError> {
+        Self::map(
+            len,
+            ProtFlags::PROT_NONE,
+            MapFlags::MAP_PRIVATE | MapFlags::MAP_ANONYMOUS | MapFlags::MAP_NORESERVE,
+            None,
+            0,
+        )
+    }
+
+    fn map(
+        len: usize,
+        prot: ProtFlags,
+        flags: MapFlags,
+        fd: Option<BorrowedFd<'_>>,
+        offset: u64,
+    ) -> Result<Self, SysCallError> {
+        let fd = fd.map_or(-1, |fd| fd.as_raw());
+        let addr = unsafe {
+            syscall_with_6_args(
+                SysCallNum::mmap as usize,
+                0,
+                len,
+                prot.bits() as usize,
+                flags.bits() as usize,
+                fd as usize,
+                offset as usize,
+            )
+        }?;
+        Ok(Self { addr, len })
+    }
+
+    // the caller must ensure that the pages are mapped and they are
+    // not owned by others.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn from_raw(addr: usize, len: usize) -> Self {
+        debug_assert!(addr.is_multiple_of(page_size()));
+        Self { addr, len }
+    }
+
+    // takes the ownership of the pages, i.e. they will not be
+    // unmapped automatically.
+    //
+    // returns `(address, length)`.
+    pub fn into_raw(self) -> (usize, usize) {
+        let raw = (self.addr, self.len);
+        std::mem::forget(self);
+        raw
+    }
+
+    pub fn addr(&self) -> usize {
+        self.addr
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_ptr(&self) -> *const u8 {
+        self.addr as *const u8
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.addr as *mut u8
+    }
+
+    // the caller must ensure that the whole mapping is readable, and
+    // it is not modified by others (e.g. other processes which share
+    // the same file) while the slice is alive.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        std::slice::from_raw_parts(self.as_ptr(), self.len)
+    }
+
+    // the caller must ensure that the whole mapping is readable and writable,
+    // and it is not accessed by others while the slice is alive.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len)
+    }
+
+    // returns the address of the range, or `EINVAL` if the range is
+    // out of the mapping.
+    fn range(&self, offset: usize, len: usize) -> Result<usize, SysCallError> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.len => Ok(self.addr + offset),
+            _ => Err(Errno::EINVAL.into()),
+        }
+    }
+
+    // changes the protection of the pages in the range.
+    pub fn protect(
+        &mut self,
+        offset: usize,
+        len: usize,
+        prot: ProtFlags,
+    ) -> Result<(), SysCallError> {
+        let addr = self.range(offset, len)?;
+        unsafe {
+            syscall_with_3_args(
+                SysCallNum::mprotect as usize,
+                addr,
+                len,
+                prot.bits() as usize,
+            )
+        }
+        .map(|_| ())
+    }
+
+    // makes the pages in the range readable and writable, the memory is
+    // allocated by the kernel on the first access.
+    pub fn commit(&mut self, offset: usize, len: usize) -> Result<(), SysCallError> {
+        self.protect(offset, len, ProtFlags::PROT_READ | ProtFlags::PROT_WRITE)
+    }
+
+    // releases the memory of the pages in the range and makes them
+    // inaccessible again, the pages are filled with zero when they
+    // are committed next time (for the anonymous mappings).
+    pub fn decommit(&mut self, offset: usize, len: usize) -> Result<(), SysCallError> {
+        self.advise(offset, len, Advice::MADV_DONTNEED)?;
+        self.protect(offset, len, ProtFlags::PROT_NONE)
+    }
+
+    // resizes the mapping, the mapping is grown or shrunk in place, or it
+    // is moved to a new address if growing in place is not possible and
+    // `may_move` is true, in which case the pointers to the old address
+    // become invalid.
+    //
+    // returns `ENOMEM` if the mapping can not be grown in place and
+    // `may_move` is false.
+    pub fn remap(&mut self, new_len: usize, may_move: bool) -> Result<(), SysCallError> {
+        let flags = if may_move {
+            MremapFlags::MREMAP_MAYMOVE
+        } else {
+            MremapFlags::empty()
+        };
+
+        let addr = unsafe {
+            syscall_with_5_args(
+                SysCallNum::mremap as usize,
+                self.addr,
+                self.len,
+                new_len,
+                flags.bits() as usize,
+                0,
+            )
+        }?;
+
+        self.addr = addr;
+        self.len = new_len;
+        Ok(())
+    }
+
+    // gives the kernel the hint of how the pages in the range will be used.
+    //
+    // note that some advices change the content of the pages, e.g.
+    // `MADV_DONTNEED` and `MADV_FREE` discard the pages of the private
+    // anonymous mappings.
+    pub fn advise(
+        &mut self,
+        offset: usize,
+        len: usize,
+        advice: Advice,
+    ) -> Result<(), SysCallError> {
+        let addr = self.range(offset, len)?;
+        unsafe {
+            syscall_with_3_args(
+                SysCallNum::madvise as usize,
+                addr,
+                len,
+                advice.raw() as usize,
+            )
+        }
+        .map(|_| ())
+    }
+
+    // locks the pages of the whole mapping in memory, `flags` can be
+    // empty or `MLOCK_ONFAULT`.
+    //
+    // the amount of the locked memory is limited by `RLIMIT_MEMLOCK` for
+    // the unprivileged processes.
+    pub fn lock(&self, flags: MlockFlags) -> Result<(), SysCallError> {
+        unsafe {
+            syscall_with_3_args(
+                SysCallNum::mlock2 as usize,
+                self.addr,
+                self.len,
+                flags.bits() as usize,
+            )
+        }
+        .map(|_| ())
+    }
+
+    pub fn unlock(&self) -> Result<(), SysCallError> {
+        unsafe { syscall_with_2_args(SysCallNum::munlock as usize, self.addr, self.len) }
+            .map(|_| ())
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // the error is ignored, `munmap` only fails when the arguments
+        // are invalid, which is impossible for a `Mapping`.
+        let _ = unsafe { syscall_with_2_args(SysCallNum::munmap as usize, self.addr, self.len) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use crate::{
+        errno::Errno,
+        flags::{Advice, MapFlags, MlockFlags, OpenFlags, ProtFlags},
+        fs::{openat, AT_FDCWD},
+        io::{pipe2, pwrite64},
+        mm::{page_size, probe_page_size, Mapping},
+        number::SysCallNum,
+        typed::syscall_with_3_args,
+    };
+
+    #[test]
+    fn test_page_size() {
+        let size = page_size();
+        assert!(size >= 4096);
+        assert!(size.is_power_of_two());
+
+        // the probed page size agrees with the auxiliary vector
+        assert_eq!(probe_page_size(), Some(size));
+    }
+
+    #[test]
+    fn test_anonymous_mapping() {
+        let size = page_size();
+        let mut mapping =
+            Mapping::anonymous(size, ProtFlags::PROT_READ | ProtFlags::PROT_WRITE).unwrap();
+        assert_eq!(mapping.len(), size);
+
+        let data = unsafe { mapping.as_mut_slice() };
+        assert!(data.iter().all(|b| *b == 0));
+        data[..5].copy_from_slice(b"hello");
+
+        // grows, the content is kept even if the mapping is moved
+        mapping.remap(size * 4, true).unwrap();
+        assert_eq!(mapping.len(), size * 4);
+        let data = unsafe { mapping.as_slice() };
+        assert_eq!(&data[..5], b"hello");
+        assert_eq!(data[size * 4 - 1], 0);
+
+        // shrinks in place
+        let addr = mapping.addr();
+        mapping.remap(size * 2, false).unwrap();
+        assert_eq!(mapping.addr(), addr);
+
+        assert_eq!(mapping.advise(0, size * 2, Advice::MADV_WILLNEED), Ok(()));
+
+        // out of the mapping
+        assert_eq!(
+            mapping.protect(size, size * 2, ProtFlags::PROT_READ),
+            Err(Errno::EINVAL.into())
+        );
+        assert_eq!(
+            mapping.advise(usize::MAX, 2, Advice::MADV_NORMAL),
+            Err(Errno::EINVAL.into())
+        );
+
+        // the offset is not aligned
+        assert_eq!(
+            mapping.protect(1, 1, ProtFlags::PROT_READ),
+            Err(Errno::EINVAL.into())
+        );
+    }
+
+    #[test]
+    fn test_reserve_and_commit() {
+        let size = page_size();
+        let mut mapping = Mapping::reserve(size * 4).unwrap();
+
+        // the pages are inaccessible, the kernel returns `EFAULT` instead of
+        // raising `SIGSEGV` when it accesses them.
+        let (_read_fd, write_fd) = pipe2(OpenFlags::empty()).unwrap();
+        let write_from = |addr: usize| unsafe {
+            syscall_with_3_args(
+                SysCallNum::write as usize,
+                write_fd.as_raw() as usize,
+                addr,
+                1,
+            )
+        };
+        assert_eq!(write_from(mapping.addr()), Err(Errno::EFAULT.into()));
+
+        // commits the second and third pages, the first and fourth pages
+        // are guard pages.
+        mapping.commit(size, size * 2).unwrap();
+        unsafe {
+            let ptr = mapping.as_mut_ptr().add(size);
+            ptr.write(1);
+            ptr.add(size * 2 - 1).write(2);
+        }
+        assert_eq!(write_from(mapping.addr() + size), Ok(1));
+        assert_eq!(write_from(mapping.addr()), Err(Errno::EFAULT.into()));
+        assert_eq!(
+            write_from(mapping.addr() + size * 3),
+            Err(Errno::EFAULT.into())
+        );
+
+        // the content is discarded by `decommit`
+        mapping.decommit(size, size).unwrap();
+        assert_eq!(write_from(mapping.addr() + size), Err(Errno::EFAULT.into()));
+        mapping.commit(size, size).unwrap();
+        unsafe {
+            let ptr = mapping.as_ptr().add(size);
+            assert_eq!(ptr.read(), 0);
+            assert_eq!(ptr.add(size * 2 - 1).read(), 2);
+        }
+    }
+
+    #[test]
+    fn test_file_mapping() {
+        let path = std::env::temp_dir().join(format!("syscall-util-mm-{}", std::process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let flags =
+            OpenFlags::O_RDWR | OpenFlags::O_CREAT | OpenFlags::O_TRUNC | OpenFlags::O_CLOEXEC;
+        let fd = openat(AT_FDCWD, &c_path, flags, 0o600).unwrap();
+        assert_eq!(pwrite64(fd.as_fd(), b"hello world", 0), Ok(11));
+
+        let mut mapping = Mapping::file(
+            fd.as_fd(),
+            0,
+            11,
+            ProtFlags::PROT_READ | ProtFlags::PROT_WRITE,
+            MapFlags::MAP_SHARED,
+        )
+        .unwrap();
+
+        // the mapping is still valid after the file is closed
+        drop(fd);
+        assert_eq!(unsafe { mapping.as_slice() }, b"hello world");
+
+        // writes through the shared mapping
+        let data = unsafe { mapping.as_mut_slice() };
+        data[6..].copy_from_slice(b"WORLD");
+        assert_eq!(mapping.lock(MlockFlags::empty()), Ok(()));
+        assert_eq!(mapping.unlock(), Ok(()));
+        drop(mapping);
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello WORLD");
+
+        // the offset is not aligned
+        let fd = openat(AT_FDCWD, &c_path, OpenFlags::O_RDONLY, 0).unwrap();
+        assert_eq!(
+            Mapping::file(
+                fd.as_fd(),
+                1,
+                10,
+                ProtFlags::PROT_READ,
+                MapFlags::MAP_PRIVATE
+            ),
+            Err(Errno::EINVAL.into())
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,978 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the socket API, e.g.
+//
+// ```rust
+// let listener = socket(
+//     AddressFamily::AF_INET,
+//     SocketType::SOCK_STREAM,
+//     SocketFlags::SOCK_CLOEXEC,
+//     0,
+// )?;
+// setsockopt(listener.as_fd(), SocketOption::SO_REUSEADDR, &1)?;
+// bind(
+//     listener.as_fd(),
+//     &SocketAddr::from(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)),
+// )?;
+// listen(listener.as_fd(), 128)?;
+//
+// let (conn, peer) = accept4(listener.as_fd(), SocketFlags::SOCK_CLOEXEC)?;
+// ```
+//
+// the addresses are converted between `SocketAddr` (which wraps the
+// address types of `std::net` and `UnixAddr`) and the C structures
+// `sockaddr_in`, `sockaddr_in6` and `sockaddr_un`.
+
+use std::{
+    ffi::OsStr,
+    fmt,
+    mem::{offset_of, size_of},
+    net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6},
+    os::{fd::RawFd, unix::ffi::OsStrExt},
+    path::Path,
+};
+
+use crate::{
+    errno::{Errno, SysCallError},
+    fd::{BorrowedFd, OwnedFd},
+    flags::{
+        AddressFamily, IpOption, Ipv6Option, MsgFlags, ShutdownHow, SocketFlags, SocketOption,
+        SocketType, TcpOption,
+    },
+    number::SysCallNum,
+    typed::{
+        syscall_with_2_args, syscall_with_3_args, syscall_with_4_args, syscall_with_5_args,
+        syscall_with_6_args,
+    },
+    types::{
+        in6_addr, in_addr, linger, sockaddr_in, sockaddr_in6, sockaddr_storage, sockaddr_un,
+        timeval, ucred,
+    },
+};
+
+// the levels of `getsockopt` and `setsockopt`, the values of `IPPROTO_*`
+// are also the argument `protocol` of `socket`.
+pub const IPPROTO_IP: i32 = 0;
+pub const SOL_SOCKET: i32 = 1;
+pub const IPPROTO_TCP: i32 = 6;
+pub const IPPROTO_UDP: i32 = 17;
+pub const IPPROTO_IPV6: i32 = 41;
+
+// i.e. the size of `sun_family`.
+const SUN_PATH_OFFSET: usize = offset_of!(sockaddr_un, sun_path);
+const SUN_PATH_LEN: usize = 108;
+
+impl From<SocketAddrV4> for sockaddr_in {
+    fn from(addr: SocketAddrV4) -> Self {
+        Self {
+            sin_family: AddressFamily::AF_INET.raw(),
+            sin_port: addr.port().to_be(),
+            sin_addr: in_addr {
+                s_addr: u32::from_ne_bytes(addr.ip().octets()),
+            },
+            sin_zero: [0; 8],
+        }
+    }
+}
+
+impl From<sockaddr_in> for SocketAddrV4 {
+    fn from(addr: sockaddr_in) -> Self {
+        SocketAddrV4::new(
+            Ipv4Addr::from(addr.sin_addr.s_addr.to_ne_bytes()),
+            u16::from_be(addr.sin_port),
+        )
+    }
+}
+
+impl From<SocketAddrV6> for sockaddr_in6 {
+    fn from(addr: SocketAddrV6) -> Self {
+        Self {
+            sin6_family: AddressFamily::AF_INET6.raw(),
+            sin6_port: addr.port().to_be(),
+            sin6_flowinfo: addr.flowinfo().to_be(),
+            sin6_addr: in6_addr {
+                s6_addr: addr.ip().octets(),
+            },
+            sin6_scope_id: addr.scope_id(),
+        }
+    }
+}
+
+impl From<sockaddr_in6> for SocketAddrV6 {
+    fn from(addr: sockaddr_in6) -> Self {
+        SocketAddrV6::new(
+            Ipv6Addr::from(addr.sin6_addr.s6_addr),
+            u16::from_be(addr.sin6_port),
+            u32::from_be(addr.sin6_flowinfo),
+            addr.sin6_scope_id,
+        )
+    }
+}
+
+// the address of the Unix domain socket, which is one of:
+//
+// - unnamed: e.g. the sockets of `socketpair` and the client sockets
+//   which are not bound.
+// - pathname: the socket file in the file system.
+// - abstract name: the name in the abstract namespace (Linux specific),
+//   which is independent of the file system, and is removed
+//   automatically when the socket is closed.
+#[derive(Clone, Copy)]
+pub struct UnixAddr {
+    addr: sockaddr_un,
+    len: u32,
+}
+
+impl UnixAddr {
+    pub fn unnamed() -> Self {
+        Self {
+            addr: sockaddr_un {
+                sun_family: AddressFamily::AF_UNIX.raw(),
+                ..Default::default()
+            },
+            len: SUN_PATH_OFFSET as u32,
+        }
+    }
+
+    // the pathname can not be empty or contain NUL, and it must be shorter
+    // than 108 bytes since it is terminated by NUL, otherwise `EINVAL`
+    // is returned.
+    pub fn pathname(path: &Path) -> Result<Self, SysCallError> {
+        let bytes = path.as_os_str().as_bytes();
+        if bytes.is_empty() || bytes.len() >= SUN_PATH_LEN || bytes.contains(&0) {
+            return Err(Errno::EINVAL.into());
+        }
+
+        let mut addr = Self::unnamed();
+        addr.addr.sun_path[..bytes.len()].copy_from_slice(bytes);
+        addr.len = (SUN_PATH_OFFSET + bytes.len() + 1) as u32;
+        Ok(addr)
+    }
+
+    // the name is arbitrary bytes (up to 107 bytes), the leading NUL
+    // is added by this function.
+    pub fn abstract_name(name: &[u8]) -> Result<Self, SysCallError> {
+        if name.len() >= SUN_PATH_LEN {
+            return Err(Errno::EINVAL.into());
+        }
+
+        let mut addr = Self::unnamed();
+        addr.addr.sun_path[1..=name.len()].copy_from_slice(name);
+        addr.len = (SUN_PATH_OFFSET + name.len() + 1) as u32;
+        Ok(addr)
+    }
+
+    // `len` is the length of the address returned by the kernel.
+    pub fn from_raw(addr: &sockaddr_un, len: u32) -> Result<Self, SysCallError> {
+        if addr.sun_family != AddressFamily::AF_UNIX.raw() {
+            return Err(Errno::EAFNOSUPPORT.into());
+        }
+        if (len as usize) < SUN_PATH_OFFSET || len as usize > size_of::<sockaddr_un>() {
+            return Err(Errno::EINVAL.into());
+        }
+
+        Ok(Self { addr: *addr, len })
+    }
+
+    pub fn as_raw(&self) -> (&sockaddr_un, u32) {
+        (&self.addr, self.len)
+    }
+
+    // the bytes of `sun_path` which are covered by the length.
+    fn name(&self) -> &[u8] {
+        &self.addr.sun_path[..self.len as usize - SUN_PATH_OFFSET]
+    }
+
+    pub fn is_unnamed(&self) -> bool {
+        self.name().is_empty()
+    }
+
+    pub fn as_pathname(&self) -> Option<&Path> {
+        let name = self.name();
+        if name.first().is_none_or(|byte| *byte == 0) {
+            return None;
+        }
+
+        // the terminating NUL may or may not be covered by the length
+        let len = name
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(name.len());
+        Some(Path::new(OsStr::from_bytes(&name[..len])))
+    }
+
+    // the name without the leading NUL.
+    pub fn as_abstract_name(&self) -> Option<&[u8]> {
+        match self.name() {
+            [0, name @ ..] => Some(name),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for UnixAddr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_pathname() == other.as_pathname()
+            && self.as_abstract_name() == other.as_abstract_name()
+    }
+}
+
+impl Eq for UnixAddr {}
+
+impl fmt::Debug for UnixAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = self.as_pathname() {
+            write!(f, "UnixAddr({:?})", path)
+        } else if let Some(name) = self.as_abstract_name() {
+            // the abstract name is shown with the prefix '@', the same as `ss`
+            write!(f, "UnixAddr(\"@{}\")", name.escape_ascii())
+        } else {
+            write!(f, "UnixAddr(unnamed)")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketAddr {
+    V4(SocketAddrV4),
+    V6(SocketAddrV6),
+    Unix(UnixAddr),
+}
+
+impl SocketAddr {
+    pub fn family(&self) -> AddressFamily {
+        match self {
+            SocketAddr::V4(_) => AddressFamily::AF_INET,
+            SocketAddr::V6(_) => AddressFamily::AF_INET6,
+            SocketAddr::Unix(_) => AddressFamily::AF_UNIX,
+        }
+    }
+
+    // converts the address to the C structure, returns the buffer
+    // and the length of the address.
+    pub fn encode(&self) -> (sockaddr_storage, u32) {
+        let mut storage = sockaddr_storage::default();
+        let len = match self {
+            SocketAddr::V4(addr) => write_addr(&mut storage, sockaddr_in::from(*addr)),
+            SocketAddr::V6(addr) => write_addr(&mut storage, sockaddr_in6::from(*addr)),
+            SocketAddr::Unix(addr) => {
+                let (addr, len) = addr.as_raw();
+                write_addr(&mut storage, *addr);
+                len
+            }
+        };
+        (storage, len)
+    }
+
+    // converts the C structure which is filled by the kernel (e.g. `accept4`
+    // and `getsockname`), `len` is the returned length of the address.
+    //
+    // `EAFNOSUPPORT` is returned for the other address families.
+    pub fn decode(storage: &sockaddr_storage, len: u32) -> Result<Self, SysCallError> {
+        let len = len.min(size_of::<sockaddr_storage>() as u32);
+        let family = AddressFamily::from_raw(storage.ss_family);
+        match family {
+            Some(AddressFamily::AF_INET) if len as usize >= size_of::<sockaddr_in>() => Ok(
+                SocketAddr::V4(SocketAddrV4::from(read_addr::<sockaddr_in>(storage))),
+            ),
+            Some(AddressFamily::AF_INET6) if len as usize >= size_of::<sockaddr_in6>() => Ok(
+                SocketAddr::V6(SocketAddrV6::from(read_addr::<sockaddr_in6>(storage))),
+            ),
+            Some(AddressFamily::AF_UNIX) => {
+                let addr = read_addr::<sockaddr_un>(storage);
+                let len = len.min(size_of::<sockaddr_un>() as u32);
+                UnixAddr::from_raw(&addr, len).map(SocketAddr::Unix)
+            }
+            Some(AddressFamily::AF_INET | AddressFamily::AF_INET6) => Err(Errno::EINVAL.into()),
+            _ => Err(Errno::EAFNOSUPPORT.into()),
+        }
+    }
+}
+
+// `sockaddr_storage` is large enough and aligned for all addresses.
+fn write_addr<T: Copy>(storage: &mut sockaddr_storage, addr: T) -> u32 {
+    const { assert!(size_of::<T>() <= size_of::<sockaddr_storage>()) };
+    unsafe { (storage as *mut sockaddr_storage as *mut T).write(addr) };
+    size_of::<T>() as u32
+}
+
+fn read_addr<T: Copy>(storage: &sockaddr_storage) -> T {
+    const { assert!(size_of::<T>() <= size_of::<sockaddr_storage>()) };
+    unsafe { (storage as *const sockaddr_storage as *const T).read() }
+}
+
+impl From<SocketAddrV4> for SocketAddr {
+    fn from(addr: SocketAddrV4) -> Self {
+        SocketAddr::V4(addr)
+    }
+}
+
+impl From<SocketAddrV6> for SocketAddr {
+    fn from(addr: SocketAddrV6) -> Self {
+        SocketAddr::V6(addr)
+    }
+}
+
+impl From<std::net::SocketAddr> for SocketAddr {
+    fn from(addr: std::net::SocketAddr) -> Self {
+        match addr {
+            std::net::SocketAddr::V4(addr) => SocketAddr::V4(addr),
+            std::net::SocketAddr::V6(addr) => SocketAddr::V6(addr),
+        }
+    }
+}
+
+impl From<UnixAddr> for SocketAddr {
+    fn from(addr: UnixAddr) -> Self {
+        SocketAddr::Unix(addr)
+    }
+}
+
+// `protocol` is usually 0, i.e. the default protocol of the family
+// and the type.
+pub fn socket(
+    family: AddressFamily,
+    ty: SocketType,
+    flags: SocketFlags,
+    protocol: i32,
+) -> Result<OwnedFd, SysCallError> {
+    let fd = unsafe {
+        syscall_with_3_args(
+            SysCallNum::socket as usize,
+            family.raw() as usize,
+            (ty.raw() | flags.bits()) as usize,
+            protocol as usize,
+        )
+    }?;
+    Ok(unsafe { OwnedFd::from_raw(fd as RawFd) })
+}
+
+// creates a pair of connected sockets, `family` is usually `AF_UNIX`.
+pub fn socketpair(
+    family: AddressFamily,
+    ty: SocketType,
+    flags: SocketFlags,
+    protocol: i32,
+) -> Result<(OwnedFd, OwnedFd), SysCallError> {
+    let mut fds: [RawFd; 2] = [-1, -1];
+    unsafe {
+        syscall_with_4_args(
+            SysCallNum::socketpair as usize,
+            family.raw() as usize,
+            (ty.raw() | flags.bits()) as usize,
+            protocol as usize,
+            fds.as_mut_ptr() as usize,
+        )
+    }?;
+    Ok(unsafe { (OwnedFd::from_raw(fds[0]), OwnedFd::from_raw(fds[1])) })
+}
+
+pub fn bind(fd: BorrowedFd<'_>, addr: &SocketAddr) -> Result<(), SysCallError> {
+    let (storage, len) = addr.encode();
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::bind as usize,
+            fd.as_raw() as usize,
+            &storage as *const sockaddr_storage as usize,
+            len as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+pub fn listen(fd: BorrowedFd<'_>, backlog: i32) -> Result<(), SysCallError> {
+    unsafe {
+        syscall_with_2_args(
+            SysCallNum::listen as usize,
+            fd.as_raw() as usize,
+            backlog as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+// returns the connected socket and the address of the peer.
+pub fn accept4(
+    fd: BorrowedFd<'_>,
+    flags: SocketFlags,
+) -> Result<(OwnedFd, SocketAddr), SysCallError> {
+    let mut storage = sockaddr_storage::default();
+    let mut len = size_of::<sockaddr_storage>() as u32;
+    let conn = unsafe {
+        syscall_with_4_args(
+            SysCallNum::accept4 as usize,
+            fd.as_raw() as usize,
+            &mut storage as *mut sockaddr_storage as usize,
+            &mut len as *mut u32 as usize,
+            flags.bits() as usize,
+        )
+    }?;
+    let conn = unsafe { OwnedFd::from_raw(conn as RawFd) };
+    Ok((conn, SocketAddr::decode(&storage, len)?))
+}
+
+// for the datagram sockets, it sets the default destination and
+// filters the incoming datagrams.
+pub fn connect(fd: BorrowedFd<'_>, addr: &SocketAddr) -> Result<(), SysCallError> {
+    let (storage, len) = addr.encode();
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::connect as usize,
+            fd.as_raw() as usize,
+            &storage as *const sockaddr_storage as usize,
+            len as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+// the address which the socket is bound to, e.g. to get the port
+// which is assigned by binding to port 0.
+pub fn getsockname(fd: BorrowedFd<'_>) -> Result<SocketAddr, SysCallError> {
+    get_name(SysCallNum::getsockname, fd)
+}
+
+pub fn getpeername(fd: BorrowedFd<'_>) -> Result<SocketAddr, SysCallError> {
+    get_name(SysCallNum::getpeername, fd)
+}
+
+fn get_name(num: SysCallNum, fd: BorrowedFd<'_>) -> Result<SocketAddr, SysCallError> {
+    let mut storage = sockaddr_storage::default();
+    let mut len = size_of::<sockaddr_storage>() as u32;
+    unsafe {
+        syscall_with_3_args(
+            num as usize,
+            fd.as_raw() as usize,
+            &mut storage as *mut sockaddr_storage as usize,
+            &mut len as *mut u32 as usize,
+        )
+    }?;
+    SocketAddr::decode(&storage, len)
+}
+
+// `addr` is the destination of the datagram sockets, it is `None` for
+// the connected sockets (i.e. `send`).
+pub fn sendto(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: MsgFlags,
+    addr: Option<&SocketAddr>,
+) -> Result<usize, SysCallError> {
+    let encoded = addr.map(SocketAddr::encode);
+    let (addr_ptr, len) = encoded.as_ref().map_or((0, 0), |(storage, len)| {
+        (storage as *const sockaddr_storage as usize, *len)
+    });
+    unsafe {
+        syscall_with_6_args(
+            SysCallNum::sendto as usize,
+            fd.as_raw() as usize,
+            buf.as_ptr() as usize,
+            buf.len(),
+            flags.bits() as usize,
+            addr_ptr,
+            len as usize,
+        )
+    }
+}
+
+// returns the length of the received data and the address of the sender,
+// the address is `None` if it is not provided by the protocol (e.g. the
+// connected stream sockets).
+pub fn recvfrom(
+    fd: BorrowedFd<'_>,
+    buf: &mut [u8],
+    flags: MsgFlags,
+) -> Result<(usize, Option<SocketAddr>), SysCallError> {
+    let mut storage = sockaddr_storage::default();
+    let mut len = size_of::<sockaddr_storage>() as u32;
+    let received = unsafe {
+        syscall_with_6_args(
+            SysCallNum::recvfrom as usize,
+            fd.as_raw() as usize,
+            buf.as_mut_ptr() as usize,
+            buf.len(),
+            flags.bits() as usize,
+            &mut storage as *mut sockaddr_storage as usize,
+            &mut len as *mut u32 as usize,
+        )
+    }?;
+
+    let addr = if len == 0 {
+        None
+    } else {
+        Some(SocketAddr::decode(&storage, len)?)
+    };
+    Ok((received, addr))
+}
+
+pub fn shutdown(fd: BorrowedFd<'_>, how: ShutdownHow) -> Result<(), SysCallError> {
+    unsafe {
+        syscall_with_2_args(
+            SysCallNum::shutdown as usize,
+            fd.as_raw() as usize,
+            how.raw() as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+// the socket option with its level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SockOpt {
+    Socket(SocketOption),
+    Tcp(TcpOption),
+    Ip(IpOption),
+    Ipv6(Ipv6Option),
+}
+
+impl SockOpt {
+    pub fn level(&self) -> i32 {
+        match self {
+            SockOpt::Socket(_) => SOL_SOCKET,
+            SockOpt::Tcp(_) => IPPROTO_TCP,
+            SockOpt::Ip(_) => IPPROTO_IP,
+            SockOpt::Ipv6(_) => IPPROTO_IPV6,
+        }
+    }
+
+    pub fn optname(&self) -> i32 {
+        match self {
+            SockOpt::Socket(opt) => opt.raw(),
+            SockOpt::Tcp(opt) => opt.raw(),
+            SockOpt::Ip(opt) => opt.raw(),
+            SockOpt::Ipv6(opt) => opt.raw(),
+        }
+    }
+}
+
+impl From<SocketOption> for SockOpt {
+    fn from(opt: SocketOption) -> Self {
+        SockOpt::Socket(opt)
+    }
+}
+
+impl From<TcpOption> for SockOpt {
+    fn from(opt: TcpOption) -> Self {
+        SockOpt::Tcp(opt)
+    }
+}
+
+impl From<IpOption> for SockOpt {
+    fn from(opt: IpOption) -> Self {
+        SockOpt::Ip(opt)
+    }
+}
+
+impl From<Ipv6Option> for SockOpt {
+    fn from(opt: Ipv6Option) -> Self {
+        SockOpt::Ipv6(opt)
+    }
+}
+
+// the types of the option values, most options are `i32` (the boolean
+// options are 0 or 1), and the others are:
+//
+// - `SO_LINGER`: `linger`
+// - `SO_RCVTIMEO` and `SO_SNDTIMEO`: `timeval`
+// - `SO_PEERCRED`: `ucred` (read only)
+//
+// the implementations must be the plain C structures since they are
+// written by the kernel.
+#[allow(clippy::missing_safety_doc)]
+pub unsafe trait SockOptValue: Copy + Default {}
+
+unsafe impl SockOptValue for i32 {}
+unsafe impl SockOptValue for linger {}
+unsafe impl SockOptValue for timeval {}
+unsafe impl SockOptValue for ucred {}
+
+// e.g. `getsockopt::<i32>(fd, SocketOption::SO_ERROR)`.
+//
+// returns `EINVAL` if the length of the option is not the size of `T`,
+// instead of returning a partially filled value.
+pub fn getsockopt<T: SockOptValue>(
+    fd: BorrowedFd<'_>,
+    opt: impl Into<SockOpt>,
+) -> Result<T, SysCallError> {
+    let opt = opt.into();
+    let mut value = T::default();
+    let mut len = size_of::<T>() as u32;
+    unsafe {
+        syscall_with_5_args(
+            SysCallNum::getsockopt as usize,
+            fd.as_raw() as usize,
+            opt.level() as usize,
+            opt.optname() as usize,
+            &mut value as *mut T as usize,
+            &mut len as *mut u32 as usize,
+        )
+    }?;
+
+    if len != size_of::<T>() as u32 {
+        return Err(Errno::EINVAL.into());
+    }
+
+    Ok(value)
+}
+
+pub fn setsockopt<T: SockOptValue>(
+    fd: BorrowedFd<'_>,
+    opt: impl Into<SockOpt>,
+    value: &T,
+) -> Result<(), SysCallError> {
+    let opt = opt.into();
+    unsafe {
+        syscall_with_5_args(
+            SysCallNum::setsockopt as usize,
+            fd.as_raw() as usize,
+            opt.level() as usize,
+            opt.optname() as usize,
+            value as *const T as usize,
+            size_of::<T>(),
+        )
+    }
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6},
+        path::Path,
+    };
+
+    use crate::{
+        errno::Errno,
+        flags::{
+            AddressFamily, Ipv6Option, MsgFlags, ShutdownHow, SocketFlags, SocketOption,
+            SocketType, TcpOption,
+        },
+        net::{
+            accept4, bind, connect, getpeername, getsockname, getsockopt, listen, recvfrom, sendto,
+            setsockopt, shutdown, socket, socketpair, SocketAddr, UnixAddr,
+        },
+        process::getpid,
+        types::{linger, sockaddr_in, sockaddr_in6, ucred},
+    };
+
+    fn bound_port(addr: SocketAddr) -> u16 {
+        match addr {
+            SocketAddr::V4(addr) => addr.port(),
+            SocketAddr::V6(addr) => addr.port(),
+            SocketAddr::Unix(_) => panic!("not an IP address"),
+        }
+    }
+
+    #[test]
+    fn test_inet_addresses() {
+        let v4 = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0x1234);
+        let raw = sockaddr_in::from(v4);
+        assert_eq!(raw.sin_family, AddressFamily::AF_INET.raw());
+        assert_eq!(raw.sin_port.to_ne_bytes(), [0x12, 0x34]);
+        assert_eq!(raw.sin_addr.s_addr.to_ne_bytes(), [127, 0, 0, 1]);
+        assert_eq!(SocketAddrV4::from(raw), v4);
+
+        let v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0x12345, 3);
+        let raw = sockaddr_in6::from(v6);
+        assert_eq!(raw.sin6_port.to_ne_bytes(), [0x1f, 0x90]);
+        assert_eq!(raw.sin6_flowinfo.to_ne_bytes(), [0, 1, 0x23, 0x45]);
+        assert_eq!(raw.sin6_scope_id, 3);
+        assert_eq!(SocketAddrV6::from(raw), v6);
+
+        for addr in [SocketAddr::from(v4), SocketAddr::from(v6)] {
+            let (storage, len) = addr.encode();
+            assert_eq!(SocketAddr::decode(&storage, len), Ok(addr));
+            assert_eq!(
+                SocketAddr::decode(&storage, len - 1),
+                Err(Errno::EINVAL.into())
+            );
+        }
+        assert_eq!(SocketAddr::from(v4).encode().1, 16);
+        assert_eq!(SocketAddr::from(v6).encode().1, 28);
+    }
+
+    #[test]
+    fn test_unix_addresses() {
+        let unnamed = UnixAddr::unnamed();
+        assert!(unnamed.is_unnamed());
+        assert_eq!(unnamed.as_raw().1, 2);
+        assert_eq!(format!("{:?}", unnamed), "UnixAddr(unnamed)");
+
+        let path = UnixAddr::pathname(Path::new("/tmp/sock")).unwrap();
+        assert_eq!(path.as_pathname(), Some(Path::new("/tmp/sock")));
+        assert_eq!(path.as_abstract_name(), None);
+        assert_eq!(path.as_raw().1, 2 + 9 + 1);
+        assert_eq!(format!("{:?}", path), "UnixAddr(\"/tmp/sock\")");
+
+        // the terminating NUL is optional in the returned length
+        let (raw, len) = path.as_raw();
+        assert_eq!(UnixAddr::from_raw(raw, len - 1), Ok(path));
+
+        let name = UnixAddr::abstract_name(b"name\0with nul").unwrap();
+        assert_eq!(name.as_abstract_name(), Some(&b"name\0with nul"[..]));
+        assert_eq!(name.as_pathname(), None);
+        assert_eq!(name.as_raw().1, 2 + 1 + 13);
+        assert_eq!(format!("{:?}", name), "UnixAddr(\"@name\\x00with nul\")");
+        assert_ne!(name, UnixAddr::abstract_name(b"name").unwrap());
+
+        // the empty abstract name is different from the unnamed address
+        let empty = UnixAddr::abstract_name(b"").unwrap();
+        assert_eq!(empty.as_abstract_name(), Some(&b""[..]));
+        assert_ne!(empty, unnamed);
+
+        assert_eq!(UnixAddr::pathname(Path::new("")), Err(Errno::EINVAL.into()));
+        assert_eq!(
+            UnixAddr::pathname(Path::new("a\0b")),
+            Err(Errno::EINVAL.into())
+        );
+        let long = "a".repeat(108);
+        assert_eq!(
+            UnixAddr::pathname(Path::new(&long)),
+            Err(Errno::EINVAL.into())
+        );
+        assert!(UnixAddr::pathname(Path::new(&long[1..])).is_ok());
+        assert!(UnixAddr::abstract_name(&[b'a'; 107]).is_ok());
+        assert_eq!(
+            UnixAddr::abstract_name(&[b'a'; 108]),
+            Err(Errno::EINVAL.into())
+        );
+
+        let (storage, len) = SocketAddr::from(path).encode();
+        assert_eq!(
+            SocketAddr::decode(&storage, len),
+            Ok(SocketAddr::Unix(path))
+        );
+    }
+
+    #[test]
+    fn test_tcp_loopback() {
+        let listener = socket(
+            AddressFamily::AF_INET,
+            SocketType::SOCK_STREAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        setsockopt(listener.as_fd(), SocketOption::SO_REUSEADDR, &1).unwrap();
+        assert_eq!(
+            getsockopt::<i32>(listener.as_fd(), SocketOption::SO_REUSEADDR),
+            Ok(1)
+        );
+
+        let loopback = SocketAddr::from(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0));
+        bind(listener.as_fd(), &loopback).unwrap();
+        listen(listener.as_fd(), 8).unwrap();
+        assert_eq!(
+            getsockopt::<i32>(listener.as_fd(), SocketOption::SO_ACCEPTCONN),
+            Ok(1)
+        );
+        let server_addr = getsockname(listener.as_fd()).unwrap();
+        assert_ne!(bound_port(server_addr), 0);
+
+        let client = socket(
+            AddressFamily::AF_INET,
+            SocketType::SOCK_STREAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        assert_eq!(getpeername(client.as_fd()), Err(Errno::ENOTCONN.into()));
+        connect(client.as_fd(), &server_addr).unwrap();
+
+        let (conn, peer) = accept4(listener.as_fd(), SocketFlags::SOCK_CLOEXEC).unwrap();
+        assert_eq!(Ok(peer), getsockname(client.as_fd()));
+        assert_eq!(getpeername(client.as_fd()), Ok(server_addr));
+
+        setsockopt(conn.as_fd(), TcpOption::TCP_NODELAY, &1).unwrap();
+        assert_eq!(
+            getsockopt::<i32>(conn.as_fd(), TcpOption::TCP_NODELAY),
+            Ok(1)
+        );
+        assert_eq!(
+            getsockopt::<i32>(conn.as_fd(), SocketOption::SO_TYPE),
+            Ok(SocketType::SOCK_STREAM.raw() as i32)
+        );
+        assert_eq!(
+            getsockopt::<i32>(conn.as_fd(), SocketOption::SO_ERROR),
+            Ok(0)
+        );
+
+        let value = linger {
+            l_onoff: 1,
+            l_linger: 5,
+        };
+        setsockopt(conn.as_fd(), SocketOption::SO_LINGER, &value).unwrap();
+        assert_eq!(
+            getsockopt::<linger>(conn.as_fd(), SocketOption::SO_LINGER),
+            Ok(value)
+        );
+
+        // the option is an `int`, the kernel writes only 4 bytes
+        assert_eq!(
+            getsockopt::<linger>(conn.as_fd(), SocketOption::SO_TYPE),
+            Err(Errno::EINVAL.into())
+        );
+
+        assert_eq!(
+            sendto(client.as_fd(), b"hello", MsgFlags::MSG_NOSIGNAL, None),
+            Ok(5)
+        );
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            recvfrom(conn.as_fd(), &mut buf, MsgFlags::MSG_PEEK),
+            Ok((5, None))
+        );
+        assert_eq!(
+            recvfrom(conn.as_fd(), &mut buf, MsgFlags::empty()),
+            Ok((5, None))
+        );
+        assert_eq!(&buf[..5], b"hello");
+
+        // the end of stream
+        shutdown(client.as_fd(), ShutdownHow::SHUT_WR).unwrap();
+        assert_eq!(
+            recvfrom(conn.as_fd(), &mut buf, MsgFlags::empty()),
+            Ok((0, None))
+        );
+        assert_eq!(
+            recvfrom(client.as_fd(), &mut buf, MsgFlags::MSG_DONTWAIT),
+            Err(Errno::EAGAIN.into())
+        );
+    }
+
+    #[test]
+    fn test_udp_loopback() {
+        let receiver = socket(
+            AddressFamily::AF_INET6,
+            SocketType::SOCK_DGRAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        setsockopt(receiver.as_fd(), Ipv6Option::IPV6_V6ONLY, &1).unwrap();
+        let loopback = SocketAddr::from(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0));
+        bind(receiver.as_fd(), &loopback).unwrap();
+        let receiver_addr = getsockname(receiver.as_fd()).unwrap();
+
+        let sender = socket(
+            AddressFamily::AF_INET6,
+            SocketType::SOCK_DGRAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        bind(sender.as_fd(), &loopback).unwrap();
+        let sender_addr = getsockname(sender.as_fd()).unwrap();
+
+        assert_eq!(
+            sendto(
+                sender.as_fd(),
+                b"one",
+                MsgFlags::empty(),
+                Some(&receiver_addr)
+            ),
+            Ok(3)
+        );
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            recvfrom(receiver.as_fd(), &mut buf, MsgFlags::empty()),
+            Ok((3, Some(sender_addr)))
+        );
+        assert_eq!(&buf[..3], b"one");
+
+        // the datagram is truncated, and the rest is discarded
+        connect(sender.as_fd(), &receiver_addr).unwrap();
+        sendto(sender.as_fd(), b"datagram", MsgFlags::empty(), None).unwrap();
+        assert_eq!(
+            recvfrom(receiver.as_fd(), &mut buf[..4], MsgFlags::MSG_TRUNC),
+            Ok((8, Some(sender_addr)))
+        );
+        assert_eq!(&buf[..4], b"data");
+        assert_eq!(
+            recvfrom(receiver.as_fd(), &mut buf, MsgFlags::MSG_DONTWAIT),
+            Err(Errno::EAGAIN.into())
+        );
+    }
+
+    #[test]
+    fn test_unix_sockets() {
+        let (left, right) = socketpair(
+            AddressFamily::AF_UNIX,
+            SocketType::SOCK_SEQPACKET,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            getsockname(left.as_fd()),
+            Ok(SocketAddr::Unix(UnixAddr::unnamed()))
+        );
+        sendto(left.as_fd(), b"pair", MsgFlags::empty(), None).unwrap();
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            recvfrom(right.as_fd(), &mut buf, MsgFlags::empty()).map(|(len, _)| len),
+            Ok(4)
+        );
+
+        // the abstract namespace
+        let name = format!("syscall-util-net-{}", getpid());
+        let abstract_addr = SocketAddr::from(UnixAddr::abstract_name(name.as_bytes()).unwrap());
+        let listener = socket(
+            AddressFamily::AF_UNIX,
+            SocketType::SOCK_STREAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        bind(listener.as_fd(), &abstract_addr).unwrap();
+        listen(listener.as_fd(), 1).unwrap();
+        assert_eq!(getsockname(listener.as_fd()), Ok(abstract_addr));
+
+        let client = socket(
+            AddressFamily::AF_UNIX,
+            SocketType::SOCK_STREAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        connect(client.as_fd(), &abstract_addr).unwrap();
+        let (conn, peer) = accept4(listener.as_fd(), SocketFlags::SOCK_CLOEXEC).unwrap();
+        assert_eq!(peer, SocketAddr::Unix(UnixAddr::unnamed()));
+
+        let cred = getsockopt::<ucred>(conn.as_fd(), SocketOption::SO_PEERCRED).unwrap();
+        assert_eq!(cred.pid, getpid());
+
+        // the pathname
+        let path = std::env::temp_dir().join(format!("syscall-util-net-{}.sock", getpid()));
+        let path_addr = SocketAddr::from(UnixAddr::pathname(&path).unwrap());
+        let server = socket(
+            AddressFamily::AF_UNIX,
+            SocketType::SOCK_DGRAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        bind(server.as_fd(), &path_addr).unwrap();
+        assert_eq!(getsockname(server.as_fd()), Ok(path_addr));
+        assert!(path.exists());
+
+        // the socket file is not removed when the socket is closed
+        drop(server);
+        let other = socket(
+            AddressFamily::AF_UNIX,
+            SocketType::SOCK_DGRAM,
+            SocketFlags::SOCK_CLOEXEC,
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            bind(other.as_fd(), &path_addr),
+            Err(Errno::EADDRINUSE.into())
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,476 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// architecture-neutral syscall identifiers.
+//
+// the enum `SysCallNum` is defined per architecture, so the code such as
+// `SysCallNum::open as usize` does not compile on the architectures which
+// do not have the syscall `open` (e.g. aarch64 and riscv64).
+//
+// `PortableSysCall` contains the syscalls of all supported architectures
+// (i.e. the union of the names), and resolves to the syscall number of
+// the specified architecture (or the current target) at runtime, e.g.
+//
+// ```rust
+// match PortableSysCall::open.number() {
+//     Some(num) => /* call `open` */,
+//     None => /* emulate it by `openat` */,
+// }
+// ```
+//
+// the function `PortableSysCall::emulation_for()` reports how a legacy
+// syscall (which comes from x86_64) is emulated on the architectures
+// without it.
+
+// the discriminants are the indices of the syscall numbers in the
+// generated table, i.e. the order of `Arch::ALL`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Arch {
+    X86_64,
+    Aarch64,
+    Riscv64,
+}
+
+impl Arch {
+    pub const ALL: [Arch; 3] = [Arch::X86_64, Arch::Aarch64, Arch::Riscv64];
+
+    // the architecture of the current target.
+    pub fn current() -> Arch {
+        #[cfg(target_arch = "x86_64")]
+        {
+            Arch::X86_64
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            Arch::Aarch64
+        }
+
+        #[cfg(target_arch = "riscv64")]
+        {
+            Arch::Riscv64
+        }
+    }
+}
+
+// the syscalls which are renamed in `SysCallNum` (e.g. `sysctl_`) are renamed
+// in the same way, and keep the kernel name by the attribute `name`, see
+// the macro `syscall_num!`.
+macro_rules! portable_syscall {
+    ($($(#[name = $kernel_name:literal])? $name:ident = [$($num:expr),*],)*) => {
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+        #[allow(non_camel_case_types)]
+        pub enum PortableSysCall {
+            $($name,)*
+        }
+
+        impl PortableSysCall {
+            pub const ALL: &'static [PortableSysCall] = &[$(PortableSysCall::$name,)*];
+
+            pub fn iter() -> impl Iterator<Item = PortableSysCall> {
+                Self::ALL.iter().copied()
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(PortableSysCall::$name => syscall_num!(@name $name $($kernel_name)?),)*
+                }
+            }
+
+            pub fn from_name(name: &str) -> Option<PortableSysCall> {
+                Self::iter().find(|item| item.name() == name)
+            }
+
+            // the syscall number on the specified architecture, or `None` if
+            // the architecture does not have this syscall.
+            pub fn number_for(&self, arch: $crate::portable::Arch) -> Option<usize> {
+                let numbers: [Option<usize>; 3] = match self {
+                    $(PortableSysCall::$name => [$($num),*],)*
+                };
+                numbers[arch as usize]
+            }
+        }
+    };
+}
+
+// the reverse mapping of `number_for()`, i.e. from the syscall number of an
+// architecture to `PortableSysCall`, so the VM can find the portable syscall
+// (and its signature) without comparing names.
+macro_rules! portable_syscall_from_number {
+    ($($arch:ident { $($num:literal => $name:ident,)* })*) => {
+        impl PortableSysCall {
+            pub fn from_number_for(
+                arch: $crate::portable::Arch,
+                number: usize,
+            ) -> Option<PortableSysCall> {
+                match arch {
+                    $($crate::portable::Arch::$arch => match number {
+                        $($num => Some(PortableSysCall::$name),)*
+                        _ => None,
+                    },)*
+                }
+            }
+        }
+    };
+}
+
+// the union of the syscall names of x86_64, aarch64 and riscv64, and their
+// numbers, see 'src/portable/table.rs'.
+mod table;
+
+pub use table::PortableSysCall;
+
+impl PortableSysCall {
+    // the syscall number on the current target.
+    pub fn number(&self) -> Option<usize> {
+        self.number_for(Arch::current())
+    }
+
+    // the portable syscall of the syscall number on the current target.
+    pub fn from_number(number: usize) -> Option<PortableSysCall> {
+        Self::from_number_for(Arch::current(), number)
+    }
+
+    pub fn is_available_on(&self, arch: Arch) -> bool {
+        self.number_for(arch).is_some()
+    }
+
+    // returns the way to emulate this syscall on the specified architecture,
+    // or `None` if this syscall is available on the architecture natively
+    // (or it is not a legacy syscall).
+    pub fn emulation_for(&self, arch: Arch) -> Option<Emulation> {
+        if self.is_available_on(arch) {
+            return None;
+        }
+
+        let (legacy, successors, note) = LEGACY_SYSCALLS
+            .iter()
+            .find(|(legacy, _, _)| legacy == self)?;
+
+        // the first successor which is available on the architecture,
+        // e.g. `rename` is emulated by `renameat` on aarch64, and by
+        // `renameat2` on riscv64 (which does not have `renameat`).
+        successors
+            .iter()
+            .find(|successor| successor.is_available_on(arch))
+            .map(|successor| Emulation {
+                legacy: *legacy,
+                successor: *successor,
+                note,
+            })
+    }
+}
+
+// a legacy syscall and its successor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Emulation {
+    pub legacy: PortableSysCall,
+    pub successor: PortableSysCall,
+
+    // how to call the successor, in C-like notation.
+    pub note: &'static str,
+}
+
+// lists all legacy syscalls which have to be emulated by their
+// successors on the specified architecture.
+pub fn legacy_emulations(arch: Arch) -> impl Iterator<Item = Emulation> {
+    LEGACY_SYSCALLS
+        .iter()
+        .filter_map(move |(legacy, _, _)| legacy.emulation_for(arch))
+}
+
+// (legacy syscall, successors in the order of preference, note)
+//
+// ref:
+// - 'include/uapi/asm-generic/unistd.h'
+// - https://man7.org/linux/man-pages/man2/syscalls.2.html
+const LEGACY_SYSCALLS: &[(PortableSysCall, &[PortableSysCall], &str)] = {
+    use PortableSysCall::*;
+    &[
+        (open, &[openat], "openat(AT_FDCWD, path, flags, mode)"),
+        (
+            creat,
+            &[openat],
+            "openat(AT_FDCWD, path, O_CREAT|O_WRONLY|O_TRUNC, mode)",
+        ),
+        (
+            stat,
+            &[newfstatat],
+            "newfstatat(AT_FDCWD, path, statbuf, 0)",
+        ),
+        (
+            lstat,
+            &[newfstatat],
+            "newfstatat(AT_FDCWD, path, statbuf, AT_SYMLINK_NOFOLLOW)",
+        ),
+        (access, &[faccessat], "faccessat(AT_FDCWD, path, mode)"),
+        (pipe, &[pipe2], "pipe2(fds, 0)"),
+        (
+            dup2,
+            &[dup3],
+            "dup3(oldfd, newfd, 0), note that dup3 fails with EINVAL if oldfd == newfd",
+        ),
+        (
+            poll,
+            &[ppoll],
+            "ppoll(fds, nfds, timeout_timespec, NULL, 0)",
+        ),
+        (
+            select,
+            &[pselect6],
+            "pselect6(nfds, readfds, writefds, exceptfds, timeout_timespec, NULL)",
+        ),
+        (fork, &[clone], "clone(SIGCHLD, 0, NULL, NULL, 0)"),
+        (
+            vfork,
+            &[clone],
+            "clone(CLONE_VM|CLONE_VFORK|SIGCHLD, 0, NULL, NULL, 0)",
+        ),
+        (mkdir, &[mkdirat], "mkdirat(AT_FDCWD, path, mode)"),
+        (mknod, &[mknodat], "mknodat(AT_FDCWD, path, mode, dev)"),
+        (rmdir, &[unlinkat], "unlinkat(AT_FDCWD, path, AT_REMOVEDIR)"),
+        (unlink, &[unlinkat], "unlinkat(AT_FDCWD, path, 0)"),
+        (
+            link,
+            &[linkat],
+            "linkat(AT_FDCWD, oldpath, AT_FDCWD, newpath, 0)",
+        ),
+        (
+            symlink,
+            &[symlinkat],
+            "symlinkat(target, AT_FDCWD, linkpath)",
+        ),
+        (
+            readlink,
+            &[readlinkat],
+            "readlinkat(AT_FDCWD, path, buf, size)",
+        ),
+        (
+            rename,
+            &[renameat, renameat2],
+            "renameat(AT_FDCWD, oldpath, AT_FDCWD, newpath), or renameat2(AT_FDCWD, oldpath, AT_FDCWD, newpath, 0) where renameat is not available",
+        ),
+        (
+            renameat,
+            &[renameat2],
+            "renameat2(olddirfd, oldpath, newdirfd, newpath, 0)",
+        ),
+        (chmod, &[fchmodat], "fchmodat(AT_FDCWD, path, mode)"),
+        (
+            chown,
+            &[fchownat],
+            "fchownat(AT_FDCWD, path, owner, group, 0)",
+        ),
+        (
+            lchown,
+            &[fchownat],
+            "fchownat(AT_FDCWD, path, owner, group, AT_SYMLINK_NOFOLLOW)",
+        ),
+        (utime, &[utimensat], "utimensat(AT_FDCWD, path, times, 0)"),
+        (utimes, &[utimensat], "utimensat(AT_FDCWD, path, times, 0)"),
+        (futimesat, &[utimensat], "utimensat(dirfd, path, times, 0)"),
+        (
+            getdents,
+            &[getdents64],
+            "getdents64(fd, dirp, count), note that the layout of the entries is different",
+        ),
+        (epoll_create, &[epoll_create1], "epoll_create1(0)"),
+        (
+            epoll_wait,
+            &[epoll_pwait],
+            "epoll_pwait(epfd, events, maxevents, timeout, NULL, 0)",
+        ),
+        (eventfd, &[eventfd2], "eventfd2(initval, 0)"),
+        (signalfd, &[signalfd4], "signalfd4(fd, mask, sizemask, 0)"),
+        (inotify_init, &[inotify_init1], "inotify_init1(0)"),
+        (
+            alarm,
+            &[setitimer],
+            "setitimer(ITIMER_REAL, new_value, old_value)",
+        ),
+        (
+            pause,
+            &[rt_sigsuspend],
+            "rt_sigsuspend(current_mask, sizeof(sigset_t))",
+        ),
+        (time, &[clock_gettime], "clock_gettime(CLOCK_REALTIME, tp)"),
+        (getpgrp, &[getpgid], "getpgid(0)"),
+    ]
+};
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        arch::{aarch64, riscv64, x86_64},
+        portable::{legacy_emulations, Arch, PortableSysCall},
+    };
+
+    #[test]
+    fn test_resolve_syscall_number() {
+        assert_eq!(PortableSysCall::read.number_for(Arch::X86_64), Some(0));
+        assert_eq!(PortableSysCall::read.number_for(Arch::Aarch64), Some(63));
+        assert_eq!(PortableSysCall::read.number_for(Arch::Riscv64), Some(63));
+
+        assert_eq!(PortableSysCall::open.number_for(Arch::X86_64), Some(2));
+        assert_eq!(PortableSysCall::open.number_for(Arch::Aarch64), None);
+        assert_eq!(PortableSysCall::open.number_for(Arch::Riscv64), None);
+
+        assert_eq!(
+            PortableSysCall::renameat.number_for(Arch::Aarch64),
+            Some(38)
+        );
+        assert_eq!(PortableSysCall::renameat.number_for(Arch::Riscv64), None);
+
+        assert_eq!(
+            PortableSysCall::riscv_flush_icache.number_for(Arch::Riscv64),
+            Some(259)
+        );
+        assert_eq!(
+            PortableSysCall::riscv_flush_icache.number_for(Arch::X86_64),
+            None
+        );
+
+        assert_eq!(
+            PortableSysCall::getpid.number(),
+            Some(crate::number::SysCallNum::getpid as usize)
+        );
+
+        // every syscall is available on at least one architecture
+        for syscall in PortableSysCall::iter() {
+            assert!(Arch::ALL.iter().any(|arch| syscall.is_available_on(*arch)));
+        }
+    }
+
+    #[test]
+    fn test_generated_table() {
+        // the generated table agrees with the syscall number tables of
+        // each architecture, i.e. 'src/portable/table.rs' is up to date.
+        for syscall in PortableSysCall::iter() {
+            let name = syscall.name();
+            assert_eq!(
+                syscall.number_for(Arch::X86_64),
+                x86_64::number::SysCallNum::from_str(name)
+                    .ok()
+                    .map(|num| num as usize)
+            );
+            assert_eq!(
+                syscall.number_for(Arch::Aarch64),
+                aarch64::number::SysCallNum::from_str(name)
+                    .ok()
+                    .map(|num| num as usize)
+            );
+            assert_eq!(
+                syscall.number_for(Arch::Riscv64),
+                riscv64::number::SysCallNum::from_str(name)
+                    .ok()
+                    .map(|num| num as usize)
+            );
+        }
+
+        // and it contains all syscalls of each architecture, the reverse
+        // mapping agrees with the names
+        for num in x86_64::number::SysCallNum::iter() {
+            let syscall = PortableSysCall::from_name(num.name());
+            assert!(syscall.is_some());
+            assert_eq!(
+                PortableSysCall::from_number_for(Arch::X86_64, num as usize),
+                syscall
+            );
+        }
+        for num in aarch64::number::SysCallNum::iter() {
+            let syscall = PortableSysCall::from_name(num.name());
+            assert!(syscall.is_some());
+            assert_eq!(
+                PortableSysCall::from_number_for(Arch::Aarch64, num as usize),
+                syscall
+            );
+        }
+        for num in riscv64::number::SysCallNum::iter() {
+            let syscall = PortableSysCall::from_name(num.name());
+            assert!(syscall.is_some());
+            assert_eq!(
+                PortableSysCall::from_number_for(Arch::Riscv64, num as usize),
+                syscall
+            );
+        }
+
+        assert_eq!(PortableSysCall::from_number_for(Arch::X86_64, 9999), None);
+
+        // the renamed syscalls keep the names of the kernel syscall table
+        assert_eq!(PortableSysCall::sysctl_.name(), "_sysctl");
+        assert_eq!(
+            PortableSysCall::from_name("_sysctl"),
+            Some(PortableSysCall::sysctl_)
+        );
+        assert_eq!(
+            PortableSysCall::from_number(crate::number::SysCallNum::getpid as usize),
+            Some(PortableSysCall::getpid)
+        );
+    }
+
+    #[test]
+    fn test_legacy_emulation() {
+        // x86_64 has all legacy syscalls
+        assert_eq!(legacy_emulations(Arch::X86_64).count(), 0);
+        assert_eq!(PortableSysCall::open.emulation_for(Arch::X86_64), None);
+
+        let e0 = PortableSysCall::open.emulation_for(Arch::Aarch64).unwrap();
+        assert_eq!(e0.legacy, PortableSysCall::open);
+        assert_eq!(e0.successor, PortableSysCall::openat);
+
+        assert_eq!(
+            PortableSysCall::dup2
+                .emulation_for(Arch::Riscv64)
+                .map(|e| e.successor),
+            Some(PortableSysCall::dup3)
+        );
+        assert_eq!(
+            PortableSysCall::fork
+                .emulation_for(Arch::Aarch64)
+                .map(|e| e.successor),
+            Some(PortableSysCall::clone)
+        );
+
+        // `rename` is emulated by `renameat` on aarch64 but by
+        // `renameat2` on riscv64.
+        assert_eq!(
+            PortableSysCall::rename
+                .emulation_for(Arch::Aarch64)
+                .map(|e| e.successor),
+            Some(PortableSysCall::renameat)
+        );
+        assert_eq!(
+            PortableSysCall::rename
+                .emulation_for(Arch::Riscv64)
+                .map(|e| e.successor),
+            Some(PortableSysCall::renameat2)
+        );
+        // and the note covers both of the successors
+        let note = PortableSysCall::rename
+            .emulation_for(Arch::Aarch64)
+            .unwrap()
+            .note;
+        assert!(note.contains("renameat(") && note.contains("renameat2("));
+        assert_eq!(PortableSysCall::renameat.emulation_for(Arch::Aarch64), None);
+
+        // the successors are always available
+        for arch in Arch::ALL {
+            for emulation in legacy_emulations(arch) {
+                assert!(!emulation.legacy.is_available_on(arch));
+                assert!(emulation.successor.is_available_on(arch));
+            }
+        }
+
+        let names = legacy_emulations(Arch::Aarch64)
+            .map(|e| e.legacy.name())
+            .collect::<Vec<_>>();
+        for name in ["open", "stat", "fork", "pipe", "dup2", "poll", "select"] {
+            assert!(names.contains(&name));
+        }
+    }
+}
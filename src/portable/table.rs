@@ -0,0 +1,1440 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// THIS FILE IS GENERATED BY 'examples/gen_portable_syscall.rs', DO NOT EDIT.
+//
+// the union of the syscall names of x86_64, aarch64 and riscv64, and the
+// syscall numbers in the order of `[x86_64, aarch64, riscv64]` (i.e. the
+// order of `Arch::ALL`).
+//
+// to regenerate this file:
+//
+// ```sh
+// $ cargo run --example gen_portable_syscall > src/portable/table.rs
+// ```
+//
+// the macro `portable_syscall!` generates the enum `PortableSysCall` as well
+// as the functions for looking up syscall numbers, and the macro
+// `portable_syscall_from_number!` generates the reverse lookup, see
+// 'src/portable.rs'.
+
+portable_syscall! {
+    read = [Some(0), Some(63), Some(63)],
+    write = [Some(1), Some(64), Some(64)],
+    open = [Some(2), None, None],
+    close = [Some(3), Some(57), Some(57)],
+    stat = [Some(4), None, None],
+    fstat = [Some(5), Some(80), Some(80)],
+    lstat = [Some(6), None, None],
+    poll = [Some(7), None, None],
+    lseek = [Some(8), Some(62), Some(62)],
+    mmap = [Some(9), Some(222), Some(222)],
+    mprotect = [Some(10), Some(226), Some(226)],
+    munmap = [Some(11), Some(215), Some(215)],
+    brk = [Some(12), Some(214), Some(214)],
+    rt_sigaction = [Some(13), Some(134), Some(134)],
+    rt_sigprocmask = [Some(14), Some(135), Some(135)],
+    rt_sigreturn = [Some(15), Some(139), Some(139)],
+    ioctl = [Some(16), Some(29), Some(29)],
+    pread64 = [Some(17), Some(67), Some(67)],
+    pwrite64 = [Some(18), Some(68), Some(68)],
+    readv = [Some(19), Some(65), Some(65)],
+    writev = [Some(20), Some(66), Some(66)],
+    access = [Some(21), None, None],
+    pipe = [Some(22), None, None],
+    select = [Some(23), None, None],
+    sched_yield = [Some(24), Some(124), Some(124)],
+    mremap = [Some(25), Some(216), Some(216)],
+    msync = [Some(26), Some(227), Some(227)],
+    mincore = [Some(27), Some(232), Some(232)],
+    madvise = [Some(28), Some(233), Some(233)],
+    shmget = [Some(29), Some(194), Some(194)],
+    shmat = [Some(30), Some(196), Some(196)],
+    shmctl = [Some(31), Some(195), Some(195)],
+    dup = [Some(32), Some(23), Some(23)],
+    dup2 = [Some(33), None, None],
+    pause = [Some(34), None, None],
+    nanosleep = [Some(35), Some(101), Some(101)],
+    getitimer = [Some(36), Some(102), Some(102)],
+    alarm = [Some(37), None, None],
+    setitimer = [Some(38), Some(103), Some(103)],
+    getpid = [Some(39), Some(172), Some(172)],
+    sendfile = [Some(40), Some(71), Some(71)],
+    socket = [Some(41), Some(198), Some(198)],
+    connect = [Some(42), Some(203), Some(203)],
+    accept = [Some(43), Some(202), Some(202)],
+    sendto = [Some(44), Some(206), Some(206)],
+    recvfrom = [Some(45), Some(207), Some(207)],
+    sendmsg = [Some(46), Some(211), Some(211)],
+    recvmsg = [Some(47), Some(212), Some(212)],
+    shutdown = [Some(48), Some(210), Some(210)],
+    bind = [Some(49), Some(200), Some(200)],
+    listen = [Some(50), Some(201), Some(201)],
+    getsockname = [Some(51), Some(204), Some(204)],
+    getpeername = [Some(52), Some(205), Some(205)],
+    socketpair = [Some(53), Some(199), Some(199)],
+    setsockopt = [Some(54), Some(208), Some(208)],
+    getsockopt = [Some(55), Some(209), Some(209)],
+    clone = [Some(56), Some(220), Some(220)],
+    fork = [Some(57), None, None],
+    vfork = [Some(58), None, None],
+    execve = [Some(59), Some(221), Some(221)],
+    exit = [Some(60), Some(93), Some(93)],
+    wait4 = [Some(61), Some(260), Some(260)],
+    kill = [Some(62), Some(129), Some(129)],
+    uname = [Some(63), Some(160), Some(160)],
+    semget = [Some(64), Some(190), Some(190)],
+    semop = [Some(65), Some(193), Some(193)],
+    semctl = [Some(66), Some(191), Some(191)],
+    shmdt = [Some(67), Some(197), Some(197)],
+    msgget = [Some(68), Some(186), Some(186)],
+    msgsnd = [Some(69), Some(189), Some(189)],
+    msgrcv = [Some(70), Some(188), Some(188)],
+    msgctl = [Some(71), Some(187), Some(187)],
+    fcntl = [Some(72), Some(25), Some(25)],
+    flock = [Some(73), Some(32), Some(32)],
+    fsync = [Some(74), Some(82), Some(82)],
+    fdatasync = [Some(75), Some(83), Some(83)],
+    truncate = [Some(76), Some(45), Some(45)],
+    ftruncate = [Some(77), Some(46), Some(46)],
+    getdents = [Some(78), None, None],
+    getcwd = [Some(79), Some(17), Some(17)],
+    chdir = [Some(80), Some(49), Some(49)],
+    fchdir = [Some(81), Some(50), Some(50)],
+    rename = [Some(82), None, None],
+    mkdir = [Some(83), None, None],
+    rmdir = [Some(84), None, None],
+    creat = [Some(85), None, None],
+    link = [Some(86), None, None],
+    unlink = [Some(87), None, None],
+    symlink = [Some(88), None, None],
+    readlink = [Some(89), None, None],
+    chmod = [Some(90), None, None],
+    fchmod = [Some(91), Some(52), Some(52)],
+    chown = [Some(92), None, None],
+    fchown = [Some(93), Some(55), Some(55)],
+    lchown = [Some(94), None, None],
+    umask = [Some(95), Some(166), Some(166)],
+    gettimeofday = [Some(96), Some(169), Some(169)],
+    getrlimit = [Some(97), Some(163), Some(163)],
+    getrusage = [Some(98), Some(165), Some(165)],
+    sysinfo = [Some(99), Some(179), Some(179)],
+    times = [Some(100), Some(153), Some(153)],
+    ptrace = [Some(101), Some(117), Some(117)],
+    getuid = [Some(102), Some(174), Some(174)],
+    syslog = [Some(103), Some(116), Some(116)],
+    getgid = [Some(104), Some(176), Some(176)],
+    setuid = [Some(105), Some(146), Some(146)],
+    setgid = [Some(106), Some(144), Some(144)],
+    geteuid = [Some(107), Some(175), Some(175)],
+    getegid = [Some(108), Some(177), Some(177)],
+    setpgid = [Some(109), Some(154), Some(154)],
+    getppid = [Some(110), Some(173), Some(173)],
+    getpgrp = [Some(111), None, None],
+    setsid = [Some(112), Some(157), Some(157)],
+    setreuid = [Some(113), Some(145), Some(145)],
+    setregid = [Some(114), Some(143), Some(143)],
+    getgroups = [Some(115), Some(158), Some(158)],
+    setgroups = [Some(116), Some(159), Some(159)],
+    setresuid = [Some(117), Some(147), Some(147)],
+    getresuid = [Some(118), Some(148), Some(148)],
+    setresgid = [Some(119), Some(149), Some(149)],
+    getresgid = [Some(120), Some(150), Some(150)],
+    getpgid = [Some(121), Some(155), Some(155)],
+    setfsuid = [Some(122), Some(151), Some(151)],
+    setfsgid = [Some(123), Some(152), Some(152)],
+    getsid = [Some(124), Some(156), Some(156)],
+    capget = [Some(125), Some(90), Some(90)],
+    capset = [Some(126), Some(91), Some(91)],
+    rt_sigpending = [Some(127), Some(136), Some(136)],
+    rt_sigtimedwait = [Some(128), Some(137), Some(137)],
+    rt_sigqueueinfo = [Some(129), Some(138), Some(138)],
+    rt_sigsuspend = [Some(130), Some(133), Some(133)],
+    sigaltstack = [Some(131), Some(132), Some(132)],
+    utime = [Some(132), None, None],
+    mknod = [Some(133), None, None],
+    uselib = [Some(134), None, None],
+    personality = [Some(135), Some(92), Some(92)],
+    ustat = [Some(136), None, None],
+    statfs = [Some(137), Some(43), Some(43)],
+    fstatfs = [Some(138), Some(44), Some(44)],
+    sysfs = [Some(139), None, None],
+    getpriority = [Some(140), Some(141), Some(141)],
+    setpriority = [Some(141), Some(140), Some(140)],
+    sched_setparam = [Some(142), Some(118), Some(118)],
+    sched_getparam = [Some(143), Some(121), Some(121)],
+    sched_setscheduler = [Some(144), Some(119), Some(119)],
+    sched_getscheduler = [Some(145), Some(120), Some(120)],
+    sched_get_priority_max = [Some(146), Some(125), Some(125)],
+    sched_get_priority_min = [Some(147), Some(126), Some(126)],
+    sched_rr_get_interval = [Some(148), Some(127), Some(127)],
+    mlock = [Some(149), Some(228), Some(228)],
+    munlock = [Some(150), Some(229), Some(229)],
+    mlockall = [Some(151), Some(230), Some(230)],
+    munlockall = [Some(152), Some(231), Some(231)],
+    vhangup = [Some(153), Some(58), Some(58)],
+    modify_ldt = [Some(154), None, None],
+    pivot_root = [Some(155), Some(41), Some(41)],
+    #[name = "_sysctl"]
+    sysctl_ = [Some(156), None, None],
+    prctl = [Some(157), Some(167), Some(167)],
+    arch_prctl = [Some(158), None, None],
+    adjtimex = [Some(159), Some(171), Some(171)],
+    setrlimit = [Some(160), Some(164), Some(164)],
+    chroot = [Some(161), Some(51), Some(51)],
+    sync = [Some(162), Some(81), Some(81)],
+    acct = [Some(163), Some(89), Some(89)],
+    settimeofday = [Some(164), Some(170), Some(170)],
+    mount = [Some(165), Some(40), Some(40)],
+    umount2 = [Some(166), Some(39), Some(39)],
+    swapon = [Some(167), Some(224), Some(224)],
+    swapoff = [Some(168), Some(225), Some(225)],
+    reboot = [Some(169), Some(142), Some(142)],
+    sethostname = [Some(170), Some(161), Some(161)],
+    setdomainname = [Some(171), Some(162), Some(162)],
+    iopl = [Some(172), None, None],
+    ioperm = [Some(173), None, None],
+    create_module = [Some(174), None, None],
+    init_module = [Some(175), Some(105), Some(105)],
+    delete_module = [Some(176), Some(106), Some(106)],
+    get_kernel_syms = [Some(177), None, None],
+    query_module = [Some(178), None, None],
+    quotactl = [Some(179), Some(60), Some(60)],
+    nfsservctl = [Some(180), Some(42), Some(42)],
+    getpmsg = [Some(181), None, None],
+    putpmsg = [Some(182), None, None],
+    afs_syscall = [Some(183), None, None],
+    tuxcall = [Some(184), None, None],
+    security = [Some(185), None, None],
+    gettid = [Some(186), Some(178), Some(178)],
+    readahead = [Some(187), Some(213), Some(213)],
+    setxattr = [Some(188), Some(5), Some(5)],
+    lsetxattr = [Some(189), Some(6), Some(6)],
+    fsetxattr = [Some(190), Some(7), Some(7)],
+    getxattr = [Some(191), Some(8), Some(8)],
+    lgetxattr = [Some(192), Some(9), Some(9)],
+    fgetxattr = [Some(193), Some(10), Some(10)],
+    listxattr = [Some(194), Some(11), Some(11)],
+    llistxattr = [Some(195), Some(12), Some(12)],
+    flistxattr = [Some(196), Some(13), Some(13)],
+    removexattr = [Some(197), Some(14), Some(14)],
+    lremovexattr = [Some(198), Some(15), Some(15)],
+    fremovexattr = [Some(199), Some(16), Some(16)],
+    tkill = [Some(200), Some(130), Some(130)],
+    time = [Some(201), None, None],
+    futex = [Some(202), Some(98), Some(98)],
+    sched_setaffinity = [Some(203), Some(122), Some(122)],
+    sched_getaffinity = [Some(204), Some(123), Some(123)],
+    set_thread_area = [Some(205), None, None],
+    io_setup = [Some(206), Some(0), Some(0)],
+    io_destroy = [Some(207), Some(1), Some(1)],
+    io_getevents = [Some(208), Some(4), Some(4)],
+    io_submit = [Some(209), Some(2), Some(2)],
+    io_cancel = [Some(210), Some(3), Some(3)],
+    get_thread_area = [Some(211), None, None],
+    lookup_dcookie = [Some(212), Some(18), Some(18)],
+    epoll_create = [Some(213), None, None],
+    epoll_ctl_old = [Some(214), None, None],
+    epoll_wait_old = [Some(215), None, None],
+    remap_file_pages = [Some(216), Some(234), Some(234)],
+    getdents64 = [Some(217), Some(61), Some(61)],
+    set_tid_address = [Some(218), Some(96), Some(96)],
+    restart_syscall = [Some(219), Some(128), Some(128)],
+    semtimedop = [Some(220), Some(192), Some(192)],
+    fadvise64 = [Some(221), Some(223), Some(223)],
+    timer_create = [Some(222), Some(107), Some(107)],
+    timer_settime = [Some(223), Some(110), Some(110)],
+    timer_gettime = [Some(224), Some(108), Some(108)],
+    timer_getoverrun = [Some(225), Some(109), Some(109)],
+    timer_delete = [Some(226), Some(111), Some(111)],
+    clock_settime = [Some(227), Some(112), Some(112)],
+    clock_gettime = [Some(228), Some(113), Some(113)],
+    clock_getres = [Some(229), Some(114), Some(114)],
+    clock_nanosleep = [Some(230), Some(115), Some(115)],
+    exit_group = [Some(231), Some(94), Some(94)],
+    epoll_wait = [Some(232), None, None],
+    epoll_ctl = [Some(233), Some(21), Some(21)],
+    tgkill = [Some(234), Some(131), Some(131)],
+    utimes = [Some(235), None, None],
+    vserver = [Some(236), None, None],
+    mbind = [Some(237), Some(235), Some(235)],
+    set_mempolicy = [Some(238), Some(237), Some(237)],
+    get_mempolicy = [Some(239), Some(236), Some(236)],
+    mq_open = [Some(240), Some(180), Some(180)],
+    mq_unlink = [Some(241), Some(181), Some(181)],
+    mq_timedsend = [Some(242), Some(182), Some(182)],
+    mq_timedreceive = [Some(243), Some(183), Some(183)],
+    mq_notify = [Some(244), Some(184), Some(184)],
+    mq_getsetattr = [Some(245), Some(185), Some(185)],
+    kexec_load = [Some(246), Some(104), Some(104)],
+    waitid = [Some(247), Some(95), Some(95)],
+    add_key = [Some(248), Some(217), Some(217)],
+    request_key = [Some(249), Some(218), Some(218)],
+    keyctl = [Some(250), Some(219), Some(219)],
+    ioprio_set = [Some(251), Some(30), Some(30)],
+    ioprio_get = [Some(252), Some(31), Some(31)],
+    inotify_init = [Some(253), None, None],
+    inotify_add_watch = [Some(254), Some(27), Some(27)],
+    inotify_rm_watch = [Some(255), Some(28), Some(28)],
+    migrate_pages = [Some(256), Some(238), Some(238)],
+    openat = [Some(257), Some(56), Some(56)],
+    mkdirat = [Some(258), Some(34), Some(34)],
+    mknodat = [Some(259), Some(33), Some(33)],
+    fchownat = [Some(260), Some(54), Some(54)],
+    futimesat = [Some(261), None, None],
+    newfstatat = [Some(262), Some(79), Some(79)],
+    unlinkat = [Some(263), Some(35), Some(35)],
+    renameat = [Some(264), Some(38), None],
+    linkat = [Some(265), Some(37), Some(37)],
+    symlinkat = [Some(266), Some(36), Some(36)],
+    readlinkat = [Some(267), Some(78), Some(78)],
+    fchmodat = [Some(268), Some(53), Some(53)],
+    faccessat = [Some(269), Some(48), Some(48)],
+    pselect6 = [Some(270), Some(72), Some(72)],
+    ppoll = [Some(271), Some(73), Some(73)],
+    unshare = [Some(272), Some(97), Some(97)],
+    set_robust_list = [Some(273), Some(99), Some(99)],
+    get_robust_list = [Some(274), Some(100), Some(100)],
+    splice = [Some(275), Some(76), Some(76)],
+    tee = [Some(276), Some(77), Some(77)],
+    sync_file_range = [Some(277), Some(84), Some(84)],
+    vmsplice = [Some(278), Some(75), Some(75)],
+    move_pages = [Some(279), Some(239), Some(239)],
+    utimensat = [Some(280), Some(88), Some(88)],
+    epoll_pwait = [Some(281), Some(22), Some(22)],
+    signalfd = [Some(282), None, None],
+    timerfd_create = [Some(283), Some(85), Some(85)],
+    eventfd = [Some(284), None, None],
+    fallocate = [Some(285), Some(47), Some(47)],
+    timerfd_settime = [Some(286), Some(86), Some(86)],
+    timerfd_gettime = [Some(287), Some(87), Some(87)],
+    accept4 = [Some(288), Some(242), Some(242)],
+    signalfd4 = [Some(289), Some(74), Some(74)],
+    eventfd2 = [Some(290), Some(19), Some(19)],
+    epoll_create1 = [Some(291), Some(20), Some(20)],
+    dup3 = [Some(292), Some(24), Some(24)],
+    pipe2 = [Some(293), Some(59), Some(59)],
+    inotify_init1 = [Some(294), Some(26), Some(26)],
+    preadv = [Some(295), Some(69), Some(69)],
+    pwritev = [Some(296), Some(70), Some(70)],
+    rt_tgsigqueueinfo = [Some(297), Some(240), Some(240)],
+    perf_event_open = [Some(298), Some(241), Some(241)],
+    recvmmsg = [Some(299), Some(243), Some(243)],
+    fanotify_init = [Some(300), Some(262), Some(262)],
+    fanotify_mark = [Some(301), Some(263), Some(263)],
+    prlimit64 = [Some(302), Some(261), Some(261)],
+    name_to_handle_at = [Some(303), Some(264), Some(264)],
+    open_by_handle_at = [Some(304), Some(265), Some(265)],
+    clock_adjtime = [Some(305), Some(266), Some(266)],
+    syncfs = [Some(306), Some(267), Some(267)],
+    sendmmsg = [Some(307), Some(269), Some(269)],
+    setns = [Some(308), Some(268), Some(268)],
+    getcpu = [Some(309), Some(168), Some(168)],
+    process_vm_readv = [Some(310), Some(270), Some(270)],
+    process_vm_writev = [Some(311), Some(271), Some(271)],
+    kcmp = [Some(312), Some(272), Some(272)],
+    finit_module = [Some(313), Some(273), Some(273)],
+    sched_setattr = [Some(314), Some(274), Some(274)],
+    sched_getattr = [Some(315), Some(275), Some(275)],
+    renameat2 = [Some(316), Some(276), Some(276)],
+    seccomp = [Some(317), Some(277), Some(277)],
+    getrandom = [Some(318), Some(278), Some(278)],
+    memfd_create = [Some(319), Some(279), Some(279)],
+    kexec_file_load = [Some(320), Some(294), Some(294)],
+    bpf = [Some(321), Some(280), Some(280)],
+    execveat = [Some(322), Some(281), Some(281)],
+    userfaultfd = [Some(323), Some(282), Some(282)],
+    membarrier = [Some(324), Some(283), Some(283)],
+    mlock2 = [Some(325), Some(284), Some(284)],
+    copy_file_range = [Some(326), Some(285), Some(285)],
+    preadv2 = [Some(327), Some(286), Some(286)],
+    pwritev2 = [Some(328), Some(287), Some(287)],
+    pkey_mprotect = [Some(329), Some(288), Some(288)],
+    pkey_alloc = [Some(330), Some(289), Some(289)],
+    pkey_free = [Some(331), Some(290), Some(290)],
+    statx = [Some(332), Some(291), Some(291)],
+    io_pgetevents = [Some(333), Some(292), Some(292)],
+    rseq = [Some(334), Some(293), Some(293)],
+    uretprobe = [Some(335), None, None],
+    pidfd_send_signal = [Some(424), Some(424), Some(424)],
+    io_uring_setup = [Some(425), Some(425), Some(425)],
+    io_uring_enter = [Some(426), Some(426), Some(426)],
+    io_uring_register = [Some(427), Some(427), Some(427)],
+    open_tree = [Some(428), Some(428), Some(428)],
+    move_mount = [Some(429), Some(429), Some(429)],
+    fsopen = [Some(430), Some(430), Some(430)],
+    fsconfig = [Some(431), Some(431), Some(431)],
+    fsmount = [Some(432), Some(432), Some(432)],
+    fspick = [Some(433), Some(433), Some(433)],
+    pidfd_open = [Some(434), Some(434), Some(434)],
+    clone3 = [Some(435), Some(435), Some(435)],
+    close_range = [Some(436), Some(436), Some(436)],
+    openat2 = [Some(437), Some(437), Some(437)],
+    pidfd_getfd = [Some(438), Some(438), Some(438)],
+    faccessat2 = [Some(439), Some(439), Some(439)],
+    process_madvise = [Some(440), Some(440), Some(440)],
+    epoll_pwait2 = [Some(441), Some(441), Some(441)],
+    mount_setattr = [Some(442), Some(442), Some(442)],
+    quotactl_fd = [Some(443), Some(443), Some(443)],
+    landlock_create_ruleset = [Some(444), Some(444), Some(444)],
+    landlock_add_rule = [Some(445), Some(445), Some(445)],
+    landlock_restrict_self = [Some(446), Some(446), Some(446)],
+    memfd_secret = [Some(447), Some(447), Some(447)],
+    process_mrelease = [Some(448), Some(448), Some(448)],
+    futex_waitv = [Some(449), Some(449), Some(449)],
+    set_mempolicy_home_node = [Some(450), Some(450), Some(450)],
+    cachestat = [Some(451), Some(451), Some(451)],
+    fchmodat2 = [Some(452), Some(452), Some(452)],
+    map_shadow_stack = [Some(453), Some(453), Some(453)],
+    futex_wake = [Some(454), Some(454), Some(454)],
+    futex_wait = [Some(455), Some(455), Some(455)],
+    futex_requeue = [Some(456), Some(456), Some(456)],
+    statmount = [Some(457), Some(457), Some(457)],
+    listmount = [Some(458), Some(458), Some(458)],
+    lsm_get_self_attr = [Some(459), Some(459), Some(459)],
+    lsm_set_self_attr = [Some(460), Some(460), Some(460)],
+    lsm_list_modules = [Some(461), Some(461), Some(461)],
+    mseal = [Some(462), Some(462), Some(462)],
+    setxattrat = [Some(463), Some(463), Some(463)],
+    getxattrat = [Some(464), Some(464), Some(464)],
+    listxattrat = [Some(465), Some(465), Some(465)],
+    removexattrat = [Some(466), Some(466), Some(466)],
+    riscv_hwprobe = [None, None, Some(258)],
+    riscv_flush_icache = [None, None, Some(259)],
+}
+
+portable_syscall_from_number! {
+    X86_64 {
+        0 => read,
+        1 => write,
+        2 => open,
+        3 => close,
+        4 => stat,
+        5 => fstat,
+        6 => lstat,
+        7 => poll,
+        8 => lseek,
+        9 => mmap,
+        10 => mprotect,
+        11 => munmap,
+        12 => brk,
+        13 => rt_sigaction,
+        14 => rt_sigprocmask,
+        15 => rt_sigreturn,
+        16 => ioctl,
+        17 => pread64,
+        18 => pwrite64,
+        19 => readv,
+        20 => writev,
+        21 => access,
+        22 => pipe,
+        23 => select,
+        24 => sched_yield,
+        25 => mremap,
+        26 => msync,
+        27 => mincore,
+        28 => madvise,
+        29 => shmget,
+        30 => shmat,
+        31 => shmctl,
+        32 => dup,
+        33 => dup2,
+        34 => pause,
+        35 => nanosleep,
+        36 => getitimer,
+        37 => alarm,
+        38 => setitimer,
+        39 => getpid,
+        40 => sendfile,
+        41 => socket,
+        42 => connect,
+        43 => accept,
+        44 => sendto,
+        45 => recvfrom,
+        46 => sendmsg,
+        47 => recvmsg,
+        48 => shutdown,
+        49 => bind,
+        50 => listen,
+        51 => getsockname,
+        52 => getpeername,
+        53 => socketpair,
+        54 => setsockopt,
+        55 => getsockopt,
+        56 => clone,
+        57 => fork,
+        58 => vfork,
+        59 => execve,
+        60 => exit,
+        61 => wait4,
+        62 => kill,
+        63 => uname,
+        64 => semget,
+        65 => semop,
+        66 => semctl,
+        67 => shmdt,
+        68 => msgget,
+        69 => msgsnd,
+        70 => msgrcv,
+        71 => msgctl,
+        72 => fcntl,
+        73 => flock,
+        74 => fsync,
+        75 => fdatasync,
+        76 => truncate,
+        77 => ftruncate,
+        78 => getdents,
+        79 => getcwd,
+        80 => chdir,
+        81 => fchdir,
+        82 => rename,
+        83 => mkdir,
+        84 => rmdir,
+        85 => creat,
+        86 => link,
+        87 => unlink,
+        88 => symlink,
+        89 => readlink,
+        90 => chmod,
+        91 => fchmod,
+        92 => chown,
+        93 => fchown,
+        94 => lchown,
+        95 => umask,
+        96 => gettimeofday,
+        97 => getrlimit,
+        98 => getrusage,
+        99 => sysinfo,
+        100 => times,
+        101 => ptrace,
+        102 => getuid,
+        103 => syslog,
+        104 => getgid,
+        105 => setuid,
+        106 => setgid,
+        107 => geteuid,
+        108 => getegid,
+        109 => setpgid,
+        110 => getppid,
+        111 => getpgrp,
+        112 => setsid,
+        113 => setreuid,
+        114 => setregid,
+        115 => getgroups,
+        116 => setgroups,
+        117 => setresuid,
+        118 => getresuid,
+        119 => setresgid,
+        120 => getresgid,
+        121 => getpgid,
+        122 => setfsuid,
+        123 => setfsgid,
+        124 => getsid,
+        125 => capget,
+        126 => capset,
+        127 => rt_sigpending,
+        128 => rt_sigtimedwait,
+        129 => rt_sigqueueinfo,
+        130 => rt_sigsuspend,
+        131 => sigaltstack,
+        132 => utime,
+        133 => mknod,
+        134 => uselib,
+        135 => personality,
+        136 => ustat,
+        137 => statfs,
+        138 => fstatfs,
+        139 => sysfs,
+        140 => getpriority,
+        141 => setpriority,
+        142 => sched_setparam,
+        143 => sched_getparam,
+        144 => sched_setscheduler,
+        145 => sched_getscheduler,
+        146 => sched_get_priority_max,
+        147 => sched_get_priority_min,
+        148 => sched_rr_get_interval,
+        149 => mlock,
+        150 => munlock,
+        151 => mlockall,
+        152 => munlockall,
+        153 => vhangup,
+        154 => modify_ldt,
+        155 => pivot_root,
+        156 => sysctl_,
+        157 => prctl,
+        158 => arch_prctl,
+        159 => adjtimex,
+        160 => setrlimit,
+        161 => chroot,
+        162 => sync,
+        163 => acct,
+        164 => settimeofday,
+        165 => mount,
+        166 => umount2,
+        167 => swapon,
+        168 => swapoff,
+        169 => reboot,
+        170 => sethostname,
+        171 => setdomainname,
+        172 => iopl,
+        173 => ioperm,
+        174 => create_module,
+        175 => init_module,
+        176 => delete_module,
+        177 => get_kernel_syms,
+        178 => query_module,
+        179 => quotactl,
+        180 => nfsservctl,
+        181 => getpmsg,
+        182 => putpmsg,
+        183 => afs_syscall,
+        184 => tuxcall,
+        185 => security,
+        186 => gettid,
+        187 => readahead,
+        188 => setxattr,
+        189 => lsetxattr,
+        190 => fsetxattr,
+        191 => getxattr,
+        192 => lgetxattr,
+        193 => fgetxattr,
+        194 => listxattr,
+        195 => llistxattr,
+        196 => flistxattr,
+        197 => removexattr,
+        198 => lremovexattr,
+        199 => fremovexattr,
+        200 => tkill,
+        201 => time,
+        202 => futex,
+        203 => sched_setaffinity,
+        204 => sched_getaffinity,
+        205 => set_thread_area,
+        206 => io_setup,
+        207 => io_destroy,
+        208 => io_getevents,
+        209 => io_submit,
+        210 => io_cancel,
+        211 => get_thread_area,
+        212 => lookup_dcookie,
+        213 => epoll_create,
+        214 => epoll_ctl_old,
+        215 => epoll_wait_old,
+        216 => remap_file_pages,
+        217 => getdents64,
+        218 => set_tid_address,
+        219 => restart_syscall,
+        220 => semtimedop,
+        221 => fadvise64,
+        222 => timer_create,
+        223 => timer_settime,
+        224 => timer_gettime,
+        225 => timer_getoverrun,
+        226 => timer_delete,
+        227 => clock_settime,
+        228 => clock_gettime,
+        229 => clock_getres,
+        230 => clock_nanosleep,
+        231 => exit_group,
+        232 => epoll_wait,
+        233 => epoll_ctl,
+        234 => tgkill,
+        235 => utimes,
+        236 => vserver,
+        237 => mbind,
+        238 => set_mempolicy,
+        239 => get_mempolicy,
+        240 => mq_open,
+        241 => mq_unlink,
+        242 => mq_timedsend,
+        243 => mq_timedreceive,
+        244 => mq_notify,
+        245 => mq_getsetattr,
+        246 => kexec_load,
+        247 => waitid,
+        248 => add_key,
+        249 => request_key,
+        250 => keyctl,
+        251 => ioprio_set,
+        252 => ioprio_get,
+        253 => inotify_init,
+        254 => inotify_add_watch,
+        255 => inotify_rm_watch,
+        256 => migrate_pages,
+        257 => openat,
+        258 => mkdirat,
+        259 => mknodat,
+        260 => fchownat,
+        261 => futimesat,
+        262 => newfstatat,
+        263 => unlinkat,
+        264 => renameat,
+        265 => linkat,
+        266 => symlinkat,
+        267 => readlinkat,
+        268 => fchmodat,
+        269 => faccessat,
+        270 => pselect6,
+        271 => ppoll,
+        272 => unshare,
+        273 => set_robust_list,
+        274 => get_robust_list,
+        275 => splice,
+        276 => tee,
+        277 => sync_file_range,
+        278 => vmsplice,
+        279 => move_pages,
+        280 => utimensat,
+        281 => epoll_pwait,
+        282 => signalfd,
+        283 => timerfd_create,
+        284 => eventfd,
+        285 => fallocate,
+        286 => timerfd_settime,
+        287 => timerfd_gettime,
+        288 => accept4,
+        289 => signalfd4,
+        290 => eventfd2,
+        291 => epoll_create1,
+        292 => dup3,
+        293 => pipe2,
+        294 => inotify_init1,
+        295 => preadv,
+        296 => pwritev,
+        297 => rt_tgsigqueueinfo,
+        298 => perf_event_open,
+        299 => recvmmsg,
+        300 => fanotify_init,
+        301 => fanotify_mark,
+        302 => prlimit64,
+        303 => name_to_handle_at,
+        304 => open_by_handle_at,
+        305 => clock_adjtime,
+        306 => syncfs,
+        307 => sendmmsg,
+        308 => setns,
+        309 => getcpu,
+        310 => process_vm_readv,
+        311 => process_vm_writev,
+        312 => kcmp,
+        313 => finit_module,
+        314 => sched_setattr,
+        315 => sched_getattr,
+        316 => renameat2,
+        317 => seccomp,
+        318 => getrandom,
+        319 => memfd_create,
+        320 => kexec_file_load,
+        321 => bpf,
+        322 => execveat,
+        323 => userfaultfd,
+        324 => membarrier,
+        325 => mlock2,
+        326 => copy_file_range,
+        327 => preadv2,
+        328 => pwritev2,
+        329 => pkey_mprotect,
+        330 => pkey_alloc,
+        331 => pkey_free,
+        332 => statx,
+        333 => io_pgetevents,
+        334 => rseq,
+        335 => uretprobe,
+        424 => pidfd_send_signal,
+        425 => io_uring_setup,
+        426 => io_uring_enter,
+        427 => io_uring_register,
+        428 => open_tree,
+        429 => move_mount,
+        430 => fsopen,
+        431 => fsconfig,
+        432 => fsmount,
+        433 => fspick,
+        434 => pidfd_open,
+        435 => clone3,
+        436 => close_range,
+        437 => openat2,
+        438 => pidfd_getfd,
+        439 => faccessat2,
+        440 => process_madvise,
+        441 => epoll_pwait2,
+        442 => mount_setattr,
+        443 => quotactl_fd,
+        444 => landlock_create_ruleset,
+        445 => landlock_add_rule,
+        446 => landlock_restrict_self,
+        447 => memfd_secret,
+        448 => process_mrelease,
+        449 => futex_waitv,
+        450 => set_mempolicy_home_node,
+        451 => cachestat,
+        452 => fchmodat2,
+        453 => map_shadow_stack,
+        454 => futex_wake,
+        455 => futex_wait,
+        456 => futex_requeue,
+        457 => statmount,
+        458 => listmount,
+        459 => lsm_get_self_attr,
+        460 => lsm_set_self_attr,
+        461 => lsm_list_modules,
+        462 => mseal,
+        463 => setxattrat,
+        464 => getxattrat,
+        465 => listxattrat,
+        466 => removexattrat,
+    }
+    Aarch64 {
+        0 => io_setup,
+        1 => io_destroy,
+        2 => io_submit,
+        3 => io_cancel,
+        4 => io_getevents,
+        5 => setxattr,
+        6 => lsetxattr,
+        7 => fsetxattr,
+        8 => getxattr,
+        9 => lgetxattr,
+        10 => fgetxattr,
+        11 => listxattr,
+        12 => llistxattr,
+        13 => flistxattr,
+        14 => removexattr,
+        15 => lremovexattr,
+        16 => fremovexattr,
+        17 => getcwd,
+        18 => lookup_dcookie,
+        19 => eventfd2,
+        20 => epoll_create1,
+        21 => epoll_ctl,
+        22 => epoll_pwait,
+        23 => dup,
+        24 => dup3,
+        25 => fcntl,
+        26 => inotify_init1,
+        27 => inotify_add_watch,
+        28 => inotify_rm_watch,
+        29 => ioctl,
+        30 => ioprio_set,
+        31 => ioprio_get,
+        32 => flock,
+        33 => mknodat,
+        34 => mkdirat,
+        35 => unlinkat,
+        36 => symlinkat,
+        37 => linkat,
+        38 => renameat,
+        39 => umount2,
+        40 => mount,
+        41 => pivot_root,
+        42 => nfsservctl,
+        43 => statfs,
+        44 => fstatfs,
+        45 => truncate,
+        46 => ftruncate,
+        47 => fallocate,
+        48 => faccessat,
+        49 => chdir,
+        50 => fchdir,
+        51 => chroot,
+        52 => fchmod,
+        53 => fchmodat,
+        54 => fchownat,
+        55 => fchown,
+        56 => openat,
+        57 => close,
+        58 => vhangup,
+        59 => pipe2,
+        60 => quotactl,
+        61 => getdents64,
+        62 => lseek,
+        63 => read,
+        64 => write,
+        65 => readv,
+        66 => writev,
+        67 => pread64,
+        68 => pwrite64,
+        69 => preadv,
+        70 => pwritev,
+        71 => sendfile,
+        72 => pselect6,
+        73 => ppoll,
+        74 => signalfd4,
+        75 => vmsplice,
+        76 => splice,
+        77 => tee,
+        78 => readlinkat,
+        79 => newfstatat,
+        80 => fstat,
+        81 => sync,
+        82 => fsync,
+        83 => fdatasync,
+        84 => sync_file_range,
+        85 => timerfd_create,
+        86 => timerfd_settime,
+        87 => timerfd_gettime,
+        88 => utimensat,
+        89 => acct,
+        90 => capget,
+        91 => capset,
+        92 => personality,
+        93 => exit,
+        94 => exit_group,
+        95 => waitid,
+        96 => set_tid_address,
+        97 => unshare,
+        98 => futex,
+        99 => set_robust_list,
+        100 => get_robust_list,
+        101 => nanosleep,
+        102 => getitimer,
+        103 => setitimer,
+        104 => kexec_load,
+        105 => init_module,
+        106 => delete_module,
+        107 => timer_create,
+        108 => timer_gettime,
+        109 => timer_getoverrun,
+        110 => timer_settime,
+        111 => timer_delete,
+        112 => clock_settime,
+        113 => clock_gettime,
+        114 => clock_getres,
+        115 => clock_nanosleep,
+        116 => syslog,
+        117 => ptrace,
+        118 => sched_setparam,
+        119 => sched_setscheduler,
+        120 => sched_getscheduler,
+        121 => sched_getparam,
+        122 => sched_setaffinity,
+        123 => sched_getaffinity,
+        124 => sched_yield,
+        125 => sched_get_priority_max,
+        126 => sched_get_priority_min,
+        127 => sched_rr_get_interval,
+        128 => restart_syscall,
+        129 => kill,
+        130 => tkill,
+        131 => tgkill,
+        132 => sigaltstack,
+        133 => rt_sigsuspend,
+        134 => rt_sigaction,
+        135 => rt_sigprocmask,
+        136 => rt_sigpending,
+        137 => rt_sigtimedwait,
+        138 => rt_sigqueueinfo,
+        139 => rt_sigreturn,
+        140 => setpriority,
+        141 => getpriority,
+        142 => reboot,
+        143 => setregid,
+        144 => setgid,
+        145 => setreuid,
+        146 => setuid,
+        147 => setresuid,
+        148 => getresuid,
+        149 => setresgid,
+        150 => getresgid,
+        151 => setfsuid,
+        152 => setfsgid,
+        153 => times,
+        154 => setpgid,
+        155 => getpgid,
+        156 => getsid,
+        157 => setsid,
+        158 => getgroups,
+        159 => setgroups,
+        160 => uname,
+        161 => sethostname,
+        162 => setdomainname,
+        163 => getrlimit,
+        164 => setrlimit,
+        165 => getrusage,
+        166 => umask,
+        167 => prctl,
+        168 => getcpu,
+        169 => gettimeofday,
+        170 => settimeofday,
+        171 => adjtimex,
+        172 => getpid,
+        173 => getppid,
+        174 => getuid,
+        175 => geteuid,
+        176 => getgid,
+        177 => getegid,
+        178 => gettid,
+        179 => sysinfo,
+        180 => mq_open,
+        181 => mq_unlink,
+        182 => mq_timedsend,
+        183 => mq_timedreceive,
+        184 => mq_notify,
+        185 => mq_getsetattr,
+        186 => msgget,
+        187 => msgctl,
+        188 => msgrcv,
+        189 => msgsnd,
+        190 => semget,
+        191 => semctl,
+        192 => semtimedop,
+        193 => semop,
+        194 => shmget,
+        195 => shmctl,
+        196 => shmat,
+        197 => shmdt,
+        198 => socket,
+        199 => socketpair,
+        200 => bind,
+        201 => listen,
+        202 => accept,
+        203 => connect,
+        204 => getsockname,
+        205 => getpeername,
+        206 => sendto,
+        207 => recvfrom,
+        208 => setsockopt,
+        209 => getsockopt,
+        210 => shutdown,
+        211 => sendmsg,
+        212 => recvmsg,
+        213 => readahead,
+        214 => brk,
+        215 => munmap,
+        216 => mremap,
+        217 => add_key,
+        218 => request_key,
+        219 => keyctl,
+        220 => clone,
+        221 => execve,
+        222 => mmap,
+        223 => fadvise64,
+        224 => swapon,
+        225 => swapoff,
+        226 => mprotect,
+        227 => msync,
+        228 => mlock,
+        229 => munlock,
+        230 => mlockall,
+        231 => munlockall,
+        232 => mincore,
+        233 => madvise,
+        234 => remap_file_pages,
+        235 => mbind,
+        236 => get_mempolicy,
+        237 => set_mempolicy,
+        238 => migrate_pages,
+        239 => move_pages,
+        240 => rt_tgsigqueueinfo,
+        241 => perf_event_open,
+        242 => accept4,
+        243 => recvmmsg,
+        260 => wait4,
+        261 => prlimit64,
+        262 => fanotify_init,
+        263 => fanotify_mark,
+        264 => name_to_handle_at,
+        265 => open_by_handle_at,
+        266 => clock_adjtime,
+        267 => syncfs,
+        268 => setns,
+        269 => sendmmsg,
+        270 => process_vm_readv,
+        271 => process_vm_writev,
+        272 => kcmp,
+        273 => finit_module,
+        274 => sched_setattr,
+        275 => sched_getattr,
+        276 => renameat2,
+        277 => seccomp,
+        278 => getrandom,
+        279 => memfd_create,
+        280 => bpf,
+        281 => execveat,
+        282 => userfaultfd,
+        283 => membarrier,
+        284 => mlock2,
+        285 => copy_file_range,
+        286 => preadv2,
+        287 => pwritev2,
+        288 => pkey_mprotect,
+        289 => pkey_alloc,
+        290 => pkey_free,
+        291 => statx,
+        292 => io_pgetevents,
+        293 => rseq,
+        294 => kexec_file_load,
+        424 => pidfd_send_signal,
+        425 => io_uring_setup,
+        426 => io_uring_enter,
+        427 => io_uring_register,
+        428 => open_tree,
+        429 => move_mount,
+        430 => fsopen,
+        431 => fsconfig,
+        432 => fsmount,
+        433 => fspick,
+        434 => pidfd_open,
+        435 => clone3,
+        436 => close_range,
+        437 => openat2,
+        438 => pidfd_getfd,
+        439 => faccessat2,
+        440 => process_madvise,
+        441 => epoll_pwait2,
+        442 => mount_setattr,
+        443 => quotactl_fd,
+        444 => landlock_create_ruleset,
+        445 => landlock_add_rule,
+        446 => landlock_restrict_self,
+        447 => memfd_secret,
+        448 => process_mrelease,
+        449 => futex_waitv,
+        450 => set_mempolicy_home_node,
+        451 => cachestat,
+        452 => fchmodat2,
+        453 => map_shadow_stack,
+        454 => futex_wake,
+        455 => futex_wait,
+        456 => futex_requeue,
+        457 => statmount,
+        458 => listmount,
+        459 => lsm_get_self_attr,
+        460 => lsm_set_self_attr,
+        461 => lsm_list_modules,
+        462 => mseal,
+        463 => setxattrat,
+        464 => getxattrat,
+        465 => listxattrat,
+        466 => removexattrat,
+    }
+    Riscv64 {
+        0 => io_setup,
+        1 => io_destroy,
+        2 => io_submit,
+        3 => io_cancel,
+        4 => io_getevents,
+        5 => setxattr,
+        6 => lsetxattr,
+        7 => fsetxattr,
+        8 => getxattr,
+        9 => lgetxattr,
+        10 => fgetxattr,
+        11 => listxattr,
+        12 => llistxattr,
+        13 => flistxattr,
+        14 => removexattr,
+        15 => lremovexattr,
+        16 => fremovexattr,
+        17 => getcwd,
+        18 => lookup_dcookie,
+        19 => eventfd2,
+        20 => epoll_create1,
+        21 => epoll_ctl,
+        22 => epoll_pwait,
+        23 => dup,
+        24 => dup3,
+        25 => fcntl,
+        26 => inotify_init1,
+        27 => inotify_add_watch,
+        28 => inotify_rm_watch,
+        29 => ioctl,
+        30 => ioprio_set,
+        31 => ioprio_get,
+        32 => flock,
+        33 => mknodat,
+        34 => mkdirat,
+        35 => unlinkat,
+        36 => symlinkat,
+        37 => linkat,
+        39 => umount2,
+        40 => mount,
+        41 => pivot_root,
+        42 => nfsservctl,
+        43 => statfs,
+        44 => fstatfs,
+        45 => truncate,
+        46 => ftruncate,
+        47 => fallocate,
+        48 => faccessat,
+        49 => chdir,
+        50 => fchdir,
+        51 => chroot,
+        52 => fchmod,
+        53 => fchmodat,
+        54 => fchownat,
+        55 => fchown,
+        56 => openat,
+        57 => close,
+        58 => vhangup,
+        59 => pipe2,
+        60 => quotactl,
+        61 => getdents64,
+        62 => lseek,
+        63 => read,
+        64 => write,
+        65 => readv,
+        66 => writev,
+        67 => pread64,
+        68 => pwrite64,
+        69 => preadv,
+        70 => pwritev,
+        71 => sendfile,
+        72 => pselect6,
+        73 => ppoll,
+        74 => signalfd4,
+        75 => vmsplice,
+        76 => splice,
+        77 => tee,
+        78 => readlinkat,
+        79 => newfstatat,
+        80 => fstat,
+        81 => sync,
+        82 => fsync,
+        83 => fdatasync,
+        84 => sync_file_range,
+        85 => timerfd_create,
+        86 => timerfd_settime,
+        87 => timerfd_gettime,
+        88 => utimensat,
+        89 => acct,
+        90 => capget,
+        91 => capset,
+        92 => personality,
+        93 => exit,
+        94 => exit_group,
+        95 => waitid,
+        96 => set_tid_address,
+        97 => unshare,
+        98 => futex,
+        99 => set_robust_list,
+        100 => get_robust_list,
+        101 => nanosleep,
+        102 => getitimer,
+        103 => setitimer,
+        104 => kexec_load,
+        105 => init_module,
+        106 => delete_module,
+        107 => timer_create,
+        108 => timer_gettime,
+        109 => timer_getoverrun,
+        110 => timer_settime,
+        111 => timer_delete,
+        112 => clock_settime,
+        113 => clock_gettime,
+        114 => clock_getres,
+        115 => clock_nanosleep,
+        116 => syslog,
+        117 => ptrace,
+        118 => sched_setparam,
+        119 => sched_setscheduler,
+        120 => sched_getscheduler,
+        121 => sched_getparam,
+        122 => sched_setaffinity,
+        123 => sched_getaffinity,
+        124 => sched_yield,
+        125 => sched_get_priority_max,
+        126 => sched_get_priority_min,
+        127 => sched_rr_get_interval,
+        128 => restart_syscall,
+        129 => kill,
+        130 => tkill,
+        131 => tgkill,
+        132 => sigaltstack,
+        133 => rt_sigsuspend,
+        134 => rt_sigaction,
+        135 => rt_sigprocmask,
+        136 => rt_sigpending,
+        137 => rt_sigtimedwait,
+        138 => rt_sigqueueinfo,
+        139 => rt_sigreturn,
+        140 => setpriority,
+        141 => getpriority,
+        142 => reboot,
+        143 => setregid,
+        144 => setgid,
+        145 => setreuid,
+        146 => setuid,
+        147 => setresuid,
+        148 => getresuid,
+        149 => setresgid,
+        150 => getresgid,
+        151 => setfsuid,
+        152 => setfsgid,
+        153 => times,
+        154 => setpgid,
+        155 => getpgid,
+        156 => getsid,
+        157 => setsid,
+        158 => getgroups,
+        159 => setgroups,
+        160 => uname,
+        161 => sethostname,
+        162 => setdomainname,
+        163 => getrlimit,
+        164 => setrlimit,
+        165 => getrusage,
+        166 => umask,
+        167 => prctl,
+        168 => getcpu,
+        169 => gettimeofday,
+        170 => settimeofday,
+        171 => adjtimex,
+        172 => getpid,
+        173 => getppid,
+        174 => getuid,
+        175 => geteuid,
+        176 => getgid,
+        177 => getegid,
+        178 => gettid,
+        179 => sysinfo,
+        180 => mq_open,
+        181 => mq_unlink,
+        182 => mq_timedsend,
+        183 => mq_timedreceive,
+        184 => mq_notify,
+        185 => mq_getsetattr,
+        186 => msgget,
+        187 => msgctl,
+        188 => msgrcv,
+        189 => msgsnd,
+        190 => semget,
+        191 => semctl,
+        192 => semtimedop,
+        193 => semop,
+        194 => shmget,
+        195 => shmctl,
+        196 => shmat,
+        197 => shmdt,
+        198 => socket,
+        199 => socketpair,
+        200 => bind,
+        201 => listen,
+        202 => accept,
+        203 => connect,
+        204 => getsockname,
+        205 => getpeername,
+        206 => sendto,
+        207 => recvfrom,
+        208 => setsockopt,
+        209 => getsockopt,
+        210 => shutdown,
+        211 => sendmsg,
+        212 => recvmsg,
+        213 => readahead,
+        214 => brk,
+        215 => munmap,
+        216 => mremap,
+        217 => add_key,
+        218 => request_key,
+        219 => keyctl,
+        220 => clone,
+        221 => execve,
+        222 => mmap,
+        223 => fadvise64,
+        224 => swapon,
+        225 => swapoff,
+        226 => mprotect,
+        227 => msync,
+        228 => mlock,
+        229 => munlock,
+        230 => mlockall,
+        231 => munlockall,
+        232 => mincore,
+        233 => madvise,
+        234 => remap_file_pages,
+        235 => mbind,
+        236 => get_mempolicy,
+        237 => set_mempolicy,
+        238 => migrate_pages,
+        239 => move_pages,
+        240 => rt_tgsigqueueinfo,
+        241 => perf_event_open,
+        242 => accept4,
+        243 => recvmmsg,
+        258 => riscv_hwprobe,
+        259 => riscv_flush_icache,
+        260 => wait4,
+        261 => prlimit64,
+        262 => fanotify_init,
+        263 => fanotify_mark,
+        264 => name_to_handle_at,
+        265 => open_by_handle_at,
+        266 => clock_adjtime,
+        267 => syncfs,
+        268 => setns,
+        269 => sendmmsg,
+        270 => process_vm_readv,
+        271 => process_vm_writev,
+        272 => kcmp,
+        273 => finit_module,
+        274 => sched_setattr,
+        275 => sched_getattr,
+        276 => renameat2,
+        277 => seccomp,
+        278 => getrandom,
+        279 => memfd_create,
+        280 => bpf,
+        281 => execveat,
+        282 => userfaultfd,
+        283 => membarrier,
+        284 => mlock2,
+        285 => copy_file_range,
+        286 => preadv2,
+        287 => pwritev2,
+        288 => pkey_mprotect,
+        289 => pkey_alloc,
+        290 => pkey_free,
+        291 => statx,
+        292 => io_pgetevents,
+        293 => rseq,
+        294 => kexec_file_load,
+        424 => pidfd_send_signal,
+        425 => io_uring_setup,
+        426 => io_uring_enter,
+        427 => io_uring_register,
+        428 => open_tree,
+        429 => move_mount,
+        430 => fsopen,
+        431 => fsconfig,
+        432 => fsmount,
+        433 => fspick,
+        434 => pidfd_open,
+        435 => clone3,
+        436 => close_range,
+        437 => openat2,
+        438 => pidfd_getfd,
+        439 => faccessat2,
+        440 => process_madvise,
+        441 => epoll_pwait2,
+        442 => mount_setattr,
+        443 => quotactl_fd,
+        444 => landlock_create_ruleset,
+        445 => landlock_add_rule,
+        446 => landlock_restrict_self,
+        447 => memfd_secret,
+        448 => process_mrelease,
+        449 => futex_waitv,
+        450 => set_mempolicy_home_node,
+        451 => cachestat,
+        452 => fchmodat2,
+        453 => map_shadow_stack,
+        454 => futex_wake,
+        455 => futex_wait,
+        456 => futex_requeue,
+        457 => statmount,
+        458 => listmount,
+        459 => lsm_get_self_attr,
+        460 => lsm_set_self_attr,
+        461 => lsm_list_modules,
+        462 => mseal,
+        463 => setxattrat,
+        464 => getxattrat,
+        465 => listxattrat,
+        466 => removexattrat,
+    }
+}
@@ -0,0 +1,679 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the process lifecycle syscalls, e.g.
+//
+// ```rust
+// let argv = CStrArray::new(&[c"sh", c"-c", c"exit 3"]);
+// let envp = CStrArray::new(&[]);
+//
+// match unsafe { fork() }? {
+//     ForkResult::Child => {
+//         execve(c"/bin/sh", &argv, &envp);
+//         exit_group(127);
+//     }
+//     ForkResult::Parent(pid) => {
+//         let (_, status) = wait4(pid, WaitOptions::empty())?.unwrap();
+//         assert_eq!(status, WaitStatus::Exited(3));
+//     }
+// }
+// ```
+//
+// note that these functions invoke the syscalls directly, the states of
+// libc (e.g. the `atfork` handlers and the locks of `malloc`) are not
+// maintained, so the child process should only call the async-signal-safe
+// functions, i.e. do NOT allocate memory in the child process of a
+// multithreaded process, build the arguments of `execve` before forking.
+
+use std::{
+    ffi::{c_char, CStr},
+    marker::PhantomData,
+    ptr,
+};
+
+use crate::{
+    arch::MAX_ERRNO,
+    call,
+    errno::SysCallError,
+    fd::BorrowedFd,
+    flags::{AtFlags, Signal, WaitOptions},
+    number::SysCallNum,
+    typed::{syscall_with_2_args, syscall_with_3_args, syscall_with_4_args, syscall_with_5_args},
+    types::siginfo_t,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ForkResult {
+    // the pid of the child process
+    Parent(i32),
+    Child,
+}
+
+fn decode_fork(result: Result<usize, SysCallError>) -> Result<ForkResult, SysCallError> {
+    result.map(|pid| match pid {
+        0 => ForkResult::Child,
+        _ => ForkResult::Parent(pid as i32),
+    })
+}
+
+// creates a child process which is a copy of the current process.
+//
+// aarch64 and riscv64 do not have the syscall `fork`, `clone` with
+// `SIGCHLD` is used instead.
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn fork() -> Result<ForkResult, SysCallError> {
+    #[cfg(target_arch = "x86_64")]
+    let result = crate::typed::syscall_without_args(SysCallNum::fork as usize);
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let result = syscall_with_5_args(
+        SysCallNum::clone as usize,
+        Signal::SIGCHLD.raw() as usize,
+        0,
+        0,
+        0,
+        0,
+    );
+
+    decode_fork(result)
+}
+
+// spawns the program `path` by `vfork` and `execve`, returns the pid of
+// the child process.
+//
+// the child shares the memory (including the stack) with the current
+// process, and the current thread is suspended until the child calls
+// `execve` or exits, so it is cheaper than `fork` for a large process.
+//
+// the child side (i.e. `execve`, and `exit_group` on failure) runs entirely
+// inside the assembly routine `raw_vfork_execve`, no Rust code runs in the
+// child, so it can not corrupt the stack of the parent. if `execve` fails,
+// the error number is stored into the shared memory before the child exits
+// (with the error number as the exit code), the child is reaped and the
+// error is returned.
+pub fn vfork_execve(
+    path: &CStr,
+    argv: &CStrArray<'_>,
+    envp: &CStrArray<'_>,
+) -> Result<i32, SysCallError> {
+    let mut error: i32 = 0;
+    let result = unsafe {
+        raw_vfork_execve(
+            path.as_ptr(),
+            argv.as_ptr(),
+            envp.as_ptr(),
+            &mut error as *mut i32,
+        )
+    };
+
+    if (-(MAX_ERRNO as isize)..=-1).contains(&result) {
+        return Err(SysCallError::from_code(-result as u32));
+    }
+
+    let pid = result as i32;
+    if error != 0 {
+        let _ = wait4(pid, WaitOptions::empty());
+        return Err(SysCallError::from_code(error as u32));
+    }
+
+    Ok(pid)
+}
+
+// `raw_vfork_execve(path, argv, envp, error) -> pid or -errno`
+//
+// the child must not touch the stack (which is shared with the parent), so
+// it uses the registers only, and the arguments are moved to the registers
+// which are preserved by the syscalls (e.g. `rcx` is clobbered by `syscall`
+// on x86_64, and `x0`/`a0` are the arguments of `clone` on aarch64/riscv64).
+//
+// aarch64 and riscv64 do not have the syscall `vfork`, `clone` with
+// `CLONE_VM | CLONE_VFORK | SIGCHLD` (i.e. 0x4111) is used instead.
+#[cfg(target_arch = "x86_64")]
+#[unsafe(naked)]
+unsafe extern "C" fn raw_vfork_execve(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    error: *mut i32,
+) -> isize {
+    std::arch::naked_asm!(
+        "mov r8, rcx",
+        "mov eax, {vfork}",
+        "syscall",
+        "test rax, rax",
+        "jnz 2f",
+        // the child, `rdi`, `rsi` and `rdx` are still the arguments
+        "mov eax, {execve}",
+        "syscall",
+        "neg eax",
+        "mov dword ptr [r8], eax",
+        "mov edi, eax",
+        "mov eax, {exit_group}",
+        "syscall",
+        "2:",
+        "ret",
+        vfork = const SysCallNum::vfork as usize,
+        execve = const SysCallNum::execve as usize,
+        exit_group = const SysCallNum::exit_group as usize,
+    )
+}
+
+#[cfg(target_arch = "aarch64")]
+#[unsafe(naked)]
+unsafe extern "C" fn raw_vfork_execve(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    error: *mut i32,
+) -> isize {
+    std::arch::naked_asm!(
+        "mov x9, x0",
+        "mov x10, x1",
+        "mov x11, x2",
+        "mov x12, x3",
+        "mov x0, #0x4111",
+        "mov x1, xzr",
+        "mov x2, xzr",
+        "mov x3, xzr",
+        "mov x4, xzr",
+        "mov x8, #{clone}",
+        "svc 0",
+        "cbnz x0, 2f",
+        // the child
+        "mov x0, x9",
+        "mov x1, x10",
+        "mov x2, x11",
+        "mov x8, #{execve}",
+        "svc 0",
+        "neg x0, x0",
+        "str w0, [x12]",
+        "mov x8, #{exit_group}",
+        "svc 0",
+        "2:",
+        "ret",
+        clone = const SysCallNum::clone as usize,
+        execve = const SysCallNum::execve as usize,
+        exit_group = const SysCallNum::exit_group as usize,
+    )
+}
+
+#[cfg(target_arch = "riscv64")]
+#[unsafe(naked)]
+unsafe extern "C" fn raw_vfork_execve(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    error: *mut i32,
+) -> isize {
+    std::arch::naked_asm!(
+        "mv t0, a0",
+        "mv t1, a1",
+        "mv t2, a2",
+        "mv t3, a3",
+        "li a0, 0x4111",
+        "li a1, 0",
+        "li a2, 0",
+        "li a3, 0",
+        "li a4, 0",
+        "li a7, {clone}",
+        "ecall",
+        "bnez a0, 2f",
+        // the child
+        "mv a0, t0",
+        "mv a1, t1",
+        "mv a2, t2",
+        "li a7, {execve}",
+        "ecall",
+        "neg a0, a0",
+        "sw a0, 0(t3)",
+        "li a7, {exit_group}",
+        "ecall",
+        "2:",
+        "ret",
+        clone = const SysCallNum::clone as usize,
+        execve = const SysCallNum::execve as usize,
+        exit_group = const SysCallNum::exit_group as usize,
+    )
+}
+
+// terminates all threads of the current process.
+//
+// the process exits immediately, i.e. the destructors are not run and
+// the buffers of `std::io` are not flushed.
+pub fn exit_group(code: i32) -> ! {
+    unsafe { call::syscall_noreturn_with_1_arg(SysCallNum::exit_group as usize, code as usize) }
+}
+
+// terminates the calling thread, the process exits if it is the last thread.
+//
+// note that the resources of the thread (e.g. the stack and the thread local
+// storage) are not released, so it is only suitable for the child process
+// (which has only one thread) and the threads created by `clone` directly.
+pub fn exit(code: i32) -> ! {
+    unsafe { call::syscall_noreturn_with_1_arg(SysCallNum::exit as usize, code as usize) }
+}
+
+// `getpid` and `gettid` always succeed.
+pub fn getpid() -> i32 {
+    unsafe { call::syscall_without_args(SysCallNum::getpid as usize) }.unwrap_or_default() as i32
+}
+
+pub fn gettid() -> i32 {
+    unsafe { call::syscall_without_args(SysCallNum::gettid as usize) }.unwrap_or_default() as i32
+}
+
+// the null-terminated array of the C strings, i.e. the `argv` and `envp`
+// of `execve`.
+//
+// the array is built in advance so that no memory is allocated between
+// `fork` and `execve`.
+#[derive(Debug)]
+pub struct CStrArray<'a> {
+    ptrs: Vec<*const c_char>,
+    _marker: PhantomData<&'a CStr>,
+}
+
+impl<'a> CStrArray<'a> {
+    pub fn new(items: &[&'a CStr]) -> Self {
+        let ptrs = items
+            .iter()
+            .map(|item| item.as_ptr())
+            .chain(std::iter::once(ptr::null()))
+            .collect();
+        Self {
+            ptrs,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *const *const c_char {
+        self.ptrs.as_ptr()
+    }
+
+    // the number of the strings, excluding the terminating null.
+    pub fn len(&self) -> usize {
+        self.ptrs.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// replaces the current process image with the program `path`.
+//
+// returns only on failure.
+pub fn execve(path: &CStr, argv: &CStrArray<'_>, envp: &CStrArray<'_>) -> SysCallError {
+    let result = unsafe {
+        syscall_with_3_args(
+            SysCallNum::execve as usize,
+            path.as_ptr() as usize,
+            argv.as_ptr() as usize,
+            envp.as_ptr() as usize,
+        )
+    };
+    match result {
+        Err(e) => e,
+        Ok(_) => unreachable!("execve returned successfully"),
+    }
+}
+
+// the same as `execve` except that `path` is relative to `dirfd`, and
+// the program is `dirfd` itself if `path` is empty and `flags` contains
+// `AT_EMPTY_PATH`.
+pub fn execveat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    argv: &CStrArray<'_>,
+    envp: &CStrArray<'_>,
+    flags: AtFlags,
+) -> SysCallError {
+    let result = unsafe {
+        syscall_with_5_args(
+            SysCallNum::execveat as usize,
+            dirfd.as_raw() as usize,
+            path.as_ptr() as usize,
+            argv.as_ptr() as usize,
+            envp.as_ptr() as usize,
+            flags.bits() as usize,
+        )
+    };
+    match result {
+        Err(e) => e,
+        Ok(_) => unreachable!("execveat returned successfully"),
+    }
+}
+
+// the signals are kept as numbers since they can be real-time signals.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WaitStatus {
+    // the exit code
+    Exited(i32),
+    Signaled { signal: i32, core_dumped: bool },
+    Stopped(i32),
+    Continued,
+}
+
+impl WaitStatus {
+    // decodes the status of `wait4`, i.e. the macros `WIFEXITED`,
+    // `WEXITSTATUS` and so on.
+    pub fn from_raw(status: i32) -> Self {
+        if status & 0x7f == 0 {
+            WaitStatus::Exited((status >> 8) & 0xff)
+        } else if status & 0xff == 0x7f {
+            // the higher bits are the ptrace events
+            WaitStatus::Stopped((status >> 8) & 0xff)
+        } else if status == 0xffff {
+            WaitStatus::Continued
+        } else {
+            WaitStatus::Signaled {
+                signal: status & 0x7f,
+                core_dumped: status & 0x80 != 0,
+            }
+        }
+    }
+
+    // decodes the `siginfo_t` of `waitid`, returns `None` if `si_code` is
+    // not one of the `CLD_*` codes.
+    pub fn from_siginfo(info: &siginfo_t) -> Option<Self> {
+        let status = info.si_status();
+        let wait_status = match info.si_code {
+            // CLD_EXITED
+            1 => WaitStatus::Exited(status),
+            // CLD_KILLED and CLD_DUMPED
+            2 | 3 => WaitStatus::Signaled {
+                signal: status,
+                core_dumped: info.si_code == 3,
+            },
+            // CLD_TRAPPED and CLD_STOPPED
+            4 | 5 => WaitStatus::Stopped(status),
+            // CLD_CONTINUED
+            6 => WaitStatus::Continued,
+            _ => return None,
+        };
+        Some(wait_status)
+    }
+}
+
+// waits for the child process `pid`, `pid` can also be:
+// - `-1`: any child process.
+// - `0`: any child process in the same process group.
+// - `-pgid`: any child process in the process group `pgid`.
+//
+// returns `(pid, status)`, or `None` if `options` contains `WNOHANG`
+// and no child process has changed state.
+pub fn wait4(pid: i32, options: WaitOptions) -> Result<Option<(i32, WaitStatus)>, SysCallError> {
+    let mut status: i32 = 0;
+    let pid = unsafe {
+        syscall_with_4_args(
+            SysCallNum::wait4 as usize,
+            pid as usize,
+            &mut status as *mut i32 as usize,
+            options.bits() as usize,
+            0,
+        )
+    }?;
+
+    match pid {
+        0 => Ok(None),
+        _ => Ok(Some((pid as i32, WaitStatus::from_raw(status)))),
+    }
+}
+
+// the child processes to wait for by `waitid`.
+#[derive(Debug, Clone, Copy)]
+pub enum WaitId<'a> {
+    All,
+    Pid(i32),
+    Pgid(i32),
+    PidFd(BorrowedFd<'a>),
+}
+
+impl WaitId<'_> {
+    // returns `(idtype, id)`.
+    fn raw(&self) -> (usize, usize) {
+        match self {
+            WaitId::All => (0, 0),
+            WaitId::Pid(pid) => (1, *pid as usize),
+            WaitId::Pgid(pgid) => (2, *pgid as usize),
+            WaitId::PidFd(fd) => (3, fd.as_raw() as usize),
+        }
+    }
+}
+
+// `options` must contain at least one of `WEXITED`, `WSTOPPED` and
+// `WCONTINUED`.
+//
+// returns `(pid, status)`, or `None` if `options` contains `WNOHANG`
+// and no child process has changed state.
+pub fn waitid(
+    id: WaitId<'_>,
+    options: WaitOptions,
+) -> Result<Option<(i32, WaitStatus)>, SysCallError> {
+    let (idtype, id) = id.raw();
+    let mut info = siginfo_t::default();
+    unsafe {
+        syscall_with_5_args(
+            SysCallNum::waitid as usize,
+            idtype,
+            id,
+            &mut info as *mut siginfo_t as usize,
+            options.bits() as usize,
+            0,
+        )
+    }?;
+
+    // `si_pid` is kept 0 if no child process has changed state
+    if info.si_pid() == 0 {
+        return Ok(None);
+    }
+
+    Ok(WaitStatus::from_siginfo(&info).map(|status| (info.si_pid(), status)))
+}
+
+// sends the signal to the process `pid`, `pid` can also be `0`, `-1`
+// and `-pgid`, see `wait4`.
+pub fn kill(pid: i32, signal: Signal) -> Result<(), SysCallError> {
+    unsafe {
+        syscall_with_2_args(
+            SysCallNum::kill as usize,
+            pid as usize,
+            signal.raw() as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+// sends the signal to the thread `tid` of the process `tgid`.
+pub fn tgkill(tgid: i32, tid: i32, signal: Signal) -> Result<(), SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::tgkill as usize,
+            tgid as usize,
+            tid as usize,
+            signal.raw() as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errno::Errno,
+        flags::{OpenFlags, Signal, WaitOptions},
+        io::{dup3, pipe2, read},
+        process::{
+            execve, exit, exit_group, fork, getpid, gettid, kill, tgkill, vfork_execve, wait4,
+            waitid, CStrArray, ForkResult, WaitId, WaitStatus,
+        },
+    };
+
+    #[test]
+    fn test_wait_status_from_raw() {
+        assert_eq!(WaitStatus::from_raw(0x0300), WaitStatus::Exited(3));
+        assert_eq!(
+            WaitStatus::from_raw(0x0009),
+            WaitStatus::Signaled {
+                signal: 9,
+                core_dumped: false
+            }
+        );
+        assert_eq!(
+            WaitStatus::from_raw(0x0086),
+            WaitStatus::Signaled {
+                signal: 6,
+                core_dumped: true
+            }
+        );
+        assert_eq!(WaitStatus::from_raw(0x137f), WaitStatus::Stopped(19));
+        assert_eq!(WaitStatus::from_raw(0xffff), WaitStatus::Continued);
+    }
+
+    #[test]
+    fn test_fork_and_exit() {
+        let pid = match unsafe { fork() }.unwrap() {
+            ForkResult::Child => exit_group(7),
+            ForkResult::Parent(pid) => pid,
+        };
+
+        assert!(pid > 0);
+        assert_eq!(
+            wait4(pid, WaitOptions::empty()),
+            Ok(Some((pid, WaitStatus::Exited(7))))
+        );
+
+        // the child has been reaped
+        assert_eq!(wait4(pid, WaitOptions::empty()), Err(Errno::ECHILD.into()));
+    }
+
+    #[test]
+    fn test_exit() {
+        // the child process has only one thread, so it exits by `exit`
+        let pid = match unsafe { fork() }.unwrap() {
+            ForkResult::Child => exit(5),
+            ForkResult::Parent(pid) => pid,
+        };
+        assert_eq!(
+            wait4(pid, WaitOptions::empty()),
+            Ok(Some((pid, WaitStatus::Exited(5))))
+        );
+    }
+
+    #[test]
+    fn test_kill() {
+        let pid = match unsafe { fork() }.unwrap() {
+            ForkResult::Child => loop {
+                std::hint::spin_loop();
+            },
+            ForkResult::Parent(pid) => pid,
+        };
+
+        assert_eq!(wait4(pid, WaitOptions::WNOHANG), Ok(None));
+        assert_eq!(
+            waitid(
+                WaitId::Pid(pid),
+                WaitOptions::WEXITED | WaitOptions::WNOHANG
+            ),
+            Ok(None)
+        );
+
+        assert_eq!(kill(pid, Signal::SIGKILL), Ok(()));
+        assert_eq!(
+            waitid(WaitId::Pid(pid), WaitOptions::WEXITED),
+            Ok(Some((
+                pid,
+                WaitStatus::Signaled {
+                    signal: Signal::SIGKILL.raw(),
+                    core_dumped: false
+                }
+            )))
+        );
+
+        // the child has been reaped
+        assert_eq!(kill(pid, Signal::SIGKILL), Err(Errno::ESRCH.into()));
+    }
+
+    #[test]
+    fn test_tgkill() {
+        let pid = match unsafe { fork() }.unwrap() {
+            ForkResult::Child => loop {
+                std::hint::spin_loop();
+            },
+            ForkResult::Parent(pid) => pid,
+        };
+
+        // the main thread of the child, the thread id is the same as the pid
+        assert_eq!(tgkill(pid, pid, Signal::SIGTERM), Ok(()));
+        assert_eq!(
+            wait4(pid, WaitOptions::empty()),
+            Ok(Some((
+                pid,
+                WaitStatus::Signaled {
+                    signal: Signal::SIGTERM.raw(),
+                    core_dumped: false
+                }
+            )))
+        );
+
+        // the thread does not belong to the process
+        assert!(gettid() > 0);
+        assert_eq!(
+            tgkill(getpid(), pid, Signal::SIGTERM),
+            Err(Errno::ESRCH.into())
+        );
+    }
+
+    #[test]
+    fn test_vfork_and_execve() {
+        let path = c"/bin/sh";
+        let argv = CStrArray::new(&[c"sh", c"-c", c"exit 3"]);
+        let envp = CStrArray::new(&[]);
+        assert_eq!(argv.len(), 3);
+        assert!(envp.is_empty());
+
+        let pid = vfork_execve(path, &argv, &envp).unwrap();
+        assert!(pid > 0);
+        assert_eq!(
+            wait4(pid, WaitOptions::empty()),
+            Ok(Some((pid, WaitStatus::Exited(3))))
+        );
+
+        // the program does not exist, the error of `execve` is returned
+        assert_eq!(
+            vfork_execve(c"/this/file/should/not/exist", &argv, &envp),
+            Err(Errno::ENOENT.into())
+        );
+    }
+
+    #[test]
+    fn test_redirect_stdout() {
+        let argv = CStrArray::new(&[c"sh", c"-c", c"echo hello"]);
+        let envp = CStrArray::new(&[]);
+        let (read_fd, write_fd) = pipe2(OpenFlags::O_CLOEXEC).unwrap();
+
+        // redirects the stdout of the child to the pipe, the duplicated
+        // fd does not inherit the flag `O_CLOEXEC`.
+        let pid = match unsafe { fork() }.unwrap() {
+            ForkResult::Child => {
+                if dup3(write_fd.as_fd(), 1, OpenFlags::empty()).is_err() {
+                    exit_group(126);
+                }
+                execve(c"/bin/sh", &argv, &envp);
+                exit_group(127);
+            }
+            ForkResult::Parent(pid) => pid,
+        };
+        assert_eq!(
+            wait4(pid, WaitOptions::empty()),
+            Ok(Some((pid, WaitStatus::Exited(0))))
+        );
+
+        let mut buf = [0u8; 16];
+        assert_eq!(read(read_fd.as_fd(), &mut buf), Ok(6));
+        assert_eq!(&buf[..6], b"hello\n");
+    }
+}
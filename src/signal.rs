@@ -0,0 +1,379 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the signal handling syscalls, e.g.
+//
+// ```rust
+// extern "C" fn handler(signum: i32, info: *mut siginfo_t, context: *mut c_void) {
+//     // only the async-signal-safe functions can be called here
+// }
+//
+// let action = sigaction::new(
+//     SigHandler::SigAction(handler),
+//     SigactionFlags::SA_RESTART,
+//     sigset_t::empty(),
+// );
+// let old_action = unsafe { rt_sigaction(Signal::SIGUSR1, Some(&action)) }?;
+// ```
+//
+// these functions use the structures of the kernel (see the module `types`)
+// instead of libc, e.g. `sigset_t` is 64 bits instead of 1024 bits, and the
+// trampoline `rt_sigreturn_trampoline` is set to `sa_restorer` on x86_64
+// and aarch64.
+
+use std::{ffi::c_void, time::Duration};
+
+use crate::{
+    errno::{Errno, SysCallError},
+    flags::{SigactionFlags, SigaltstackFlags, SigmaskHow},
+    number::SysCallNum,
+    typed::{syscall_with_2_args, syscall_with_3_args, syscall_with_4_args},
+    types::{sigaction, siginfo_t, sigset_t, stack_t, timespec},
+};
+
+pub use crate::flags::Signal;
+
+// the values of `si_code`, a negative value means that the signal
+// is sent by the user process.
+pub const SI_USER: i32 = 0;
+pub const SI_KERNEL: i32 = 0x80;
+pub const SI_QUEUE: i32 = -1;
+pub const SI_TKILL: i32 = -6;
+
+// the size of the kernel `sigset_t`, which is passed to the `rt_sig*` syscalls
+// and `signalfd4`.
+pub(crate) const SIGSET_SIZE: usize = std::mem::size_of::<sigset_t>();
+
+#[derive(Debug, Clone, Copy)]
+pub enum SigHandler {
+    // i.e. `SIG_DFL`
+    Default,
+    // i.e. `SIG_IGN`
+    Ignore,
+    Handler(extern "C" fn(i32)),
+    // the handler with the flag `SA_SIGINFO`, the third argument is
+    // the `ucontext_t` of the interrupted context.
+    SigAction(extern "C" fn(i32, *mut siginfo_t, *mut c_void)),
+}
+
+impl sigaction {
+    // `SA_SIGINFO` is added for `SigHandler::SigAction`, and `SA_RESTORER`
+    // (with the trampoline) is added on x86_64 and aarch64.
+    pub fn new(handler: SigHandler, flags: SigactionFlags, mask: sigset_t) -> Self {
+        let mut flags = flags;
+        let sa_handler = match handler {
+            SigHandler::Default => 0,
+            SigHandler::Ignore => 1,
+            SigHandler::Handler(f) => f as usize,
+            SigHandler::SigAction(f) => {
+                flags |= SigactionFlags::SA_SIGINFO;
+                f as usize
+            }
+        };
+
+        #[cfg(not(target_arch = "riscv64"))]
+        {
+            flags |= SigactionFlags::SA_RESTORER;
+            Self {
+                sa_handler,
+                sa_flags: flags.bits(),
+                sa_restorer: crate::call::rt_sigreturn_trampoline as *const () as usize,
+                sa_mask: mask,
+            }
+        }
+
+        #[cfg(target_arch = "riscv64")]
+        Self {
+            sa_handler,
+            sa_flags: flags.bits(),
+            sa_mask: mask,
+        }
+    }
+}
+
+// changes the action of the signal (if `action` is not `None`), and
+// returns the old action.
+//
+// the caller must ensure that the handler only calls the async-signal-safe
+// functions, note that the action of `SIGKILL` and `SIGSTOP` can not be changed.
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn rt_sigaction(
+    signal: Signal,
+    action: Option<&sigaction>,
+) -> Result<sigaction, SysCallError> {
+    let mut old_action = sigaction::default();
+    syscall_with_4_args(
+        SysCallNum::rt_sigaction as usize,
+        signal.raw() as usize,
+        action.map_or(0, |action| action as *const sigaction as usize),
+        &mut old_action as *mut sigaction as usize,
+        SIGSET_SIZE,
+    )?;
+    Ok(old_action)
+}
+
+// changes the signal mask of the calling thread (if `set` is not `None`),
+// and returns the old mask.
+pub fn rt_sigprocmask(how: SigmaskHow, set: Option<&sigset_t>) -> Result<sigset_t, SysCallError> {
+    let mut old_set = sigset_t::empty();
+    unsafe {
+        syscall_with_4_args(
+            SysCallNum::rt_sigprocmask as usize,
+            how.raw() as usize,
+            set.map_or(0, |set| set as *const sigset_t as usize),
+            &mut old_set as *mut sigset_t as usize,
+            SIGSET_SIZE,
+        )
+    }?;
+    Ok(old_set)
+}
+
+// sets the alternate signal stack of the calling thread (if `stack` is not
+// `None`), and returns the old one. the handlers with `SA_ONSTACK` run on it.
+//
+// the caller must ensure that the memory of the stack stays valid until
+// the stack is replaced or disabled (by `SS_DISABLE`).
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sigaltstack(stack: Option<&stack_t>) -> Result<stack_t, SysCallError> {
+    let mut old_stack = stack_t::default();
+    syscall_with_2_args(
+        SysCallNum::sigaltstack as usize,
+        stack.map_or(0, |stack| stack as *const stack_t as usize),
+        &mut old_stack as *mut stack_t as usize,
+    )?;
+    Ok(old_stack)
+}
+
+impl stack_t {
+    pub fn new(sp: *mut c_void, size: usize, flags: SigaltstackFlags) -> Self {
+        Self {
+            ss_sp: sp,
+            ss_flags: flags.bits() as i32,
+            ss_size: size,
+        }
+    }
+}
+
+// waits for one of the signals in `set` to be pending, the signals should
+// be blocked (by `rt_sigprocmask`) before waiting.
+//
+// returns the information of the signal (which is removed from the pending
+// signals), or `None` if the timeout expires. it waits forever if
+// `timeout` is `None`.
+pub fn rt_sigtimedwait(
+    set: &sigset_t,
+    timeout: Option<Duration>,
+) -> Result<Option<siginfo_t>, SysCallError> {
+    let timeout = timeout.map(timespec::from);
+
+    let mut info = siginfo_t::default();
+    let result = unsafe {
+        syscall_with_4_args(
+            SysCallNum::rt_sigtimedwait as usize,
+            set as *const sigset_t as usize,
+            &mut info as *mut siginfo_t as usize,
+            timeout
+                .as_ref()
+                .map_or(0, |timeout| timeout as *const timespec as usize),
+            SIGSET_SIZE,
+        )
+    };
+
+    match result {
+        Ok(_) => Ok(Some(info)),
+        Err(e) if e == Errno::EAGAIN => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// sends the signal with the information to the process `tgid`.
+//
+// `si_code` must be negative (e.g. `SI_QUEUE`) unless the target is the
+// calling process, `si_signo` is ignored by the kernel.
+pub fn rt_sigqueueinfo(tgid: i32, signal: Signal, info: &siginfo_t) -> Result<(), SysCallError> {
+    unsafe {
+        syscall_with_3_args(
+            SysCallNum::rt_sigqueueinfo as usize,
+            tgid as usize,
+            signal.raw() as usize,
+            info as *const siginfo_t as usize,
+        )
+    }
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::c_void,
+        sync::atomic::{AtomicI32, AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use crate::{
+        flags::{SigactionFlags, SigaltstackFlags, SigmaskHow, Signal, WaitOptions},
+        process::{exit_group, fork, getpid, gettid, tgkill, wait4, ForkResult, WaitStatus},
+        signal::{
+            rt_sigaction, rt_sigprocmask, rt_sigqueueinfo, rt_sigtimedwait, sigaltstack,
+            SigHandler, SI_QUEUE, SI_TKILL,
+        },
+        types::{sigaction, siginfo_t, sigset_t, stack_t},
+    };
+
+    #[test]
+    fn test_sigset() {
+        let mut set = sigset_t::empty();
+        set.add(Signal::SIGUSR1.raw());
+        set.add(Signal::SIGRTMAX);
+        assert!(set.contains(Signal::SIGUSR1.raw()));
+        assert!(!set.contains(Signal::SIGUSR2.raw()));
+        assert!(set.contains(64));
+        assert_eq!(set.sig[0], (1 << 9) | (1 << 63));
+
+        set.remove(Signal::SIGUSR1.raw());
+        assert!(!set.contains(Signal::SIGUSR1.raw()));
+        assert!(sigset_t::fill().contains(1));
+    }
+
+    static SIGNO: AtomicI32 = AtomicI32::new(0);
+    static CODE: AtomicI32 = AtomicI32::new(0);
+
+    extern "C" fn record_handler(signum: i32, info: *mut siginfo_t, _context: *mut c_void) {
+        SIGNO.store(signum, Ordering::SeqCst);
+        CODE.store(unsafe { (*info).si_code }, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_handler() {
+        let action = sigaction::new(
+            SigHandler::SigAction(record_handler),
+            SigactionFlags::SA_RESTART,
+            sigset_t::empty(),
+        );
+        let old_action = unsafe { rt_sigaction(Signal::SIGUSR1, Some(&action)) }.unwrap();
+
+        // the action is queried without being changed
+        let current_action = unsafe { rt_sigaction(Signal::SIGUSR1, None) }.unwrap();
+        assert_eq!(current_action.sa_handler, action.sa_handler);
+        assert!(
+            SigactionFlags::from_bits(current_action.sa_flags).contains(SigactionFlags::SA_SIGINFO)
+        );
+
+        // the signal is delivered to the current thread before `tgkill` returns,
+        // and the handler returns through the trampoline.
+        assert_eq!(tgkill(getpid(), gettid(), Signal::SIGUSR1), Ok(()));
+        assert_eq!(SIGNO.load(Ordering::SeqCst), Signal::SIGUSR1.raw());
+        assert_eq!(CODE.load(Ordering::SeqCst), SI_TKILL);
+
+        unsafe { rt_sigaction(Signal::SIGUSR1, Some(&old_action)) }.unwrap();
+    }
+
+    static STACK_ADDR: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn stack_handler(_signum: i32) {
+        let local = 0u8;
+        STACK_ADDR.store(&local as *const u8 as usize, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_sigaltstack() {
+        let mut stack = vec![0u8; 64 * 1024];
+        let stack_range = stack.as_ptr_range();
+        let new_stack = stack_t::new(
+            stack.as_mut_ptr() as *mut c_void,
+            stack.len(),
+            SigaltstackFlags::empty(),
+        );
+        // the old stack is the one installed by `std` for detecting the
+        // stack overflow, it is restored at the end.
+        let old_stack = unsafe { sigaltstack(Some(&new_stack)) }.unwrap();
+
+        let action = sigaction::new(
+            SigHandler::Handler(stack_handler),
+            SigactionFlags::SA_ONSTACK,
+            sigset_t::empty(),
+        );
+        let old_action = unsafe { rt_sigaction(Signal::SIGUSR2, Some(&action)) }.unwrap();
+
+        assert_eq!(tgkill(getpid(), gettid(), Signal::SIGUSR2), Ok(()));
+        let addr = STACK_ADDR.load(Ordering::SeqCst) as *const u8;
+        assert!(stack_range.contains(&addr));
+
+        unsafe {
+            rt_sigaction(Signal::SIGUSR2, Some(&old_action)).unwrap();
+            sigaltstack(Some(&old_stack)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sigprocmask_and_sigtimedwait() {
+        let mut set = sigset_t::empty();
+        set.add(Signal::SIGURG.raw());
+
+        let old_set = rt_sigprocmask(SigmaskHow::SIG_BLOCK, Some(&set)).unwrap();
+        let current_set = rt_sigprocmask(SigmaskHow::SIG_BLOCK, None).unwrap();
+        assert!(current_set.contains(Signal::SIGURG.raw()));
+
+        // times out since no signal is pending
+        assert_eq!(
+            rt_sigtimedwait(&set, Some(Duration::from_millis(10))).map(|info| info.is_none()),
+            Ok(true)
+        );
+
+        // the blocked signal is kept pending until it is taken by `rt_sigtimedwait`
+        assert_eq!(tgkill(getpid(), gettid(), Signal::SIGURG), Ok(()));
+        let info = rt_sigtimedwait(&set, Some(Duration::from_secs(1)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.si_signo, Signal::SIGURG.raw());
+        assert_eq!(info.si_code, SI_TKILL);
+        assert_eq!(info.si_pid(), getpid());
+
+        rt_sigprocmask(SigmaskHow::SIG_SETMASK, Some(&old_set)).unwrap();
+    }
+
+    #[test]
+    fn test_sigqueueinfo() {
+        // the signal is sent to the process (i.e. any thread), so it is
+        // tested in the child process which has only one thread.
+        let pid = match unsafe { fork() }.unwrap() {
+            ForkResult::Child => {
+                let mut set = sigset_t::empty();
+                set.add(Signal::SIGUSR2.raw());
+                if rt_sigprocmask(SigmaskHow::SIG_BLOCK, Some(&set)).is_err() {
+                    exit_group(1);
+                }
+
+                let mut info = siginfo_t {
+                    si_code: SI_QUEUE,
+                    ..Default::default()
+                };
+                info._sifields[0] = getpid();
+                info.set_si_value(0x1234);
+                if rt_sigqueueinfo(getpid(), Signal::SIGUSR2, &info).is_err() {
+                    exit_group(2);
+                }
+
+                match rt_sigtimedwait(&set, None) {
+                    Ok(Some(info))
+                        if info.si_signo == Signal::SIGUSR2.raw()
+                            && info.si_code == SI_QUEUE
+                            && info.si_value() == 0x1234 =>
+                    {
+                        exit_group(0)
+                    }
+                    _ => exit_group(3),
+                }
+            }
+            ForkResult::Parent(pid) => pid,
+        };
+
+        assert_eq!(
+            wait4(pid, WaitOptions::empty()),
+            Ok(Some((pid, WaitStatus::Exited(0))))
+        );
+    }
+}
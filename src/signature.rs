@@ -0,0 +1,544 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the argument metadata of syscalls.
+//
+// the VM forwards the syscalls of the guest through the functions
+// `syscall_with_N_args`, the signature tells how many arguments a syscall
+// takes and what they mean, so the VM can validate and translate the
+// guest pointers generically, e.g.
+//
+// ```rust
+// let signature = SysCallNum::read.signature().unwrap();
+// for (index, kind) in signature.args.iter().enumerate() {
+//     match kind {
+//         ArgKind::PtrOut(Some(size_index)) => {
+//             let length = args[*size_index];
+//             /* translate the guest pointer args[index] with length */
+//         }
+//         _ => {}
+//     }
+// }
+// ```
+//
+// the signatures describe the 64-bit ABI (x86_64, aarch64 and riscv64),
+// they do not apply to the i386 and x32 syscalls.
+//
+// ref:
+// - https://man7.org/linux/man-pages/man2/syscalls.2.html
+// - 'include/linux/syscalls.h'
+
+use crate::{
+    number::SysCallNum,
+    portable::{Arch, PortableSysCall},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ArgKind {
+    // a file descriptor, e.g. the `fd` of `read`, and the `dirfd` of
+    // `openat` (which may be `AT_FDCWD`).
+    Fd,
+
+    // a pointer to the memory which is read by the kernel, e.g. the `buf` of `write`.
+    //
+    // the value is the index of the argument which holds the length of the memory,
+    // the length is in bytes, or in elements for the arrays (e.g. the `iov`
+    // of `writev`). `None` if the length is determined by the type of the
+    // data (e.g. `struct timespec`) or the other data.
+    PtrIn(Option<usize>),
+
+    // a pointer to the memory which is written by the kernel without reading
+    // its initial content, e.g. the `buf` of `read`.
+    //
+    // the value is the index of the argument which holds the length of
+    // the memory, the same as `PtrIn`.
+    PtrOut(Option<usize>),
+
+    // a pointer to the memory which is read and then written back by the
+    // kernel, e.g. the `timeout` of `ppoll` (which is updated to the remaining
+    // time), the `fds` of `poll` and the `addrlen` of `accept`.
+    //
+    // the value is the index of the argument which holds the length of
+    // the memory, the same as `PtrIn`.
+    PtrInOut(Option<usize>),
+
+    // a pointer to a null-terminated string, e.g. the `pathname` of `openat`.
+    CStr,
+
+    // the bit flags, e.g. the `flags` of `openat`.
+    Flags,
+
+    // an address of the memory mapping which is not dereferenced by the
+    // kernel, e.g. the `addr` of `mmap`, `munmap` and `mprotect`.
+    //
+    // the memory may be inaccessible (e.g. `PROT_NONE`) or not mapped at all,
+    // so it must not be validated as a readable or writable memory.
+    Addr,
+
+    // an integer, or a value which is not dereferenced by the kernel, e.g.
+    // the `count` of `read`, the `mode` of `openat` and the `arg` of `ioctl`.
+    Int,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReturnKind {
+    // returns 0 on success.
+    Zero,
+
+    // an integer, e.g. the `getuid` and `fcntl`.
+    Int,
+
+    // the number of bytes (or elements), e.g. the `read` and `write`.
+    Size,
+
+    // a new file descriptor, e.g. the `openat` and `socket`.
+    Fd,
+
+    // a process ID or thread ID, e.g. the `getpid` and `fork`.
+    Pid,
+
+    // an address, e.g. the `mmap` and `brk`.
+    Ptr,
+
+    // never returns, i.e. the `exit` and `exit_group`.
+    //
+    // note that `execve` returns only on failure, it is marked as `Zero`.
+    NoReturn,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Signature {
+    pub args: &'static [ArgKind],
+    pub ret: ReturnKind,
+}
+
+impl Signature {
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+}
+
+// defines the function `lookup_signature()` from a list of
+// `name(arg kinds, ...) -> return kind`.
+macro_rules! signature_table {
+    ($($name:ident($($arg:expr),*) -> $ret:ident,)*) => {
+        fn lookup_signature(syscall: PortableSysCall) -> Option<&'static Signature> {
+            use ArgKind::*;
+
+            match syscall {
+                $(PortableSysCall::$name => Some(&Signature {
+                    args: &[$($arg),*],
+                    ret: ReturnKind::$ret,
+                }),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+// the signature of `clone` on x86_64:
+// `clone(flags, stack, parent_tid, child_tid, tls)`
+//
+// the order of the last two arguments are swapped on aarch64 and riscv64
+// (i.e. `CONFIG_CLONE_BACKWARDS`):
+// `clone(flags, stack, parent_tid, tls, child_tid)`
+const CLONE_BACKWARDS_SIGNATURE: Signature = Signature {
+    args: &[
+        ArgKind::Flags,
+        ArgKind::Int,
+        ArgKind::PtrOut(None),
+        ArgKind::Int,
+        ArgKind::PtrOut(None),
+    ],
+    ret: ReturnKind::Pid,
+};
+
+impl PortableSysCall {
+    // the signature on the specified architecture, or `None` if the syscall
+    // is not listed in the table.
+    pub fn signature_for(&self, arch: Arch) -> Option<&'static Signature> {
+        match (self, arch) {
+            (PortableSysCall::clone, Arch::Aarch64 | Arch::Riscv64) => {
+                Some(&CLONE_BACKWARDS_SIGNATURE)
+            }
+            _ => lookup_signature(*self),
+        }
+    }
+
+    pub fn signature(&self) -> Option<&'static Signature> {
+        self.signature_for(Arch::current())
+    }
+}
+
+impl SysCallNum {
+    // the signature of the syscall on the current target, or `None` if the
+    // syscall is not listed in the table.
+    pub fn signature(&self) -> Option<&'static Signature> {
+        PortableSysCall::from_number(*self as usize)?.signature()
+    }
+}
+
+// the signatures in the order of the x86_64 syscall table.
+signature_table! {
+    read(Fd, PtrOut(Some(2)), Int) -> Size,
+    write(Fd, PtrIn(Some(2)), Int) -> Size,
+    open(CStr, Flags, Int) -> Fd,
+    close(Fd) -> Zero,
+    stat(CStr, PtrOut(None)) -> Zero,
+    fstat(Fd, PtrOut(None)) -> Zero,
+    lstat(CStr, PtrOut(None)) -> Zero,
+    poll(PtrInOut(Some(1)), Int, Int) -> Int,
+    lseek(Fd, Int, Int) -> Int,
+    mmap(Addr, Int, Flags, Flags, Fd, Int) -> Ptr,
+    mprotect(Addr, Int, Flags) -> Zero,
+    munmap(Addr, Int) -> Zero,
+    brk(Addr) -> Ptr,
+    rt_sigaction(Int, PtrIn(None), PtrOut(None), Int) -> Zero,
+    rt_sigprocmask(Int, PtrIn(Some(3)), PtrOut(Some(3)), Int) -> Zero,
+    ioctl(Fd, Int, Int) -> Int,
+    pread64(Fd, PtrOut(Some(2)), Int, Int) -> Size,
+    pwrite64(Fd, PtrIn(Some(2)), Int, Int) -> Size,
+    readv(Fd, PtrIn(Some(2)), Int) -> Size,
+    writev(Fd, PtrIn(Some(2)), Int) -> Size,
+    access(CStr, Int) -> Zero,
+    pipe(PtrOut(None)) -> Zero,
+    select(Int, PtrInOut(None), PtrInOut(None), PtrInOut(None), PtrInOut(None)) -> Int,
+    sched_yield() -> Zero,
+    mremap(Addr, Int, Int, Flags, Addr) -> Ptr,
+    msync(Addr, Int, Flags) -> Zero,
+    mincore(Addr, Int, PtrOut(None)) -> Zero,
+    madvise(Addr, Int, Int) -> Zero,
+    dup(Fd) -> Fd,
+    dup2(Fd, Fd) -> Fd,
+    pause() -> Int,
+    nanosleep(PtrIn(None), PtrOut(None)) -> Zero,
+    getitimer(Int, PtrOut(None)) -> Zero,
+    alarm(Int) -> Int,
+    setitimer(Int, PtrIn(None), PtrOut(None)) -> Zero,
+    getpid() -> Pid,
+    sendfile(Fd, Fd, PtrInOut(None), Int) -> Size,
+    socket(Int, Flags, Int) -> Fd,
+    connect(Fd, PtrIn(Some(2)), Int) -> Zero,
+    accept(Fd, PtrOut(None), PtrInOut(None)) -> Fd,
+    sendto(Fd, PtrIn(Some(2)), Int, Flags, PtrIn(Some(5)), Int) -> Size,
+    recvfrom(Fd, PtrOut(Some(2)), Int, Flags, PtrOut(None), PtrInOut(None)) -> Size,
+    sendmsg(Fd, PtrIn(None), Flags) -> Size,
+    recvmsg(Fd, PtrInOut(None), Flags) -> Size,
+    shutdown(Fd, Int) -> Zero,
+    bind(Fd, PtrIn(Some(2)), Int) -> Zero,
+    listen(Fd, Int) -> Zero,
+    getsockname(Fd, PtrOut(None), PtrInOut(None)) -> Zero,
+    getpeername(Fd, PtrOut(None), PtrInOut(None)) -> Zero,
+    socketpair(Int, Flags, Int, PtrOut(None)) -> Zero,
+    setsockopt(Fd, Int, Int, PtrIn(Some(4)), Int) -> Zero,
+    getsockopt(Fd, Int, Int, PtrOut(None), PtrInOut(None)) -> Zero,
+    clone(Flags, Int, PtrOut(None), PtrOut(None), Int) -> Pid,
+    fork() -> Pid,
+    vfork() -> Pid,
+    execve(CStr, PtrIn(None), PtrIn(None)) -> Zero,
+    exit(Int) -> NoReturn,
+    wait4(Int, PtrOut(None), Flags, PtrOut(None)) -> Pid,
+    kill(Int, Int) -> Zero,
+    uname(PtrOut(None)) -> Zero,
+    fcntl(Fd, Int, Int) -> Int,
+    flock(Fd, Int) -> Zero,
+    fsync(Fd) -> Zero,
+    fdatasync(Fd) -> Zero,
+    truncate(CStr, Int) -> Zero,
+    ftruncate(Fd, Int) -> Zero,
+    getdents(Fd, PtrOut(Some(2)), Int) -> Size,
+    getcwd(PtrOut(Some(1)), Int) -> Size,
+    chdir(CStr) -> Zero,
+    fchdir(Fd) -> Zero,
+    rename(CStr, CStr) -> Zero,
+    mkdir(CStr, Int) -> Zero,
+    rmdir(CStr) -> Zero,
+    creat(CStr, Int) -> Fd,
+    link(CStr, CStr) -> Zero,
+    unlink(CStr) -> Zero,
+    symlink(CStr, CStr) -> Zero,
+    readlink(CStr, PtrOut(Some(2)), Int) -> Size,
+    chmod(CStr, Int) -> Zero,
+    fchmod(Fd, Int) -> Zero,
+    chown(CStr, Int, Int) -> Zero,
+    fchown(Fd, Int, Int) -> Zero,
+    lchown(CStr, Int, Int) -> Zero,
+    umask(Int) -> Int,
+    gettimeofday(PtrOut(None), PtrOut(None)) -> Zero,
+    getrlimit(Int, PtrOut(None)) -> Zero,
+    getrusage(Int, PtrOut(None)) -> Zero,
+    sysinfo(PtrOut(None)) -> Zero,
+    times(PtrOut(None)) -> Int,
+    ptrace(Int, Int, Int, Int) -> Int,
+    getuid() -> Int,
+    getgid() -> Int,
+    setuid(Int) -> Zero,
+    setgid(Int) -> Zero,
+    geteuid() -> Int,
+    getegid() -> Int,
+    setpgid(Int, Int) -> Zero,
+    getppid() -> Pid,
+    getpgrp() -> Pid,
+    setsid() -> Pid,
+    setreuid(Int, Int) -> Zero,
+    setregid(Int, Int) -> Zero,
+    getgroups(Int, PtrOut(Some(0))) -> Size,
+    setgroups(Int, PtrIn(Some(0))) -> Zero,
+    setresuid(Int, Int, Int) -> Zero,
+    getresuid(PtrOut(None), PtrOut(None), PtrOut(None)) -> Zero,
+    setresgid(Int, Int, Int) -> Zero,
+    getresgid(PtrOut(None), PtrOut(None), PtrOut(None)) -> Zero,
+    getpgid(Int) -> Pid,
+    getsid(Int) -> Pid,
+    rt_sigpending(PtrOut(Some(1)), Int) -> Zero,
+    rt_sigtimedwait(PtrIn(Some(3)), PtrOut(None), PtrIn(None), Int) -> Int,
+    rt_sigqueueinfo(Int, Int, PtrIn(None)) -> Zero,
+    rt_sigsuspend(PtrIn(Some(1)), Int) -> Int,
+    sigaltstack(PtrIn(None), PtrOut(None)) -> Zero,
+    mknod(CStr, Int, Int) -> Zero,
+    statfs(CStr, PtrOut(None)) -> Zero,
+    fstatfs(Fd, PtrOut(None)) -> Zero,
+    getpriority(Int, Int) -> Int,
+    setpriority(Int, Int, Int) -> Zero,
+    mlock(Addr, Int) -> Zero,
+    munlock(Addr, Int) -> Zero,
+    mlockall(Flags) -> Zero,
+    munlockall() -> Zero,
+    prctl(Int, Int, Int, Int, Int) -> Int,
+    arch_prctl(Int, Int) -> Zero,
+    setrlimit(Int, PtrIn(None)) -> Zero,
+    chroot(CStr) -> Zero,
+    sync() -> Zero,
+    mount(CStr, CStr, CStr, Flags, PtrIn(None)) -> Zero,
+    umount2(CStr, Flags) -> Zero,
+    gettid() -> Pid,
+    readahead(Fd, Int, Int) -> Zero,
+    tkill(Int, Int) -> Zero,
+    time(PtrOut(None)) -> Int,
+    futex(PtrIn(None), Int, Int, PtrIn(None), PtrIn(None), Int) -> Int,
+    sched_setaffinity(Int, Int, PtrIn(Some(1))) -> Zero,
+    sched_getaffinity(Int, Int, PtrOut(Some(1))) -> Size,
+    getdents64(Fd, PtrOut(Some(2)), Int) -> Size,
+    set_tid_address(PtrOut(None)) -> Pid,
+    restart_syscall() -> Int,
+    fadvise64(Fd, Int, Int, Int) -> Zero,
+    timer_create(Int, PtrIn(None), PtrOut(None)) -> Zero,
+    timer_settime(Int, Flags, PtrIn(None), PtrOut(None)) -> Zero,
+    timer_gettime(Int, PtrOut(None)) -> Zero,
+    timer_getoverrun(Int) -> Int,
+    timer_delete(Int) -> Zero,
+    clock_settime(Int, PtrIn(None)) -> Zero,
+    clock_gettime(Int, PtrOut(None)) -> Zero,
+    clock_getres(Int, PtrOut(None)) -> Zero,
+    clock_nanosleep(Int, Flags, PtrIn(None), PtrOut(None)) -> Zero,
+    exit_group(Int) -> NoReturn,
+    epoll_wait(Fd, PtrOut(Some(2)), Int, Int) -> Size,
+    epoll_ctl(Fd, Int, Fd, PtrIn(None)) -> Zero,
+    tgkill(Int, Int, Int) -> Zero,
+    utimes(CStr, PtrIn(None)) -> Zero,
+    waitid(Int, Int, PtrOut(None), Flags, PtrOut(None)) -> Zero,
+    inotify_init() -> Fd,
+    inotify_add_watch(Fd, CStr, Flags) -> Int,
+    inotify_rm_watch(Fd, Int) -> Zero,
+    openat(Fd, CStr, Flags, Int) -> Fd,
+    mkdirat(Fd, CStr, Int) -> Zero,
+    mknodat(Fd, CStr, Int, Int) -> Zero,
+    fchownat(Fd, CStr, Int, Int, Flags) -> Zero,
+    newfstatat(Fd, CStr, PtrOut(None), Flags) -> Zero,
+    unlinkat(Fd, CStr, Flags) -> Zero,
+    renameat(Fd, CStr, Fd, CStr) -> Zero,
+    linkat(Fd, CStr, Fd, CStr, Flags) -> Zero,
+    symlinkat(CStr, Fd, CStr) -> Zero,
+    readlinkat(Fd, CStr, PtrOut(Some(3)), Int) -> Size,
+    fchmodat(Fd, CStr, Int) -> Zero,
+    faccessat(Fd, CStr, Int) -> Zero,
+    pselect6(Int, PtrInOut(None), PtrInOut(None), PtrInOut(None), PtrInOut(None), PtrIn(None)) -> Int,
+    ppoll(PtrInOut(Some(1)), Int, PtrInOut(None), PtrIn(Some(4)), Int) -> Int,
+    unshare(Flags) -> Zero,
+    set_robust_list(PtrIn(Some(1)), Int) -> Zero,
+    get_robust_list(Int, PtrOut(None), PtrOut(None)) -> Zero,
+    splice(Fd, PtrInOut(None), Fd, PtrInOut(None), Int, Flags) -> Size,
+    tee(Fd, Fd, Int, Flags) -> Size,
+    sync_file_range(Fd, Int, Int, Flags) -> Zero,
+    utimensat(Fd, CStr, PtrIn(None), Flags) -> Zero,
+    epoll_pwait(Fd, PtrOut(Some(2)), Int, Int, PtrIn(Some(5)), Int) -> Size,
+    signalfd(Fd, PtrIn(Some(2)), Int) -> Fd,
+    timerfd_create(Int, Flags) -> Fd,
+    eventfd(Int) -> Fd,
+    fallocate(Fd, Flags, Int, Int) -> Zero,
+    timerfd_settime(Fd, Flags, PtrIn(None), PtrOut(None)) -> Zero,
+    timerfd_gettime(Fd, PtrOut(None)) -> Zero,
+    accept4(Fd, PtrOut(None), PtrInOut(None), Flags) -> Fd,
+    signalfd4(Fd, PtrIn(Some(2)), Int, Flags) -> Fd,
+    eventfd2(Int, Flags) -> Fd,
+    epoll_create(Int) -> Fd,
+    epoll_create1(Flags) -> Fd,
+    dup3(Fd, Fd, Flags) -> Fd,
+    pipe2(PtrOut(None), Flags) -> Zero,
+    inotify_init1(Flags) -> Fd,
+    preadv(Fd, PtrIn(Some(2)), Int, Int, Int) -> Size,
+    pwritev(Fd, PtrIn(Some(2)), Int, Int, Int) -> Size,
+    recvmmsg(Fd, PtrInOut(Some(2)), Int, Flags, PtrInOut(None)) -> Size,
+    prlimit64(Int, Int, PtrIn(None), PtrOut(None)) -> Zero,
+    syncfs(Fd) -> Zero,
+    sendmmsg(Fd, PtrInOut(Some(2)), Int, Flags) -> Size,
+    getcpu(PtrOut(None), PtrOut(None), PtrOut(None)) -> Zero,
+    renameat2(Fd, CStr, Fd, CStr, Flags) -> Zero,
+    seccomp(Int, Flags, PtrIn(None)) -> Int,
+    getrandom(PtrOut(Some(1)), Int, Flags) -> Size,
+    memfd_create(CStr, Flags) -> Fd,
+    execveat(Fd, CStr, PtrIn(None), PtrIn(None), Flags) -> Zero,
+    membarrier(Int, Flags, Int) -> Int,
+    mlock2(Addr, Int, Flags) -> Zero,
+    copy_file_range(Fd, PtrInOut(None), Fd, PtrInOut(None), Int, Flags) -> Size,
+    preadv2(Fd, PtrIn(Some(2)), Int, Int, Int, Flags) -> Size,
+    pwritev2(Fd, PtrIn(Some(2)), Int, Int, Int, Flags) -> Size,
+    statx(Fd, CStr, Flags, Flags, PtrOut(None)) -> Zero,
+    rseq(PtrIn(Some(1)), Int, Flags, Int) -> Zero,
+    pidfd_send_signal(Fd, Int, PtrIn(None), Flags) -> Zero,
+    io_uring_setup(Int, PtrInOut(None)) -> Fd,
+    io_uring_enter(Fd, Int, Int, Flags, PtrIn(Some(5)), Int) -> Size,
+    io_uring_register(Fd, Int, PtrIn(None), Int) -> Int,
+    pidfd_open(Int, Flags) -> Fd,
+    clone3(PtrIn(Some(1)), Int) -> Pid,
+    close_range(Int, Int, Flags) -> Zero,
+    openat2(Fd, CStr, PtrIn(Some(3)), Int) -> Fd,
+    pidfd_getfd(Fd, Int, Flags) -> Fd,
+    faccessat2(Fd, CStr, Int, Flags) -> Zero,
+    epoll_pwait2(Fd, PtrOut(Some(2)), Int, PtrIn(None), PtrIn(Some(5)), Int) -> Size,
+    memfd_secret(Flags) -> Fd,
+    futex_waitv(PtrIn(Some(1)), Int, Flags, PtrIn(None), Int) -> Int,
+    fchmodat2(Fd, CStr, Int, Flags) -> Zero,
+    mseal(Addr, Int, Flags) -> Zero,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        number::SysCallNum,
+        portable::{Arch, PortableSysCall},
+        signature::{ArgKind, ReturnKind},
+    };
+
+    #[test]
+    fn test_signature() {
+        let s0 = SysCallNum::read.signature().unwrap();
+        assert_eq!(s0.arg_count(), 3);
+        assert_eq!(
+            s0.args,
+            &[ArgKind::Fd, ArgKind::PtrOut(Some(2)), ArgKind::Int]
+        );
+        assert_eq!(s0.ret, ReturnKind::Size);
+
+        let s1 = SysCallNum::openat.signature().unwrap();
+        assert_eq!(s1.arg_count(), 4);
+        assert_eq!(s1.args[1], ArgKind::CStr);
+        assert_eq!(s1.ret, ReturnKind::Fd);
+
+        assert_eq!(SysCallNum::getpid.signature().unwrap().arg_count(), 0);
+        assert_eq!(
+            SysCallNum::exit_group.signature().unwrap().ret,
+            ReturnKind::NoReturn
+        );
+
+        // the `child_tid` and `tls` of `clone` are swapped on aarch64 and riscv64
+        let clone_ = PortableSysCall::clone;
+        assert_eq!(
+            clone_.signature_for(Arch::X86_64).unwrap().args[3],
+            ArgKind::PtrOut(None)
+        );
+        assert_eq!(
+            clone_.signature_for(Arch::Aarch64).unwrap().args[3],
+            ArgKind::Int
+        );
+        assert_eq!(
+            clone_.signature_for(Arch::Riscv64).unwrap().args[4],
+            ArgKind::PtrOut(None)
+        );
+
+        // the kernel writes the remaining time back into the timeouts
+        assert_eq!(
+            PortableSysCall::pselect6.signature().unwrap().args[4],
+            ArgKind::PtrInOut(None)
+        );
+        assert_eq!(
+            PortableSysCall::ppoll.signature().unwrap().args[2],
+            ArgKind::PtrInOut(None)
+        );
+        assert_eq!(
+            PortableSysCall::recvmmsg.signature().unwrap().args[4],
+            ArgKind::PtrInOut(None)
+        );
+        assert_eq!(
+            PortableSysCall::epoll_pwait2.signature().unwrap().args[3],
+            ArgKind::PtrIn(None)
+        );
+        // the addresses of the memory mappings are not dereferenced
+        for syscall in [
+            PortableSysCall::mmap,
+            PortableSysCall::munmap,
+            PortableSysCall::mprotect,
+        ] {
+            assert_eq!(syscall.signature().unwrap().args[0], ArgKind::Addr);
+        }
+    }
+
+    #[test]
+    fn test_signature_table() {
+        for syscall in PortableSysCall::iter() {
+            for arch in Arch::ALL {
+                let Some(signature) = syscall.signature_for(arch) else {
+                    continue;
+                };
+
+                // all syscalls take at most 6 arguments
+                assert!(signature.arg_count() <= 6, "{}", syscall.name());
+
+                // the length of the memory comes from an integer argument
+                for kind in signature.args {
+                    if let ArgKind::PtrIn(Some(index))
+                    | ArgKind::PtrOut(Some(index))
+                    | ArgKind::PtrInOut(Some(index)) = kind
+                    {
+                        assert_eq!(
+                            signature.args.get(*index),
+                            Some(&ArgKind::Int),
+                            "{}",
+                            syscall.name()
+                        );
+                    }
+                }
+
+                assert_eq!(
+                    signature.ret == ReturnKind::NoReturn,
+                    matches!(syscall, PortableSysCall::exit | PortableSysCall::exit_group)
+                );
+            }
+        }
+
+        // agrees with the column "noreturn" of the kernel syscall table
+        #[cfg(target_arch = "x86_64")]
+        for num in SysCallNum::iter() {
+            if let Some(signature) = num.signature() {
+                assert_eq!(signature.ret == ReturnKind::NoReturn, num.is_noreturn());
+            }
+        }
+
+        // the common syscalls are listed on all architectures
+        for num in [
+            SysCallNum::read,
+            SysCallNum::write,
+            SysCallNum::openat,
+            SysCallNum::close,
+            SysCallNum::mmap,
+            SysCallNum::clone,
+            SysCallNum::execve,
+            SysCallNum::exit_group,
+        ] {
+            assert!(num.signature().is_some(), "{}", num.name());
+        }
+    }
+}
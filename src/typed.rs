@@ -0,0 +1,203 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the typed version of the functions in the module `call`.
+//
+// the functions in this module have the same names and arguments as
+// the functions in the module `call`, but the error number is decoded
+// into `SysCallError` instead of a bare `usize`, e.g.
+//
+// ```rust
+// match unsafe { syscall_with_4_args(SysCallNum::openat as usize, dirfd, path, flags, mode) } {
+//     Ok(fd) => ...,
+//     Err(SysCallError::Errno(Errno::ENOENT)) => ...,
+//     Err(e) => ...,
+// }
+// ```
+
+use crate::{
+    call, dispatch,
+    errno::{Errno, SysCallError},
+};
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_without_args(num: usize) -> Result<usize, SysCallError> {
+    call::syscall_without_args(num).map_err(convert_error_number)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_1_arg(num: usize, arg1: usize) -> Result<usize, SysCallError> {
+    call::syscall_with_1_arg(num, arg1).map_err(convert_error_number)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_2_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+) -> Result<usize, SysCallError> {
+    call::syscall_with_2_args(num, arg1, arg2).map_err(convert_error_number)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_3_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+) -> Result<usize, SysCallError> {
+    call::syscall_with_3_args(num, arg1, arg2, arg3).map_err(convert_error_number)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_4_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+) -> Result<usize, SysCallError> {
+    call::syscall_with_4_args(num, arg1, arg2, arg3, arg4).map_err(convert_error_number)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_5_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+) -> Result<usize, SysCallError> {
+    call::syscall_with_5_args(num, arg1, arg2, arg3, arg4, arg5).map_err(convert_error_number)
+}
+
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall_with_6_args(
+    num: usize,
+    arg1: usize,
+    arg2: usize,
+    arg3: usize,
+    arg4: usize,
+    arg5: usize,
+    arg6: usize,
+) -> Result<usize, SysCallError> {
+    call::syscall_with_6_args(num, arg1, arg2, arg3, arg4, arg5, arg6).map_err(convert_error_number)
+}
+
+// the typed version of `dispatch::syscall()`, returns `Err(EINVAL)` if
+// there are more than 6 arguments.
+#[allow(clippy::missing_safety_doc)]
+#[inline]
+pub unsafe fn syscall(num: usize, args: &[usize]) -> Result<usize, SysCallError> {
+    dispatch::syscall(num, args).map_err(convert_error_number)
+}
+
+// the following functions decode the results of the syscalls with
+// non-standard return conventions, e.g.
+//
+// ```rust
+// let result = unsafe { syscall_with_2_args(SysCallNum::getpriority as usize, PRIO_PROCESS, 0) };
+// let nice = decode_getpriority(result)?;
+// ```
+//
+// note that `ptrace(PTRACE_PEEK*)` does not need a decoder, the raw syscall
+// stores the word into the address of the argument `data` and returns 0
+// (it is the glibc wrapper which returns the word).
+
+// the kernel returns `20 - nice` (in the range of `1..=40`) to
+// avoid the negative values which look like errors.
+pub fn decode_getpriority(result: Result<usize, SysCallError>) -> Result<i32, SysCallError> {
+    result.map(|value| 20 - value as i32)
+}
+
+// the kernel returns the current program break on failure instead of an error
+// number, it is converted to `ENOMEM`. the `requested` is the argument of `brk`,
+// 0 is for querying the current program break.
+pub fn decode_brk(
+    requested: usize,
+    result: Result<usize, SysCallError>,
+) -> Result<usize, SysCallError> {
+    let current = result?;
+    if requested != 0 && current != requested {
+        Err(SysCallError::Errno(Errno::ENOMEM))
+    } else {
+        Ok(current)
+    }
+}
+
+#[inline(always)]
+fn convert_error_number(code: usize) -> SysCallError {
+    // the kernel returns error numbers in the range `1..=4095`,
+    // so it always fits in an `u32`.
+    SysCallError::from_code(code as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errno::{Errno, SysCallError},
+        number::SysCallNum,
+        typed::{
+            decode_brk, decode_getpriority, syscall, syscall_with_1_arg, syscall_with_2_args,
+            syscall_with_4_args, syscall_without_args,
+        },
+    };
+
+    #[test]
+    fn test_typed_syscall() {
+        let result0 = unsafe { syscall_without_args(SysCallNum::getpid as usize) };
+        assert!(matches!(result0, Ok(pid) if pid > 0));
+
+        // AT_FDCWD = -100
+        let file_path0 = b"/this/file/should/not/exist\0";
+        let result1 = unsafe {
+            syscall_with_4_args(
+                SysCallNum::openat as usize,
+                -100isize as usize,
+                file_path0.as_ptr() as usize,
+                0,
+                0,
+            )
+        };
+        assert!(matches!(result1, Err(SysCallError::Errno(Errno::ENOENT))));
+
+        let result2 = unsafe { syscall_with_1_arg(SysCallNum::close as usize, usize::MAX >> 1) };
+        assert_eq!(result2, Err(SysCallError::Errno(Errno::EBADF)));
+
+        let result3 = unsafe { syscall(SysCallNum::getpid as usize, &[0; 7]) };
+        assert_eq!(result3, Err(SysCallError::Errno(Errno::EINVAL)));
+    }
+
+    #[test]
+    fn test_decode_return_value() {
+        // PRIO_PROCESS = 0
+        let result0 = unsafe { syscall_with_2_args(SysCallNum::getpriority as usize, 0, 0) };
+        let nice = decode_getpriority(result0).unwrap();
+        assert!((-20..=19).contains(&nice));
+
+        let result1 = unsafe { syscall_with_1_arg(SysCallNum::brk as usize, 0) };
+        let current = decode_brk(0, result1).unwrap();
+        assert!(current > 0);
+
+        // the kernel returns the current program break instead of an error.
+        // the other tests (which run in parallel) may move the program break
+        // by allocating, so it is not necessarily the same as `current`.
+        let result2 = unsafe { syscall_with_1_arg(SysCallNum::brk as usize, 1) };
+        assert!(result2.unwrap() >= current);
+        assert_eq!(
+            decode_brk(1, result2),
+            Err(SysCallError::Errno(Errno::ENOMEM))
+        );
+    }
+}
@@ -0,0 +1,1169 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the structures which are read or written by the kernel.
+//
+// the names of the structures and fields are the same as the C definitions
+// of the kernel (which are not always the same as libc, e.g. `sigaction`
+// and `sigset_t`), so they are easy to look up in the man pages.
+//
+// the layouts are checked by the compile-time assertions at the end of
+// this file, and they are also checked against the C definitions by the
+// unit tests (which require the system C compiler `cc`).
+//
+// ref:
+// - 'arch/x86/include/uapi/asm/stat.h'
+// - 'include/uapi/asm-generic/stat.h'
+// - 'include/uapi/linux/stat.h'
+// - 'include/uapi/linux/time_types.h'
+// - 'include/uapi/linux/uio.h'
+// - 'include/linux/socket.h'
+// - 'include/uapi/linux/in.h'
+// - 'include/uapi/linux/in6.h'
+// - 'include/uapi/linux/un.h'
+// - 'arch/x86/include/uapi/asm/signal.h'
+// - 'include/uapi/asm-generic/signal.h'
+// - 'include/uapi/asm-generic/siginfo.h'
+// - 'include/uapi/linux/signalfd.h'
+// - 'include/uapi/asm-generic/poll.h'
+// - 'include/uapi/linux/eventpoll.h'
+// - 'include/uapi/linux/resource.h'
+// - 'include/uapi/linux/utsname.h'
+// - 'include/uapi/linux/sysinfo.h'
+// - 'include/uapi/linux/sched.h'
+// - 'include/uapi/linux/openat2.h'
+// - 'include/uapi/linux/io_uring.h'
+
+#![allow(non_camel_case_types)]
+
+use std::{ffi::c_void, time::Duration};
+
+// the `struct stat` of `fstat` and `newfstatat` on x86_64.
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct stat {
+    pub st_dev: u64,
+    pub st_ino: u64,
+    pub st_nlink: u64,
+    pub st_mode: u32,
+    pub st_uid: u32,
+    pub st_gid: u32,
+    pub __pad0: u32,
+    pub st_rdev: u64,
+    pub st_size: i64,
+    pub st_blksize: i64,
+    pub st_blocks: i64,
+    pub st_atime: i64,
+    pub st_atime_nsec: u64,
+    pub st_mtime: i64,
+    pub st_mtime_nsec: u64,
+    pub st_ctime: i64,
+    pub st_ctime_nsec: u64,
+    pub __unused: [i64; 3],
+}
+
+// the `struct stat` of asm-generic, i.e. aarch64 and riscv64.
+#[cfg(not(target_arch = "x86_64"))]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct stat {
+    pub st_dev: u64,
+    pub st_ino: u64,
+    pub st_mode: u32,
+    pub st_nlink: u32,
+    pub st_uid: u32,
+    pub st_gid: u32,
+    pub st_rdev: u64,
+    pub __pad1: u64,
+    pub st_size: i64,
+    pub st_blksize: i32,
+    pub __pad2: i32,
+    pub st_blocks: i64,
+    pub st_atime: i64,
+    pub st_atime_nsec: u64,
+    pub st_mtime: i64,
+    pub st_mtime_nsec: u64,
+    pub st_ctime: i64,
+    pub st_ctime_nsec: u64,
+    pub __unused4: u32,
+    pub __unused5: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct statx_timestamp {
+    pub tv_sec: i64,
+    pub tv_nsec: u32,
+    pub __reserved: i32,
+}
+
+// the `struct statx` of kernel 6.14.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct statx {
+    pub stx_mask: u32,
+    pub stx_blksize: u32,
+    pub stx_attributes: u64,
+    pub stx_nlink: u32,
+    pub stx_uid: u32,
+    pub stx_gid: u32,
+    pub stx_mode: u16,
+    pub __spare0: [u16; 1],
+    pub stx_ino: u64,
+    pub stx_size: u64,
+    pub stx_blocks: u64,
+    pub stx_attributes_mask: u64,
+    pub stx_atime: statx_timestamp,
+    pub stx_btime: statx_timestamp,
+    pub stx_ctime: statx_timestamp,
+    pub stx_mtime: statx_timestamp,
+    pub stx_rdev_major: u32,
+    pub stx_rdev_minor: u32,
+    pub stx_dev_major: u32,
+    pub stx_dev_minor: u32,
+    pub stx_mnt_id: u64,
+    pub stx_dio_mem_align: u32,
+    pub stx_dio_offset_align: u32,
+    pub stx_subvol: u64,
+    pub stx_atomic_write_unit_min: u32,
+    pub stx_atomic_write_unit_max: u32,
+    pub stx_atomic_write_segments_max: u32,
+    pub stx_dio_read_offset_align: u32,
+    pub __spare3: [u64; 9],
+}
+
+// i.e. `struct __kernel_timespec`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+// the durations which do not fit in `tv_sec` are saturated to the
+// maximum `timespec`, i.e. "effectively forever", rather than wrapping
+// to a negative value (which the kernel rejects with `EINVAL`).
+impl From<Duration> for timespec {
+    fn from(duration: Duration) -> Self {
+        match i64::try_from(duration.as_secs()) {
+            Ok(tv_sec) => Self {
+                tv_sec,
+                tv_nsec: duration.subsec_nanos() as i64,
+            },
+            Err(_) => Self {
+                tv_sec: i64::MAX,
+                tv_nsec: 999_999_999,
+            },
+        }
+    }
+}
+
+// i.e. `struct __kernel_itimerspec`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct itimerspec {
+    pub it_interval: timespec,
+    pub it_value: timespec,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct timeval {
+    pub tv_sec: i64,
+    pub tv_usec: i64,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct iovec {
+    pub iov_base: *mut c_void,
+    pub iov_len: usize,
+}
+
+// i.e. `struct user_msghdr` of the kernel.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct msghdr {
+    pub msg_name: *mut c_void,
+    pub msg_namelen: u32,
+    pub msg_iov: *mut iovec,
+    pub msg_iovlen: usize,
+    pub msg_control: *mut c_void,
+    pub msg_controllen: usize,
+    pub msg_flags: u32,
+}
+
+// the header of the control message, the data follows the header
+// (aligned to `usize`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct cmsghdr {
+    pub cmsg_len: usize,
+    pub cmsg_level: i32,
+    pub cmsg_type: i32,
+}
+
+// the IPv4 address in the network byte order.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct in_addr {
+    pub s_addr: u32,
+}
+
+// `sin_port` is in the network byte order (big-endian).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct sockaddr_in {
+    pub sin_family: u16,
+    pub sin_port: u16,
+    pub sin_addr: in_addr,
+    pub sin_zero: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct in6_addr {
+    pub s6_addr: [u8; 16],
+}
+
+// `sin6_port` and `sin6_flowinfo` are in the network byte order,
+// `sin6_scope_id` is in the host byte order.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct sockaddr_in6 {
+    pub sin6_family: u16,
+    pub sin6_port: u16,
+    pub sin6_flowinfo: u32,
+    pub sin6_addr: in6_addr,
+    pub sin6_scope_id: u32,
+}
+
+// the Unix domain socket address, `sun_path` is a pathname (terminated
+// by NUL), or an abstract name (starting with NUL, which is not
+// terminated), or empty (unnamed). the length of the address determines
+// the length of the name.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct sockaddr_un {
+    pub sun_family: u16,
+    pub sun_path: [u8; 108],
+}
+
+impl Default for sockaddr_un {
+    fn default() -> Self {
+        Self {
+            sun_family: 0,
+            sun_path: [0; 108],
+        }
+    }
+}
+
+// the buffer which is large enough for any socket address, i.e.
+// `struct __kernel_sockaddr_storage`.
+#[repr(C, align(8))]
+#[derive(Debug, Clone, Copy)]
+pub struct sockaddr_storage {
+    pub ss_family: u16,
+    pub __data: [u8; 126],
+}
+
+impl Default for sockaddr_storage {
+    fn default() -> Self {
+        Self {
+            ss_family: 0,
+            __data: [0; 126],
+        }
+    }
+}
+
+// the value of the socket option `SO_LINGER`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct linger {
+    pub l_onoff: i32,
+    pub l_linger: i32,
+}
+
+// the credentials of the peer process, i.e. the value of the socket
+// option `SO_PEERCRED`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ucred {
+    pub pid: i32,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+// the signal set of the kernel (64 signals), which is different from the
+// `sigset_t` of glibc (1024 signals).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct sigset_t {
+    pub sig: [u64; 1],
+}
+
+// the signal numbers are `1..=64`, i.e. the standard signals (see
+// `flags::Signal`) and the real-time signals.
+impl sigset_t {
+    pub fn empty() -> Self {
+        Self { sig: [0] }
+    }
+
+    pub fn fill() -> Self {
+        Self { sig: [u64::MAX] }
+    }
+
+    pub fn add(&mut self, signum: i32) {
+        debug_assert!((1..=64).contains(&signum));
+        self.sig[0] |= 1 << (signum - 1);
+    }
+
+    pub fn remove(&mut self, signum: i32) {
+        debug_assert!((1..=64).contains(&signum));
+        self.sig[0] &= !(1 << (signum - 1));
+    }
+
+    pub fn contains(&self, signum: i32) -> bool {
+        (1..=64).contains(&signum) && self.sig[0] & (1 << (signum - 1)) != 0
+    }
+}
+
+// the `struct sigaction` of `rt_sigaction`, which is different from
+// the `struct sigaction` of glibc.
+//
+// `sa_handler` is the address of the handler function, or `SIG_DFL` (0)
+// or `SIG_IGN` (1).
+//
+// `sa_restorer` (with the flag `SA_RESTORER`) is required on x86_64 and it is
+// optional on aarch64, riscv64 does not have this field, the kernel uses the
+// VDSO trampoline instead.
+#[cfg(not(target_arch = "riscv64"))]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct sigaction {
+    pub sa_handler: usize,
+    pub sa_flags: u64,
+    pub sa_restorer: usize,
+    pub sa_mask: sigset_t,
+}
+
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct sigaction {
+    pub sa_handler: usize,
+    pub sa_flags: u64,
+    pub sa_mask: sigset_t,
+}
+
+// the signal information which is read from the signalfd.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct signalfd_siginfo {
+    pub ssi_signo: u32,
+    pub ssi_errno: i32,
+    pub ssi_code: i32,
+    pub ssi_pid: u32,
+    pub ssi_uid: u32,
+    pub ssi_fd: i32,
+    pub ssi_tid: u32,
+    pub ssi_band: u32,
+    pub ssi_overrun: u32,
+    pub ssi_trapno: u32,
+    pub ssi_status: i32,
+    pub ssi_int: i32,
+    pub ssi_ptr: u64,
+    pub ssi_utime: u64,
+    pub ssi_stime: u64,
+    pub ssi_addr: u64,
+    pub ssi_addr_lsb: u16,
+    pub __pad2: u16,
+    pub ssi_syscall: i32,
+    pub ssi_call_addr: u64,
+    pub ssi_arch: u32,
+    pub __pad: [u8; 28],
+}
+
+// the signal information of `waitid`, `rt_sigtimedwait` and the signal
+// handlers with `SA_SIGINFO`.
+//
+// the fields after `si_code` are a union in C, which depends on the signal
+// and `si_code`, they are read by the accessor methods.
+#[repr(C, align(8))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct siginfo_t {
+    pub si_signo: i32,
+    pub si_errno: i32,
+    pub si_code: i32,
+    pub __pad0: i32,
+    pub _sifields: [i32; 28],
+}
+
+impl siginfo_t {
+    // the sender of `kill`, `sigqueue` and `SIGCHLD`.
+    pub fn si_pid(&self) -> i32 {
+        self._sifields[0]
+    }
+
+    pub fn si_uid(&self) -> u32 {
+        self._sifields[1] as u32
+    }
+
+    // the exit code or the signal of the child, for `SIGCHLD`.
+    pub fn si_status(&self) -> i32 {
+        self._sifields[2]
+    }
+
+    // the value (i.e. `union sigval`) of `sigqueue` and `rt_sigqueueinfo`,
+    // which is at the same offset as `si_status`.
+    pub fn si_value(&self) -> usize {
+        unsafe { self._sifields.as_ptr().add(2).cast::<usize>().read() }
+    }
+
+    pub fn set_si_value(&mut self, value: usize) {
+        unsafe {
+            self._sifields
+                .as_mut_ptr()
+                .add(2)
+                .cast::<usize>()
+                .write(value)
+        }
+    }
+}
+
+// the alternate signal stack of `sigaltstack`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct stack_t {
+    pub ss_sp: *mut c_void,
+    pub ss_flags: i32,
+    pub ss_size: usize,
+}
+
+impl Default for stack_t {
+    fn default() -> Self {
+        Self {
+            ss_sp: std::ptr::null_mut(),
+            ss_flags: 0,
+            ss_size: 0,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct pollfd {
+    pub fd: i32,
+    pub events: i16,
+    pub revents: i16,
+}
+
+// `struct epoll_event` is packed on x86_64 (for compatible with the 32-bit
+// x86), so the field `data` is not aligned, copy the fields before use.
+#[cfg(target_arch = "x86_64")]
+#[repr(C, packed)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct epoll_event {
+    pub events: u32,
+    pub data: u64,
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct epoll_event {
+    pub events: u32,
+    pub data: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct rlimit {
+    pub rlim_cur: u64,
+    pub rlim_max: u64,
+}
+
+// the fields are null-terminated strings.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct utsname {
+    pub sysname: [u8; 65],
+    pub nodename: [u8; 65],
+    pub release: [u8; 65],
+    pub version: [u8; 65],
+    pub machine: [u8; 65],
+    pub domainname: [u8; 65],
+}
+
+impl Default for utsname {
+    fn default() -> Self {
+        Self {
+            sysname: [0; 65],
+            nodename: [0; 65],
+            release: [0; 65],
+            version: [0; 65],
+            machine: [0; 65],
+            domainname: [0; 65],
+        }
+    }
+}
+
+// the field `_f` (the padding for the 32-bit architectures) is empty
+// on the 64-bit architectures.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct sysinfo {
+    pub uptime: i64,
+    pub loads: [u64; 3],
+    pub totalram: u64,
+    pub freeram: u64,
+    pub sharedram: u64,
+    pub bufferram: u64,
+    pub totalswap: u64,
+    pub freeswap: u64,
+    pub procs: u16,
+    pub pad: u16,
+    pub totalhigh: u64,
+    pub freehigh: u64,
+    pub mem_unit: u32,
+    pub _f: [u8; 0],
+}
+
+// the argument of `clone3`, all pointers are passed as `u64`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct clone_args {
+    pub flags: u64,
+    pub pidfd: u64,
+    pub child_tid: u64,
+    pub parent_tid: u64,
+    pub exit_signal: u64,
+    pub stack: u64,
+    pub stack_size: u64,
+    pub tls: u64,
+    pub set_tid: u64,
+    pub set_tid_size: u64,
+    pub cgroup: u64,
+}
+
+// the argument of `openat2`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct open_how {
+    pub flags: u64,
+    pub mode: u64,
+    pub resolve: u64,
+}
+
+// the offsets of the fields in the submission queue ring of io_uring.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_sqring_offsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    pub array: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+// the offsets of the fields in the completion queue ring of io_uring.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_cqring_offsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    pub cqes: u32,
+    pub flags: u32,
+    pub resv1: u32,
+    pub user_addr: u64,
+}
+
+// the argument of `io_uring_setup`, the fields after `wq_fd` are
+// filled by the kernel.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_uring_params {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    pub flags: u32,
+    pub sq_thread_cpu: u32,
+    pub sq_thread_idle: u32,
+    pub features: u32,
+    pub wq_fd: u32,
+    pub resv: [u32; 3],
+    pub sq_off: io_sqring_offsets,
+    pub cq_off: io_cqring_offsets,
+}
+
+// the submission queue entry of io_uring.
+//
+// the unions of the C structure are flattened to their first member,
+// e.g. `off` is also `addr2`, `rw_flags` is also `fsync_flags`, `msg_flags`,
+// `timeout_flags`, `accept_flags` and so on, and `splice_fd_in` is
+// also `file_index`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_uring_sqe {
+    pub opcode: u8,
+    pub flags: u8,
+    pub ioprio: u16,
+    pub fd: i32,
+    pub off: u64,
+    pub addr: u64,
+    pub len: u32,
+    pub rw_flags: u32,
+    pub user_data: u64,
+    pub buf_index: u16,
+    pub personality: u16,
+    pub splice_fd_in: i32,
+    pub addr3: u64,
+    pub __pad2: [u64; 1],
+}
+
+// the completion queue entry of io_uring, `res` is the result of the
+// operation, i.e. the negative errno on failure.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_uring_cqe {
+    pub user_data: u64,
+    pub res: i32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_uring_probe_op {
+    pub op: u8,
+    pub resv: u8,
+    pub flags: u16,
+    pub resv2: u32,
+}
+
+// the header of the result of `IORING_REGISTER_PROBE`, it is followed
+// by the array of `io_uring_probe_op`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct io_uring_probe {
+    pub last_op: u8,
+    pub ops_len: u8,
+    pub resv: u16,
+    pub resv2: [u32; 3],
+    pub ops: [io_uring_probe_op; 0],
+}
+
+// the compile-time layout assertions (64-bit ABI).
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+    use std::mem::{offset_of, size_of};
+
+    #[cfg(target_arch = "x86_64")]
+    assert!(size_of::<stat>() == 144);
+    #[cfg(not(target_arch = "x86_64"))]
+    assert!(size_of::<stat>() == 128);
+    assert!(offset_of!(stat, st_size) == 48);
+    assert!(offset_of!(stat, st_atime) == 72);
+
+    assert!(size_of::<statx_timestamp>() == 16);
+    assert!(size_of::<statx>() == 256);
+    assert!(offset_of!(statx, stx_mode) == 28);
+    assert!(offset_of!(statx, stx_atime) == 64);
+    assert!(offset_of!(statx, stx_mnt_id) == 144);
+    assert!(offset_of!(statx, __spare3) == 184);
+
+    assert!(size_of::<timespec>() == 16);
+    assert!(size_of::<itimerspec>() == 32);
+    assert!(size_of::<timeval>() == 16);
+    assert!(size_of::<iovec>() == 16);
+
+    assert!(size_of::<msghdr>() == 56);
+    assert!(offset_of!(msghdr, msg_iov) == 16);
+    assert!(offset_of!(msghdr, msg_flags) == 48);
+    assert!(size_of::<cmsghdr>() == 16);
+
+    assert!(size_of::<sockaddr_in>() == 16);
+    assert!(size_of::<sockaddr_in6>() == 28);
+    assert!(offset_of!(sockaddr_in6, sin6_scope_id) == 24);
+    assert!(size_of::<sockaddr_un>() == 110);
+    assert!(size_of::<sockaddr_storage>() == 128);
+    assert!(size_of::<linger>() == 8);
+    assert!(size_of::<ucred>() == 12);
+
+    assert!(size_of::<sigset_t>() == 8);
+    #[cfg(not(target_arch = "riscv64"))]
+    assert!(size_of::<sigaction>() == 32);
+    #[cfg(target_arch = "riscv64")]
+    assert!(size_of::<sigaction>() == 24);
+
+    assert!(size_of::<siginfo_t>() == 128);
+    assert!(offset_of!(siginfo_t, _sifields) == 16);
+    assert!(size_of::<stack_t>() == 24);
+    assert!(size_of::<signalfd_siginfo>() == 128);
+    assert!(offset_of!(signalfd_siginfo, ssi_ptr) == 48);
+    assert!(offset_of!(signalfd_siginfo, ssi_arch) == 96);
+
+    assert!(size_of::<pollfd>() == 8);
+
+    #[cfg(target_arch = "x86_64")]
+    assert!(size_of::<epoll_event>() == 12);
+    #[cfg(target_arch = "x86_64")]
+    assert!(offset_of!(epoll_event, data) == 4);
+    #[cfg(not(target_arch = "x86_64"))]
+    assert!(size_of::<epoll_event>() == 16);
+
+    assert!(size_of::<rlimit>() == 16);
+    assert!(size_of::<utsname>() == 390);
+    assert!(size_of::<sysinfo>() == 112);
+    assert!(offset_of!(sysinfo, procs) == 80);
+    assert!(offset_of!(sysinfo, mem_unit) == 104);
+    assert!(size_of::<clone_args>() == 88);
+    assert!(size_of::<open_how>() == 24);
+
+    assert!(size_of::<io_sqring_offsets>() == 40);
+    assert!(size_of::<io_cqring_offsets>() == 40);
+    assert!(size_of::<io_uring_params>() == 120);
+    assert!(offset_of!(io_uring_params, sq_off) == 40);
+    assert!(size_of::<io_uring_sqe>() == 64);
+    assert!(offset_of!(io_uring_sqe, rw_flags) == 28);
+    assert!(offset_of!(io_uring_sqe, splice_fd_in) == 44);
+    assert!(size_of::<io_uring_cqe>() == 16);
+    assert!(size_of::<io_uring_probe_op>() == 8);
+    assert!(size_of::<io_uring_probe>() == 16);
+};
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fmt::Write,
+        mem::{offset_of, size_of},
+        process::Command,
+        time::Duration,
+    };
+
+    use crate::types::{
+        clone_args, cmsghdr, epoll_event, io_cqring_offsets, io_sqring_offsets, io_uring_cqe,
+        io_uring_params, io_uring_probe, io_uring_probe_op, io_uring_sqe, iovec, itimerspec,
+        linger, msghdr, open_how, pollfd, rlimit, sigaction, siginfo_t, signalfd_siginfo, sigset_t,
+        sockaddr_in, sockaddr_in6, sockaddr_storage, sockaddr_un, stack_t, stat, statx, sysinfo,
+        timespec, timeval, ucred, utsname,
+    };
+
+    // a structure to be checked: (C type, size, [(field, offset)])
+    type Layout = (&'static str, usize, Vec<(&'static str, usize)>);
+
+    macro_rules! layout {
+        ($c_type:literal, $t:ty, [$($field:ident),* $(,)?]) => {
+            (
+                $c_type,
+                size_of::<$t>(),
+                vec![$((stringify!($field), offset_of!($t, $field)),)*],
+            )
+        };
+    }
+
+    // generates the C program which prints the layouts in the same
+    // format as `format_layouts()`.
+    fn generate_c_program(headers: &[&str], layouts: &[Layout]) -> String {
+        // `_GNU_SOURCE` is required for the field `domainname` of `struct utsname`.
+        let mut source = String::from("#define _GNU_SOURCE\n");
+        for header in ["stdio.h", "stddef.h"].iter().chain(headers) {
+            writeln!(source, "#include <{}>", header).unwrap();
+        }
+
+        writeln!(source, "int main(void) {{").unwrap();
+        for (c_type, _, fields) in layouts {
+            writeln!(source, "    printf(\"{0} %zu\\n\", sizeof({0}));", c_type).unwrap();
+            for (field, _) in fields {
+                writeln!(
+                    source,
+                    "    printf(\"{0}.{1} %zu\\n\", offsetof({0}, {1}));",
+                    c_type, field
+                )
+                .unwrap();
+            }
+        }
+        writeln!(source, "    return 0;\n}}").unwrap();
+        source
+    }
+
+    fn format_layouts(layouts: &[Layout]) -> String {
+        let mut text = String::new();
+        for (c_type, size, fields) in layouts {
+            writeln!(text, "{} {}", c_type, size).unwrap();
+            for (field, offset) in fields {
+                writeln!(text, "{}.{} {}", c_type, field, offset).unwrap();
+            }
+        }
+        text
+    }
+
+    // compiles and runs the C program, returns `None` if the C compiler
+    // is not available (e.g. cross compiling).
+    fn run_c_program(name: &str, source: &str) -> Option<String> {
+        // each test has its own directory since the tests run in parallel
+        let dir = std::env::temp_dir().join(format!(
+            "syscall-util-types-{}-{}",
+            name,
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let source_path = dir.join(format!("{}.c", name));
+        let binary_path = dir.join(name);
+        std::fs::write(&source_path, source).unwrap();
+
+        let Ok(status) = Command::new("cc")
+            .arg("-o")
+            .arg(&binary_path)
+            .arg(&source_path)
+            .status()
+        else {
+            eprintln!("C compiler `cc` is not available, skip checking the layouts");
+            return None;
+        };
+        assert!(status.success(), "failed to compile:\n{}", source);
+
+        let output = Command::new(&binary_path).output().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        Some(String::from_utf8(output.stdout).unwrap())
+    }
+
+    fn check_layouts(name: &str, headers: &[&str], layouts: &[Layout]) {
+        let source = generate_c_program(headers, layouts);
+        if let Some(output) = run_c_program(name, &source) {
+            assert_eq!(output, format_layouts(layouts));
+        }
+    }
+
+    #[test]
+    fn test_layout_of_kernel_structs() {
+        // these headers can not be included with the headers of libc
+        // because of the conflicting definitions.
+        let headers = [
+            "asm/stat.h",
+            "asm/signal.h",
+            "linux/stat.h",
+            "linux/time_types.h",
+            "linux/sched.h",
+            "linux/openat2.h",
+            "linux/signalfd.h",
+            "linux/io_uring.h",
+        ];
+
+        let layouts = vec![
+            layout!(
+                "struct stat",
+                stat,
+                [
+                    st_dev,
+                    st_ino,
+                    st_nlink,
+                    st_mode,
+                    st_uid,
+                    st_gid,
+                    st_rdev,
+                    st_size,
+                    st_blksize,
+                    st_blocks,
+                    st_atime,
+                    st_atime_nsec,
+                    st_mtime,
+                    st_mtime_nsec,
+                    st_ctime,
+                    st_ctime_nsec,
+                ]
+            ),
+            // the fields since kernel 6.11 are not checked because the
+            // headers of the system may be older.
+            layout!(
+                "struct statx",
+                statx,
+                [
+                    stx_mask,
+                    stx_blksize,
+                    stx_attributes,
+                    stx_nlink,
+                    stx_uid,
+                    stx_gid,
+                    stx_mode,
+                    stx_ino,
+                    stx_size,
+                    stx_blocks,
+                    stx_attributes_mask,
+                    stx_atime,
+                    stx_btime,
+                    stx_ctime,
+                    stx_mtime,
+                    stx_rdev_major,
+                    stx_rdev_minor,
+                    stx_dev_major,
+                    stx_dev_minor,
+                    stx_mnt_id,
+                    stx_dio_mem_align,
+                    stx_dio_offset_align,
+                ]
+            ),
+            layout!("struct __kernel_timespec", timespec, [tv_sec, tv_nsec]),
+            layout!("sigset_t", sigset_t, []),
+            #[cfg(not(target_arch = "riscv64"))]
+            layout!(
+                "struct sigaction",
+                sigaction,
+                [sa_handler, sa_flags, sa_restorer, sa_mask]
+            ),
+            #[cfg(target_arch = "riscv64")]
+            layout!(
+                "struct sigaction",
+                sigaction,
+                [sa_handler, sa_flags, sa_mask]
+            ),
+            layout!(
+                "struct clone_args",
+                clone_args,
+                [
+                    flags,
+                    pidfd,
+                    child_tid,
+                    parent_tid,
+                    exit_signal,
+                    stack,
+                    stack_size,
+                    tls,
+                    set_tid,
+                    set_tid_size,
+                    cgroup,
+                ]
+            ),
+            layout!("struct open_how", open_how, [flags, mode, resolve]),
+            layout!(
+                "struct __kernel_itimerspec",
+                itimerspec,
+                [it_interval, it_value]
+            ),
+            layout!(
+                "struct signalfd_siginfo",
+                signalfd_siginfo,
+                [
+                    ssi_signo,
+                    ssi_errno,
+                    ssi_code,
+                    ssi_pid,
+                    ssi_uid,
+                    ssi_fd,
+                    ssi_tid,
+                    ssi_band,
+                    ssi_overrun,
+                    ssi_trapno,
+                    ssi_status,
+                    ssi_int,
+                    ssi_ptr,
+                    ssi_utime,
+                    ssi_stime,
+                    ssi_addr,
+                    ssi_addr_lsb,
+                    ssi_syscall,
+                    ssi_call_addr,
+                    ssi_arch,
+                ]
+            ),
+            // `user_addr` (since kernel 6.5) is not checked because the
+            // headers of the system may be older.
+            layout!(
+                "struct io_sqring_offsets",
+                io_sqring_offsets,
+                [
+                    head,
+                    tail,
+                    ring_mask,
+                    ring_entries,
+                    flags,
+                    dropped,
+                    array,
+                    resv1
+                ]
+            ),
+            layout!(
+                "struct io_cqring_offsets",
+                io_cqring_offsets,
+                [
+                    head,
+                    tail,
+                    ring_mask,
+                    ring_entries,
+                    overflow,
+                    cqes,
+                    flags,
+                    resv1
+                ]
+            ),
+            layout!(
+                "struct io_uring_params",
+                io_uring_params,
+                [
+                    sq_entries,
+                    cq_entries,
+                    flags,
+                    sq_thread_cpu,
+                    sq_thread_idle,
+                    features,
+                    wq_fd,
+                    resv,
+                    sq_off,
+                    cq_off,
+                ]
+            ),
+            layout!(
+                "struct io_uring_sqe",
+                io_uring_sqe,
+                [
+                    opcode,
+                    flags,
+                    ioprio,
+                    fd,
+                    off,
+                    addr,
+                    len,
+                    rw_flags,
+                    user_data,
+                    buf_index,
+                    personality,
+                    splice_fd_in,
+                    addr3,
+                    __pad2,
+                ]
+            ),
+            layout!("struct io_uring_cqe", io_uring_cqe, [user_data, res, flags]),
+            layout!(
+                "struct io_uring_probe_op",
+                io_uring_probe_op,
+                [op, resv, flags, resv2]
+            ),
+            layout!(
+                "struct io_uring_probe",
+                io_uring_probe,
+                [last_op, ops_len, resv, resv2, ops]
+            ),
+        ];
+
+        check_layouts("kernel_structs", &headers, &layouts);
+    }
+
+    #[test]
+    fn test_layout_of_libc_compatible_structs() {
+        // these structures are the same in the kernel and libc.
+        let headers = [
+            "time.h",
+            "sys/time.h",
+            "sys/uio.h",
+            "sys/socket.h",
+            "sys/un.h",
+            "netinet/in.h",
+            "signal.h",
+            "poll.h",
+            "sys/epoll.h",
+            "sys/resource.h",
+            "sys/utsname.h",
+            "sys/sysinfo.h",
+        ];
+
+        let layouts = vec![
+            layout!("struct timespec", timespec, [tv_sec, tv_nsec]),
+            layout!("struct timeval", timeval, [tv_sec, tv_usec]),
+            layout!("struct iovec", iovec, [iov_base, iov_len]),
+            layout!(
+                "struct msghdr",
+                msghdr,
+                [
+                    msg_name,
+                    msg_namelen,
+                    msg_iov,
+                    msg_iovlen,
+                    msg_control,
+                    msg_controllen,
+                    msg_flags,
+                ]
+            ),
+            layout!("struct cmsghdr", cmsghdr, [cmsg_len, cmsg_level, cmsg_type]),
+            layout!(
+                "struct sockaddr_in",
+                sockaddr_in,
+                [sin_family, sin_port, sin_addr, sin_zero]
+            ),
+            layout!(
+                "struct sockaddr_in6",
+                sockaddr_in6,
+                [
+                    sin6_family,
+                    sin6_port,
+                    sin6_flowinfo,
+                    sin6_addr,
+                    sin6_scope_id,
+                ]
+            ),
+            layout!("struct sockaddr_un", sockaddr_un, [sun_family, sun_path]),
+            layout!("struct sockaddr_storage", sockaddr_storage, [ss_family]),
+            layout!("struct linger", linger, [l_onoff, l_linger]),
+            layout!("struct ucred", ucred, [pid, uid, gid]),
+            layout!("siginfo_t", siginfo_t, [si_signo, si_errno, si_code]),
+            layout!("stack_t", stack_t, [ss_sp, ss_flags, ss_size]),
+            layout!("struct pollfd", pollfd, [fd, events, revents]),
+            layout!("struct epoll_event", epoll_event, [events, data]),
+            layout!("struct rlimit", rlimit, [rlim_cur, rlim_max]),
+            layout!(
+                "struct utsname",
+                utsname,
+                [sysname, nodename, release, version, machine, domainname]
+            ),
+            layout!(
+                "struct sysinfo",
+                sysinfo,
+                [
+                    uptime, loads, totalram, freeram, sharedram, bufferram, totalswap, freeswap,
+                    procs, totalhigh, freehigh, mem_unit,
+                ]
+            ),
+        ];
+
+        check_layouts("libc_compatible_structs", &headers, &layouts);
+    }
+
+    #[test]
+    fn test_timespec_from_duration() {
+        assert_eq!(
+            timespec::from(Duration::new(3, 500)),
+            timespec {
+                tv_sec: 3,
+                tv_nsec: 500
+            }
+        );
+
+        // saturates instead of wrapping to a negative `tv_sec`
+        let max = timespec {
+            tv_sec: i64::MAX,
+            tv_nsec: 999_999_999,
+        };
+        assert_eq!(timespec::from(Duration::MAX), max);
+        assert_eq!(
+            timespec::from(Duration::from_secs(i64::MAX as u64 + 1)),
+            max
+        );
+        assert_eq!(
+            timespec::from(Duration::new(i64::MAX as u64, 1)),
+            timespec {
+                tv_sec: i64::MAX,
+                tv_nsec: 1
+            }
+        );
+    }
+}